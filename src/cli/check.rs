@@ -0,0 +1,131 @@
+//! `flux9s check` — verify the Flux installation, not just connectivity
+//!
+//! Exit codes: 0 healthy, 1 a check failed, 2 warnings only, 3 the API server
+//! is unreachable.
+
+use anyhow::{Context, Result};
+use clap::{Args, ValueEnum};
+use std::path::Path;
+
+use super::cluster;
+use crate::kube::check::{CheckReport, CheckStatus, run_check};
+
+/// Output format for `flux9s check`
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CheckOutput {
+    /// Aligned plain-text table
+    #[default]
+    Table,
+    /// JSON document
+    Json,
+}
+
+/// Arguments for `flux9s check`
+#[derive(Args, Debug)]
+pub struct CheckArgs {
+    /// Namespace the Flux controllers run in (defaults to `defaultControllerNamespace`)
+    #[arg(long)]
+    pub controller_namespace: Option<String>,
+
+    /// Output format
+    #[arg(long, short = 'o', value_enum, default_value_t = CheckOutput::Table)]
+    pub output: CheckOutput,
+}
+
+/// Handle `flux9s check`. Exits with the report's exit code when it is not 0.
+pub async fn handle_check_command(args: CheckArgs, kubeconfig: Option<&Path>) -> Result<()> {
+    let config = cluster::load_config();
+    let controller_namespace = args
+        .controller_namespace
+        .unwrap_or_else(|| config.default_controller_namespace.clone());
+    let timeout = std::time::Duration::from_secs(config.connect_timeout_seconds);
+
+    let client = cluster::create_client(kubeconfig).await?;
+    let mut report = run_check(&client, &controller_namespace, timeout).await;
+    report.context = match kubeconfig {
+        Some(path) => crate::kube::get_context_from_kubeconfig_path(path).ok(),
+        None => crate::kube::get_context().await.ok(),
+    };
+
+    match args.output {
+        CheckOutput::Table => print!("{}", render_report(&report)),
+        CheckOutput::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&report).context("Failed to serialize check report")?
+        ),
+    }
+
+    let code = report.exit_code();
+    if code != 0 {
+        std::process::exit(code);
+    }
+    Ok(())
+}
+
+/// Table of checks followed by a one-line summary.
+fn render_report(report: &CheckReport) -> String {
+    let rows: Vec<Vec<String>> = report
+        .checks
+        .iter()
+        .map(|check| {
+            vec![
+                check.category.clone(),
+                check.name.clone(),
+                check.status.as_str().to_string(),
+                check.message.clone(),
+            ]
+        })
+        .collect();
+    let mut out = cluster::render_table(&["CATEGORY", "NAME", "STATUS", "MESSAGE"], &rows);
+
+    let count = |status| report.checks.iter().filter(|c| c.status == status).count();
+    out.push_str(&format!(
+        "\nContext: {}  Flux: {}\n",
+        report.context.as_deref().unwrap_or("unknown"),
+        report
+            .distribution_version
+            .as_deref()
+            .or(report.flux_version.as_deref())
+            .unwrap_or("unknown"),
+    ));
+    out.push_str(&format!(
+        "{} failed, {} warning(s), {} passed\n",
+        count(CheckStatus::Failed),
+        count(CheckStatus::Warning),
+        count(CheckStatus::Ok),
+    ));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kube::check::CheckItem;
+
+    #[test]
+    fn report_ends_with_summary() {
+        let report = CheckReport {
+            context: Some("kind-flux".to_string()),
+            flux_version: Some("v2.7.5".to_string()),
+            distribution_version: None,
+            checks: vec![
+                CheckItem {
+                    category: "controller".to_string(),
+                    name: "source-controller".to_string(),
+                    status: CheckStatus::Ok,
+                    message: "1/1 replicas ready".to_string(),
+                },
+                CheckItem {
+                    category: "crd".to_string(),
+                    name: "HelmRelease".to_string(),
+                    status: CheckStatus::Warning,
+                    message: "deprecated API version(s) still stored: v2beta1".to_string(),
+                },
+            ],
+        };
+        let out = render_report(&report);
+        assert!(out.starts_with("CATEGORY"));
+        assert!(out.contains("Context: kind-flux  Flux: v2.7.5"));
+        assert!(out.ends_with("0 failed, 1 warning(s), 1 passed\n"));
+    }
+}
@@ -0,0 +1,414 @@
+//! Shared plumbing for the cluster-facing subcommands (`get`, `suspend`, ...)
+//!
+//! Connects a headless [`ClusterSession`], resolves kind arguments, and
+//! renders plain-text tables — everything a non-interactive command needs
+//! without touching ratatui.
+
+use anyhow::{Context, Result};
+use clap::{Args, ValueEnum};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::config::ConfigLoader;
+use crate::config::schema::Config;
+use crate::models::FluxResourceKind;
+use crate::services::ClusterSession;
+
+/// How long to wait for the watchers' initial list before printing whatever
+/// has arrived. Generous: large clusters can take a while to list every kind.
+pub const INITIAL_SYNC_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Namespace scope flags shared by cluster subcommands
+#[derive(Args, Debug, Clone, Default)]
+pub struct NamespaceArgs {
+    /// Namespace to query (defaults to `defaultNamespace` from config)
+    #[arg(long, short = 'n', conflicts_with = "all_namespaces")]
+    pub namespace: Option<String>,
+
+    /// Query all namespaces
+    #[arg(long = "all-namespaces", short = 'A')]
+    pub all_namespaces: bool,
+}
+
+/// Output format for commands that print resources
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Aligned plain-text table
+    #[default]
+    Table,
+    /// JSON document
+    Json,
+    /// YAML document
+    Yaml,
+}
+
+/// Load the configuration, falling back to the defaults (with a warning on
+/// stderr) when it cannot be read.
+pub fn load_config() -> Config {
+    ConfigLoader::load(None, None).unwrap_or_else(|e| {
+        eprintln!("warning: failed to load config, using defaults: {}", e);
+        ConfigLoader::load_defaults()
+    })
+}
+
+/// Connect a headless session scoped by the namespace flags.
+///
+/// `-A` watches every namespace, `-n` a single one; with neither the
+/// configured `defaultNamespace` applies, exactly as in the TUI.
+pub async fn connect_session(
+    kubeconfig: Option<&Path>,
+    namespaces: &NamespaceArgs,
+    config: &Config,
+) -> Result<ClusterSession> {
+    let session = if namespaces.all_namespaces {
+        ClusterSession::connect_in_namespace(kubeconfig, None, config).await?
+    } else if let Some(ns) = namespaces.namespace.clone() {
+        ClusterSession::connect_in_namespace(kubeconfig, Some(ns), config).await?
+    } else {
+        match kubeconfig {
+            Some(path) => ClusterSession::connect_from_kubeconfig(path, config).await?,
+            None => ClusterSession::connect_default(config).await?,
+        }
+    };
+    Ok(session)
+}
+
+/// Connect and wait for the initial list, warning on stderr about any kind
+/// that did not finish syncing in time.
+pub async fn connect_synced_session(
+    kubeconfig: Option<&Path>,
+    namespaces: &NamespaceArgs,
+    config: &Config,
+) -> Result<ClusterSession> {
+    let mut session = connect_session(kubeconfig, namespaces, config)
+        .await
+        .context("Failed to connect to the cluster")?;
+    let pending = session.wait_for_initial_sync(INITIAL_SYNC_TIMEOUT).await;
+    if !pending.is_empty() {
+        eprintln!(
+            "warning: initial sync did not complete for: {} (results may be incomplete)",
+            pending.join(", ")
+        );
+    }
+    Ok(session)
+}
+
+/// Create a Kubernetes client from `--kubeconfig` or the default kubeconfig.
+pub async fn create_client(kubeconfig: Option<&Path>) -> Result<kube::Client> {
+    match kubeconfig {
+        Some(path) => crate::kube::create_client_from_kubeconfig_path(path).await,
+        None => crate::kube::create_client().await,
+    }
+    .context("Failed to create Kubernetes client")
+}
+
+/// Resolve the namespace scope the flags select: `None` means all namespaces.
+///
+/// Without `-n`/`-A` this follows `defaultNamespace` the same way
+/// [`ClusterSession::connect_default`] does.
+pub async fn resolve_namespace(namespaces: &NamespaceArgs, config: &Config) -> Option<String> {
+    if namespaces.all_namespaces {
+        None
+    } else if let Some(ns) = &namespaces.namespace {
+        Some(ns.clone())
+    } else if config.default_namespace.is_empty()
+        || config.default_namespace == "all"
+        || config.default_namespace == "-A"
+    {
+        crate::kube::get_default_namespace().await
+    } else {
+        Some(config.default_namespace.clone())
+    }
+}
+
+/// A single resource a command acts on or waits for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceTarget {
+    pub kind: FluxResourceKind,
+    pub namespace: String,
+    pub name: String,
+}
+
+impl ResourceTarget {
+    /// The state key (`kind:namespace:name`) this target is tracked under.
+    pub fn key(&self) -> String {
+        crate::watcher::resource_key(&self.namespace, &self.name, self.kind.as_str())
+    }
+}
+
+impl std::fmt::Display for ResourceTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}/{} ({})",
+            self.kind.as_str(),
+            self.name,
+            self.namespace
+        )
+    }
+}
+
+/// Expand positional targets into concrete resources.
+///
+/// `<kind>/<name>` needs a single namespace; a bare `<kind>` lists matching
+/// resources (in one namespace, or all with `-A`) and requires `selector`
+/// so a typo can't turn into "every Kustomization in the cluster".
+pub async fn resolve_targets(
+    client: &kube::Client,
+    raw_targets: &[String],
+    selector: Option<&str>,
+    namespace: Option<&str>,
+) -> Result<Vec<ResourceTarget>> {
+    let mut targets = Vec::new();
+    for raw in raw_targets {
+        let (kind, name) = parse_target(raw)?;
+        match (name, selector) {
+            (Some(_), Some(_)) => {
+                return Err(anyhow::anyhow!(
+                    "'{}' names a resource; use a bare kind with --selector",
+                    raw
+                ));
+            }
+            (Some(name), None) => {
+                let namespace = namespace.ok_or_else(|| {
+                    anyhow::anyhow!("'{}' needs a namespace: pass -n instead of -A", raw)
+                })?;
+                targets.push(ResourceTarget {
+                    kind,
+                    namespace: namespace.to_string(),
+                    name,
+                });
+            }
+            (None, Some(selector)) => {
+                let objects =
+                    crate::kube::fetch::list_resources(client, kind, namespace, Some(selector))
+                        .await?;
+                targets.extend(
+                    objects
+                        .iter()
+                        .filter_map(|obj| target_from_object(kind, obj)),
+                );
+            }
+            (None, None) => {
+                return Err(anyhow::anyhow!(
+                    "'{}' is a bare kind: give <kind>/<name> or add --selector",
+                    raw
+                ));
+            }
+        }
+    }
+    Ok(dedup_targets(targets))
+}
+
+/// Drop repeated targets, keeping the first occurrence of each in order.
+fn dedup_targets(targets: Vec<ResourceTarget>) -> Vec<ResourceTarget> {
+    let mut seen = std::collections::HashSet::new();
+    targets
+        .into_iter()
+        .filter(|target| seen.insert(target.key()))
+        .collect()
+}
+
+fn target_from_object(kind: FluxResourceKind, obj: &serde_json::Value) -> Option<ResourceTarget> {
+    let metadata = obj.get("metadata")?;
+    Some(ResourceTarget {
+        kind,
+        namespace: metadata.get("namespace")?.as_str()?.to_string(),
+        name: metadata.get("name")?.as_str()?.to_string(),
+    })
+}
+
+/// Split a `<kind>/<name>` target; a bare `<kind>` yields no name.
+pub fn parse_target(target: &str) -> Result<(FluxResourceKind, Option<String>)> {
+    match target.split_once('/') {
+        Some((kind, name)) => {
+            if name.is_empty() || name.contains('/') {
+                return Err(anyhow::anyhow!(
+                    "Invalid target '{}': expected <kind>/<name>",
+                    target
+                ));
+            }
+            Ok((parse_kind(kind)?, Some(name.to_string())))
+        }
+        None => Ok((parse_kind(target)?, None)),
+    }
+}
+
+/// Parse a target that must name a single resource (`<kind>/<name>`).
+pub fn parse_named_target(target: &str) -> Result<(FluxResourceKind, String)> {
+    match parse_target(target)? {
+        (kind, Some(name)) => Ok((kind, name)),
+        (_, None) => Err(anyhow::anyhow!(
+            "Invalid target '{}': expected <kind>/<name>",
+            target
+        )),
+    }
+}
+
+/// Resolve a kind argument — display name, plural, or short alias (`ks`, `hr`).
+pub fn parse_kind(kind: &str) -> Result<FluxResourceKind> {
+    FluxResourceKind::from_str_case_insensitive(kind).ok_or_else(|| {
+        let valid: Vec<_> = FluxResourceKind::all().iter().map(|k| k.as_str()).collect();
+        anyhow::anyhow!(
+            "Unknown resource kind '{}'. Valid kinds: {}",
+            kind,
+            valid.join(", ")
+        )
+    })
+}
+
+/// Text form of the STATUS column — the TUI's no-icons variant.
+pub fn status_text(ready: Option<bool>, suspended: Option<bool>) -> &'static str {
+    match (ready, suspended) {
+        (Some(true), Some(false)) => "OK",
+        (Some(true), Some(true)) | (None, Some(true)) => "PAUSED",
+        (Some(false), _) => "ERR",
+        _ => "?",
+    }
+}
+
+/// Render `True`/`False`/`?` for an optional condition, as the list view does.
+pub fn bool_text(value: Option<bool>) -> &'static str {
+    match value {
+        Some(true) => "True",
+        Some(false) => "False",
+        None => "?",
+    }
+}
+
+/// Render rows as a left-aligned table separated by three spaces (kubectl style).
+///
+/// The last column is not padded so long messages don't leave trailing spaces.
+pub fn render_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            if let Some(width) = widths.get_mut(i) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+    }
+
+    let render_line = |cells: Vec<&str>| -> String {
+        let last = cells.len().saturating_sub(1);
+        let mut line = String::new();
+        for (i, cell) in cells.into_iter().enumerate() {
+            if i == last {
+                line.push_str(cell);
+            } else {
+                let pad = widths[i].saturating_sub(cell.chars().count());
+                line.push_str(cell);
+                line.push_str(&" ".repeat(pad + 3));
+            }
+        }
+        line.trim_end().to_string()
+    };
+
+    let mut out = render_line(header.to_vec());
+    out.push('\n');
+    for row in rows {
+        out.push_str(&render_line(row.iter().map(String::as_str).collect()));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_kind_accepts_aliases_and_rejects_unknown() {
+        assert_eq!(parse_kind("hr").unwrap(), FluxResourceKind::HelmRelease);
+        assert_eq!(
+            parse_kind("Kustomizations").unwrap(),
+            FluxResourceKind::Kustomization
+        );
+        let err = parse_kind("deployment").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Unknown resource kind 'deployment'")
+        );
+    }
+
+    #[test]
+    fn parse_target_splits_kind_and_name() {
+        let (kind, name) = parse_target("ks/apps").unwrap();
+        assert_eq!(kind, FluxResourceKind::Kustomization);
+        assert_eq!(name.as_deref(), Some("apps"));
+
+        let (kind, name) = parse_target("HelmRelease").unwrap();
+        assert_eq!(kind, FluxResourceKind::HelmRelease);
+        assert!(name.is_none());
+
+        assert!(parse_target("ks/").is_err());
+        assert!(parse_target("ks/a/b").is_err());
+        assert!(parse_named_target("ks").is_err());
+    }
+
+    #[test]
+    fn dedup_targets_drops_repeats_anywhere_in_order() {
+        let target = |kind, name: &str| ResourceTarget {
+            kind,
+            namespace: "flux-system".to_string(),
+            name: name.to_string(),
+        };
+        let deduped = dedup_targets(vec![
+            target(FluxResourceKind::Kustomization, "a"),
+            target(FluxResourceKind::HelmRelease, "b"),
+            target(FluxResourceKind::Kustomization, "a"),
+        ]);
+        assert_eq!(
+            deduped,
+            vec![
+                target(FluxResourceKind::Kustomization, "a"),
+                target(FluxResourceKind::HelmRelease, "b"),
+            ]
+        );
+    }
+
+    #[test]
+    fn target_from_listed_object() {
+        let obj = serde_json::json!({"metadata": {"name": "apps", "namespace": "flux-system"}});
+        let target = target_from_object(FluxResourceKind::Kustomization, &obj).unwrap();
+        assert_eq!(
+            target,
+            ResourceTarget {
+                kind: FluxResourceKind::Kustomization,
+                namespace: "flux-system".to_string(),
+                name: "apps".to_string(),
+            }
+        );
+        assert_eq!(target.key(), "Kustomization:flux-system:apps");
+        assert_eq!(target.to_string(), "Kustomization/apps (flux-system)");
+        assert!(
+            target_from_object(FluxResourceKind::Kustomization, &serde_json::json!({})).is_none()
+        );
+    }
+
+    #[test]
+    fn status_text_matches_no_icons_column() {
+        assert_eq!(status_text(Some(true), Some(false)), "OK");
+        assert_eq!(status_text(Some(true), Some(true)), "PAUSED");
+        assert_eq!(status_text(None, Some(true)), "PAUSED");
+        assert_eq!(status_text(Some(false), Some(false)), "ERR");
+        assert_eq!(status_text(None, None), "?");
+    }
+
+    #[test]
+    fn render_table_aligns_columns() {
+        let rows = vec![
+            vec!["flux-system".to_string(), "a".to_string(), "x".to_string()],
+            vec![
+                "apps".to_string(),
+                "longer-name".to_string(),
+                "y".to_string(),
+            ],
+        ];
+        let table = render_table(&["NAMESPACE", "NAME", "MESSAGE"], &rows);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], "NAMESPACE     NAME          MESSAGE");
+        assert_eq!(lines[1], "flux-system   a             x");
+        assert_eq!(lines[2], "apps          longer-name   y");
+    }
+}
@@ -131,7 +131,16 @@ pub async fn handle_config_command(cmd: ConfigSubcommand) -> Result<()> {
 
             // Validate by actually loading and parsing the config
             // This will catch YAML syntax errors, invalid types, etc.
-            match ConfigLoader::validate(cluster, context) {
+            // The keymap and aliases are checked here as the TUI would build
+            // them, catching unknown actions, keys bound twice and aliases
+            // that shadow built-in commands.
+            let result = ConfigLoader::validate(cluster, context).and_then(|_| {
+                let config = ConfigLoader::load(cluster, context)?;
+                crate::tui::keybindings::Keymap::from_config(&config.keymap)?
+                    .with_plugins(&config.plugins)?;
+                crate::tui::commands::validate_aliases(&config.aliases)
+            });
+            match result {
                 Ok(_) => {
                     println!("flux9s configuration is valid");
                 }
@@ -378,6 +387,7 @@ fn reference_text() -> String {
         "defaultResourceFilter - Resource type filter at startup, e.g. \"Kustomization\" (default: none, shows all)",
         "connectTimeoutSeconds - Startup Kubernetes API health-check timeout in seconds (default: 10)",
         "editor - Editor command for resource editing; falls back through $VISUAL, $EDITOR, vi (default: none)",
+        "historyRetentionDays - Days of observed reconciliation history kept on disk per context, 0 = memory only (default: 30)",
         "ui.enableMouse - Enable mouse support (default: false)",
         "ui.headless - Hide header (default: false)",
         "ui.noIcons - Disable Unicode icons (default: false)",
@@ -389,6 +399,13 @@ fn reference_text() -> String {
         "contextSkins - Map of context name to skin name (default: empty)",
         "cluster - Map of cluster name to cluster-specific settings (default: empty)",
         "favorites - List of favorited resource keys, e.g. \"Kustomization:flux-system:my-app\" (default: empty)",
+        "hooks - Commands run on status changes: [{on: becameNotReady|becameReady|suspended|resumed|revisionChanged, kinds, namespaces, command}] (default: empty)",
+        "views - Named list views recalled with :view <name>: {name: {resourceType, query, health: healthy|unhealthy, sort: name|age|type|status|<column>, sortReverse, namespace, hotkey: F1-F12}} (default: empty)",
+        "columns - Per-kind list columns: {Kind: [{name, path: JSONPath, hide}]}; naming a built-in column reorders (default: empty)",
+        "keymap - Action to key overrides, e.g. {describe: i, delete: ctrl+x}; conflicts fail `config validate` (default: empty)",
+        "plugins - Commands on the selected resource: {name: {shortcut, description, scopes: [kind|view], command, args, background}}; $NAME $NAMESPACE $KIND $CONTEXT $REVISION are replaced (default: empty)",
+        "aliases - `:` command aliases, e.g. {prod: \"ctx prod-eu-1\", broken: \"hr status:failed\"} (default: empty)",
+        "clusters - Kubeconfig contexts shown together by `:clusters` without arguments (default: empty)",
     ] {
         s.push_str(&format!("#   {line}\n"));
     }
@@ -0,0 +1,298 @@
+//! `flux9s get` — print a snapshot of Flux resources and exit
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use clap::Args;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use super::cluster::{self, NamespaceArgs, OutputFormat};
+use crate::models::FluxResourceKind;
+use crate::watcher::{ResourceInfo, resource_key};
+
+/// Columns of the all-kinds table, matching the TUI's unified list view
+const UNIFIED_COLUMNS: &[&str] = &[
+    "STATUS",
+    "NAMESPACE",
+    "NAME",
+    "TYPE",
+    "SUSPENDED",
+    "READY",
+    "AGE",
+    "MESSAGE",
+];
+
+/// Arguments for `flux9s get`
+#[derive(Args, Debug)]
+pub struct GetArgs {
+    /// Resource kind (e.g. Kustomization, ks, hr); omit to list every kind
+    pub kind: Option<String>,
+
+    #[command(flatten)]
+    pub namespaces: NamespaceArgs,
+
+    /// Output format
+    #[arg(long, short = 'o', value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+}
+
+/// One resource as printed by `get -o json|yaml`
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceRecord {
+    pub kind: String,
+    pub namespace: String,
+    pub name: String,
+    pub ready: Option<bool>,
+    pub suspended: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_reconciled: Option<DateTime<Utc>>,
+    /// Per-kind columns from `FluxResourceKind::extract_fields`, keyed by
+    /// lower-cased column name (`url`, `chart`, ...)
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, String>,
+}
+
+impl ResourceRecord {
+    /// Build a record from tracked state plus the full object (for per-kind fields).
+    pub fn new(info: &ResourceInfo, obj: Option<&serde_json::Value>) -> Self {
+        let fields = match (FluxResourceKind::parse_optional(&info.resource_type), obj) {
+            (Some(kind), Some(obj)) => kind
+                .extract_fields(obj)
+                .into_iter()
+                .map(|(k, v)| (k.to_lowercase(), v))
+                .collect(),
+            _ => BTreeMap::new(),
+        };
+        Self {
+            kind: info.resource_type.clone(),
+            namespace: info.namespace.clone(),
+            name: info.name.clone(),
+            ready: info.ready,
+            suspended: info.suspended,
+            revision: info.revision.clone(),
+            message: info.message.clone(),
+            created: info.age,
+            last_reconciled: info.last_reconciled,
+            fields,
+        }
+    }
+
+    /// Cell text for a column, mirroring the TUI list view's rendering.
+    fn cell(&self, column: &str) -> String {
+        match column {
+            "STATUS" => cluster::status_text(self.ready, self.suspended).to_string(),
+            "NAMESPACE" => self.namespace.clone(),
+            "NAME" => self.name.clone(),
+            "TYPE" => self
+                .fields
+                .get("type")
+                .cloned()
+                .unwrap_or_else(|| self.kind.clone()),
+            "SUSPENDED" => cluster::bool_text(self.suspended).to_string(),
+            "READY" => cluster::bool_text(self.ready).to_string(),
+            "REVISION" => self.revision.clone().unwrap_or_else(|| "-".to_string()),
+            "AGE" => crate::tui::views::format_age(self.created),
+            // Keep each row on one line; the full message is in -o json/yaml
+            "MESSAGE" => self
+                .message
+                .as_deref()
+                .map(|m| m.replace('\n', " "))
+                .unwrap_or_else(|| "-".to_string()),
+            other => self
+                .fields
+                .get(&other.to_lowercase())
+                .cloned()
+                .unwrap_or_else(|| "-".to_string()),
+        }
+    }
+}
+
+/// Handle `flux9s get`
+pub async fn handle_get_command(args: GetArgs, kubeconfig: Option<&Path>) -> Result<()> {
+    let kind = args.kind.as_deref().map(cluster::parse_kind).transpose()?;
+    let config = cluster::load_config();
+
+    let session = cluster::connect_synced_session(kubeconfig, &args.namespaces, &config).await?;
+
+    let resources = match kind {
+        Some(kind) => session.state().by_type(kind.as_str()),
+        None => session.snapshot(),
+    };
+    let records = collect_records(resources, |key| session.object(key));
+
+    print!("{}", render_records(&records, kind, args.output)?);
+    Ok(())
+}
+
+/// Build sorted records (kind, namespace, name) from a state snapshot.
+pub fn collect_records(
+    resources: Vec<ResourceInfo>,
+    object: impl Fn(&str) -> Option<serde_json::Value>,
+) -> Vec<ResourceRecord> {
+    let mut records: Vec<ResourceRecord> = resources
+        .iter()
+        .map(|info| {
+            let obj = object(&resource_key(
+                &info.namespace,
+                &info.name,
+                &info.resource_type,
+            ));
+            ResourceRecord::new(info, obj.as_ref())
+        })
+        .collect();
+    records.sort_by(|a, b| (&a.kind, &a.namespace, &a.name).cmp(&(&b.kind, &b.namespace, &b.name)));
+    records
+}
+
+/// Render records in the requested format. A single kind gets that kind's
+/// columns (plus AGE, as in the TUI); otherwise the unified columns are used.
+pub fn render_records(
+    records: &[ResourceRecord],
+    kind: Option<FluxResourceKind>,
+    output: OutputFormat,
+) -> Result<String> {
+    match output {
+        OutputFormat::Json => serde_json::to_string_pretty(records)
+            .map(|s| s + "\n")
+            .context("Failed to serialize resources to JSON"),
+        OutputFormat::Yaml => {
+            serde_yaml::to_string(records).context("Failed to serialize resources to YAML")
+        }
+        OutputFormat::Table => {
+            if records.is_empty() {
+                return Ok("No resources found.\n".to_string());
+            }
+            let columns: Vec<&str> = match kind {
+                Some(kind) => {
+                    let mut columns = kind.columns();
+                    columns.push("AGE");
+                    columns
+                }
+                None => UNIFIED_COLUMNS.to_vec(),
+            };
+            let rows: Vec<Vec<String>> = records
+                .iter()
+                .map(|record| columns.iter().map(|col| record.cell(col)).collect())
+                .collect();
+            Ok(cluster::render_table(&columns, &rows))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn info(resource_type: &str, ns: &str, name: &str, ready: Option<bool>) -> ResourceInfo {
+        ResourceInfo {
+            name: name.to_string(),
+            namespace: ns.to_string(),
+            resource_type: resource_type.to_string(),
+            age: None,
+            suspended: Some(false),
+            ready,
+            message: Some("Applied revision: main@sha1:abc".to_string()),
+            revision: Some("main@sha1:abc".to_string()),
+            labels: HashMap::new(),
+            annotations: HashMap::new(),
+            last_reconciled: None,
+            reconciliation_history: Vec::new(),
+        }
+    }
+
+    fn git_repo_object() -> serde_json::Value {
+        serde_json::json!({
+            "spec": {"url": "https://github.com/org/repo", "ref": {"branch": "main"}}
+        })
+    }
+
+    #[test]
+    fn records_are_sorted_and_carry_kind_fields() {
+        let resources = vec![
+            info("Kustomization", "flux-system", "b", Some(true)),
+            info("GitRepository", "flux-system", "repo", Some(true)),
+            info("Kustomization", "apps", "a", Some(false)),
+        ];
+        let records = collect_records(resources, |key| {
+            (key == "GitRepository:flux-system:repo").then(git_repo_object)
+        });
+
+        let order: Vec<_> = records.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(order, vec!["repo", "a", "b"]);
+        assert_eq!(
+            records[0].fields.get("url").map(String::as_str),
+            Some("https://github.com/org/repo")
+        );
+        assert!(records[1].fields.is_empty());
+    }
+
+    #[test]
+    fn table_uses_kind_columns_plus_age() {
+        let records = collect_records(
+            vec![info("GitRepository", "flux-system", "repo", Some(true))],
+            |_| Some(git_repo_object()),
+        );
+        let table = render_records(
+            &records,
+            Some(FluxResourceKind::GitRepository),
+            OutputFormat::Table,
+        )
+        .unwrap();
+
+        let mut lines = table.lines();
+        let header: Vec<_> = lines.next().unwrap().split_whitespace().collect();
+        let mut expected = FluxResourceKind::GitRepository.columns();
+        expected.push("AGE");
+        assert_eq!(header, expected);
+        let row = lines.next().unwrap();
+        assert!(row.starts_with("OK"));
+        assert!(row.contains("https://github.com/org/repo"));
+        assert!(row.contains("main@sha1:abc"));
+    }
+
+    #[test]
+    fn table_without_kind_uses_unified_columns() {
+        let records = collect_records(
+            vec![info("Kustomization", "apps", "a", Some(false))],
+            |_| None,
+        );
+        let table = render_records(&records, None, OutputFormat::Table).unwrap();
+        let header: Vec<_> = table.lines().next().unwrap().split_whitespace().collect();
+        assert_eq!(header, UNIFIED_COLUMNS);
+        assert!(table.lines().nth(1).unwrap().starts_with("ERR"));
+    }
+
+    #[test]
+    fn empty_table_says_so() {
+        let table = render_records(&[], None, OutputFormat::Table).unwrap();
+        assert_eq!(table, "No resources found.\n");
+    }
+
+    #[test]
+    fn json_and_yaml_serialize_records() {
+        let records = collect_records(
+            vec![info("GitRepository", "flux-system", "repo", Some(true))],
+            |_| Some(git_repo_object()),
+        );
+
+        let json = render_records(&records, None, OutputFormat::Json).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed[0]["kind"], "GitRepository");
+        assert_eq!(parsed[0]["ready"], true);
+        assert_eq!(parsed[0]["fields"]["url"], "https://github.com/org/repo");
+        assert!(parsed[0].get("lastReconciled").is_none());
+
+        let yaml = render_records(&records, None, OutputFormat::Yaml).unwrap();
+        let parsed: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed[0]["name"].as_str(), Some("repo"));
+    }
+}
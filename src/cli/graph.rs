@@ -0,0 +1,73 @@
+//! `flux9s graph` — export the resource graph as DOT, Mermaid, or JSON
+
+use anyhow::{Context, Result};
+use clap::Args;
+use std::path::{Path, PathBuf};
+
+use super::cluster::{self, NamespaceArgs};
+use crate::models::FluxResourceKind;
+use crate::trace::GraphFormat;
+
+/// Arguments for `flux9s graph`
+#[derive(Args, Debug)]
+pub struct GraphArgs {
+    /// Resource to graph as <kind>/<name> (e.g. ks/apps, hr/podinfo)
+    #[arg(value_name = "KIND/NAME")]
+    pub target: String,
+
+    #[command(flatten)]
+    pub namespaces: NamespaceArgs,
+
+    /// Output format: dot, mermaid, or json
+    #[arg(long, short = 'o', alias = "format", default_value_t = GraphFormat::Dot)]
+    pub output: GraphFormat,
+
+    /// Write to this file instead of stdout
+    #[arg(long, value_name = "FILE")]
+    pub out_file: Option<PathBuf>,
+}
+
+/// Handle `flux9s graph`
+pub async fn handle_graph_command(args: GraphArgs, kubeconfig: Option<&Path>) -> Result<()> {
+    let (kind, name) = cluster::parse_named_target(&args.target)?;
+    if !kind.supports_graph() {
+        let supported: Vec<_> = FluxResourceKind::all()
+            .iter()
+            .filter(|k| k.supports_graph())
+            .map(|k| k.as_str())
+            .collect();
+        return Err(anyhow::anyhow!(
+            "{} has no resource graph. Supported kinds: {}",
+            kind.as_str(),
+            supported.join(", ")
+        ));
+    }
+    let config = cluster::load_config();
+
+    let namespace = cluster::resolve_namespace(&args.namespaces, &config)
+        .await
+        .ok_or_else(|| anyhow::anyhow!("graph needs a namespace: pass -n instead of -A"))?;
+    let client = cluster::create_client(kubeconfig).await?;
+
+    let graph = crate::trace::build_resource_graph(&client, kind.as_str(), &namespace, &name)
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to build graph for {}/{} in {}",
+                kind.as_str(),
+                name,
+                namespace
+            )
+        })?;
+    let rendered = crate::trace::export_graph(&graph, args.output)?;
+
+    match args.out_file {
+        Some(path) => {
+            std::fs::write(&path, rendered)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            eprintln!("Wrote {}", path.display());
+        }
+        None => print!("{}", rendered),
+    }
+    Ok(())
+}
@@ -2,10 +2,30 @@
 //!
 //! Handles all CLI subcommands and argument parsing.
 
+mod check;
+mod cluster;
 mod config;
+mod get;
+mod graph;
 mod logging;
+mod operate;
+mod serve;
+mod trace;
 mod version;
+mod wait;
+mod watch;
 
+pub use check::{CheckArgs, CheckOutput, handle_check_command};
+pub use cluster::{NamespaceArgs, OutputFormat};
 pub use config::{ConfigSubcommand, handle_config_command};
+pub use get::{GetArgs, handle_get_command};
+pub use graph::{GraphArgs, handle_graph_command};
 pub use logging::*;
+pub use operate::{
+    DeleteArgs, OperateArgs, OperationCommand, ReconcileArgs, handle_operation_command,
+};
+pub use serve::{ServeArgs, handle_serve_command};
+pub use trace::{TraceArgs, TraceOutput, handle_trace_command};
 pub use version::{check_for_updates_blocking, display_version};
+pub use wait::{WaitArgs, WaitCondition, handle_wait_command};
+pub use watch::{WatchArgs, WatchOutput, handle_watch_command};
@@ -0,0 +1,271 @@
+//! `flux9s suspend|resume|reconcile|delete` — run TUI operations from scripts
+//!
+//! Every command resolves to the same [`FluxOperation`] the TUI keybinding
+//! runs (looked up in the [`OperationRegistry`]), so runbooks and operators
+//! share one code path.
+
+use anyhow::Result;
+use clap::Args;
+use std::io::IsTerminal;
+use std::path::Path;
+
+use super::cluster::{self, NamespaceArgs, ResourceTarget};
+use crate::config::schema::Config;
+use crate::operations::{FluxOperation, OperationRegistry};
+
+/// Target and safety flags shared by the operation subcommands
+#[derive(Args, Debug)]
+pub struct OperateArgs {
+    /// Resources as <kind>/<name>, or a bare <kind> together with --selector
+    #[arg(required = true, value_name = "KIND/NAME")]
+    pub targets: Vec<String>,
+
+    #[command(flatten)]
+    pub namespaces: NamespaceArgs,
+
+    /// Label selector for bare-kind targets (e.g. team=payments,tier!=db)
+    #[arg(long, short = 'l')]
+    pub selector: Option<String>,
+
+    /// Allow the operation even when `readOnly` is enabled in config
+    #[arg(long)]
+    pub allow_write: bool,
+}
+
+/// Arguments for `flux9s reconcile`
+#[derive(Args, Debug)]
+pub struct ReconcileArgs {
+    #[command(flatten)]
+    pub common: OperateArgs,
+
+    /// Reconcile the source first (Kustomization and HelmRelease only)
+    #[arg(long)]
+    pub with_source: bool,
+}
+
+/// Arguments for `flux9s delete`
+#[derive(Args, Debug)]
+pub struct DeleteArgs {
+    #[command(flatten)]
+    pub common: OperateArgs,
+
+    /// Skip the confirmation prompt (required when stdin is not a terminal)
+    #[arg(long, short = 'y')]
+    pub yes: bool,
+}
+
+/// An operation subcommand, as dispatched from `main`
+#[derive(Debug)]
+pub enum OperationCommand {
+    Suspend(OperateArgs),
+    Resume(OperateArgs),
+    Reconcile(ReconcileArgs),
+    Delete(DeleteArgs),
+}
+
+impl OperationCommand {
+    /// The registry keybinding of the operation this command runs.
+    fn keybinding(&self) -> char {
+        match self {
+            OperationCommand::Suspend(_) => 's',
+            OperationCommand::Resume(_) => 'r',
+            OperationCommand::Reconcile(args) if args.with_source => 'W',
+            OperationCommand::Reconcile(_) => 'R',
+            OperationCommand::Delete(_) => 'd',
+        }
+    }
+
+    fn common(&self) -> &OperateArgs {
+        match self {
+            OperationCommand::Suspend(args) | OperationCommand::Resume(args) => args,
+            OperationCommand::Reconcile(args) => &args.common,
+            OperationCommand::Delete(args) => &args.common,
+        }
+    }
+}
+
+/// Handle the operation subcommands
+pub async fn handle_operation_command(
+    command: OperationCommand,
+    kubeconfig: Option<&Path>,
+) -> Result<()> {
+    let registry = OperationRegistry::new();
+    let operation = registry
+        .get_by_keybinding(command.keybinding())
+        .ok_or_else(|| anyhow::anyhow!("Operation is not registered"))?;
+    let args = command.common();
+
+    let config = cluster::load_config();
+    check_write_allowed(&config, args.allow_write, operation)?;
+
+    let client = cluster::create_client(kubeconfig).await?;
+    let namespace = cluster::resolve_namespace(&args.namespaces, &config).await;
+    let targets = cluster::resolve_targets(
+        &client,
+        &args.targets,
+        args.selector.as_deref(),
+        namespace.as_deref(),
+    )
+    .await?;
+    if targets.is_empty() {
+        println!("No resources matched.");
+        return Ok(());
+    }
+
+    for target in &targets {
+        if !operation.is_valid_for(target.kind.as_str()) {
+            return Err(anyhow::anyhow!(
+                "{} is not supported for {}",
+                operation.name(),
+                target.kind.as_str()
+            ));
+        }
+    }
+
+    if operation.requires_confirmation() {
+        let skip_prompt = matches!(&command, OperationCommand::Delete(args) if args.yes);
+        if !skip_prompt && !confirm(operation, &targets)? {
+            println!("Aborted.");
+            return Ok(());
+        }
+    }
+
+    let mut failures = 0;
+    for target in &targets {
+        match operation
+            .execute(
+                &client,
+                target.kind.as_str(),
+                &target.namespace,
+                &target.name,
+            )
+            .await
+        {
+            Ok(()) => println!("{}: {} succeeded", target, operation.name()),
+            Err(e) => {
+                failures += 1;
+                eprintln!("{}: {} failed: {:#}", target, operation.name(), e);
+            }
+        }
+    }
+
+    if failures > 0 {
+        return Err(anyhow::anyhow!(
+            "{} of {} {} operations failed",
+            failures,
+            targets.len(),
+            operation.name()
+        ));
+    }
+    Ok(())
+}
+
+/// Refuse write operations in readonly mode unless explicitly overridden.
+fn check_write_allowed(
+    config: &Config,
+    allow_write: bool,
+    operation: &dyn FluxOperation,
+) -> Result<()> {
+    if config.read_only && !allow_write {
+        return Err(anyhow::anyhow!(
+            "{} refused: readOnly is enabled. Pass --allow-write, or run `flux9s config set readOnly false`",
+            operation.name()
+        ));
+    }
+    Ok(())
+}
+
+/// Ask for a y/N confirmation on the terminal. Refuses (rather than assumes
+/// yes) when stdin is not interactive.
+fn confirm(operation: &dyn FluxOperation, targets: &[ResourceTarget]) -> Result<bool> {
+    if !std::io::stdin().is_terminal() {
+        return Err(anyhow::anyhow!(
+            "{} requires confirmation; pass --yes when running non-interactively",
+            operation.name()
+        ));
+    }
+    eprintln!("{}", confirmation_prompt(operation, targets));
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
+fn confirmation_prompt(operation: &dyn FluxOperation, targets: &[ResourceTarget]) -> String {
+    let mut prompt = String::new();
+    for target in targets {
+        prompt.push_str(&format!("  {}\n", target));
+    }
+    prompt.push_str(&format!(
+        "{} {} resource(s)? (y/N)",
+        operation.name(),
+        targets.len()
+    ));
+    prompt
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::FluxResourceKind;
+    use crate::operations::{DeleteOperation, SuspendOperation};
+
+    #[test]
+    fn keybindings_resolve_to_registered_operations() {
+        let registry = OperationRegistry::new();
+        let reconcile = |with_source| {
+            OperationCommand::Reconcile(ReconcileArgs {
+                common: OperateArgs {
+                    targets: vec!["ks/apps".to_string()],
+                    namespaces: NamespaceArgs::default(),
+                    selector: None,
+                    allow_write: false,
+                },
+                with_source,
+            })
+        };
+
+        let plain = registry.get_by_keybinding(reconcile(false).keybinding());
+        assert_eq!(plain.map(|op| op.name()), Some("Reconcile"));
+        let with_source = registry.get_by_keybinding(reconcile(true).keybinding());
+        assert_eq!(
+            with_source.map(|op| op.name()),
+            Some("Reconcile with Source")
+        );
+    }
+
+    #[test]
+    fn read_only_refuses_without_override() {
+        let config = Config::default();
+        assert!(config.read_only);
+
+        let err = check_write_allowed(&config, false, &SuspendOperation).unwrap_err();
+        assert!(err.to_string().contains("--allow-write"));
+        assert!(check_write_allowed(&config, true, &SuspendOperation).is_ok());
+
+        let writable = Config {
+            read_only: false,
+            ..Default::default()
+        };
+        assert!(check_write_allowed(&writable, false, &SuspendOperation).is_ok());
+    }
+
+    #[test]
+    fn confirmation_prompt_lists_every_target() {
+        let targets = vec![
+            ResourceTarget {
+                kind: FluxResourceKind::HelmRelease,
+                namespace: "apps".to_string(),
+                name: "podinfo".to_string(),
+            },
+            ResourceTarget {
+                kind: FluxResourceKind::Kustomization,
+                namespace: "flux-system".to_string(),
+                name: "infra".to_string(),
+            },
+        ];
+        let prompt = confirmation_prompt(&DeleteOperation, &targets);
+        assert!(prompt.contains("HelmRelease/podinfo (apps)"));
+        assert!(prompt.contains("Kustomization/infra (flux-system)"));
+        assert!(prompt.ends_with("Delete 2 resource(s)? (y/N)"));
+    }
+}
@@ -0,0 +1,221 @@
+//! `flux9s serve --metrics` — headless Prometheus exporter
+//!
+//! Keeps a [`ClusterSession`](crate::services::ClusterSession) running and
+//! answers scrapes from its state. The HTTP side is deliberately minimal
+//! (GET `/metrics` and `/healthz`, one request per connection): it only ever
+//! talks to a Prometheus scraper. Each connection is served by its own task,
+//! so a slow client never holds up the watch loop.
+
+use anyhow::{Context, Result};
+use clap::Args;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use super::cluster::{self, NamespaceArgs};
+use crate::metrics::{CONTENT_TYPE, MetricsCollector};
+use crate::watcher::ResourceState;
+
+/// How long a client gets to send its request line before being dropped
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+/// How long a client gets to read the response before being dropped
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Requests larger than this are rejected; scrapes are a few hundred bytes
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+
+/// Arguments for `flux9s serve`
+#[derive(Args, Debug)]
+pub struct ServeArgs {
+    /// Serve Prometheus metrics on this address (`:9090` listens on all interfaces)
+    #[arg(long, value_name = "ADDR", value_parser = parse_listen_addr)]
+    pub metrics: SocketAddr,
+
+    #[command(flatten)]
+    pub namespaces: NamespaceArgs,
+}
+
+/// Handle `flux9s serve`. Runs until interrupted.
+pub async fn handle_serve_command(args: ServeArgs, kubeconfig: Option<&Path>) -> Result<()> {
+    let config = cluster::load_config();
+    let mut session = cluster::connect_session(kubeconfig, &args.namespaces, &config)
+        .await
+        .context("Failed to connect to the cluster")?;
+    let listener = TcpListener::bind(args.metrics)
+        .await
+        .with_context(|| format!("Failed to listen on {}", args.metrics))?;
+    eprintln!(
+        "Serving metrics for context '{}' on http://{}/metrics",
+        session.context(),
+        args.metrics
+    );
+
+    // Connection tasks render from the same collector and state the watch
+    // loop updates
+    let collector = Arc::new(RwLock::new(MetricsCollector::new()));
+    loop {
+        tokio::select! {
+            event = session.recv_event() => {
+                let event = event.ok_or_else(|| anyhow::anyhow!("Watch stream closed"))?;
+                collector
+                    .write()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .observe(&event);
+                session.apply_event(event);
+            }
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    let collector = Arc::clone(&collector);
+                    let state = session.state().clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = respond(stream, &collector, &state).await {
+                            tracing::debug!("Metrics request failed: {:#}", e);
+                        }
+                    });
+                }
+                Err(e) => tracing::warn!("Failed to accept metrics connection: {}", e),
+            },
+        }
+    }
+}
+
+/// Read one request and write the response.
+async fn respond(
+    mut stream: TcpStream,
+    collector: &RwLock<MetricsCollector>,
+    state: &ResourceState,
+) -> Result<()> {
+    let request = tokio::time::timeout(REQUEST_TIMEOUT, read_request_head(&mut stream))
+        .await
+        .context("Timed out reading request")??;
+    let (status, content_type, body) = route(&request, || {
+        collector
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .render(state, chrono::Utc::now())
+    });
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    );
+    tokio::time::timeout(RESPONSE_TIMEOUT, async {
+        stream.write_all(response.as_bytes()).await?;
+        stream.shutdown().await
+    })
+    .await
+    .context("Timed out writing response")??;
+    Ok(())
+}
+
+/// Read up to the end of the request headers; the body (if any) is ignored.
+async fn read_request_head(stream: &mut TcpStream) -> Result<String> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 1024];
+    while !buf.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if buf.len() > MAX_REQUEST_BYTES {
+            return Err(anyhow::anyhow!("Request too large"));
+        }
+    }
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Status line, content type and body for a request.
+fn route(request: &str, metrics: impl FnOnce() -> String) -> (&'static str, &'static str, String) {
+    let mut parts = request.split_whitespace();
+    let (method, target) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+    let path = target.split('?').next().unwrap_or("");
+    match (method, path) {
+        ("GET", "/metrics") => ("200 OK", CONTENT_TYPE, metrics()),
+        ("GET", "/healthz") => ("200 OK", "text/plain", "ok\n".to_string()),
+        ("GET", _) => ("404 Not Found", "text/plain", "not found\n".to_string()),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain",
+            "method not allowed\n".to_string(),
+        ),
+    }
+}
+
+/// Parse a listen address; a bare `:port` listens on all interfaces.
+fn parse_listen_addr(s: &str) -> Result<SocketAddr> {
+    let addr = match s.strip_prefix(':') {
+        Some(port) => format!("0.0.0.0:{}", port),
+        None => s.to_string(),
+    };
+    addr.parse()
+        .with_context(|| format!("Invalid listen address '{}': expected [HOST]:PORT", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn listen_addr_accepts_bare_port() {
+        assert_eq!(
+            parse_listen_addr(":9090").unwrap(),
+            "0.0.0.0:9090".parse::<SocketAddr>().unwrap()
+        );
+        assert_eq!(
+            parse_listen_addr("127.0.0.1:9090").unwrap(),
+            "127.0.0.1:9090".parse::<SocketAddr>().unwrap()
+        );
+        assert!(parse_listen_addr("9090").is_err());
+    }
+
+    #[test]
+    fn routes_metrics_and_health() {
+        let metrics = || "flux9s_resources 1\n".to_string();
+        let (status, content_type, body) =
+            route("GET /metrics HTTP/1.1\r\nHost: x\r\n\r\n", metrics);
+        assert_eq!((status, content_type), ("200 OK", CONTENT_TYPE));
+        assert_eq!(body, "flux9s_resources 1\n");
+
+        assert_eq!(route("GET /healthz HTTP/1.1\r\n\r\n", metrics).0, "200 OK");
+        assert_eq!(route("GET / HTTP/1.1\r\n\r\n", metrics).0, "404 Not Found");
+        assert_eq!(
+            route("POST /metrics HTTP/1.1\r\n\r\n", metrics).0,
+            "405 Method Not Allowed"
+        );
+    }
+
+    #[tokio::test]
+    async fn stalled_client_does_not_block_other_connections() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let collector = Arc::new(RwLock::new(MetricsCollector::new()));
+        let state = ResourceState::new();
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let (collector, state) = (Arc::clone(&collector), state.clone());
+                tokio::spawn(async move { respond(stream, &collector, &state).await });
+            }
+        });
+
+        // Connects but never sends its request
+        let _stalled = TcpStream::connect(addr).await.unwrap();
+        let mut client = TcpStream::connect(addr).await.unwrap();
+        client
+            .write_all(b"GET /healthz HTTP/1.1\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        tokio::time::timeout(Duration::from_secs(2), client.read_to_string(&mut response))
+            .await
+            .expect("served while the other client stalls")
+            .unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with("ok\n"));
+    }
+}
@@ -0,0 +1,135 @@
+//! `flux9s trace` — print the Flux chain managing an object, like `flux trace`
+
+use anyhow::{Context, Result};
+use clap::{Args, ValueEnum};
+use std::path::Path;
+
+use super::cluster::{self, NamespaceArgs};
+use crate::models::FluxResourceKind;
+use crate::trace::TraceResult;
+
+/// Kubernetes built-in kinds `trace_object` knows how to fetch without discovery
+const BUILTIN_KINDS: &[&str] = &[
+    "Deployment",
+    "StatefulSet",
+    "DaemonSet",
+    "ReplicaSet",
+    "Service",
+    "ConfigMap",
+    "Secret",
+    "Pod",
+    "Namespace",
+    "ServiceAccount",
+    "Ingress",
+    "NetworkPolicy",
+];
+
+/// Output format for `flux9s trace`
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TraceOutput {
+    /// Human-readable chain, as printed by `flux trace`
+    #[default]
+    Text,
+    /// JSON document
+    Json,
+    /// YAML document
+    Yaml,
+}
+
+/// Arguments for `flux9s trace`
+#[derive(Args, Debug)]
+pub struct TraceArgs {
+    /// Object to trace as <kind>/<name> (Flux kinds or e.g. Deployment/podinfo)
+    #[arg(value_name = "KIND/NAME")]
+    pub target: String,
+
+    #[command(flatten)]
+    pub namespaces: NamespaceArgs,
+
+    /// Output format
+    #[arg(long, short = 'o', value_enum, default_value_t = TraceOutput::Text)]
+    pub output: TraceOutput,
+}
+
+/// Handle `flux9s trace`
+pub async fn handle_trace_command(args: TraceArgs, kubeconfig: Option<&Path>) -> Result<()> {
+    let (kind, name) = parse_trace_target(&args.target)?;
+    let config = cluster::load_config();
+
+    let namespace = cluster::resolve_namespace(&args.namespaces, &config)
+        .await
+        .ok_or_else(|| anyhow::anyhow!("trace needs a namespace: pass -n instead of -A"))?;
+    let client = cluster::create_client(kubeconfig).await?;
+
+    let result = crate::trace::trace_object(&client, &kind, &namespace, &name)
+        .await
+        .with_context(|| format!("Failed to trace {}/{} in {}", kind, name, namespace))?;
+
+    print!("{}", render_trace(&result, args.output)?);
+    Ok(())
+}
+
+/// Split `<kind>/<name>` and normalise the kind to the name `trace_object`
+/// expects: Flux aliases (`ks`, `hr`) resolve to the display name, built-ins
+/// match case-insensitively (`deployment`, `deployments`), and anything else
+/// is passed through for discovered kinds.
+pub fn parse_trace_target(target: &str) -> Result<(String, String)> {
+    let (kind, name) = target
+        .split_once('/')
+        .filter(|(kind, name)| !kind.is_empty() && !name.is_empty() && !name.contains('/'))
+        .ok_or_else(|| anyhow::anyhow!("Invalid target '{}': expected <kind>/<name>", target))?;
+
+    if let Some(flux_kind) = FluxResourceKind::from_str_case_insensitive(kind) {
+        return Ok((flux_kind.as_str().to_string(), name.to_string()));
+    }
+    let lower = kind.to_lowercase();
+    let builtin = BUILTIN_KINDS.iter().find(|builtin| {
+        let singular = builtin.to_lowercase();
+        lower == singular || lower == format!("{}s", singular) || lower == format!("{}es", singular)
+    });
+    let kind = builtin
+        .map(|k| k.to_string())
+        .unwrap_or_else(|| kind.to_string());
+    Ok((kind, name.to_string()))
+}
+
+/// Render a trace result in the requested format.
+pub fn render_trace(result: &TraceResult, output: TraceOutput) -> Result<String> {
+    match output {
+        TraceOutput::Text => Ok(crate::trace::format_trace_result(result) + "\n"),
+        TraceOutput::Json => serde_json::to_string_pretty(result)
+            .map(|s| s + "\n")
+            .context("Failed to serialize trace to JSON"),
+        TraceOutput::Yaml => {
+            serde_yaml::to_string(result).context("Failed to serialize trace to YAML")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trace_target_normalises_kinds() {
+        assert_eq!(
+            parse_trace_target("ks/apps").unwrap(),
+            ("Kustomization".to_string(), "apps".to_string())
+        );
+        assert_eq!(
+            parse_trace_target("deployments/podinfo").unwrap(),
+            ("Deployment".to_string(), "podinfo".to_string())
+        );
+        assert_eq!(
+            parse_trace_target("ingress/web").unwrap().0,
+            "Ingress".to_string()
+        );
+        assert_eq!(
+            parse_trace_target("Certificate/tls").unwrap().0,
+            "Certificate".to_string()
+        );
+        assert!(parse_trace_target("Deployment").is_err());
+        assert!(parse_trace_target("Deployment/").is_err());
+        assert!(parse_trace_target("/podinfo").is_err());
+    }
+}
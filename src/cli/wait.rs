@@ -0,0 +1,416 @@
+//! `flux9s wait` — block until Flux resources are Ready, for CI/CD gates
+//!
+//! Targets are resolved once (`<kind>/<name>` or a bare kind with
+//! `--selector`), then a headless [`ClusterSession`] watches them until every
+//! target meets the condition or the timeout expires. The timeout starts once
+//! the session has synced, so it bounds the wait for the condition only.
+//!
+//! [`ClusterSession`]: crate::services::ClusterSession
+
+use anyhow::{Context, Result};
+use clap::Args;
+use std::path::Path;
+use std::time::Duration;
+
+use super::cluster::{self, NamespaceArgs, ResourceTarget};
+use crate::services::ClusterSession;
+use crate::watcher::{ResourceInfo, WatchEvent};
+
+/// Annotation `flux reconcile` and the reconcile operations set to request a run
+const RECONCILE_REQUESTED_AT: &str = "reconcile.fluxcd.io/requestedAt";
+
+/// Arguments for `flux9s wait`
+#[derive(Args, Debug)]
+pub struct WaitArgs {
+    /// Resources as <kind>/<name>, or a bare <kind> together with --selector
+    #[arg(required = true, value_name = "KIND/NAME")]
+    pub targets: Vec<String>,
+
+    #[command(flatten)]
+    pub namespaces: NamespaceArgs,
+
+    /// Label selector for bare-kind targets (e.g. team=payments,tier!=db)
+    #[arg(long, short = 'l')]
+    pub selector: Option<String>,
+
+    /// Give up after this long (e.g. 90s, 5m, 1h30m; 0 checks once)
+    #[arg(long, default_value = "5m", value_parser = parse_duration)]
+    pub timeout: Duration,
+
+    /// Condition to wait for: `ready` or `reconciled-revision=<sha>`
+    #[arg(long = "for", value_name = "CONDITION", default_value = "ready")]
+    pub condition: WaitCondition,
+}
+
+/// What a target must satisfy for `wait` to succeed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WaitCondition {
+    /// Ready and not suspended, with any pending reconcile request handled
+    Ready,
+    /// Ready, and the applied/artifact revision matches this revision or digest
+    ReconciledRevision(String),
+}
+
+impl std::str::FromStr for WaitCondition {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.split_once('=') {
+            None if s == "ready" => Ok(WaitCondition::Ready),
+            Some(("reconciled-revision", revision)) if !revision.is_empty() => {
+                Ok(WaitCondition::ReconciledRevision(revision.to_string()))
+            }
+            _ => Err(anyhow::anyhow!(
+                "Invalid condition '{}': expected `ready` or `reconciled-revision=<sha>`",
+                s
+            )),
+        }
+    }
+}
+
+/// Handle `flux9s wait`
+pub async fn handle_wait_command(args: WaitArgs, kubeconfig: Option<&Path>) -> Result<()> {
+    let config = cluster::load_config();
+
+    let client = cluster::create_client(kubeconfig).await?;
+    let namespace = cluster::resolve_namespace(&args.namespaces, &config).await;
+    let targets = cluster::resolve_targets(
+        &client,
+        &args.targets,
+        args.selector.as_deref(),
+        namespace.as_deref(),
+    )
+    .await?;
+    if targets.is_empty() {
+        return Err(anyhow::anyhow!("No resources matched; nothing to wait for"));
+    }
+
+    let mut session = ClusterSession::connect_in_namespace(kubeconfig, namespace, &config)
+        .await
+        .context("Failed to connect to the cluster")?;
+    session
+        .wait_for_initial_sync(cluster::INITIAL_SYNC_TIMEOUT)
+        .await;
+
+    wait_for_condition(&mut session, &targets, &args.condition, args.timeout).await
+}
+
+/// The watched state `wait` checks targets against
+trait WaitSource {
+    /// The resource and its full object tracked under `key`
+    fn resource(&self, key: &str) -> (Option<ResourceInfo>, Option<serde_json::Value>);
+
+    /// The next watch event; `None` when the stream ended
+    async fn next_event(&mut self) -> Option<WatchEvent>;
+
+    /// Apply `event` and whatever else is queued behind it
+    fn apply(&mut self, event: WatchEvent);
+}
+
+impl WaitSource for ClusterSession {
+    fn resource(&self, key: &str) -> (Option<ResourceInfo>, Option<serde_json::Value>) {
+        (self.state().get(key), self.object(key))
+    }
+
+    async fn next_event(&mut self) -> Option<WatchEvent> {
+        self.recv_event().await
+    }
+
+    fn apply(&mut self, event: WatchEvent) {
+        self.apply_event(event);
+        self.drain_events();
+    }
+}
+
+/// Check the targets, then re-check after every event until all of them
+/// meet `condition` or `timeout` expires. A zero timeout checks once.
+async fn wait_for_condition(
+    session: &mut impl WaitSource,
+    targets: &[ResourceTarget],
+    condition: &WaitCondition,
+    timeout: Duration,
+) -> Result<()> {
+    let deadline = tokio::time::Instant::now() + timeout;
+    let mut met = vec![false; targets.len()];
+    loop {
+        let pending = pending_targets(session, targets, condition);
+        for (idx, target) in targets.iter().enumerate() {
+            let now_met = !pending.iter().any(|(pending, _)| *pending == target);
+            if now_met && !met[idx] {
+                println!("{}: condition met", target);
+            }
+            met[idx] = now_met;
+        }
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        match tokio::time::timeout_at(deadline, session.next_event()).await {
+            Ok(Some(event)) => session.apply(event),
+            Ok(None) => {
+                for (target, reason) in &pending {
+                    eprintln!("{}: {}", target, reason);
+                }
+                return Err(anyhow::anyhow!(
+                    "The watch stream ended: {} of {} resources not ready",
+                    pending.len(),
+                    targets.len()
+                ));
+            }
+            Err(_) => {
+                for (target, reason) in &pending {
+                    eprintln!("{}: {}", target, reason);
+                }
+                return Err(anyhow::anyhow!(
+                    "Timed out after {}s: {} of {} resources not ready",
+                    timeout.as_secs(),
+                    pending.len(),
+                    targets.len()
+                ));
+            }
+        }
+    }
+}
+
+/// Targets that do not meet the condition yet, with the reason why.
+fn pending_targets<'a>(
+    session: &impl WaitSource,
+    targets: &'a [ResourceTarget],
+    condition: &WaitCondition,
+) -> Vec<(&'a ResourceTarget, String)> {
+    targets
+        .iter()
+        .filter_map(|target| {
+            let (info, obj) = session.resource(&target.key());
+            pending_reason(info.as_ref(), obj.as_ref(), condition).map(|reason| (target, reason))
+        })
+        .collect()
+}
+
+/// Why a resource does not meet `condition` yet, or `None` when it does.
+///
+/// Beyond [`ResourceInfo::is_healthy`], a gate must not pass on stale status:
+/// readiness has to be reported (unknown counts as healthy in the list views),
+/// the controller must have observed the latest generation, and a pending
+/// `reconcile.fluxcd.io/requestedAt` must have been handled — the same
+/// comparison `ReconcileWithSourceOperation` polls for.
+pub fn pending_reason(
+    info: Option<&ResourceInfo>,
+    obj: Option<&serde_json::Value>,
+    condition: &WaitCondition,
+) -> Option<String> {
+    let Some(info) = info else {
+        return Some("not found".to_string());
+    };
+    if info.effective_suspended() {
+        return Some("suspended".to_string());
+    }
+    if info.ready.is_none() {
+        return Some("waiting for Ready condition".to_string());
+    }
+
+    let status = obj.and_then(|o| o.get("status"));
+    let generation = obj
+        .and_then(|o| o.pointer("/metadata/generation"))
+        .and_then(|g| g.as_i64());
+    let observed = status
+        .and_then(|s| s.get("observedGeneration"))
+        .and_then(|g| g.as_i64());
+    if let (Some(generation), Some(observed)) = (generation, observed) {
+        if observed < generation {
+            return Some(format!(
+                "waiting for generation {} to be observed (at {})",
+                generation, observed
+            ));
+        }
+    }
+
+    if let Some(requested_at) = info.annotations.get(RECONCILE_REQUESTED_AT) {
+        let handled_at = status
+            .and_then(|s| s.get("lastHandledReconcileAt"))
+            .and_then(|t| t.as_str());
+        if handled_at != Some(requested_at.as_str()) {
+            return Some(format!(
+                "waiting for reconcile request {} to be handled",
+                requested_at
+            ));
+        }
+    }
+
+    if !info.is_healthy() {
+        return Some(
+            info.message
+                .as_deref()
+                .map(|m| format!("not ready: {}", m.replace('\n', " ")))
+                .unwrap_or_else(|| "not ready".to_string()),
+        );
+    }
+
+    if let WaitCondition::ReconciledRevision(want) = condition {
+        match info.revision.as_deref() {
+            Some(revision) if revision_matches(revision, want) => {}
+            Some(revision) => {
+                return Some(format!("at revision {}, waiting for {}", revision, want));
+            }
+            None => return Some(format!("no revision reported, waiting for {}", want)),
+        }
+    }
+    None
+}
+
+/// Match a Flux revision (`main@sha1:abc123…`, `6.3.5@sha256:…`, or a bare
+/// digest) against a full revision, a digest, or a digest prefix of at least
+/// seven characters.
+fn revision_matches(revision: &str, want: &str) -> bool {
+    if revision == want {
+        return true;
+    }
+    let digest = revision.rsplit(':').next().unwrap_or(revision);
+    let want_digest = want.rsplit(':').next().unwrap_or(want);
+    want_digest.len() >= 7 && digest.starts_with(want_digest)
+}
+
+/// `--timeout` value parser; see [`crate::duration::parse_duration`].
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    crate::duration::parse_duration(s).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn info(ready: Option<bool>, suspended: Option<bool>) -> ResourceInfo {
+        ResourceInfo {
+            name: "apps".to_string(),
+            namespace: "flux-system".to_string(),
+            resource_type: "Kustomization".to_string(),
+            age: None,
+            suspended,
+            ready,
+            message: Some("Applied revision: main@sha1:abc1234def".to_string()),
+            revision: Some("main@sha1:abc1234def".to_string()),
+            labels: HashMap::new(),
+            annotations: HashMap::new(),
+            last_reconciled: None,
+            reconciliation_history: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn condition_parsing() {
+        assert_eq!(
+            "ready".parse::<WaitCondition>().unwrap(),
+            WaitCondition::Ready
+        );
+        assert_eq!(
+            "reconciled-revision=abc1234"
+                .parse::<WaitCondition>()
+                .unwrap(),
+            WaitCondition::ReconciledRevision("abc1234".to_string())
+        );
+        assert!("reconciled-revision=".parse::<WaitCondition>().is_err());
+        assert!("healthy".parse::<WaitCondition>().is_err());
+    }
+
+    #[test]
+    fn ready_requires_reported_readiness() {
+        let ready = WaitCondition::Ready;
+        assert_eq!(
+            pending_reason(None, None, &ready).as_deref(),
+            Some("not found")
+        );
+        assert!(pending_reason(Some(&info(Some(true), Some(false))), None, &ready).is_none());
+        assert_eq!(
+            pending_reason(Some(&info(None, None)), None, &ready).as_deref(),
+            Some("waiting for Ready condition")
+        );
+        assert_eq!(
+            pending_reason(Some(&info(Some(true), Some(true))), None, &ready).as_deref(),
+            Some("suspended")
+        );
+        let reason = pending_reason(Some(&info(Some(false), Some(false))), None, &ready).unwrap();
+        assert!(reason.starts_with("not ready: Applied revision"));
+    }
+
+    #[test]
+    fn stale_generation_and_unhandled_request_are_pending() {
+        let mut resource = info(Some(true), Some(false));
+        let stale = serde_json::json!({
+            "metadata": {"generation": 3},
+            "status": {"observedGeneration": 2}
+        });
+        let reason = pending_reason(Some(&resource), Some(&stale), &WaitCondition::Ready).unwrap();
+        assert!(reason.contains("generation 3"));
+
+        resource.annotations.insert(
+            RECONCILE_REQUESTED_AT.to_string(),
+            "2026-01-01T00:00:00Z".to_string(),
+        );
+        let unhandled = serde_json::json!({
+            "status": {"lastHandledReconcileAt": "2025-12-31T00:00:00Z"}
+        });
+        let reason =
+            pending_reason(Some(&resource), Some(&unhandled), &WaitCondition::Ready).unwrap();
+        assert!(reason.contains("2026-01-01T00:00:00Z"));
+
+        let handled = serde_json::json!({
+            "status": {"lastHandledReconcileAt": "2026-01-01T00:00:00Z"}
+        });
+        assert!(pending_reason(Some(&resource), Some(&handled), &WaitCondition::Ready).is_none());
+    }
+
+    #[test]
+    fn reconciled_revision_matches_digest_prefix() {
+        let resource = info(Some(true), Some(false));
+        let want = |rev: &str| WaitCondition::ReconciledRevision(rev.to_string());
+        assert!(pending_reason(Some(&resource), None, &want("abc1234")).is_none());
+        assert!(pending_reason(Some(&resource), None, &want("main@sha1:abc1234def")).is_none());
+        assert!(pending_reason(Some(&resource), None, &want("sha1:abc1234")).is_none());
+        // Too short to be a meaningful prefix
+        assert!(pending_reason(Some(&resource), None, &want("abc")).is_some());
+        let reason = pending_reason(Some(&resource), None, &want("fff0000")).unwrap();
+        assert!(reason.contains("waiting for fff0000"));
+    }
+
+    /// Already-synced state with no further events
+    struct Synced(HashMap<String, ResourceInfo>);
+
+    impl WaitSource for Synced {
+        fn resource(&self, key: &str) -> (Option<ResourceInfo>, Option<serde_json::Value>) {
+            (self.0.get(key).cloned(), None)
+        }
+
+        async fn next_event(&mut self) -> Option<WatchEvent> {
+            std::future::pending().await
+        }
+
+        fn apply(&mut self, _event: WatchEvent) {}
+    }
+
+    #[tokio::test]
+    async fn zero_timeout_checks_the_synced_state_once() {
+        let target = ResourceTarget {
+            kind: crate::models::FluxResourceKind::Kustomization,
+            namespace: "flux-system".to_string(),
+            name: "apps".to_string(),
+        };
+        let mut session = Synced(HashMap::from([(
+            target.key(),
+            info(Some(true), Some(false)),
+        )]));
+        let targets = [target];
+        let ready = WaitCondition::Ready;
+        assert!(
+            wait_for_condition(&mut session, &targets, &ready, Duration::ZERO)
+                .await
+                .is_ok()
+        );
+
+        session.0.clear();
+        let error = wait_for_condition(&mut session, &targets, &ready, Duration::ZERO)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().starts_with("Timed out after 0s"));
+    }
+}
@@ -0,0 +1,193 @@
+//! `flux9s watch` — stream watch events to stdout for headless monitoring
+//!
+//! `-o jsonl` writes one [`WatchRecord`] per line for `jq` and log shippers;
+//! the default text output is one readable line per event.
+
+use anyhow::{Context, Result};
+use clap::{Args, ValueEnum};
+use std::io::Write;
+use std::path::Path;
+
+use super::cluster::{self, NamespaceArgs};
+use crate::watcher::{WatchRecord, WatchRecordKind};
+
+/// Output format for `flux9s watch`
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WatchOutput {
+    /// One readable line per event
+    #[default]
+    Text,
+    /// One JSON object per line
+    Jsonl,
+}
+
+/// Arguments for `flux9s watch`
+#[derive(Args, Debug)]
+pub struct WatchArgs {
+    /// Only stream this kind (e.g. Kustomization, ks, hr); omit for every kind
+    pub kind: Option<String>,
+
+    #[command(flatten)]
+    pub namespaces: NamespaceArgs,
+
+    /// Output format
+    #[arg(long, short = 'o', value_enum, default_value_t = WatchOutput::Text)]
+    pub output: WatchOutput,
+
+    /// Don't stream Kubernetes Events, only Flux resources and watcher notices
+    #[arg(long)]
+    pub no_events: bool,
+}
+
+/// Handle `flux9s watch`. Runs until interrupted or stdout is closed.
+pub async fn handle_watch_command(args: WatchArgs, kubeconfig: Option<&Path>) -> Result<()> {
+    let kind = args.kind.as_deref().map(cluster::parse_kind).transpose()?;
+    let config = cluster::load_config();
+
+    let mut session = cluster::connect_session(kubeconfig, &args.namespaces, &config)
+        .await
+        .context("Failed to connect to the cluster")?;
+    if !args.no_events {
+        session
+            .watch_kube_events()
+            .context("Failed to start the Kubernetes Events watcher")?;
+    }
+
+    let mut stdout = std::io::stdout().lock();
+    while let Some(record) = session.next_record().await {
+        if let Some(kind) = kind {
+            // Watcher notices aren't about a resource; keep only this kind's
+            if record.subject_kind() != Some(kind.as_str())
+                && !matches!(
+                    &record.event,
+                    WatchRecordKind::WatcherDegraded { watcher }
+                        | WatchRecordKind::WatcherRecovered { watcher }
+                        | WatchRecordKind::WatcherForbidden { watcher }
+                        | WatchRecordKind::InitialSyncDone { watcher }
+                        if watcher == kind.as_str()
+                )
+            {
+                continue;
+            }
+        }
+        let line = match args.output {
+            WatchOutput::Jsonl => record
+                .to_json_line()
+                .context("Failed to serialize watch event")?,
+            WatchOutput::Text => render_text(&record),
+        };
+        // Flush every line so pipes see events as they happen; a closed pipe
+        // (e.g. `| head`) ends the stream quietly.
+        if let Err(e) = writeln!(stdout, "{}", line).and_then(|()| stdout.flush()) {
+            if e.kind() == std::io::ErrorKind::BrokenPipe {
+                return Ok(());
+            }
+            return Err(e).context("Failed to write to stdout");
+        }
+    }
+    Err(anyhow::anyhow!("Watch stream closed"))
+}
+
+/// One readable line: time, event type, subject, details.
+fn render_text(record: &WatchRecord) -> String {
+    let time = record.time.format("%H:%M:%S");
+    match &record.event {
+        WatchRecordKind::Applied {
+            kind,
+            namespace,
+            name,
+            ready,
+            suspended,
+            message,
+            ..
+        } => format!(
+            "{} APPLIED  {}/{} ({}) {} {}",
+            time,
+            kind,
+            name,
+            namespace,
+            cluster::status_text(*ready, *suspended),
+            message.as_deref().unwrap_or("").replace('\n', " ")
+        )
+        .trim_end()
+        .to_string(),
+        WatchRecordKind::Deleted {
+            kind,
+            namespace,
+            name,
+        } => format!("{} DELETED  {}/{} ({})", time, kind, name, namespace),
+        WatchRecordKind::Error { message } => format!("{} ERROR    {}", time, message),
+        WatchRecordKind::InitialSyncDone { watcher } => {
+            format!("{} SYNCED   {}", time, watcher)
+        }
+        WatchRecordKind::WatcherDegraded { watcher } => {
+            format!("{} DEGRADED {} (retrying)", time, watcher)
+        }
+        WatchRecordKind::WatcherRecovered { watcher } => {
+            format!("{} RECOVERED {}", time, watcher)
+        }
+        WatchRecordKind::WatcherForbidden { watcher } => {
+            format!("{} FORBIDDEN {} (RBAC)", time, watcher)
+        }
+        WatchRecordKind::KubeEvent {
+            event_type,
+            reason,
+            message,
+            involved_kind,
+            involved_namespace,
+            involved_name,
+            ..
+        } => format!(
+            "{} EVENT    {}/{} ({}) {} {}: {}",
+            time,
+            involved_kind,
+            involved_name,
+            involved_namespace,
+            event_type,
+            reason,
+            message.replace('\n', " ")
+        ),
+        WatchRecordKind::KubeEventDeleted { uid } => format!("{} EXPIRED  event {}", time, uid),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn text_lines_name_the_subject() {
+        let time = chrono::Utc
+            .with_ymd_and_hms(2026, 10, 16, 9, 30, 0)
+            .unwrap();
+        let applied = WatchRecord {
+            time,
+            event: WatchRecordKind::Applied {
+                kind: "HelmRelease".to_string(),
+                namespace: "apps".to_string(),
+                name: "podinfo".to_string(),
+                ready: Some(false),
+                suspended: Some(false),
+                revision: None,
+                message: Some("install retries exhausted".to_string()),
+                last_reconciled: None,
+            },
+        };
+        assert_eq!(
+            render_text(&applied),
+            "09:30:00 APPLIED  HelmRelease/podinfo (apps) ERR install retries exhausted"
+        );
+
+        let degraded = WatchRecord {
+            time,
+            event: WatchRecordKind::WatcherDegraded {
+                watcher: "Kustomization".to_string(),
+            },
+        };
+        assert_eq!(
+            render_text(&degraded),
+            "09:30:00 DEGRADED Kustomization (retrying)"
+        );
+    }
+}
@@ -3,7 +3,10 @@
 //! Handles loading configuration from multiple sources and merging them
 //! according to precedence rules.
 
-use super::{defaults, paths, schema::Config};
+use super::{
+    defaults, paths,
+    schema::{Config, SavedView},
+};
 use anyhow::{Context, Result};
 use std::path::PathBuf;
 
@@ -25,27 +28,10 @@ impl ConfigLoader {
             Err(_) => serde_yaml::Value::Mapping(serde_yaml::Mapping::new()),
         };
 
-        // Load root config
-        if let Ok(root_yaml) = Self::load_yaml_file(&paths::root_config_path()) {
-            Self::merge_yaml(&mut merged_yaml, root_yaml);
-        }
-
-        // Load cluster-specific config if cluster is provided
-        if let Some(cluster_name) = cluster {
-            if let Ok(cluster_yaml) =
-                Self::load_yaml_file(&paths::cluster_config_path(cluster_name, None))
-            {
-                Self::merge_yaml(&mut merged_yaml, cluster_yaml);
-            }
-
-            // Load context-specific config if context is provided
-            if let Some(context_name) = context {
-                if let Ok(context_yaml) = Self::load_yaml_file(&paths::cluster_config_path(
-                    cluster_name,
-                    Some(context_name),
-                )) {
-                    Self::merge_yaml(&mut merged_yaml, context_yaml);
-                }
+        // Root, then cluster-specific, then context-specific config
+        for path in Self::layer_paths(cluster, context) {
+            if let Ok(layer_yaml) = Self::load_yaml_file(&path) {
+                Self::merge_yaml(&mut merged_yaml, layer_yaml);
             }
         }
 
@@ -59,6 +45,74 @@ impl ConfigLoader {
         Ok(config)
     }
 
+    /// Config files [`Self::load`] merges for `cluster` and `context`,
+    /// lowest precedence first. The context config needs a cluster.
+    pub fn layer_paths(cluster: Option<&str>, context: Option<&str>) -> Vec<PathBuf> {
+        let mut layers = vec![paths::root_config_path()];
+        if let Some(cluster_name) = cluster {
+            layers.push(paths::cluster_config_path(cluster_name, None));
+            if let Some(context_name) = context {
+                layers.push(paths::cluster_config_path(cluster_name, Some(context_name)));
+            }
+        }
+        layers
+    }
+
+    /// Whether the config file at `path` defines the saved view `name`
+    pub fn defines_view(path: &PathBuf, name: &str) -> bool {
+        Self::load_yaml_file(path)
+            .ok()
+            .and_then(|yaml| yaml.get("views")?.get(name).cloned())
+            .is_some()
+    }
+
+    /// Set the saved view `name` in the config file at `path`, or remove it
+    /// with `None`. The rest of the file is left as written, so a cluster
+    /// config does not gain every default.
+    pub fn save_view(path: &PathBuf, name: &str, view: Option<&SavedView>) -> Result<()> {
+        let mut yaml = if path.exists() {
+            Self::load_yaml_file(path)?
+        } else {
+            serde_yaml::Value::Mapping(serde_yaml::Mapping::new())
+        };
+        let serde_yaml::Value::Mapping(ref mut root) = yaml else {
+            return Err(anyhow::anyhow!(
+                "Config file is not a YAML mapping: {}",
+                path.display()
+            ));
+        };
+        let views = root
+            .entry("views".into())
+            .or_insert_with(|| serde_yaml::Value::Mapping(serde_yaml::Mapping::new()));
+        if !views.is_mapping() {
+            *views = serde_yaml::Value::Mapping(serde_yaml::Mapping::new());
+        }
+        if let serde_yaml::Value::Mapping(views) = views {
+            match view {
+                Some(view) => {
+                    let value = serde_yaml::to_value(view)
+                        .context("Failed to serialize saved view to YAML")?;
+                    views.insert(name.into(), value);
+                }
+                None => {
+                    views.remove(name);
+                }
+            }
+            if views.is_empty() {
+                root.remove("views");
+            }
+        }
+
+        if let Some(parent) = path.parent() {
+            paths::ensure_dir(parent)?;
+        }
+        let contents =
+            serde_yaml::to_string(&yaml).context("Failed to serialize configuration to YAML")?;
+        std::fs::write(path, contents)
+            .with_context(|| format!("Failed to write config file: {}", path.display()))?;
+        Ok(())
+    }
+
     /// Load configuration from a file
     pub fn load_file(path: &PathBuf) -> Result<Config> {
         if !path.exists() {
@@ -158,7 +212,9 @@ impl ConfigLoader {
         }
 
         // Try to load the full merged config to catch any merge issues
-        let _ = Self::load(cluster, context).context("Failed to load merged configuration")?;
+        let merged = Self::load(cluster, context).context("Failed to load merged configuration")?;
+        crate::models::columns::validate(&merged.columns)?;
+        crate::plugins::validate(&merged.plugins)?;
 
         Ok(())
     }
@@ -410,4 +466,23 @@ mod tests {
         assert!(merged_config.read_only);
         assert_eq!(merged_config.default_namespace, "my-ns");
     }
+
+    #[test]
+    fn test_merge_yaml_overrides_saved_views_per_cluster() {
+        let root = "views:\n  payments:\n    resourceType: HelmRelease\n    namespace: payments\n    hotkey: F1\n  infra:\n    resourceType: Kustomization";
+        let cluster = "views:\n  payments:\n    namespace: payments-prod\n  edge:\n    query: label:team=edge";
+
+        let mut base_val: serde_yaml::Value = serde_yaml::from_str(root).unwrap();
+        let other_val: serde_yaml::Value = serde_yaml::from_str(cluster).unwrap();
+        ConfigLoader::merge_yaml(&mut base_val, other_val);
+
+        let merged: Config = serde_yaml::from_value(base_val).unwrap();
+        assert_eq!(merged.views.len(), 3);
+        let payments = &merged.views["payments"];
+        // The cluster overrides only the fields it sets
+        assert_eq!(payments.namespace.as_deref(), Some("payments-prod"));
+        assert_eq!(payments.resource_type.as_deref(), Some("HelmRelease"));
+        assert_eq!(payments.hotkey.as_deref(), Some("F1"));
+        assert_eq!(merged.views["edge"].query, "label:team=edge");
+    }
 }
@@ -17,6 +17,8 @@ pub use loader::ConfigLoader;
 pub use schema::Config;
 #[allow(unused_imports)] // Public API exports - may be used by external code
 pub use schema::UiConfig;
+#[allow(unused_imports)] // Public API exports - may be used by external code
+pub use schema::{SavedHealthFilter, SavedSortField, SavedView};
 #[cfg(feature = "tui")]
 pub use theme_loader::ThemeLoader;
 
@@ -188,6 +190,24 @@ pub fn set_config_value(config: &mut schema::Config, key: &str, value: &str) ->
                 config.editor = Some(value.to_string());
             }
         }
+        "historyRetentionDays" => {
+            config.history_retention_days = value
+                .parse::<u32>()
+                .context("historyRetentionDays must be a non-negative integer")?;
+        }
+        "clusters" => {
+            // YAML array or comma-separated list of contexts
+            config.clusters = if value.trim_start().starts_with('[') {
+                serde_yaml::from_str(value)
+                    .context("clusters must be a YAML array (e.g., ['prod-eu-1', 'prod-us-1'])")?
+            } else {
+                value
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            };
+        }
         _ => return Err(anyhow::anyhow!("Unknown configuration key: {}", key)),
     }
 
@@ -259,4 +279,19 @@ mod tests {
         assert_eq!(get_config_value(&config, "editor").unwrap(), "");
         assert!(config.editor.is_none());
     }
+
+    #[test]
+    fn clusters_set_accepts_lists() {
+        let mut config = schema::Config::default();
+
+        set_config_value(&mut config, "clusters", "prod-eu-1, prod-us-1").unwrap();
+        assert_eq!(config.clusters, vec!["prod-eu-1", "prod-us-1"]);
+
+        set_config_value(&mut config, "clusters", "[staging]").unwrap();
+        assert_eq!(config.clusters, vec!["staging"]);
+        assert_eq!(get_config_value(&config, "clusters").unwrap(), "- staging");
+
+        set_config_value(&mut config, "clusters", "").unwrap();
+        assert!(config.clusters.is_empty());
+    }
 }
@@ -103,6 +103,36 @@ pub fn update_notifier_state_path() -> PathBuf {
     data_dir().join("update_notifier.json")
 }
 
+/// Get the path to the persisted reconciliation history for a context
+///
+/// One JSON file per kubeconfig context under `history/`. Characters that
+/// are not safe in a file name (context names are often ARNs or URLs) are
+/// replaced with `_`, and a hash of the raw name keeps contexts that only
+/// differ in those characters apart.
+pub fn history_path(context: &str) -> PathBuf {
+    let file_name: String = context
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    data_dir()
+        .join("history")
+        .join(format!("{}-{:08x}.json", file_name, fnv1a(context) as u32))
+}
+
+/// FNV-1a: a hash that, unlike `DefaultHasher`, is stable across Rust
+/// releases, as file names must be.
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
 /// Get the cluster-specific config directory path
 pub fn cluster_config_dir(cluster: &str, context: Option<&str>) -> PathBuf {
     let mut path = data_dir().join("clusters").join(cluster);
@@ -140,4 +170,17 @@ mod tests {
         assert!(config_dir().is_absolute() || config_dir().to_string_lossy().starts_with("."));
         assert!(data_dir().is_absolute() || data_dir().to_string_lossy().starts_with("."));
     }
+
+    #[test]
+    fn test_history_path_sanitizes_context() {
+        let path = history_path("arn:aws:eks:us-east-1:123:cluster/prod");
+        let file_name = path.file_name().unwrap().to_string_lossy().into_owned();
+        assert!(file_name.starts_with("arn_aws_eks_us-east-1_123_cluster_prod-"));
+        assert!(file_name.ends_with(".json"));
+        assert!(path.parent().unwrap().ends_with("history"));
+
+        // Contexts that sanitize to the same name still get their own file
+        assert_ne!(path, history_path("arn_aws_eks_us-east-1_123_cluster_prod"));
+        assert_eq!(path, history_path("arn:aws:eks:us-east-1:123:cluster/prod"));
+    }
 }
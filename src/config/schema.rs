@@ -67,6 +67,48 @@ pub struct Config {
     /// before $VISUAL and $EDITOR. Leave unset to use $VISUAL/$EDITOR/vi.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub editor: Option<String>,
+
+    /// Commands run when a resource's readiness, suspension or revision
+    /// changes (see [`crate::hooks`])
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hooks: Vec<crate::hooks::HookConfig>,
+
+    /// Days of observed reconciliation history kept on disk per context.
+    /// `0` keeps history in memory only.
+    #[serde(default = "default_history_retention_days")]
+    pub history_retention_days: u32,
+
+    /// Named list views (type filter, query, health filter, sort, namespace)
+    /// recalled with `:view <name>`. Cluster configs override views by name.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub views: HashMap<String, SavedView>,
+
+    /// Columns added, hidden or reordered per kind in the type-specific list
+    /// (see [`crate::models::columns`])
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub columns: crate::models::columns::ColumnsConfig,
+
+    /// Action name → key overrides for navigation, view and operation keys,
+    /// e.g. `describe: i` or `delete: ctrl+x`. Checked for conflicts by
+    /// `flux9s config validate` and at startup.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub keymap: HashMap<String, String>,
+
+    /// Commands bound to a shortcut and run on the selected resource, k9s
+    /// `plugins.yaml` style (see [`crate::plugins`])
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub plugins: crate::plugins::PluginsConfig,
+
+    /// `:` command aliases, e.g. `prod: "ctx prod-eu-1"` or
+    /// `broken: "hr status:failed"`; one-word aliases of discovered kinds
+    /// also resolve wherever kind names do
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub aliases: HashMap<String, String>,
+
+    /// Kubeconfig contexts watched together by `:clusters` when it is given
+    /// no contexts
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub clusters: Vec<String>,
 }
 
 impl Config {
@@ -102,6 +144,53 @@ impl Config {
             default_resource_filter: Some("Kustomization".to_string()),
             connect_timeout_seconds: default_connect_timeout_seconds(),
             editor: Some("vim".to_string()),
+            hooks: vec![crate::hooks::HookConfig {
+                on: crate::hooks::HookTrigger::BecameNotReady,
+                kinds: vec!["HelmRelease".to_string()],
+                namespaces: vec!["flux-system".to_string()],
+                command: "notify-send \"$FLUX9S_NAME is not ready\"".to_string(),
+            }],
+            history_retention_days: default_history_retention_days(),
+            views: HashMap::from([(
+                "payments".to_string(),
+                SavedView {
+                    resource_type: Some("HelmRelease".to_string()),
+                    query: "label:team=payments".to_string(),
+                    health: Some(SavedHealthFilter::Unhealthy),
+                    sort: Some(SavedSortField::Age),
+                    sort_reverse: true,
+                    namespace: Some("apps".to_string()),
+                    hotkey: Some("F1".to_string()),
+                },
+            )]),
+            columns: HashMap::from([(
+                "HelmRelease".to_string(),
+                vec![crate::models::columns::ColumnConfig {
+                    name: "VALUES_FROM".to_string(),
+                    path: Some(".spec.valuesFrom[*].name".to_string()),
+                    hide: false,
+                }],
+            )]),
+            keymap: HashMap::from([("describe".to_string(), "i".to_string())]),
+            plugins: HashMap::from([(
+                "flux-diff".to_string(),
+                crate::plugins::PluginConfig {
+                    shortcut: "ctrl+k".to_string(),
+                    description: Some("flux diff".to_string()),
+                    scopes: vec!["Kustomization".to_string()],
+                    command: "flux".to_string(),
+                    args: vec![
+                        "diff".to_string(),
+                        "ks".to_string(),
+                        "$NAME".to_string(),
+                        "-n".to_string(),
+                        "$NAMESPACE".to_string(),
+                    ],
+                    background: false,
+                },
+            )]),
+            aliases: HashMap::from([("broken".to_string(), "hr status:failed".to_string())]),
+            clusters: vec!["prod-eu-1".to_string(), "prod-us-1".to_string()],
         }
     }
 
@@ -175,6 +264,83 @@ pub struct UiConfig {
     pub rbac_warnings: bool,
 }
 
+/// A saved list view recalled with `:view <name>` or its hotkey
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedView {
+    /// Resource type filter (display name or alias); unset shows all types
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<String>,
+
+    /// `/` filter query
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub query: String,
+
+    /// Health filter; unset shows healthy and unhealthy resources
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<SavedHealthFilter>,
+
+    /// Sort column; unset keeps the default namespace/type/name order
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SavedSortField>,
+
+    /// Reverse the sort order
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub sort_reverse: bool,
+
+    /// Namespace to watch (`all` for every namespace); unset keeps the current one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+
+    /// Function key recalling the view, `F1` to `F12`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hotkey: Option<String>,
+}
+
+/// Health filter of a saved view
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SavedHealthFilter {
+    Healthy,
+    Unhealthy,
+}
+
+/// Sort column of a saved view: `name`, `age`, `type`, `status`, or the
+/// header of any other list column (e.g. a configured custom column)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
+pub enum SavedSortField {
+    Name,
+    Age,
+    Type,
+    Status,
+    Column(String),
+}
+
+impl From<String> for SavedSortField {
+    fn from(value: String) -> Self {
+        match value.to_lowercase().as_str() {
+            "name" => SavedSortField::Name,
+            "age" => SavedSortField::Age,
+            "type" => SavedSortField::Type,
+            "status" => SavedSortField::Status,
+            _ => SavedSortField::Column(value.to_uppercase()),
+        }
+    }
+}
+
+impl From<SavedSortField> for String {
+    fn from(value: SavedSortField) -> Self {
+        match value {
+            SavedSortField::Name => "name".to_string(),
+            SavedSortField::Age => "age".to_string(),
+            SavedSortField::Type => "type".to_string(),
+            SavedSortField::Status => "status".to_string(),
+            SavedSortField::Column(column) => column,
+        }
+    }
+}
+
 // Default value functions
 fn default_read_only() -> bool {
     true
@@ -201,6 +367,11 @@ fn default_connect_timeout_seconds() -> u64 {
     crate::kube::health::DEFAULT_CONNECT_TIMEOUT_SECS
 }
 
+/// Default retention for persisted reconciliation history.
+fn default_history_retention_days() -> u32 {
+    30
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -216,6 +387,14 @@ impl Default for Config {
             default_resource_filter: None,
             connect_timeout_seconds: default_connect_timeout_seconds(),
             editor: None,
+            hooks: Vec::new(),
+            history_retention_days: default_history_retention_days(),
+            views: HashMap::new(),
+            columns: HashMap::new(),
+            keymap: HashMap::new(),
+            plugins: HashMap::new(),
+            aliases: HashMap::new(),
+            clusters: Vec::new(),
         }
     }
 }
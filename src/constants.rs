@@ -9,6 +9,13 @@ pub const RESOURCE_KEY_FORMAT: &str = "resource_type:namespace:name";
 /// Maximum number of reconciliation history events to store per resource
 pub const MAX_RECONCILIATION_HISTORY: usize = 50;
 
+/// Maximum number of observed reconciliation events persisted per resource
+/// (see [`crate::services::HistoryStore`]); older events are dropped first.
+pub const MAX_PERSISTED_HISTORY: usize = 200;
+
+/// Minimum interval between writes of the persisted reconciliation history.
+pub const HISTORY_FLUSH_INTERVAL_SECS: u64 = 30;
+
 /// Cap on the live Kubernetes events feed. Events are the churniest resource
 /// in a cluster; the store evicts oldest-seen entries past this bound so a
 /// busy cluster can't grow memory without limit.
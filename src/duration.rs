@@ -0,0 +1,75 @@
+//! Human-friendly durations
+//!
+//! One parser for every duration a user types: `flux9s wait --timeout` and
+//! `age>` terms in `/` filter queries.
+
+use anyhow::{Result, anyhow};
+use std::time::Duration;
+
+/// Parse a duration like `90s`, `5m`, `2h`, `7d`, `1w` or `1h30m`. A bare
+/// number counts seconds; `0` and `0s` are both zero.
+pub fn parse_duration(value: &str) -> Result<Duration> {
+    let invalid = || {
+        anyhow!(
+            "Invalid duration '{}': use e.g. 90s, 5m, 1h30m or 7d",
+            value
+        )
+    };
+    if value.is_empty() {
+        return Err(invalid());
+    }
+    if let Ok(secs) = value.parse::<u64>() {
+        return Ok(Duration::from_secs(secs));
+    }
+
+    let mut total = 0u64;
+    let mut number = String::new();
+    for c in value.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let amount: u64 = number.parse().map_err(|_| invalid())?;
+        number.clear();
+        let unit = match c {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86_400,
+            'w' => 604_800,
+            _ => return Err(invalid()),
+        };
+        total = amount
+            .checked_mul(unit)
+            .and_then(|secs| total.checked_add(secs))
+            .ok_or_else(invalid)?;
+    }
+    if !number.is_empty() {
+        return Err(invalid());
+    }
+    Ok(Duration::from_secs(total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_units_and_bare_seconds() {
+        assert_eq!(parse_duration("90").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_duration("5m").unwrap(), Duration::from_secs(300));
+        assert_eq!(parse_duration("1h30m").unwrap(), Duration::from_secs(5400));
+        assert_eq!(parse_duration("7d").unwrap(), Duration::from_secs(604_800));
+        assert_eq!(parse_duration("1w").unwrap(), Duration::from_secs(604_800));
+        assert_eq!(parse_duration("0").unwrap(), Duration::ZERO);
+        assert_eq!(parse_duration("0s").unwrap(), Duration::ZERO);
+    }
+
+    #[test]
+    fn rejects_malformed_durations() {
+        for bad in ["", "5x", "m", "10m5", "-5m", "1.5h"] {
+            let err = parse_duration(bad).unwrap_err().to_string();
+            assert!(err.starts_with("Invalid duration"), "{}: {}", bad, err);
+        }
+    }
+}
@@ -0,0 +1,318 @@
+//! Status-transition hooks
+//!
+//! Hooks declared under `hooks:` in config run a local command when a
+//! resource's readiness, suspension or revision changes between two
+//! consecutive `Applied` events. Both the TUI and
+//! [`ClusterSession`](crate::services::ClusterSession) fire them, so desktop
+//! notifications and custom paging work without an extra controller.
+//!
+//! The first `Applied` for a resource (the initial list, or a newly created
+//! object) never fires: there is no previous status to transition from.
+//!
+//! Commands run through `sh -c` (`cmd /C` on Windows) with stdio detached and
+//! these environment variables set:
+//!
+//! | Variable | Value |
+//! |----------|-------|
+//! | `FLUX9S_HOOK_EVENT` | the trigger, e.g. `becameNotReady` |
+//! | `FLUX9S_CONTEXT` | kubeconfig context |
+//! | `FLUX9S_KIND` / `FLUX9S_NAMESPACE` / `FLUX9S_NAME` | the resource |
+//! | `FLUX9S_READY` / `FLUX9S_SUSPENDED` | `true`, `false` or empty when unknown |
+//! | `FLUX9S_MESSAGE` / `FLUX9S_REVISION` | from the Ready condition and status |
+//! | `FLUX9S_PREVIOUS_REVISION` | revision before the change |
+
+use serde::{Deserialize, Serialize};
+use std::process::{Command, Stdio};
+
+use crate::models::FluxResourceKind;
+use crate::watcher::ResourceInfo;
+
+/// A status transition a hook can fire on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HookTrigger {
+    /// Ready went from True (or unknown) to False
+    BecameNotReady,
+    /// Ready went from False (or unknown) to True
+    BecameReady,
+    /// The resource was suspended
+    Suspended,
+    /// The resource was resumed
+    Resumed,
+    /// The applied/artifact revision changed
+    RevisionChanged,
+}
+
+impl HookTrigger {
+    /// Name as written in config and passed in `FLUX9S_HOOK_EVENT`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HookTrigger::BecameNotReady => "becameNotReady",
+            HookTrigger::BecameReady => "becameReady",
+            HookTrigger::Suspended => "suspended",
+            HookTrigger::Resumed => "resumed",
+            HookTrigger::RevisionChanged => "revisionChanged",
+        }
+    }
+}
+
+/// One configured hook
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HookConfig {
+    /// Transition that fires the hook
+    pub on: HookTrigger,
+    /// Kinds to match, display names or aliases (`hr`, `ks`); empty matches every kind
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub kinds: Vec<String>,
+    /// Namespaces to match; empty matches every namespace
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub namespaces: Vec<String>,
+    /// Shell command to run
+    pub command: String,
+}
+
+impl HookConfig {
+    /// Whether the hook applies to this resource.
+    pub fn matches(&self, info: &ResourceInfo) -> bool {
+        let kind_matches = self.kinds.is_empty()
+            || self.kinds.iter().any(|kind| {
+                FluxResourceKind::from_str_case_insensitive(kind)
+                    .map(|k| k.as_str() == info.resource_type)
+                    .unwrap_or_else(|| kind.eq_ignore_ascii_case(&info.resource_type))
+            });
+        let namespace_matches =
+            self.namespaces.is_empty() || self.namespaces.contains(&info.namespace);
+        kind_matches && namespace_matches
+    }
+}
+
+/// The transitions between two consecutive states of a resource.
+pub fn transitions(previous: &ResourceInfo, current: &ResourceInfo) -> Vec<HookTrigger> {
+    let mut triggers = Vec::new();
+    match (previous.ready, current.ready) {
+        (Some(false), Some(false)) | (Some(true), Some(true)) => {}
+        (_, Some(false)) => triggers.push(HookTrigger::BecameNotReady),
+        (_, Some(true)) => triggers.push(HookTrigger::BecameReady),
+        _ => {}
+    }
+    match (
+        previous.effective_suspended(),
+        current.effective_suspended(),
+    ) {
+        (false, true) => triggers.push(HookTrigger::Suspended),
+        (true, false) => triggers.push(HookTrigger::Resumed),
+        _ => {}
+    }
+    if previous.revision.is_some()
+        && current.revision.is_some()
+        && previous.revision != current.revision
+    {
+        triggers.push(HookTrigger::RevisionChanged);
+    }
+    triggers
+}
+
+/// Runs configured hooks on status transitions
+#[derive(Debug, Clone, Default)]
+pub struct HookRunner {
+    hooks: Vec<HookConfig>,
+}
+
+impl HookRunner {
+    pub fn new(hooks: Vec<HookConfig>) -> Self {
+        Self { hooks }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// The hooks that fire for an update, with their trigger.
+    pub fn matching<'a>(
+        &'a self,
+        previous: &ResourceInfo,
+        current: &'a ResourceInfo,
+    ) -> Vec<(HookTrigger, &'a HookConfig)> {
+        let triggers = transitions(previous, current);
+        self.hooks
+            .iter()
+            .filter(|hook| triggers.contains(&hook.on) && hook.matches(current))
+            .map(|hook| (hook.on, hook))
+            .collect()
+    }
+
+    /// Fire every matching hook for an update. `previous` is `None` for the
+    /// first sighting of a resource, which never fires.
+    pub fn fire(&self, context: &str, previous: Option<&ResourceInfo>, current: &ResourceInfo) {
+        if self.hooks.is_empty() {
+            return;
+        }
+        let Some(previous) = previous else {
+            return;
+        };
+        for (trigger, hook) in self.matching(previous, current) {
+            let env = hook_env(trigger, context, previous, current);
+            spawn(&hook.command, &env);
+        }
+    }
+}
+
+/// Environment passed to a hook command.
+pub fn hook_env(
+    trigger: HookTrigger,
+    context: &str,
+    previous: &ResourceInfo,
+    current: &ResourceInfo,
+) -> Vec<(&'static str, String)> {
+    let flag = |value: Option<bool>| value.map(|v| v.to_string()).unwrap_or_default();
+    vec![
+        ("FLUX9S_HOOK_EVENT", trigger.as_str().to_string()),
+        ("FLUX9S_CONTEXT", context.to_string()),
+        ("FLUX9S_KIND", current.resource_type.clone()),
+        ("FLUX9S_NAMESPACE", current.namespace.clone()),
+        ("FLUX9S_NAME", current.name.clone()),
+        ("FLUX9S_READY", flag(current.ready)),
+        ("FLUX9S_SUSPENDED", flag(current.suspended)),
+        (
+            "FLUX9S_MESSAGE",
+            current.message.clone().unwrap_or_default(),
+        ),
+        (
+            "FLUX9S_REVISION",
+            current.revision.clone().unwrap_or_default(),
+        ),
+        (
+            "FLUX9S_PREVIOUS_REVISION",
+            previous.revision.clone().unwrap_or_default(),
+        ),
+    ]
+}
+
+/// Start the command without waiting on it; a reaper thread collects the
+/// exit status so failures end up in the log rather than as zombies.
+fn spawn(command: &str, env: &[(&'static str, String)]) {
+    #[cfg(windows)]
+    let mut cmd = {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    };
+    #[cfg(not(windows))]
+    let mut cmd = {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    };
+    // Detached stdio: inherited handles would draw over the TUI
+    cmd.envs(env.iter().map(|(k, v)| (*k, v)))
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+
+    match cmd.spawn() {
+        Ok(mut child) => {
+            let command = command.to_string();
+            std::thread::spawn(move || match child.wait() {
+                Ok(status) if !status.success() => {
+                    tracing::warn!("Hook '{}' exited with {}", command, status);
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!("Failed to wait for hook '{}': {}", command, e),
+            });
+        }
+        Err(e) => tracing::warn!("Failed to run hook '{}': {}", command, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(ready: Option<bool>, suspended: bool, revision: Option<&str>) -> ResourceInfo {
+        ResourceInfo {
+            name: "podinfo".to_string(),
+            namespace: "apps".to_string(),
+            resource_type: "HelmRelease".to_string(),
+            age: None,
+            suspended: Some(suspended),
+            ready,
+            message: Some("install retries exhausted".to_string()),
+            revision: revision.map(str::to_string),
+            labels: Default::default(),
+            annotations: Default::default(),
+            last_reconciled: None,
+            reconciliation_history: Vec::new(),
+        }
+    }
+
+    fn hook(on: HookTrigger, kinds: &[&str], namespaces: &[&str]) -> HookConfig {
+        HookConfig {
+            on,
+            kinds: kinds.iter().map(|s| s.to_string()).collect(),
+            namespaces: namespaces.iter().map(|s| s.to_string()).collect(),
+            command: "true".to_string(),
+        }
+    }
+
+    #[test]
+    fn transitions_detect_each_change() {
+        let before = info(Some(true), false, Some("1.0.0"));
+        assert!(transitions(&before, &before).is_empty());
+        assert_eq!(
+            transitions(&before, &info(Some(false), false, Some("1.0.0"))),
+            vec![HookTrigger::BecameNotReady]
+        );
+        assert_eq!(
+            transitions(&info(None, false, None), &info(Some(true), false, None)),
+            vec![HookTrigger::BecameReady]
+        );
+        assert_eq!(
+            transitions(&before, &info(Some(true), true, Some("1.1.0"))),
+            vec![HookTrigger::Suspended, HookTrigger::RevisionChanged]
+        );
+        // Losing or gaining a revision is not a revision change
+        assert!(transitions(&info(Some(true), false, None), &before).is_empty());
+    }
+
+    #[test]
+    fn hooks_filter_by_kind_alias_and_namespace() {
+        let runner = HookRunner::new(vec![
+            hook(HookTrigger::BecameNotReady, &["hr"], &["apps"]),
+            hook(HookTrigger::BecameNotReady, &["Kustomization"], &[]),
+            hook(HookTrigger::BecameNotReady, &[], &["other"]),
+            hook(HookTrigger::Suspended, &[], &[]),
+        ]);
+        let before = info(Some(true), false, None);
+        let after = info(Some(false), false, None);
+        let fired = runner.matching(&before, &after);
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].1.kinds, vec!["hr".to_string()]);
+    }
+
+    #[test]
+    fn env_describes_the_resource() {
+        let before = info(Some(true), false, Some("1.0.0"));
+        let after = info(Some(false), false, Some("1.1.0"));
+        let env = hook_env(HookTrigger::BecameNotReady, "prod", &before, &after);
+        let get = |key: &str| env.iter().find(|(k, _)| *k == key).map(|(_, v)| v.as_str());
+        assert_eq!(get("FLUX9S_HOOK_EVENT"), Some("becameNotReady"));
+        assert_eq!(get("FLUX9S_CONTEXT"), Some("prod"));
+        assert_eq!(get("FLUX9S_KIND"), Some("HelmRelease"));
+        assert_eq!(get("FLUX9S_READY"), Some("false"));
+        assert_eq!(get("FLUX9S_MESSAGE"), Some("install retries exhausted"));
+        assert_eq!(get("FLUX9S_REVISION"), Some("1.1.0"));
+        assert_eq!(get("FLUX9S_PREVIOUS_REVISION"), Some("1.0.0"));
+    }
+
+    #[test]
+    fn hooks_parse_from_yaml() {
+        let hooks: Vec<HookConfig> = serde_yaml::from_str(
+            "- on: becameNotReady\n  kinds: [HelmRelease]\n  command: notify-send \"$FLUX9S_NAME\"\n",
+        )
+        .unwrap();
+        assert_eq!(hooks[0].on, HookTrigger::BecameNotReady);
+        assert!(hooks[0].namespaces.is_empty());
+        assert!(serde_yaml::from_str::<Vec<HookConfig>>("- on: exploded\n  command: x\n").is_err());
+    }
+}
@@ -157,7 +157,7 @@ pub fn get_gvk_for_resource_type(resource_type: &str) -> Result<(String, String,
 /// This generates common fallback versions without hardcoding specific resource types.
 /// For example, if default is "v1", it will try "v1beta2", "v1beta1", "v1alpha1".
 /// If default is "v2", it will try "v2beta2", "v2beta1", "v1", "v1beta2", etc.
-fn generate_fallback_versions(default_version: &str) -> Vec<String> {
+pub(crate) fn generate_fallback_versions(default_version: &str) -> Vec<String> {
     let mut fallbacks = Vec::new();
 
     // Parse version (e.g., "v1", "v2beta1", "v1beta2")
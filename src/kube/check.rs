@@ -0,0 +1,629 @@
+//! Flux installation health check
+//!
+//! Goes beyond [`check_connectivity`](super::health::check_connectivity):
+//! controller Deployments must be available, installed CRDs must serve the API
+//! versions flux9s was generated from (`crds/`), no deprecated version may
+//! linger in a CRD's `status.storedVersions`, and the Flux Operator's
+//! FluxReport (when present) must be Ready.
+//!
+//! The evaluation functions are pure so they can be tested against fixtures;
+//! [`run_check`] does the fetching.
+
+use anyhow::Result;
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+use kube::Api;
+use serde::Serialize;
+use std::time::Duration;
+
+use crate::models::FluxResourceKind;
+use crate::watcher::{ResourceWatcher, WatchEvent};
+
+/// Outcome of a single check, ordered by severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    /// Healthy
+    Ok,
+    /// Worth knowing, not a problem (e.g. an optional component is absent)
+    Info,
+    /// Works, but needs attention (deprecated versions, older Flux)
+    Warning,
+    /// Broken
+    Failed,
+}
+
+impl CheckStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CheckStatus::Ok => "OK",
+            CheckStatus::Info => "INFO",
+            CheckStatus::Warning => "WARN",
+            CheckStatus::Failed => "FAIL",
+        }
+    }
+}
+
+/// One line of the check report
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CheckItem {
+    /// `connectivity`, `controller`, `crd`, or `fluxreport`
+    pub category: String,
+    pub name: String,
+    pub status: CheckStatus,
+    pub message: String,
+}
+
+impl CheckItem {
+    fn new(category: &str, name: &str, status: CheckStatus, message: impl Into<String>) -> Self {
+        Self {
+            category: category.to_string(),
+            name: name.to_string(),
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+/// The full check result
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckReport {
+    /// Kubeconfig context that was checked
+    pub context: Option<String>,
+    /// Flux bundle version from the controllers' `app.kubernetes.io/version` label
+    pub flux_version: Option<String>,
+    /// Distribution version from the FluxReport, when the Flux Operator runs
+    pub distribution_version: Option<String>,
+    pub checks: Vec<CheckItem>,
+}
+
+/// Exit code: everything passed (info items allowed)
+pub const EXIT_OK: i32 = 0;
+/// Exit code: at least one check failed
+pub const EXIT_FAILED: i32 = 1;
+/// Exit code: no failures, but at least one warning
+pub const EXIT_WARNING: i32 = 2;
+/// Exit code: the API server could not be reached, nothing else was checked
+pub const EXIT_UNREACHABLE: i32 = 3;
+
+impl CheckReport {
+    /// The most severe status across all checks.
+    pub fn status(&self) -> CheckStatus {
+        self.checks
+            .iter()
+            .map(|c| c.status)
+            .max()
+            .unwrap_or(CheckStatus::Ok)
+    }
+
+    /// Process exit code for this report (see the `EXIT_*` constants).
+    pub fn exit_code(&self) -> i32 {
+        let unreachable = self
+            .checks
+            .iter()
+            .any(|c| c.category == "connectivity" && c.status == CheckStatus::Failed);
+        if unreachable {
+            return EXIT_UNREACHABLE;
+        }
+        match self.status() {
+            CheckStatus::Ok | CheckStatus::Info => EXIT_OK,
+            CheckStatus::Warning => EXIT_WARNING,
+            CheckStatus::Failed => EXIT_FAILED,
+        }
+    }
+}
+
+/// Flux bundle version from a controller Deployment's `app.kubernetes.io/version`
+/// label (e.g. `v2.7.5`).
+pub fn flux_bundle_version(deployment_json: &serde_json::Value) -> Option<String> {
+    deployment_json["metadata"]["labels"]["app.kubernetes.io/version"]
+        .as_str()
+        .map(|s| s.to_string())
+}
+
+/// Check a controller Deployment: `Available=True` and all replicas ready.
+pub fn check_controller(deployment_json: &serde_json::Value) -> CheckItem {
+    let name = deployment_json["metadata"]["name"]
+        .as_str()
+        .unwrap_or("unknown");
+    let desired = deployment_json["spec"]["replicas"].as_i64().unwrap_or(1);
+    let ready = deployment_json["status"]["readyReplicas"]
+        .as_i64()
+        .unwrap_or(0);
+    let available = deployment_json["status"]["conditions"]
+        .as_array()
+        .and_then(|conds| {
+            conds
+                .iter()
+                .find(|c| c["type"].as_str() == Some("Available"))
+        });
+
+    if desired == 0 {
+        return CheckItem::new(
+            "controller",
+            name,
+            CheckStatus::Warning,
+            "scaled to 0 replicas",
+        );
+    }
+    match available {
+        Some(cond) if cond["status"].as_str() == Some("True") && ready >= desired => {
+            CheckItem::new(
+                "controller",
+                name,
+                CheckStatus::Ok,
+                format!("{}/{} replicas ready", ready, desired),
+            )
+        }
+        Some(cond) if cond["status"].as_str() != Some("True") => CheckItem::new(
+            "controller",
+            name,
+            CheckStatus::Failed,
+            cond["message"]
+                .as_str()
+                .unwrap_or("Deployment is not available")
+                .to_string(),
+        ),
+        _ => CheckItem::new(
+            "controller",
+            name,
+            CheckStatus::Failed,
+            format!("{}/{} replicas ready", ready, desired),
+        ),
+    }
+}
+
+/// Check the controllers as a set: at least one must exist, and they should
+/// all come from the same Flux bundle.
+pub fn check_controllers(deployments: &[serde_json::Value], namespace: &str) -> Vec<CheckItem> {
+    if deployments.is_empty() {
+        return vec![CheckItem::new(
+            "controller",
+            namespace,
+            CheckStatus::Failed,
+            format!(
+                "no Flux controller Deployments found in namespace '{}' (or listing them is forbidden)",
+                namespace
+            ),
+        )];
+    }
+    let mut items: Vec<CheckItem> = deployments.iter().map(check_controller).collect();
+    let mut versions: Vec<String> = deployments.iter().filter_map(flux_bundle_version).collect();
+    versions.sort();
+    versions.dedup();
+    if versions.len() > 1 {
+        items.push(CheckItem::new(
+            "controller",
+            "bundle",
+            CheckStatus::Warning,
+            format!("controllers report mixed versions: {}", versions.join(", ")),
+        ));
+    }
+    items
+}
+
+/// Check an installed CRD against the API version flux9s was generated from.
+///
+/// `crd` is `None` when the CRD is not installed, which is informational:
+/// image automation and the Flux Operator are optional.
+pub fn check_crd(kind: FluxResourceKind, crd: Option<&serde_json::Value>) -> Vec<CheckItem> {
+    let name = kind.as_str();
+    let Some(crd) = crd else {
+        return vec![CheckItem::new(
+            "crd",
+            name,
+            CheckStatus::Info,
+            "not installed",
+        )];
+    };
+    let Ok((_, expected, _)) = crate::kube::api::get_gvk_for_resource_type(name) else {
+        return Vec::new();
+    };
+
+    let versions = crd["spec"]["versions"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    let version_flag = |version: &str, flag: &str| {
+        versions
+            .iter()
+            .find(|v| v["name"].as_str() == Some(version))
+            .and_then(|v| v[flag].as_bool())
+            .unwrap_or(false)
+    };
+    let served: Vec<&str> = versions
+        .iter()
+        .filter(|v| v["served"].as_bool().unwrap_or(false))
+        .filter_map(|v| v["name"].as_str())
+        .collect();
+    let storage = versions
+        .iter()
+        .find(|v| v["storage"].as_bool().unwrap_or(false))
+        .and_then(|v| v["name"].as_str())
+        .unwrap_or("unknown");
+
+    let mut items = Vec::new();
+    if served.contains(&expected.as_str()) {
+        if version_flag(&expected, "deprecated") {
+            items.push(CheckItem::new(
+                "crd",
+                name,
+                CheckStatus::Warning,
+                format!(
+                    "{} is deprecated in this cluster (storage {}); flux9s may be outdated",
+                    expected, storage
+                ),
+            ));
+        } else {
+            items.push(CheckItem::new(
+                "crd",
+                name,
+                CheckStatus::Ok,
+                format!("serves {} (storage {})", expected, storage),
+            ));
+        }
+    } else {
+        let fallback_served = crate::kube::api::generate_fallback_versions(&expected)
+            .iter()
+            .any(|v| served.contains(&v.as_str()));
+        let (status, hint) = if fallback_served {
+            (CheckStatus::Warning, "older Flux; flux9s falls back")
+        } else {
+            (CheckStatus::Failed, "flux9s cannot read this kind")
+        };
+        items.push(CheckItem::new(
+            "crd",
+            name,
+            status,
+            format!(
+                "flux9s expects {}, cluster serves {} ({})",
+                expected,
+                served.join(", "),
+                hint
+            ),
+        ));
+    }
+
+    // A version left in storedVersions means objects may still be persisted
+    // in it; once it is deprecated or no longer served that blocks upgrades.
+    let stale: Vec<&str> = crd["status"]["storedVersions"]
+        .as_array()
+        .map(|stored| {
+            stored
+                .iter()
+                .filter_map(|v| v.as_str())
+                .filter(|v| *v != storage)
+                .filter(|v| version_flag(v, "deprecated") || !served.contains(v))
+                .collect()
+        })
+        .unwrap_or_default();
+    if !stale.is_empty() {
+        items.push(CheckItem::new(
+            "crd",
+            name,
+            CheckStatus::Warning,
+            format!(
+                "deprecated API version(s) still stored: {} (migrate objects, e.g. `flux migrate`)",
+                stale.join(", ")
+            ),
+        ));
+    }
+    items
+}
+
+/// Check the Flux Operator's FluxReport: its Ready condition plus the
+/// distribution it reports.
+pub fn check_flux_report(report: &serde_json::Value) -> CheckItem {
+    let name = report["metadata"]["name"].as_str().unwrap_or("flux");
+    let ready = report["status"]["conditions"]
+        .as_array()
+        .and_then(|conds| conds.iter().find(|c| c["type"].as_str() == Some("Ready")));
+    let distribution = report
+        .pointer("/spec/distribution/version")
+        .and_then(|v| v.as_str())
+        .map(|version| {
+            let status = report
+                .pointer("/spec/distribution/status")
+                .and_then(|v| v.as_str())
+                .unwrap_or("Unknown");
+            format!("distribution {} ({})", version, status)
+        })
+        .unwrap_or_else(|| "no distribution reported".to_string());
+
+    match ready {
+        Some(cond) if cond["status"].as_str() == Some("True") => {
+            CheckItem::new("fluxreport", name, CheckStatus::Ok, distribution)
+        }
+        Some(cond) => CheckItem::new(
+            "fluxreport",
+            name,
+            CheckStatus::Failed,
+            format!(
+                "{}: {}",
+                distribution,
+                cond["message"].as_str().unwrap_or("not ready")
+            ),
+        ),
+        None => CheckItem::new(
+            "fluxreport",
+            name,
+            CheckStatus::Warning,
+            format!("{}: no Ready condition yet", distribution),
+        ),
+    }
+}
+
+/// Run every check against a cluster.
+///
+/// Controller Deployments come from the same watcher that feeds the TUI
+/// header ([`ResourceWatcher::watch_flux_deployments`]), collected until its
+/// initial list completes or `timeout` passes.
+pub async fn run_check(
+    client: &kube::Client,
+    controller_namespace: &str,
+    timeout: Duration,
+) -> CheckReport {
+    let mut report = CheckReport::default();
+    if let Err(e) = super::health::check_connectivity(client, timeout).await {
+        report.checks.push(CheckItem::new(
+            "connectivity",
+            "api-server",
+            CheckStatus::Failed,
+            e.to_string(),
+        ));
+        return report;
+    }
+    report.checks.push(CheckItem::new(
+        "connectivity",
+        "api-server",
+        CheckStatus::Ok,
+        "reachable",
+    ));
+
+    let deployments = collect_controller_deployments(client, controller_namespace, timeout).await;
+    report.flux_version = deployments.iter().find_map(flux_bundle_version);
+    report
+        .checks
+        .extend(check_controllers(&deployments, controller_namespace));
+
+    let mut flux_report_installed = false;
+    for kind in FluxResourceKind::all() {
+        match fetch_crd(client, *kind).await {
+            Ok(crd) => {
+                flux_report_installed |= *kind == FluxResourceKind::FluxReport && crd.is_some();
+                report.checks.extend(check_crd(*kind, crd.as_ref()));
+            }
+            Err(e) => report.checks.push(CheckItem::new(
+                "crd",
+                kind.as_str(),
+                CheckStatus::Warning,
+                format!("could not read CRD: {:#}", e),
+            )),
+        }
+    }
+
+    if flux_report_installed {
+        match crate::kube::fetch::list_resources(client, FluxResourceKind::FluxReport, None, None)
+            .await
+        {
+            Ok(reports) => {
+                if let Some(flux_report) = reports.first() {
+                    report.distribution_version = flux_report
+                        .pointer("/spec/distribution/version")
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string);
+                    report.checks.push(check_flux_report(flux_report));
+                }
+            }
+            Err(e) => report.checks.push(CheckItem::new(
+                "fluxreport",
+                "flux",
+                CheckStatus::Warning,
+                format!("could not list FluxReports: {:#}", e),
+            )),
+        }
+    }
+    report
+}
+
+/// Fetch a Flux kind's CRD as JSON, `None` when it is not installed.
+async fn fetch_crd(
+    client: &kube::Client,
+    kind: FluxResourceKind,
+) -> Result<Option<serde_json::Value>> {
+    let (group, _, plural) = crate::kube::api::get_gvk_for_resource_type(kind.as_str())?;
+    let api: Api<CustomResourceDefinition> = Api::all(client.clone());
+    match api.get_opt(&format!("{}.{}", plural, group)).await? {
+        Some(crd) => Ok(Some(serde_json::to_value(crd)?)),
+        None => Ok(None),
+    }
+}
+
+/// Gather the controller Deployments the header watcher sees.
+async fn collect_controller_deployments(
+    client: &kube::Client,
+    controller_namespace: &str,
+    timeout: Duration,
+) -> Vec<serde_json::Value> {
+    let (mut watcher, mut rx) = ResourceWatcher::new(
+        client.clone(),
+        None,
+        controller_namespace.to_string(),
+        false,
+    );
+    let mut deployments = Vec::new();
+    if let Err(e) = watcher.watch_flux_deployments() {
+        tracing::warn!("Failed to start controller deployment watcher: {}", e);
+        return deployments;
+    }
+
+    let deadline = tokio::time::Instant::now() + timeout;
+    while let Ok(Some(event)) = tokio::time::timeout_at(deadline, rx.recv()).await {
+        match event {
+            WatchEvent::DeploymentApplied(deployment) => deployments.push(deployment),
+            WatchEvent::InitialSyncDone(_) => break,
+            _ => {}
+        }
+    }
+    watcher.stop();
+    deployments.sort_by(|a, b| {
+        a["metadata"]["name"]
+            .as_str()
+            .cmp(&b["metadata"]["name"].as_str())
+    });
+    deployments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn deployment(name: &str, version: &str, available: &str, ready: i64) -> serde_json::Value {
+        json!({
+            "metadata": {"name": name, "labels": {"app.kubernetes.io/version": version}},
+            "spec": {"replicas": 1},
+            "status": {
+                "readyReplicas": ready,
+                "conditions": [{"type": "Available", "status": available, "message": "MinimumReplicasUnavailable"}]
+            }
+        })
+    }
+
+    fn crd(versions: serde_json::Value, stored: &[&str]) -> serde_json::Value {
+        json!({"spec": {"versions": versions}, "status": {"storedVersions": stored}})
+    }
+
+    #[test]
+    fn controllers_must_be_available() {
+        let items = check_controllers(
+            &[
+                deployment("source-controller", "v2.7.5", "True", 1),
+                deployment("kustomize-controller", "v2.7.5", "False", 0),
+            ],
+            "flux-system",
+        );
+        assert_eq!(items[0].status, CheckStatus::Ok);
+        assert_eq!(items[1].status, CheckStatus::Failed);
+        assert_eq!(items[1].message, "MinimumReplicasUnavailable");
+        assert_eq!(items.len(), 2);
+
+        let none = check_controllers(&[], "flux-system");
+        assert_eq!(none[0].status, CheckStatus::Failed);
+    }
+
+    #[test]
+    fn mixed_bundle_versions_warn() {
+        let items = check_controllers(
+            &[
+                deployment("source-controller", "v2.7.5", "True", 1),
+                deployment("helm-controller", "v2.6.0", "True", 1),
+            ],
+            "flux-system",
+        );
+        let bundle = items.last().unwrap();
+        assert_eq!(bundle.status, CheckStatus::Warning);
+        assert!(bundle.message.contains("v2.6.0, v2.7.5"));
+    }
+
+    #[test]
+    fn crd_serving_expected_version_is_ok() {
+        let installed = crd(
+            json!([{"name": "v1", "served": true, "storage": true}]),
+            &["v1"],
+        );
+        let items = check_crd(FluxResourceKind::Kustomization, Some(&installed));
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].status, CheckStatus::Ok);
+
+        let missing = check_crd(FluxResourceKind::ImagePolicy, None);
+        assert_eq!(missing[0].status, CheckStatus::Info);
+    }
+
+    #[test]
+    fn crd_on_older_flux_warns_and_unknown_versions_fail() {
+        let older = crd(
+            json!([{"name": "v2beta2", "served": true, "storage": true}]),
+            &["v2beta2"],
+        );
+        let items = check_crd(FluxResourceKind::HelmRelease, Some(&older));
+        assert_eq!(items[0].status, CheckStatus::Warning);
+        assert!(items[0].message.contains("cluster serves v2beta2"));
+
+        let unknown = crd(
+            json!([{"name": "v9", "served": true, "storage": true}]),
+            &["v9"],
+        );
+        let items = check_crd(FluxResourceKind::Kustomization, Some(&unknown));
+        assert_eq!(items[0].status, CheckStatus::Failed);
+    }
+
+    #[test]
+    fn deprecated_stored_versions_are_flagged() {
+        let installed = crd(
+            json!([
+                {"name": "v2", "served": true, "storage": true},
+                {"name": "v2beta1", "served": true, "storage": false, "deprecated": true}
+            ]),
+            &["v2beta1", "v2"],
+        );
+        let items = check_crd(FluxResourceKind::HelmRelease, Some(&installed));
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[1].status, CheckStatus::Warning);
+        assert!(items[1].message.contains("v2beta1"));
+    }
+
+    #[test]
+    fn flux_report_ready_condition() {
+        let ready = json!({
+            "metadata": {"name": "flux"},
+            "spec": {"distribution": {"version": "v2.7.5", "status": "Installed"}},
+            "status": {"conditions": [{"type": "Ready", "status": "True"}]}
+        });
+        let item = check_flux_report(&ready);
+        assert_eq!(item.status, CheckStatus::Ok);
+        assert_eq!(item.message, "distribution v2.7.5 (Installed)");
+
+        let failing = json!({
+            "metadata": {"name": "flux"},
+            "status": {"conditions": [{"type": "Ready", "status": "False", "message": "reconciliation failed"}]}
+        });
+        let item = check_flux_report(&failing);
+        assert_eq!(item.status, CheckStatus::Failed);
+        assert!(item.message.ends_with("reconciliation failed"));
+    }
+
+    #[test]
+    fn exit_code_reflects_worst_status() {
+        let mut report = CheckReport::default();
+        assert_eq!(report.exit_code(), EXIT_OK);
+        report.checks.push(CheckItem::new(
+            "crd",
+            "ImagePolicy",
+            CheckStatus::Info,
+            "not installed",
+        ));
+        assert_eq!(report.exit_code(), EXIT_OK);
+        report.checks.push(CheckItem::new(
+            "crd",
+            "HelmRelease",
+            CheckStatus::Warning,
+            "old",
+        ));
+        assert_eq!(report.exit_code(), EXIT_WARNING);
+        report.checks.push(CheckItem::new(
+            "controller",
+            "x",
+            CheckStatus::Failed,
+            "down",
+        ));
+        assert_eq!(report.exit_code(), EXIT_FAILED);
+        report.checks.push(CheckItem::new(
+            "connectivity",
+            "api-server",
+            CheckStatus::Failed,
+            "unreachable",
+        ));
+        assert_eq!(report.exit_code(), EXIT_UNREACHABLE);
+    }
+}
@@ -0,0 +1,447 @@
+//! Live-vs-applied spec diff
+//!
+//! Compares a Flux object's live `spec` with the version that was last
+//! applied to it, so edits made by hand (for example with `e`, which applies
+//! as the `flux9s` field manager) can be spotted before the next reconcile
+//! reverts them.
+//!
+//! Two baselines are supported, tried in order:
+//!
+//! 1. The `kubectl.kubernetes.io/last-applied-configuration` annotation left
+//!    by `kubectl apply`.
+//! 2. The fields the parent Kustomization applied. kustomize-controller uses
+//!    server-side apply, so `metadata.managedFields` records which fields it
+//!    owns; fields owned only by other managers are what diverged from Git.
+//!    Their previous value is not recorded, so they show up as additions.
+
+use serde_json::Value;
+
+/// Annotation written by `kubectl apply`
+pub const LAST_APPLIED_ANNOTATION: &str = "kubectl.kubernetes.io/last-applied-configuration";
+
+/// Field manager kustomize-controller applies with
+const KUSTOMIZE_MANAGER: &str = "kustomize-controller";
+
+/// Labels kustomize-controller stamps on the objects it applies
+const KUSTOMIZE_NAME_LABEL: &str = "kustomize.toolkit.fluxcd.io/name";
+const KUSTOMIZE_NAMESPACE_LABEL: &str = "kustomize.toolkit.fluxcd.io/namespace";
+
+/// What the live spec was compared against
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffBaseline {
+    /// The `kubectl.kubernetes.io/last-applied-configuration` annotation
+    LastApplied,
+    /// The fields the parent Kustomization still owns
+    Kustomization {
+        namespace: Option<String>,
+        name: Option<String>,
+    },
+}
+
+impl DiffBaseline {
+    /// Human-readable description, used in the diff header.
+    pub fn label(&self) -> String {
+        match self {
+            DiffBaseline::LastApplied => "last-applied-configuration".to_string(),
+            DiffBaseline::Kustomization {
+                namespace: Some(namespace),
+                name: Some(name),
+            } => format!("applied by Kustomization {}/{}", namespace, name),
+            DiffBaseline::Kustomization { .. } => "applied by kustomize-controller".to_string(),
+        }
+    }
+}
+
+/// Kind of a line in a unified diff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    /// `---` / `+++` file header
+    Header,
+    /// `@@ … @@` hunk header
+    Hunk,
+    /// Unchanged line shown for context
+    Context,
+    /// Line only in the live spec
+    Added,
+    /// Line only in the applied baseline
+    Removed,
+}
+
+/// One line of a unified diff, including its `+`/`-`/` ` prefix
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub text: String,
+}
+
+/// Result of comparing a live spec with its applied baseline
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecDiff {
+    pub baseline: DiffBaseline,
+    /// Unified diff lines; empty when the live spec matches the baseline
+    pub lines: Vec<DiffLine>,
+}
+
+impl SpecDiff {
+    /// Whether the live spec diverged from the baseline.
+    pub fn has_drift(&self) -> bool {
+        self.lines
+            .iter()
+            .any(|line| matches!(line.kind, DiffLineKind::Added | DiffLineKind::Removed))
+    }
+}
+
+/// Diff a full live object (as fetched, including `metadata.managedFields`)
+/// against its applied baseline.
+///
+/// Errors when the object has neither baseline: it was not applied with
+/// `kubectl apply` and is not managed by a Kustomization.
+pub fn spec_diff(obj: &Value) -> anyhow::Result<SpecDiff> {
+    let live = spec_of(obj);
+    let (baseline, applied) = if let Some(applied) = last_applied_spec(obj)? {
+        (DiffBaseline::LastApplied, applied)
+    } else if let Some(applied) = kustomization_applied_spec(obj) {
+        let label = |key: &str| {
+            obj.pointer("/metadata/labels")
+                .and_then(|labels| labels.get(key))
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        };
+        (
+            DiffBaseline::Kustomization {
+                namespace: label(KUSTOMIZE_NAMESPACE_LABEL),
+                name: label(KUSTOMIZE_NAME_LABEL),
+            },
+            applied,
+        )
+    } else {
+        anyhow::bail!(
+            "No applied baseline: no {} annotation and not managed by a Kustomization",
+            LAST_APPLIED_ANNOTATION
+        );
+    };
+
+    let to_yaml = |spec: &Value| {
+        serde_yaml::to_string(&serde_json::json!({ "spec": spec })).unwrap_or_default()
+    };
+    let lines = unified_diff(
+        &to_yaml(&applied),
+        &to_yaml(&live),
+        &baseline.label(),
+        "live",
+    );
+    Ok(SpecDiff { baseline, lines })
+}
+
+/// The object's `spec`, or an empty map when it has none.
+fn spec_of(obj: &Value) -> Value {
+    obj.get("spec")
+        .cloned()
+        .unwrap_or_else(|| Value::Object(Default::default()))
+}
+
+/// The `spec` recorded in the last-applied-configuration annotation.
+fn last_applied_spec(obj: &Value) -> anyhow::Result<Option<Value>> {
+    let Some(raw) = obj
+        .pointer("/metadata/annotations")
+        .and_then(|annotations| annotations.get(LAST_APPLIED_ANNOTATION))
+        .and_then(Value::as_str)
+    else {
+        return Ok(None);
+    };
+    let applied: Value = serde_json::from_str(raw)
+        .map_err(|e| anyhow::anyhow!("Invalid {} annotation: {}", LAST_APPLIED_ANNOTATION, e))?;
+    Ok(Some(spec_of(&applied)))
+}
+
+/// The live spec minus the fields that other field managers own and
+/// kustomize-controller does not. `None` when kustomize-controller never
+/// applied the object.
+fn kustomization_applied_spec(obj: &Value) -> Option<Value> {
+    let entries = obj
+        .pointer("/metadata/managedFields")
+        .and_then(Value::as_array)?;
+
+    let mut kustomize_paths = Vec::new();
+    let mut other_paths = Vec::new();
+    let mut applied_by_kustomize = false;
+    for entry in entries {
+        // Status writes never touch spec
+        if entry.get("subresource").and_then(Value::as_str) == Some("status") {
+            continue;
+        }
+        let Some(spec_fields) = entry.pointer("/fieldsV1/f:spec") else {
+            continue;
+        };
+        let manager = entry.get("manager").and_then(Value::as_str).unwrap_or("");
+        if manager == KUSTOMIZE_MANAGER {
+            applied_by_kustomize = true;
+            collect_owned_paths(spec_fields, &mut Vec::new(), &mut kustomize_paths);
+        } else {
+            collect_owned_paths(spec_fields, &mut Vec::new(), &mut other_paths);
+        }
+    }
+    if !applied_by_kustomize {
+        return None;
+    }
+
+    let mut applied = spec_of(obj);
+    for path in &other_paths {
+        // Shared with kustomize-controller, nested under a value it owns
+        // whole, or a parent of fields it owns: the applied value is still live
+        let shared = kustomize_paths.iter().any(|owned| {
+            owned.path.starts_with(&path.path) || (owned.leaf && path.path.starts_with(&owned.path))
+        });
+        if !shared {
+            remove_path(&mut applied, &path.path);
+        }
+    }
+    Some(applied)
+}
+
+/// One step into a managed-fields path
+#[derive(Debug, Clone, PartialEq)]
+enum PathSegment {
+    /// `f:<name>`: an object field
+    Field(String),
+    /// `k:{...}`: the list item whose fields match
+    Key(serde_json::Map<String, Value>),
+    /// `v:<json>`: the set item equal to the value
+    Value(Value),
+    /// `i:<n>`: the list item at an index
+    Index(usize),
+}
+
+impl PathSegment {
+    fn parse(raw: &str) -> Option<Self> {
+        let (prefix, rest) = raw.split_at_checked(2)?;
+        match prefix {
+            "f:" => Some(PathSegment::Field(rest.to_string())),
+            "k:" => match serde_json::from_str(rest).ok()? {
+                Value::Object(map) => Some(PathSegment::Key(map)),
+                _ => None,
+            },
+            "v:" => serde_json::from_str(rest).ok().map(PathSegment::Value),
+            "i:" => rest.parse().ok().map(PathSegment::Index),
+            _ => None,
+        }
+    }
+}
+
+/// A path marked as owned in a `fieldsV1` tree
+#[derive(Debug, Clone, PartialEq)]
+struct OwnedPath {
+    path: Vec<PathSegment>,
+    /// Owned as a whole value rather than as a node with owned children
+    leaf: bool,
+}
+
+/// Collect the paths a `fieldsV1` tree marks as owned: leaves, and nodes
+/// with a `.` entry (the node itself is owned).
+fn collect_owned_paths(fields: &Value, prefix: &mut Vec<PathSegment>, out: &mut Vec<OwnedPath>) {
+    let Some(map) = fields.as_object() else {
+        return;
+    };
+    let children: Vec<(&String, &Value)> = map.iter().filter(|(key, _)| *key != ".").collect();
+    if !prefix.is_empty() && (children.is_empty() || map.contains_key(".")) {
+        out.push(OwnedPath {
+            path: prefix.clone(),
+            leaf: children.is_empty(),
+        });
+    }
+    for (key, child) in children {
+        if let Some(segment) = PathSegment::parse(key) {
+            prefix.push(segment);
+            collect_owned_paths(child, prefix, out);
+            prefix.pop();
+        }
+    }
+}
+
+/// Remove the value at a managed-fields path, if it still exists.
+fn remove_path(value: &mut Value, path: &[PathSegment]) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let mut current = value;
+    for segment in parents {
+        let Some(next) = child_mut(current, segment) else {
+            return;
+        };
+        current = next;
+    }
+    match (current, last) {
+        (Value::Object(map), PathSegment::Field(name)) => {
+            map.remove(name);
+        }
+        (Value::Array(items), segment) => {
+            if let Some(idx) = list_index(items, segment) {
+                items.remove(idx);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn child_mut<'a>(value: &'a mut Value, segment: &PathSegment) -> Option<&'a mut Value> {
+    match (value, segment) {
+        (Value::Object(map), PathSegment::Field(name)) => map.get_mut(name),
+        (Value::Array(items), segment) => {
+            let idx = list_index(items, segment)?;
+            items.get_mut(idx)
+        }
+        _ => None,
+    }
+}
+
+fn list_index(items: &[Value], segment: &PathSegment) -> Option<usize> {
+    match segment {
+        PathSegment::Key(keys) => items
+            .iter()
+            .position(|item| keys.iter().all(|(k, v)| item.get(k) == Some(v))),
+        PathSegment::Value(expected) => items.iter().position(|item| item == expected),
+        PathSegment::Index(idx) => (*idx < items.len()).then_some(*idx),
+        PathSegment::Field(_) => None,
+    }
+}
+
+/// Unified diff of two texts, line by line, with three lines of context.
+///
+/// Returns no lines at all (not even the `---`/`+++` header) when the texts
+/// are identical.
+pub fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str) -> Vec<DiffLine> {
+    let diff = similar::TextDiff::from_lines(old, new);
+
+    let mut lines = Vec::new();
+    for hunk in diff.unified_diff().context_radius(3).iter_hunks() {
+        if lines.is_empty() {
+            lines.push(DiffLine {
+                kind: DiffLineKind::Header,
+                text: format!("--- {}", old_label),
+            });
+            lines.push(DiffLine {
+                kind: DiffLineKind::Header,
+                text: format!("+++ {}", new_label),
+            });
+        }
+        lines.push(DiffLine {
+            kind: DiffLineKind::Hunk,
+            text: hunk.header().to_string(),
+        });
+        for change in hunk.iter_changes() {
+            let (kind, sign) = match change.tag() {
+                similar::ChangeTag::Equal => (DiffLineKind::Context, ' '),
+                similar::ChangeTag::Insert => (DiffLineKind::Added, '+'),
+                similar::ChangeTag::Delete => (DiffLineKind::Removed, '-'),
+            };
+            lines.push(DiffLine {
+                kind,
+                text: format!("{}{}", sign, change.value().trim_end_matches('\n')),
+            });
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn texts(diff: &SpecDiff, kind: DiffLineKind) -> Vec<&str> {
+        diff.lines
+            .iter()
+            .filter(|line| line.kind == kind)
+            .map(|line| line.text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn diffs_against_last_applied_configuration() {
+        let applied = json!({"spec": {"interval": "10m", "path": "./apps"}});
+        let obj = json!({
+            "metadata": {"annotations": {LAST_APPLIED_ANNOTATION: applied.to_string()}},
+            "spec": {"interval": "1m", "path": "./apps"}
+        });
+        let diff = spec_diff(&obj).unwrap();
+        assert_eq!(diff.baseline, DiffBaseline::LastApplied);
+        assert!(diff.has_drift());
+        assert_eq!(texts(&diff, DiffLineKind::Removed), ["-  interval: 10m"]);
+        assert_eq!(texts(&diff, DiffLineKind::Added), ["+  interval: 1m"]);
+        assert_eq!(diff.lines[0].text, "--- last-applied-configuration");
+        assert_eq!(diff.lines[1].text, "+++ live");
+        assert!(diff.lines[2].text.starts_with("@@"));
+    }
+
+    #[test]
+    fn fields_taken_over_from_kustomize_controller_show_as_drift() {
+        let obj = json!({
+            "metadata": {
+                "labels": {
+                    KUSTOMIZE_NAME_LABEL: "apps",
+                    KUSTOMIZE_NAMESPACE_LABEL: "flux-system"
+                },
+                "managedFields": [
+                    {"manager": "kustomize-controller", "operation": "Apply", "fieldsV1": {
+                        "f:spec": {"f:interval": {}, "f:chart": {"f:spec": {"f:chart": {}}},
+                            "f:values": {"f:env": {"k:{\"name\":\"A\"}": {".": {}, "f:name": {}}}}}
+                    }},
+                    {"manager": "flux9s", "operation": "Apply", "fieldsV1": {
+                        "f:spec": {"f:suspend": {}, "f:chart": {"f:spec": {"f:chart": {}}},
+                            "f:values": {"f:replicas": {},
+                                "f:env": {"k:{\"name\":\"A\"}": {"f:value": {}}}}}
+                    }},
+                    {"manager": "helm-controller", "operation": "Update",
+                        "subresource": "status", "fieldsV1": {"f:status": {"f:ready": {}}}}
+                ]
+            },
+            "spec": {
+                "interval": "5m",
+                "suspend": true,
+                "chart": {"spec": {"chart": "podinfo"}},
+                "values": {"replicas": 3, "env": [{"name": "A", "value": "x"}]}
+            }
+        });
+        let diff = spec_diff(&obj).unwrap();
+        assert_eq!(
+            diff.baseline,
+            DiffBaseline::Kustomization {
+                namespace: Some("flux-system".to_string()),
+                name: Some("apps".to_string())
+            }
+        );
+        assert_eq!(
+            diff.lines[0].text,
+            "--- applied by Kustomization flux-system/apps"
+        );
+        assert!(texts(&diff, DiffLineKind::Removed).is_empty());
+        // Shared fields (chart) stay; fields only flux9s owns are added
+        assert_eq!(
+            texts(&diff, DiffLineKind::Added),
+            ["+  suspend: true", "+      value: x", "+    replicas: 3"]
+        );
+    }
+
+    #[test]
+    fn unchanged_or_unmanaged_objects() {
+        let obj = json!({
+            "metadata": {"managedFields": [
+                {"manager": "kustomize-controller", "operation": "Apply",
+                    "fieldsV1": {"f:spec": {"f:interval": {}}}}
+            ]},
+            "spec": {"interval": "5m"}
+        });
+        let diff = spec_diff(&obj).unwrap();
+        assert!(!diff.has_drift());
+        assert!(diff.lines.is_empty());
+        assert_eq!(diff.baseline.label(), "applied by kustomize-controller");
+
+        let unmanaged = json!({
+            "metadata": {"managedFields": [
+                {"manager": "flux", "operation": "Apply", "fieldsV1": {"f:spec": {"f:interval": {}}}}
+            ]},
+            "spec": {"interval": "5m"}
+        });
+        assert!(spec_diff(&unmanaged).is_err());
+    }
+}
@@ -46,6 +46,62 @@ pub async fn fetch_resource_yaml(
     fetch_resource(client, resource_type, namespace, name).await
 }
 
+/// List every resource of a Flux kind, optionally filtered by a label selector.
+///
+/// `namespace = None` lists across all namespaces. Served API versions are
+/// probed newest-first (like the watchers), so clusters on older Flux
+/// releases still answer. The selector is evaluated server-side, so the full
+/// Kubernetes syntax (`team=a`, `tier!=db`, `env in (a,b)`) is supported.
+pub async fn list_resources(
+    client: &kube::Client,
+    kind: crate::models::FluxResourceKind,
+    namespace: Option<&str>,
+    label_selector: Option<&str>,
+) -> anyhow::Result<Vec<serde_json::Value>> {
+    use kube::api::ListParams;
+
+    let mut params = ListParams::default();
+    if let Some(selector) = label_selector {
+        params = params.labels(selector);
+    }
+
+    let mut last_error = None;
+    for api_resource in crate::kube::api::get_flux_api_resources_with_fallback(kind)? {
+        let api: Api<DynamicObject> = match namespace {
+            Some(ns) => Api::namespaced_with(client.clone(), ns, &api_resource),
+            None => Api::all_with(client.clone(), &api_resource),
+        };
+        match api.list(&params).await {
+            Ok(list) => {
+                return list
+                    .items
+                    .iter()
+                    .map(|obj| {
+                        serde_json::to_value(obj).context("Failed to serialize listed resource")
+                    })
+                    .collect();
+            }
+            Err(e) if crate::kube::api::is_version_missing_error(&e.to_string()) => {
+                tracing::debug!(
+                    "{} version {} not served, trying next",
+                    kind.as_str(),
+                    api_resource.version
+                );
+                last_error = Some(e);
+            }
+            Err(e) => {
+                return Err(e).with_context(|| format!("Failed to list {}", kind.as_str()));
+            }
+        }
+    }
+
+    Err(match last_error {
+        Some(e) => anyhow::anyhow!(e),
+        None => anyhow::anyhow!("no API versions to try"),
+    })
+    .with_context(|| format!("{} is not served by this cluster", kind.as_str()))
+}
+
 /// Payload backing the describe view: the full object plus its Kubernetes
 /// Events, fetched together so the view renders in one pass.
 #[derive(Debug, Clone)]
@@ -0,0 +1,489 @@
+//! Helm release storage
+//!
+//! helm-controller stores every revision of a release the way the Helm CLI
+//! does: one `sh.helm.release.v1.<release>.v<revision>` Secret per revision
+//! in the storage namespace, labelled `owner=helm,name=<release>`. The
+//! `release` key holds the release JSON, gzipped and base64-encoded (on top
+//! of the Secret's own encoding).
+//!
+//! Decoding those Secrets answers what `helm get values|notes|hooks` and
+//! `helm history` would, without leaving flux9s.
+
+use anyhow::{Context, Result, bail};
+use k8s_openapi::api::core::v1::Secret;
+use kube::Api;
+use kube::api::ListParams;
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::diff::{DiffLine, DiffLineKind, unified_diff};
+use super::helm_values::{EffectiveValues, SecretRedaction};
+
+/// One revision of a Helm release, as decoded from its storage Secret
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct StoredRelease {
+    pub name: String,
+    pub namespace: String,
+    /// Revision number
+    pub version: i64,
+    pub info: ReleaseInfo,
+    pub chart: Chart,
+    /// User-supplied values the revision was installed or upgraded with
+    pub config: Value,
+    /// Rendered manifest, multi-document YAML
+    pub manifest: String,
+    pub hooks: Vec<ReleaseHook>,
+}
+
+/// Status of a revision
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ReleaseInfo {
+    pub first_deployed: Option<String>,
+    pub last_deployed: Option<String>,
+    pub description: String,
+    /// `deployed`, `superseded`, `failed`, `pending-upgrade`, ...
+    pub status: String,
+    /// Rendered NOTES.txt
+    pub notes: String,
+}
+
+/// The chart a revision was rendered from. Templates are not kept.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Chart {
+    pub metadata: ChartMetadata,
+    /// The chart's default `values.yaml`
+    pub values: Value,
+}
+
+/// `Chart.yaml` of a chart
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ChartMetadata {
+    pub name: String,
+    pub version: String,
+    pub app_version: Option<String>,
+    pub description: Option<String>,
+}
+
+/// A chart hook of a revision
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ReleaseHook {
+    pub name: String,
+    pub kind: String,
+    /// Template the hook was rendered from
+    pub path: String,
+    /// `pre-install`, `post-upgrade`, `test`, ...
+    pub events: Vec<String>,
+    pub weight: i64,
+    pub last_run: HookRun,
+}
+
+/// Last execution of a hook; empty when it never ran
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct HookRun {
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    /// `Succeeded`, `Failed`, `Running`, or empty
+    pub phase: String,
+}
+
+impl StoredRelease {
+    /// The user-supplied values as YAML, `{}` when there are none.
+    pub fn values_yaml(&self, redaction: SecretRedaction) -> String {
+        match self.config {
+            Value::Null => "{}\n".to_string(),
+            Value::Object(ref map) if map.is_empty() => "{}\n".to_string(),
+            ref values => yaml_text(&redaction.values(values)),
+        }
+    }
+
+    /// The values the revision was rendered with: the user-supplied values
+    /// coalesced over the chart defaults, as `helm get values --all` shows.
+    pub fn merged_values(&self) -> Value {
+        coalesce_values(&self.chart.values, &self.config)
+    }
+
+    /// `name-version` of the chart, as `helm history` shows it.
+    pub fn chart_label(&self) -> String {
+        format!(
+            "{}-{}",
+            self.chart.metadata.name, self.chart.metadata.version
+        )
+    }
+}
+
+/// Every stored revision of the release behind a HelmRelease
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReleaseInspection {
+    pub release_name: String,
+    pub storage_namespace: String,
+    /// Newest revision first
+    pub revisions: Vec<StoredRelease>,
+    /// Why the revisions could not be read, if they could not
+    pub storage_error: Option<String>,
+    /// What the HelmRelease's `valuesFrom` and `values` resolve to now
+    pub effective_values: EffectiveValues,
+}
+
+impl ReleaseInspection {
+    /// The deployed revision, or the newest one if none is deployed (for
+    /// example after a failed install).
+    pub fn current(&self) -> Option<&StoredRelease> {
+        self.revisions
+            .iter()
+            .find(|release| release.info.status == "deployed")
+            .or_else(|| self.revisions.first())
+    }
+}
+
+/// Changes between two revisions of a release
+#[derive(Debug, Clone, PartialEq)]
+pub struct RevisionDiff {
+    pub from: i64,
+    pub to: i64,
+    /// Unified diff of the merged values, then of the rendered manifest
+    pub lines: Vec<DiffLine>,
+}
+
+impl RevisionDiff {
+    /// Diff the merged values and the manifest of `from` against `to`.
+    /// Redacted Secret values that changed read `<redacted, changed>` in
+    /// `to`, so the diff never reports a hidden change as no change.
+    pub fn between(from: &StoredRelease, to: &StoredRelease, redaction: SecretRedaction) -> Self {
+        let mut lines = Vec::new();
+        let (from_values, to_values) = (from.merged_values(), to.merged_values());
+        let sections = [
+            (
+                "values",
+                yaml_text(&redaction.values_against(&from_values, &from_values)),
+                yaml_text(&redaction.values_against(&to_values, &from_values)),
+            ),
+            (
+                "manifest",
+                redaction.manifest_against(&from.manifest, &from.manifest),
+                redaction.manifest_against(&to.manifest, &from.manifest),
+            ),
+        ];
+        for (section, old, new) in sections {
+            let diff = unified_diff(
+                &old,
+                &new,
+                &format!("{} v{}", section, from.version),
+                &format!("{} v{}", section, to.version),
+            );
+            if diff.is_empty() {
+                lines.push(DiffLine {
+                    kind: DiffLineKind::Header,
+                    text: format!("=== {} unchanged", section),
+                });
+            }
+            lines.extend(diff);
+        }
+        Self {
+            from: from.version,
+            to: to.version,
+            lines,
+        }
+    }
+}
+
+fn yaml_text(values: &Value) -> String {
+    serde_yaml::to_string(values).unwrap_or_else(|e| format!("# {}\n", e))
+}
+
+/// Coalesce user values over chart defaults like Helm: maps merge key by
+/// key, any other user value replaces the default, and a user `null` deletes
+/// the key.
+pub fn coalesce_values(defaults: &Value, overrides: &Value) -> Value {
+    match (defaults, overrides) {
+        (Value::Object(defaults), Value::Object(overrides)) => {
+            let mut merged = defaults.clone();
+            for (key, value) in overrides {
+                if value.is_null() {
+                    merged.remove(key);
+                    continue;
+                }
+                let coalesced = match merged.get(key) {
+                    Some(default) => coalesce_values(default, value),
+                    None => value.clone(),
+                };
+                merged.insert(key.clone(), coalesced);
+            }
+            Value::Object(merged)
+        }
+        (defaults, Value::Null) => defaults.clone(),
+        (_, overrides) => overrides.clone(),
+    }
+}
+
+/// Decode the `release` key of a Helm storage Secret: base64, then gzip when
+/// compressed, then JSON.
+pub fn decode_release(data: &[u8]) -> Result<Value> {
+    use base64::Engine;
+    let decoded = base64::engine::general_purpose::STANDARD
+        .decode(data)
+        .context("Failed to decode base64 release data")?;
+
+    // Check for gzip magic bytes (0x1f, 0x8b, 0x08)
+    let is_gzipped =
+        decoded.len() >= 3 && decoded[0] == 0x1f && decoded[1] == 0x8b && decoded[2] == 0x08;
+
+    let decompressed = if is_gzipped {
+        use std::io::Read;
+        let mut decoder = flate2::read::GzDecoder::new(&decoded[..]);
+        let mut buf = Vec::new();
+        decoder
+            .read_to_end(&mut buf)
+            .context("Failed to decompress gzip release data")?;
+        buf
+    } else {
+        decoded
+    };
+
+    serde_json::from_slice(&decompressed).context("Failed to parse release JSON")
+}
+
+/// Release name and storage namespace of a HelmRelease object.
+///
+/// The latest `status.history` entry is authoritative; before the first
+/// install the name follows helm-controller's defaulting (`spec.releaseName`,
+/// else `[targetNamespace-]name`), and storage falls back from
+/// `storageNamespace` to `targetNamespace` to the HelmRelease's namespace.
+pub fn release_location(obj: &Value, namespace: &str) -> (String, String) {
+    let spec = obj.get("spec");
+    let status = obj.get("status");
+    let str_at = |v: Option<&Value>, field: &str| {
+        v.and_then(|v| v.get(field))
+            .and_then(Value::as_str)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    };
+    let target_namespace = str_at(spec, "targetNamespace");
+
+    let latest = status
+        .and_then(|s| s.get("history"))
+        .and_then(Value::as_array)
+        .and_then(|history| history.first());
+    let release_name = str_at(latest, "name")
+        .or_else(|| str_at(spec, "releaseName"))
+        .unwrap_or_else(|| {
+            let name = str_at(obj.get("metadata"), "name").unwrap_or_default();
+            match target_namespace {
+                Some(ref target) => format!("{}-{}", target, name),
+                None => name,
+            }
+        });
+    let storage_namespace = str_at(status, "storageNamespace")
+        .or_else(|| str_at(spec, "storageNamespace"))
+        .or(target_namespace)
+        .unwrap_or_else(|| namespace.to_string());
+    (release_name, storage_namespace)
+}
+
+/// Fetch and decode every stored revision of a HelmRelease's release, and
+/// resolve its effective values.
+///
+/// The effective values are read even when the revisions cannot be: a
+/// release whose `valuesFrom` is broken is never installed.
+pub async fn fetch_release_inspection(
+    client: &kube::Client,
+    helmrelease_obj: &Value,
+    namespace: &str,
+) -> Result<ReleaseInspection> {
+    let (release_name, storage_namespace) = release_location(helmrelease_obj, namespace);
+    let effective_values =
+        super::helm_values::resolve_effective_values(client, helmrelease_obj, namespace).await;
+
+    let remote = helmrelease_obj
+        .get("spec")
+        .and_then(|spec| spec.get("kubeConfig"))
+        .is_some();
+    let stored = if remote {
+        Err(anyhow::anyhow!(
+            "The release is stored in a remote cluster (spec.kubeConfig)"
+        ))
+    } else {
+        fetch_revisions(client, &release_name, &storage_namespace).await
+    };
+    let (revisions, storage_error) = match stored {
+        Ok(revisions) => (revisions, None),
+        Err(e) => (Vec::new(), Some(format!("{:#}", e))),
+    };
+
+    Ok(ReleaseInspection {
+        release_name,
+        storage_namespace,
+        revisions,
+        storage_error,
+        effective_values,
+    })
+}
+
+/// The decoded revisions of a release, newest first.
+async fn fetch_revisions(
+    client: &kube::Client,
+    release_name: &str,
+    storage_namespace: &str,
+) -> Result<Vec<StoredRelease>> {
+    let secrets: Api<Secret> = Api::namespaced(client.clone(), storage_namespace);
+    let params = ListParams::default().labels(&format!("owner=helm,name={}", release_name));
+    let list = secrets.list(&params).await.with_context(|| {
+        format!(
+            "Failed to list Helm storage Secrets for release {}/{}",
+            storage_namespace, release_name
+        )
+    })?;
+
+    let mut revisions = Vec::new();
+    for secret in list.items {
+        let secret_name = secret.metadata.name.clone().unwrap_or_default();
+        let Some(data) = secret.data.as_ref().and_then(|data| data.get("release")) else {
+            tracing::debug!("Helm storage Secret {} has no release key", secret_name);
+            continue;
+        };
+        match decode_release(&data.0)
+            .and_then(|json| serde_json::from_value(json).context("Unexpected release JSON"))
+        {
+            Ok(release) => revisions.push(release),
+            Err(e) => tracing::warn!("Failed to decode Helm release {}: {:#}", secret_name, e),
+        }
+    }
+    if revisions.is_empty() {
+        bail!(
+            "No Helm storage Secrets found for release {}/{}",
+            storage_namespace,
+            release_name
+        );
+    }
+    revisions.sort_by_key(|release: &StoredRelease| std::cmp::Reverse(release.version));
+    Ok(revisions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn encode(release: &Value) -> Vec<u8> {
+        use base64::Engine;
+        use std::io::Write;
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        encoder.write_all(release.to_string().as_bytes()).unwrap();
+        let gzipped = encoder.finish().unwrap();
+        base64::engine::general_purpose::STANDARD
+            .encode(gzipped)
+            .into_bytes()
+    }
+
+    #[test]
+    fn decodes_stored_release() {
+        let data = encode(&json!({
+            "name": "podinfo",
+            "namespace": "apps",
+            "version": 3,
+            "info": {"status": "deployed", "notes": "Visit http://podinfo", "description": "Upgrade complete"},
+            "chart": {"metadata": {"name": "podinfo", "version": "6.5.0", "appVersion": "6.5.0"}, "templates": []},
+            "config": {"replicaCount": 2},
+            "manifest": "---\nkind: Service\n",
+            "hooks": [{"name": "podinfo-test", "kind": "Pod", "events": ["test"], "last_run": {"phase": "Succeeded"}}]
+        }));
+        let release: StoredRelease =
+            serde_json::from_value(decode_release(&data).unwrap()).unwrap();
+
+        assert_eq!(release.version, 3);
+        assert_eq!(release.info.status, "deployed");
+        assert_eq!(release.chart_label(), "podinfo-6.5.0");
+        assert_eq!(release.chart.metadata.app_version.as_deref(), Some("6.5.0"));
+        assert_eq!(
+            release.values_yaml(SecretRedaction::Reveal),
+            "replicaCount: 2\n"
+        );
+        assert_eq!(release.hooks[0].last_run.phase, "Succeeded");
+
+        let plain = StoredRelease::default();
+        assert_eq!(plain.values_yaml(SecretRedaction::Reveal), "{}\n");
+    }
+
+    #[test]
+    fn release_location_follows_helm_controller_defaults() {
+        let installed = json!({
+            "metadata": {"name": "podinfo"},
+            "spec": {"targetNamespace": "apps"},
+            "status": {"storageNamespace": "apps", "history": [{"name": "podinfo-custom", "version": 2}]}
+        });
+        assert_eq!(
+            release_location(&installed, "flux-system"),
+            ("podinfo-custom".to_string(), "apps".to_string())
+        );
+
+        let pending = json!({
+            "metadata": {"name": "podinfo"},
+            "spec": {"targetNamespace": "apps"}
+        });
+        assert_eq!(
+            release_location(&pending, "flux-system"),
+            ("apps-podinfo".to_string(), "apps".to_string())
+        );
+
+        let plain = json!({"metadata": {"name": "podinfo"}, "spec": {}});
+        assert_eq!(
+            release_location(&plain, "flux-system"),
+            ("podinfo".to_string(), "flux-system".to_string())
+        );
+    }
+
+    #[test]
+    fn coalesces_values_and_diffs_revisions() {
+        let defaults = json!({"replicaCount": 1, "image": {"tag": "6.4.0", "pullPolicy": "IfNotPresent"}, "ingress": {"enabled": false}});
+        let overrides = json!({"replicaCount": 2, "image": {"tag": "6.5.0"}, "ingress": null});
+        assert_eq!(
+            coalesce_values(&defaults, &overrides),
+            json!({"replicaCount": 2, "image": {"tag": "6.5.0", "pullPolicy": "IfNotPresent"}})
+        );
+        assert_eq!(coalesce_values(&defaults, &Value::Null), defaults);
+
+        let revision = |version, config: Value, manifest: &str| StoredRelease {
+            version,
+            chart: Chart {
+                values: defaults.clone(),
+                ..Chart::default()
+            },
+            config,
+            manifest: manifest.to_string(),
+            ..StoredRelease::default()
+        };
+        let v12 = revision(12, Value::Null, "kind: Service\n");
+        let v13 = revision(13, json!({"replicaCount": 3}), "kind: Service\n");
+        let diff = RevisionDiff::between(&v12, &v13, SecretRedaction::Reveal);
+        assert_eq!((diff.from, diff.to), (12, 13));
+        let texts: Vec<&str> = diff.lines.iter().map(|l| l.text.as_str()).collect();
+        assert!(texts.contains(&"--- values v12"));
+        assert!(texts.contains(&"-replicaCount: 1"));
+        assert!(texts.contains(&"+replicaCount: 3"));
+        assert_eq!(texts.last(), Some(&"=== manifest unchanged"));
+    }
+
+    #[test]
+    fn current_prefers_the_deployed_revision() {
+        let revision = |version, status: &str| StoredRelease {
+            version,
+            info: ReleaseInfo {
+                status: status.to_string(),
+                ..ReleaseInfo::default()
+            },
+            ..StoredRelease::default()
+        };
+        let mut inspection = ReleaseInspection {
+            revisions: vec![revision(3, "failed"), revision(2, "deployed")],
+            ..ReleaseInspection::default()
+        };
+        assert_eq!(inspection.current().unwrap().version, 2);
+        inspection.revisions.remove(1);
+        assert_eq!(inspection.current().unwrap().version, 3);
+    }
+}
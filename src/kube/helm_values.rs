@@ -0,0 +1,729 @@
+//! HelmRelease values inputs
+//!
+//! helm-controller builds the values it passes to Helm from
+//! `spec.valuesFrom` and `spec.values`: every ConfigMap or Secret reference
+//! in list order, then the inline values on top. A reference's `valuesKey`
+//! (default `values.yaml`) is parsed as a YAML map and merged in, or, with a
+//! `targetPath`, placed as a string at that path. Missing `optional`
+//! references are skipped; any other missing reference fails the release.
+//!
+//! Resolving the same way shows what Helm actually receives and which input
+//! each key came from.
+
+use anyhow::{Context, Result};
+use k8s_openapi::api::core::v1::{ConfigMap, Secret};
+use kube::Api;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+
+/// Default `valuesKey` of a `valuesFrom` reference
+pub const DEFAULT_VALUES_KEY: &str = "values.yaml";
+
+/// Shown in place of values that came from a Secret
+pub const REDACTED: &str = "<redacted>";
+
+/// Shown in a revision diff in place of a Secret value that changed
+pub const REDACTED_CHANGED: &str = "<redacted, changed>";
+
+/// One `spec.valuesFrom` entry
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValuesReference {
+    /// `ConfigMap` or `Secret`
+    pub kind: String,
+    pub name: String,
+    pub values_key: String,
+    pub target_path: Option<String>,
+    pub optional: bool,
+}
+
+impl ValuesReference {
+    /// The `valuesFrom` entries of a HelmRelease, in order.
+    pub fn from_helmrelease(obj: &Value) -> Vec<Self> {
+        let Some(refs) = obj
+            .get("spec")
+            .and_then(|spec| spec.get("valuesFrom"))
+            .and_then(Value::as_array)
+        else {
+            return Vec::new();
+        };
+        refs.iter()
+            .map(|r| {
+                let str_at = |field: &str| {
+                    r.get(field)
+                        .and_then(Value::as_str)
+                        .filter(|s| !s.is_empty())
+                        .map(str::to_string)
+                };
+                Self {
+                    kind: str_at("kind").unwrap_or_default(),
+                    name: str_at("name").unwrap_or_default(),
+                    values_key: str_at("valuesKey")
+                        .unwrap_or_else(|| DEFAULT_VALUES_KEY.to_string()),
+                    target_path: str_at("targetPath"),
+                    optional: r.get("optional").and_then(Value::as_bool) == Some(true),
+                }
+            })
+            .collect()
+    }
+
+    pub fn is_secret(&self) -> bool {
+        self.kind == "Secret"
+    }
+
+    /// `ConfigMap/name[key]`, plus `→ path` with a target path
+    pub fn label(&self) -> String {
+        let mut label = format!("{}/{}[{}]", self.kind, self.name, self.values_key);
+        if let Some(ref path) = self.target_path {
+            label.push_str(&format!(" → {}", path));
+        }
+        label
+    }
+}
+
+/// Where a key of the effective values came from
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValuesOrigin {
+    /// `spec.valuesFrom[index]`
+    Reference(usize),
+    /// `spec.values`
+    Inline,
+}
+
+/// Outcome of reading one `valuesFrom` reference
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputStatus {
+    Applied,
+    /// Optional and missing
+    Skipped(String),
+    /// Missing or unreadable; helm-controller would fail the release
+    Error(String),
+}
+
+/// A `valuesFrom` reference and how it resolved
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValuesInput {
+    pub reference: ValuesReference,
+    pub status: InputStatus,
+}
+
+/// The values a HelmRelease sends to Helm, with the input of every key
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EffectiveValues {
+    pub values: Value,
+    pub inputs: Vec<ValuesInput>,
+    /// Last writer of each key path, segments joined by `.`
+    origins: HashMap<String, ValuesOrigin>,
+}
+
+impl EffectiveValues {
+    /// Merge the fetched data of each reference (`None` when the object does
+    /// not exist) and the inline values in helm-controller's order.
+    pub fn resolve(
+        references: Vec<(ValuesReference, Result<Option<BTreeMap<String, String>>>)>,
+        inline: Option<&Value>,
+    ) -> Self {
+        let mut resolved = Self {
+            values: Value::Object(Map::new()),
+            ..Self::default()
+        };
+        for (index, (reference, data)) in references.into_iter().enumerate() {
+            let status = match data {
+                Err(e) => InputStatus::Error(format!("{:#}", e)),
+                Ok(data) => resolved.apply(index, &reference, data),
+            };
+            resolved.inputs.push(ValuesInput { reference, status });
+        }
+        if let Some(inline) = inline.filter(|inline| inline.is_object()) {
+            resolved.merge(inline, ValuesOrigin::Inline);
+        }
+        resolved
+    }
+
+    fn apply(
+        &mut self,
+        index: usize,
+        reference: &ValuesReference,
+        data: Option<BTreeMap<String, String>>,
+    ) -> InputStatus {
+        let missing = match data {
+            None => format!("{} {} not found", reference.kind, reference.name),
+            Some(ref data) => match data.get(&reference.values_key) {
+                Some(text) => return self.apply_text(index, reference, text),
+                None => format!(
+                    "{} {} has no key {}",
+                    reference.kind, reference.name, reference.values_key
+                ),
+            },
+        };
+        if reference.optional {
+            InputStatus::Skipped(missing)
+        } else {
+            InputStatus::Error(missing)
+        }
+    }
+
+    fn apply_text(&mut self, index: usize, reference: &ValuesReference, text: &str) -> InputStatus {
+        let origin = ValuesOrigin::Reference(index);
+        if let Some(ref path) = reference.target_path {
+            let segments = split_target_path(path);
+            set_path(&mut self.values, &segments, Value::String(text.to_string()));
+            self.origins.insert(segments.join("."), origin);
+            return InputStatus::Applied;
+        }
+        match serde_yaml::from_str::<Value>(text) {
+            Ok(Value::Null) => InputStatus::Applied,
+            Ok(values @ Value::Object(_)) => {
+                self.merge(&values, origin);
+                InputStatus::Applied
+            }
+            Ok(_) => InputStatus::Error(format!("{} is not a YAML map", reference.label())),
+            Err(e) => InputStatus::Error(format!("Failed to parse {}: {}", reference.label(), e)),
+        }
+    }
+
+    fn merge(&mut self, overrides: &Value, origin: ValuesOrigin) {
+        let mut leaves = Vec::new();
+        leaf_paths(overrides, String::new(), &mut leaves);
+        for path in leaves {
+            self.origins.insert(path, origin.clone());
+        }
+        self.values = merge_maps(&self.values, overrides);
+    }
+
+    /// Whether any reference failed to resolve
+    pub fn has_errors(&self) -> bool {
+        self.inputs
+            .iter()
+            .any(|input| matches!(input.status, InputStatus::Error(_)))
+    }
+
+    /// The input that set the key at `path`
+    pub fn origin(&self, path: &str) -> Option<&ValuesOrigin> {
+        self.origins.get(path)
+    }
+
+    /// Short name of an origin, as shown next to each key
+    pub fn origin_label(&self, origin: &ValuesOrigin) -> String {
+        match origin {
+            ValuesOrigin::Inline => "spec.values".to_string(),
+            ValuesOrigin::Reference(index) => self
+                .inputs
+                .get(*index)
+                .map(|input| input.reference.label())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn is_secret_origin(&self, origin: Option<&ValuesOrigin>) -> bool {
+        match origin {
+            Some(ValuesOrigin::Reference(index)) => self
+                .inputs
+                .get(*index)
+                .is_some_and(|input| input.reference.is_secret()),
+            _ => false,
+        }
+    }
+
+    /// The values as YAML-like lines, each leaf annotated with its input.
+    /// Values from Secrets read `<redacted>` unless `reveal`.
+    pub fn annotated_lines(&self, reveal: bool) -> Vec<String> {
+        let mut lines = Vec::new();
+        if let Value::Object(ref map) = self.values {
+            self.annotate_map(map, "", 0, reveal, &mut lines);
+        }
+        lines
+    }
+
+    fn annotate_map(
+        &self,
+        map: &Map<String, Value>,
+        prefix: &str,
+        depth: usize,
+        reveal: bool,
+        lines: &mut Vec<String>,
+    ) {
+        let indent = "  ".repeat(depth);
+        for (key, value) in map {
+            let path = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            match value {
+                Value::Object(nested) if !nested.is_empty() => {
+                    lines.push(format!("{}{}:", indent, key));
+                    self.annotate_map(nested, &path, depth + 1, reveal, lines);
+                }
+                leaf => {
+                    let origin = self.origin(&path);
+                    let text = if self.is_secret_origin(origin) && !reveal {
+                        REDACTED.to_string()
+                    } else {
+                        leaf.to_string()
+                    };
+                    let source = origin
+                        .map(|origin| format!("  # {}", self.origin_label(origin)))
+                        .unwrap_or_default();
+                    lines.push(format!("{}{}: {}{}", indent, key, text, source));
+                }
+            }
+        }
+    }
+
+    /// `values` with every key these values took from a Secret, and all
+    /// below it, replaced by `<redacted>`, or `<redacted, changed>` where it
+    /// differs from the same key of `baseline`.
+    fn redact(&self, values: &Value, baseline: Option<&Value>, prefix: &str) -> Value {
+        if !prefix.is_empty() && self.is_secret_origin(self.origin(prefix)) {
+            return redacted(baseline.is_some_and(|baseline| baseline != values));
+        }
+        match values {
+            Value::Object(map) => Value::Object(
+                map.iter()
+                    .map(|(key, value)| {
+                        let path = if prefix.is_empty() {
+                            key.clone()
+                        } else {
+                            format!("{}.{}", prefix, key)
+                        };
+                        let baseline = baseline.and_then(|baseline| baseline.get(key));
+                        (key.clone(), self.redact(value, baseline, &path))
+                    })
+                    .collect(),
+            ),
+            other => other.clone(),
+        }
+    }
+}
+
+/// Placeholder for a masked value; `changed` when it differs from the
+/// revision it is diffed against
+fn redacted(changed: bool) -> Value {
+    Value::String(if changed { REDACTED_CHANGED } else { REDACTED }.to_string())
+}
+
+/// What the release inspector hides until `x` reveals it, on every tab
+/// that shows values or manifests
+#[derive(Debug, Clone, Copy)]
+pub enum SecretRedaction<'a> {
+    Reveal,
+    /// Mask the keys these effective values took from Secrets, and the
+    /// `data` and `stringData` of Secret manifests. Keys are matched by
+    /// path, so older revisions are masked where the current inputs place
+    /// Secret values.
+    Redact(&'a EffectiveValues),
+}
+
+impl SecretRedaction<'_> {
+    pub fn is_revealed(&self) -> bool {
+        matches!(self, SecretRedaction::Reveal)
+    }
+
+    /// Release values (deployed or merged with the chart defaults)
+    pub fn values(&self, values: &Value) -> Value {
+        self.values_against(values, values)
+    }
+
+    /// `values` for a diff against `baseline`: masked keys whose value
+    /// differs from the baseline's read `<redacted, changed>`, so a hidden
+    /// change still shows up as a change.
+    pub fn values_against(&self, values: &Value, baseline: &Value) -> Value {
+        match self {
+            SecretRedaction::Reveal => values.clone(),
+            SecretRedaction::Redact(effective) => effective.redact(values, Some(baseline), ""),
+        }
+    }
+
+    /// A rendered multi-document manifest for a diff against `baseline`,
+    /// marked like [`Self::values_against`]
+    pub fn manifest_against(&self, manifest: &str, baseline: &str) -> String {
+        match self {
+            SecretRedaction::Reveal => manifest.to_string(),
+            SecretRedaction::Redact(_) => {
+                redact_secret_manifests(manifest, &secret_sections(baseline))
+            }
+        }
+    }
+}
+
+/// `data` and `stringData` of the Secrets of a manifest, by Secret name and
+/// section
+type SecretSections = HashMap<(String, String), Value>;
+
+fn secret_sections(manifest: &str) -> SecretSections {
+    let mut sections = SecretSections::new();
+    for document in split_documents(manifest) {
+        let Some(secret) = parse_secret(&document) else {
+            continue;
+        };
+        let name = secret_name(&secret);
+        for section in ["data", "stringData"] {
+            if let Some(data) = secret.get(section) {
+                sections.insert((name.clone(), section.to_string()), data.clone());
+            }
+        }
+    }
+    sections
+}
+
+/// The lines of each `---`-separated document, separators included with the
+/// document they open
+fn split_documents(manifest: &str) -> Vec<Vec<&str>> {
+    let mut documents = vec![Vec::new()];
+    for line in manifest.lines() {
+        if line.starts_with("---") {
+            documents.push(Vec::new());
+        }
+        if let Some(document) = documents.last_mut() {
+            document.push(line);
+        }
+    }
+    documents
+}
+
+fn is_secret_document(lines: &[&str]) -> bool {
+    lines.iter().any(|line| line.trim_end() == "kind: Secret")
+}
+
+fn parse_secret(lines: &[&str]) -> Option<Value> {
+    if !is_secret_document(lines) {
+        return None;
+    }
+    let body: Vec<&str> = lines
+        .iter()
+        .copied()
+        .filter(|line| !line.starts_with("---"))
+        .collect();
+    serde_yaml::from_str(&body.join("\n")).ok()
+}
+
+fn secret_name(secret: &Value) -> String {
+    secret
+        .pointer("/metadata/name")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Mask the entries of `data` and `stringData` in the `kind: Secret`
+/// documents of a manifest, keeping their keys and the layout of the rest.
+fn redact_secret_manifests(manifest: &str, baseline: &SecretSections) -> String {
+    let mut out = String::with_capacity(manifest.len());
+    for document in split_documents(manifest) {
+        redact_secret_document(&document, baseline, &mut out);
+    }
+    out
+}
+
+fn redact_secret_document(lines: &[&str], baseline: &SecretSections, out: &mut String) {
+    let is_secret = is_secret_document(lines);
+    let secret = parse_secret(lines);
+    let name = secret.as_ref().map(secret_name).unwrap_or_default();
+    // Whether a masked section, or one of its entries, differs from the
+    // baseline; unparsable documents are never marked
+    let changed = |section: &str, key: Option<&str>| {
+        let current = secret.as_ref().and_then(|secret| secret.get(section));
+        let previous = baseline.get(&(name.clone(), section.to_string()));
+        match (key, current, previous) {
+            (None, Some(current), Some(previous)) => current != previous,
+            (Some(key), Some(current), Some(previous)) => previous
+                .get(key)
+                .is_some_and(|previous| current.get(key) != Some(previous)),
+            _ => false,
+        }
+    };
+    let mark = |changed: bool| if changed { REDACTED_CHANGED } else { REDACTED };
+    // Inside `data`/`stringData`: its name and the indentation of its
+    // entries, once seen
+    let mut section: Option<(&str, Option<usize>)> = None;
+    for line in lines {
+        let indent = line.len() - line.trim_start().len();
+        if let Some((section_name, entry_indent)) = section.as_mut() {
+            if line.trim().is_empty() {
+                out.push_str(line);
+                out.push('\n');
+                continue;
+            }
+            if indent > 0 {
+                // Deeper lines continue a block scalar and are dropped
+                if indent == *entry_indent.get_or_insert(indent) {
+                    let key = line.split_once(':').map_or(*line, |(key, _)| key);
+                    let unquoted = key.trim().trim_matches(|c| c == '"' || c == '\'');
+                    let text = mark(changed(section_name, Some(unquoted)));
+                    out.push_str(&format!("{}: {}\n", key, text));
+                }
+                continue;
+            }
+            section = None;
+        }
+        if is_secret {
+            if let Some((key, rest)) = line
+                .split_once(':')
+                .filter(|(key, _)| *key == "data" || *key == "stringData")
+            {
+                let rest = rest.trim();
+                if rest.is_empty() || rest.starts_with('#') {
+                    section = Some((key, None));
+                } else {
+                    // Flow-style map on one line
+                    out.push_str(&format!("{}: {}\n", key, mark(changed(key, None))));
+                    continue;
+                }
+            }
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+}
+
+/// Merge `overrides` into `base` like helm-controller's `MergeMaps`: maps
+/// merge key by key, anything else (including `null`) replaces.
+pub fn merge_maps(base: &Value, overrides: &Value) -> Value {
+    match (base, overrides) {
+        (Value::Object(base), Value::Object(overrides)) => {
+            let mut merged = base.clone();
+            for (key, value) in overrides {
+                let value = match merged.get(key) {
+                    Some(existing @ Value::Object(_)) if value.is_object() => {
+                        merge_maps(existing, value)
+                    }
+                    _ => value.clone(),
+                };
+                merged.insert(key.clone(), value);
+            }
+            Value::Object(merged)
+        }
+        (_, overrides) => overrides.clone(),
+    }
+}
+
+/// Key paths of the leaves of a values map; an empty map is a leaf.
+fn leaf_paths(value: &Value, prefix: String, out: &mut Vec<String>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, nested) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                leaf_paths(nested, path, out);
+            }
+        }
+        _ if !prefix.is_empty() => out.push(prefix),
+        _ => {}
+    }
+}
+
+/// Split a `targetPath` on `.`, keeping escaped `\.` inside a segment.
+fn split_target_path(path: &str) -> Vec<String> {
+    let mut segments = Vec::new();
+    let mut segment = String::new();
+    let mut chars = path.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'.') => {
+                chars.next();
+                segment.push('.');
+            }
+            '.' => segments.push(std::mem::take(&mut segment)),
+            c => segment.push(c),
+        }
+    }
+    segments.push(segment);
+    segments
+}
+
+/// Set `value` at `segments`, replacing non-map values on the way.
+fn set_path(target: &mut Value, segments: &[String], value: Value) {
+    let Some((first, rest)) = segments.split_first() else {
+        *target = value;
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    let Value::Object(map) = target else {
+        return;
+    };
+    let entry = map.entry(first.clone()).or_insert(Value::Null);
+    set_path(entry, rest, value);
+}
+
+/// Read the data of a `valuesFrom` reference, `None` when it does not exist.
+async fn fetch_reference_data(
+    client: &kube::Client,
+    namespace: &str,
+    reference: &ValuesReference,
+) -> Result<Option<BTreeMap<String, String>>> {
+    let context = || format!("Failed to get {} {}", reference.kind, reference.name);
+    match reference.kind.as_str() {
+        "ConfigMap" => {
+            let api: Api<ConfigMap> = Api::namespaced(client.clone(), namespace);
+            let configmap = api.get_opt(&reference.name).await.with_context(context)?;
+            Ok(configmap.map(|cm| cm.data.unwrap_or_default()))
+        }
+        "Secret" => {
+            let api: Api<Secret> = Api::namespaced(client.clone(), namespace);
+            let secret = api.get_opt(&reference.name).await.with_context(context)?;
+            Ok(secret.map(|secret| {
+                secret
+                    .data
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(key, bytes)| (key, String::from_utf8_lossy(&bytes.0).into_owned()))
+                    .collect()
+            }))
+        }
+        kind => anyhow::bail!("Unsupported valuesFrom kind '{}'", kind),
+    }
+}
+
+/// Resolve the effective values of a HelmRelease, reading its `valuesFrom`
+/// references from the HelmRelease's namespace.
+pub async fn resolve_effective_values(
+    client: &kube::Client,
+    helmrelease_obj: &Value,
+    namespace: &str,
+) -> EffectiveValues {
+    let mut references = Vec::new();
+    for reference in ValuesReference::from_helmrelease(helmrelease_obj) {
+        let data = fetch_reference_data(client, namespace, &reference).await;
+        references.push((reference, data));
+    }
+    let inline = helmrelease_obj
+        .get("spec")
+        .and_then(|spec| spec.get("values"));
+    EffectiveValues::resolve(references, inline)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn data(key: &str, text: &str) -> Result<Option<BTreeMap<String, String>>> {
+        Ok(Some(BTreeMap::from([(key.to_string(), text.to_string())])))
+    }
+
+    #[test]
+    fn resolves_values_from_in_flux_order() {
+        let hr = json!({"spec": {
+            "valuesFrom": [
+                {"kind": "ConfigMap", "name": "defaults"},
+                {"kind": "Secret", "name": "creds", "valuesKey": "db.yaml"},
+                {"kind": "Secret", "name": "token", "valuesKey": "token", "targetPath": "auth.token"},
+                {"kind": "ConfigMap", "name": "extra", "optional": true},
+                {"kind": "ConfigMap", "name": "gone"},
+            ],
+            "values": {"replicas": 3},
+        }});
+        let refs = ValuesReference::from_helmrelease(&hr);
+        assert_eq!(refs[0].values_key, DEFAULT_VALUES_KEY);
+        assert_eq!(refs[2].label(), "Secret/token[token] → auth.token");
+        let data = vec![
+            data(
+                "values.yaml",
+                "replicas: 1\ndb:\n  host: db\n  port: 5432\n",
+            ),
+            data("db.yaml", "db:\n  password: hunter2\n"),
+            data("token", "12345"),
+            Ok(None),
+            Ok(None),
+        ];
+        let values = EffectiveValues::resolve(
+            refs.into_iter().zip(data).collect(),
+            hr["spec"].get("values"),
+        );
+
+        assert_eq!(
+            values.values,
+            json!({
+                "replicas": 3,
+                "db": {"host": "db", "port": 5432, "password": "hunter2"},
+                "auth": {"token": "12345"},
+            })
+        );
+        assert_eq!(values.origin("replicas"), Some(&ValuesOrigin::Inline));
+        assert_eq!(values.origin("db.port"), Some(&ValuesOrigin::Reference(0)));
+        assert_eq!(
+            values.origin("auth.token"),
+            Some(&ValuesOrigin::Reference(2))
+        );
+        assert!(matches!(values.inputs[3].status, InputStatus::Skipped(_)));
+        assert_eq!(
+            values.inputs[4].status,
+            InputStatus::Error("ConfigMap gone not found".to_string())
+        );
+        assert!(values.has_errors());
+
+        let lines = values.annotated_lines(false);
+        assert!(lines.contains(&"  password: <redacted>  # Secret/creds[db.yaml]".to_string()));
+        assert!(lines.contains(&"  port: 5432  # ConfigMap/defaults[values.yaml]".to_string()));
+        assert!(lines.contains(&"replicas: 3  # spec.values".to_string()));
+        let revealed = values.annotated_lines(true);
+        assert!(
+            revealed
+                .contains(&"  token: \"12345\"  # Secret/token[token] → auth.token".to_string())
+        );
+    }
+
+    #[test]
+    fn target_path_keeps_escaped_dots() {
+        assert_eq!(
+            split_target_path(r"ingress.annotations.cert-manager\.io/issuer"),
+            vec!["ingress", "annotations", "cert-manager.io/issuer"]
+        );
+        let mut values = json!({"ingress": "off"});
+        set_path(&mut values, &split_target_path("ingress.host"), json!("a"));
+        assert_eq!(values, json!({"ingress": {"host": "a"}}));
+    }
+
+    #[test]
+    fn redaction_masks_secret_keys_and_secret_manifest_data() {
+        let reference = ValuesReference {
+            kind: "Secret".to_string(),
+            name: "creds".to_string(),
+            values_key: DEFAULT_VALUES_KEY.to_string(),
+            ..ValuesReference::default()
+        };
+        let effective = EffectiveValues::resolve(
+            vec![(
+                reference,
+                data(DEFAULT_VALUES_KEY, "db:\n  password: hunter2\n"),
+            )],
+            Some(&json!({"replicas": 2})),
+        );
+        let deployed = json!({"replicas": 2, "db": {"host": "db", "password": "hunter2"}});
+        assert_eq!(
+            SecretRedaction::Redact(&effective).values(&deployed),
+            json!({"replicas": 2, "db": {"host": "db", "password": REDACTED}})
+        );
+        assert_eq!(SecretRedaction::Reveal.values(&deployed), deployed);
+
+        let manifest = "---\n# Source: app/secret.yaml\napiVersion: v1\nkind: Secret\ndata:\n  token: aHVudGVyMg==\n  ca.crt: |\n    -----BEGIN-----\nstringData: {password: hunter2}\ntype: Opaque\n---\nkind: ConfigMap\ndata:\n  mode: debug\n";
+        assert_eq!(
+            SecretRedaction::Redact(&effective).manifest_against(manifest, manifest),
+            "---\n# Source: app/secret.yaml\napiVersion: v1\nkind: Secret\ndata:\n  token: <redacted>\n  ca.crt: <redacted>\nstringData: <redacted>\ntype: Opaque\n---\nkind: ConfigMap\ndata:\n  mode: debug\n"
+        );
+        assert_eq!(
+            SecretRedaction::Reveal.manifest_against(manifest, manifest),
+            manifest
+        );
+
+        // Against another revision, only the masked entries that differ are
+        // marked
+        let redact = SecretRedaction::Redact(&effective);
+        let older = json!({"replicas": 1, "db": {"host": "db", "password": "hunter1"}});
+        assert_eq!(
+            redact.values_against(&deployed, &older),
+            json!({"replicas": 2, "db": {"host": "db", "password": REDACTED_CHANGED}})
+        );
+        let v1 = "kind: Secret\nmetadata:\n  name: creds\ndata:\n  a: MQ==\n  b: Mg==\n";
+        let v2 = "kind: Secret\nmetadata:\n  name: creds\ndata:\n  a: MQ==\n  b: Mw==\n  c: NA==\n";
+        assert_eq!(
+            redact.manifest_against(v2, v1),
+            "kind: Secret\nmetadata:\n  name: creds\ndata:\n  a: <redacted>\n  b: <redacted, changed>\n  c: <redacted>\n"
+        );
+    }
+}
@@ -12,9 +12,13 @@
 //! to prevent proxy issues with corporate environments.
 
 pub mod api;
+pub mod check;
+pub mod diff;
 pub mod events;
 pub mod fetch;
 pub mod health;
+pub mod helm;
+pub mod helm_values;
 pub mod inventory;
 pub mod workloads;
 
@@ -28,7 +32,7 @@ pub use health::{
     resolve_connect_timeout,
 };
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use kube::config::Kubeconfig;
 use kube::{Client, Config};
 use std::path::Path;
@@ -480,6 +484,27 @@ pub async fn get_default_namespace() -> Option<String> {
     Some("flux-system".to_string())
 }
 
+/// List the namespaces whose labels match `selector` (e.g. `team=payments`),
+/// sorted by name. Backs `:ns <selector>`; needs permission to list
+/// namespaces.
+pub async fn list_namespaces_matching(client: &Client, selector: &str) -> Result<Vec<String>> {
+    use k8s_openapi::api::core::v1::Namespace;
+    use kube::api::{Api, ListParams};
+
+    let api: Api<Namespace> = Api::all(client.clone());
+    let list = api
+        .list(&ListParams::default().labels(selector))
+        .await
+        .with_context(|| format!("Failed to list namespaces matching '{}'", selector))?;
+    let mut namespaces: Vec<String> = list
+        .items
+        .into_iter()
+        .filter_map(|ns| ns.metadata.name)
+        .collect();
+    namespaces.sort();
+    Ok(namespaces)
+}
+
 /// Discover namespaces that contain Flux resources
 ///
 /// Returns a list of namespaces sorted by the number of Flux resources they contain.
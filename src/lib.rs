@@ -43,10 +43,14 @@
 pub mod cli;
 pub mod config;
 pub mod constants;
+pub mod duration;
 pub mod editor;
+pub mod hooks;
 pub mod kube;
+pub mod metrics;
 pub mod models;
 pub mod operations;
+pub mod plugins;
 pub mod services;
 pub mod trace;
 #[cfg(feature = "tui")]
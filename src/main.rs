@@ -26,7 +26,8 @@ struct Args {
     #[arg(long)]
     kubeconfig: Option<std::path::PathBuf>,
 
-    /// Check connection health and exit (0 = healthy, 1 = unhealthy) without starting the UI
+    /// Check connection health and exit (0 = healthy, 1 = unhealthy) without starting the UI.
+    /// See `flux9s check` for a full Flux installation check
     #[arg(long)]
     check: bool,
 
@@ -43,6 +44,28 @@ enum Command {
         #[command(subcommand)]
         subcommand: cli::ConfigSubcommand,
     },
+    /// Print Flux resources (same columns as the TUI) and exit
+    Get(cli::GetArgs),
+    /// Suspend reconciliation of Flux resources
+    Suspend(cli::OperateArgs),
+    /// Resume reconciliation of Flux resources
+    Resume(cli::OperateArgs),
+    /// Trigger reconciliation of Flux resources
+    Reconcile(cli::ReconcileArgs),
+    /// Delete Flux resources
+    Delete(cli::DeleteArgs),
+    /// Show the Flux objects and source managing a resource
+    Trace(cli::TraceArgs),
+    /// Export the resource graph as Graphviz DOT, Mermaid or JSON
+    Graph(cli::GraphArgs),
+    /// Wait until Flux resources are Ready (for CI/CD gates)
+    Wait(cli::WaitArgs),
+    /// Check controllers, CRD versions and the FluxReport of the Flux installation
+    Check(cli::CheckArgs),
+    /// Stream resource changes, watcher notices and Kubernetes Events (text or JSON lines)
+    Watch(cli::WatchArgs),
+    /// Run headless and serve Prometheus metrics (e.g. `serve --metrics :9090`)
+    Serve(cli::ServeArgs),
     /// Display version information
     Version,
     /// Generate shell completions (bash, zsh, fish, elvish, powershell)
@@ -91,6 +114,39 @@ async fn main() -> Result<()> {
         tracing::debug!("Debug logging enabled");
     }
 
+    // Handle non-interactive cluster commands
+    if let Some(Command::Get(get_args)) = args.command {
+        return cli::handle_get_command(get_args, args.kubeconfig.as_deref()).await;
+    }
+    if let Some(Command::Trace(trace_args)) = args.command {
+        return cli::handle_trace_command(trace_args, args.kubeconfig.as_deref()).await;
+    }
+    if let Some(Command::Graph(graph_args)) = args.command {
+        return cli::handle_graph_command(graph_args, args.kubeconfig.as_deref()).await;
+    }
+    if let Some(Command::Wait(wait_args)) = args.command {
+        return cli::handle_wait_command(wait_args, args.kubeconfig.as_deref()).await;
+    }
+    if let Some(Command::Check(check_args)) = args.command {
+        return cli::handle_check_command(check_args, args.kubeconfig.as_deref()).await;
+    }
+    if let Some(Command::Watch(watch_args)) = args.command {
+        return cli::handle_watch_command(watch_args, args.kubeconfig.as_deref()).await;
+    }
+    if let Some(Command::Serve(serve_args)) = args.command {
+        return cli::handle_serve_command(serve_args, args.kubeconfig.as_deref()).await;
+    }
+    let operation = match args.command {
+        Some(Command::Suspend(op_args)) => Some(cli::OperationCommand::Suspend(op_args)),
+        Some(Command::Resume(op_args)) => Some(cli::OperationCommand::Resume(op_args)),
+        Some(Command::Reconcile(op_args)) => Some(cli::OperationCommand::Reconcile(op_args)),
+        Some(Command::Delete(op_args)) => Some(cli::OperationCommand::Delete(op_args)),
+        _ => None,
+    };
+    if let Some(operation) = operation {
+        return cli::handle_operation_command(operation, args.kubeconfig.as_deref()).await;
+    }
+
     // Load configuration — capture any parse/IO error so we can warn the user in the TUI
     let cluster: Option<&str> = None;
     let context_name: Option<&str> = None;
@@ -165,6 +221,7 @@ async fn main() -> Result<()> {
         args.debug,
         args.kubeconfig.as_deref(),
         config_warning,
+        config::ConfigLoader::layer_paths(cluster, context_name),
         log_file,
     )
     .await?;
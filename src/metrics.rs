@@ -0,0 +1,382 @@
+//! Prometheus metrics for headless monitoring
+//!
+//! [`MetricsCollector`] follows the controller pod, deployment and watcher
+//! health events from a [`ResourceWatcher`](crate::watcher::ResourceWatcher);
+//! resource gauges are computed from [`ResourceState`] at scrape time.
+//! [`MetricsCollector::render`] produces the Prometheus text exposition
+//! format served by `flux9s serve --metrics`.
+
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use crate::watcher::{ResourceState, WatchEvent};
+
+/// Content type of the text exposition format
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Controller pod status as exported
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PodMetrics {
+    ready: bool,
+    restarts: i64,
+}
+
+/// Watcher-fed state that `ResourceState` does not hold
+#[derive(Debug, Default)]
+pub struct MetricsCollector {
+    pods: BTreeMap<String, PodMetrics>,
+    /// Every watcher seen, with whether it is currently degraded
+    watchers: BTreeMap<String, bool>,
+    forbidden: BTreeSet<String>,
+    flux_version: Option<String>,
+}
+
+impl MetricsCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Track the events that feed non-resource metrics. Resource events are
+    /// ignored here; apply them to the `ResourceState` as usual.
+    pub fn observe(&mut self, event: &WatchEvent) {
+        match event {
+            WatchEvent::PodApplied(name, pod_json) => {
+                self.pods.insert(name.clone(), pod_metrics(pod_json));
+            }
+            WatchEvent::PodDeleted(name) => {
+                self.pods.remove(name);
+            }
+            WatchEvent::DeploymentApplied(deployment_json) => {
+                if let Some(version) = crate::kube::check::flux_bundle_version(deployment_json) {
+                    self.flux_version = Some(version);
+                }
+            }
+            WatchEvent::InitialSyncDone(watcher) => {
+                self.watchers.entry(watcher.clone()).or_insert(false);
+            }
+            WatchEvent::WatcherDegraded(watcher) => {
+                self.watchers.insert(watcher.clone(), true);
+            }
+            WatchEvent::WatcherRecovered(watcher) => {
+                self.watchers.insert(watcher.clone(), false);
+            }
+            WatchEvent::WatcherForbidden(watcher) => {
+                self.forbidden.insert(watcher.clone());
+            }
+            _ => {}
+        }
+    }
+
+    /// Render every metric in the Prometheus text format.
+    pub fn render(&self, state: &ResourceState, now: DateTime<Utc>) -> String {
+        let mut resources = state.all();
+        resources.sort_by(|a, b| {
+            (&a.resource_type, &a.namespace, &a.name).cmp(&(
+                &b.resource_type,
+                &b.namespace,
+                &b.name,
+            ))
+        });
+
+        // Per kind/namespace counts, keyed by (kind, namespace)
+        let mut by_ready: BTreeMap<(&str, &str), [u64; 3]> = BTreeMap::new();
+        let mut suspended: BTreeMap<(&str, &str), u64> = BTreeMap::new();
+        for info in &resources {
+            let key = (info.resource_type.as_str(), info.namespace.as_str());
+            let slot = match info.ready {
+                Some(true) => 0,
+                Some(false) => 1,
+                None => 2,
+            };
+            by_ready.entry(key).or_default()[slot] += 1;
+            *suspended.entry(key).or_default() += u64::from(info.effective_suspended());
+        }
+
+        let mut out = String::new();
+        header(
+            &mut out,
+            "flux9s_resources",
+            "Flux resources by kind, namespace and Ready condition",
+        );
+        for ((kind, namespace), counts) in &by_ready {
+            for (ready, count) in ["true", "false", "unknown"].iter().zip(counts) {
+                sample(
+                    &mut out,
+                    "flux9s_resources",
+                    &[("kind", kind), ("namespace", namespace), ("ready", ready)],
+                    *count as f64,
+                );
+            }
+        }
+
+        header(
+            &mut out,
+            "flux9s_resources_suspended",
+            "Suspended Flux resources by kind and namespace",
+        );
+        for ((kind, namespace), count) in &suspended {
+            sample(
+                &mut out,
+                "flux9s_resources_suspended",
+                &[("kind", kind), ("namespace", namespace)],
+                *count as f64,
+            );
+        }
+
+        header(
+            &mut out,
+            "flux9s_resource_ready",
+            "1 when the resource's Ready condition is True, 0 when False (absent when unknown)",
+        );
+        for info in &resources {
+            if let Some(ready) = info.ready {
+                sample(
+                    &mut out,
+                    "flux9s_resource_ready",
+                    &resource_labels(info),
+                    f64::from(u8::from(ready)),
+                );
+            }
+        }
+
+        header(
+            &mut out,
+            "flux9s_resource_suspended",
+            "1 when the resource is suspended",
+        );
+        for info in &resources {
+            sample(
+                &mut out,
+                "flux9s_resource_suspended",
+                &resource_labels(info),
+                f64::from(u8::from(info.effective_suspended())),
+            );
+        }
+
+        header(
+            &mut out,
+            "flux9s_resource_reconcile_age_seconds",
+            "Seconds since the resource last reported a reconciliation",
+        );
+        for info in &resources {
+            if let Some(last) = info.last_reconciled {
+                let age = (now - last).num_seconds().max(0);
+                sample(
+                    &mut out,
+                    "flux9s_resource_reconcile_age_seconds",
+                    &resource_labels(info),
+                    age as f64,
+                );
+            }
+        }
+
+        header(
+            &mut out,
+            "flux9s_watcher_degraded",
+            "1 while a watcher is erroring and retrying with backoff",
+        );
+        for (watcher, degraded) in &self.watchers {
+            sample(
+                &mut out,
+                "flux9s_watcher_degraded",
+                &[("watcher", watcher)],
+                f64::from(u8::from(*degraded)),
+            );
+        }
+
+        header(
+            &mut out,
+            "flux9s_watcher_forbidden",
+            "1 for watchers stopped because RBAC forbids the kind",
+        );
+        for watcher in &self.forbidden {
+            sample(
+                &mut out,
+                "flux9s_watcher_forbidden",
+                &[("watcher", watcher)],
+                1.0,
+            );
+        }
+
+        header(
+            &mut out,
+            "flux9s_controller_pod_ready",
+            "1 when the Flux controller pod is Ready",
+        );
+        for (pod, metrics) in &self.pods {
+            sample(
+                &mut out,
+                "flux9s_controller_pod_ready",
+                &[("pod", pod)],
+                f64::from(u8::from(metrics.ready)),
+            );
+        }
+
+        header(
+            &mut out,
+            "flux9s_controller_pod_restarts",
+            "Container restarts of the Flux controller pod",
+        );
+        for (pod, metrics) in &self.pods {
+            sample(
+                &mut out,
+                "flux9s_controller_pod_restarts",
+                &[("pod", pod)],
+                metrics.restarts as f64,
+            );
+        }
+
+        if let Some(version) = &self.flux_version {
+            header(
+                &mut out,
+                "flux9s_flux_info",
+                "Flux bundle version reported by the controller deployments",
+            );
+            sample(&mut out, "flux9s_flux_info", &[("version", version)], 1.0);
+        }
+        out
+    }
+}
+
+fn pod_metrics(pod_json: &serde_json::Value) -> PodMetrics {
+    let ready = pod_json["status"]["conditions"]
+        .as_array()
+        .and_then(|conds| conds.iter().find(|c| c["type"] == "Ready"))
+        .and_then(|c| c["status"].as_str())
+        .is_some_and(|s| s == "True");
+    let restarts = pod_json["status"]["containerStatuses"]
+        .as_array()
+        .map(|statuses| {
+            statuses
+                .iter()
+                .filter_map(|s| s["restartCount"].as_i64())
+                .sum()
+        })
+        .unwrap_or(0);
+    PodMetrics { ready, restarts }
+}
+
+fn resource_labels(info: &crate::watcher::ResourceInfo) -> [(&str, &str); 3] {
+    [
+        ("kind", info.resource_type.as_str()),
+        ("namespace", info.namespace.as_str()),
+        ("name", info.name.as_str()),
+    ]
+}
+
+fn header(out: &mut String, name: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} gauge", name);
+}
+
+fn sample(out: &mut String, name: &str, labels: &[(&str, &str)], value: f64) {
+    let labels: Vec<String> = labels
+        .iter()
+        .map(|(key, value)| format!("{}=\"{}\"", key, escape_label(value)))
+        .collect();
+    let _ = writeln!(out, "{}{{{}}} {}", name, labels.join(","), value);
+}
+
+/// Escape a label value: backslash, double quote and newline.
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::watcher::ResourceInfo;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    fn info(kind: &str, name: &str, ready: Option<bool>, suspended: bool) -> ResourceInfo {
+        ResourceInfo {
+            name: name.to_string(),
+            namespace: "flux-system".to_string(),
+            resource_type: kind.to_string(),
+            age: None,
+            suspended: Some(suspended),
+            ready,
+            message: None,
+            revision: None,
+            labels: Default::default(),
+            annotations: Default::default(),
+            last_reconciled: None,
+            reconciliation_history: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn resource_gauges_aggregate_per_kind_and_namespace() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 16, 9, 30, 0).unwrap();
+        let state = ResourceState::new();
+        state.upsert("a".into(), info("Kustomization", "apps", Some(true), false));
+        state.upsert(
+            "b".into(),
+            info("Kustomization", "infra", Some(false), true),
+        );
+        let mut reconciled = info("HelmRelease", "podinfo", None, false);
+        reconciled.last_reconciled = Some(now - chrono::Duration::seconds(90));
+        state.upsert("c".into(), reconciled);
+
+        let out = MetricsCollector::new().render(&state, now);
+        assert!(out.contains(
+            "flux9s_resources{kind=\"Kustomization\",namespace=\"flux-system\",ready=\"true\"} 1\n"
+        ));
+        assert!(out.contains(
+            "flux9s_resources{kind=\"Kustomization\",namespace=\"flux-system\",ready=\"false\"} 1\n"
+        ));
+        assert!(out.contains(
+            "flux9s_resources_suspended{kind=\"Kustomization\",namespace=\"flux-system\"} 1\n"
+        ));
+        assert!(out.contains(
+            "flux9s_resource_ready{kind=\"Kustomization\",namespace=\"flux-system\",name=\"infra\"} 0\n"
+        ));
+        // Unknown readiness has no per-resource sample
+        assert!(!out.contains("flux9s_resource_ready{kind=\"HelmRelease\""));
+        assert!(out.contains(
+            "flux9s_resource_reconcile_age_seconds{kind=\"HelmRelease\",namespace=\"flux-system\",name=\"podinfo\"} 90\n"
+        ));
+    }
+
+    #[test]
+    fn watcher_and_pod_events_feed_collector() {
+        let mut collector = MetricsCollector::new();
+        collector.observe(&WatchEvent::InitialSyncDone("GitRepository".into()));
+        collector.observe(&WatchEvent::WatcherDegraded("HelmRelease".into()));
+        collector.observe(&WatchEvent::WatcherForbidden("Bucket".into()));
+        collector.observe(&WatchEvent::PodApplied(
+            "source-controller-abc".into(),
+            json!({"status": {
+                "conditions": [{"type": "Ready", "status": "True"}],
+                "containerStatuses": [{"restartCount": 2}, {"restartCount": 1}]
+            }}),
+        ));
+        collector.observe(&WatchEvent::DeploymentApplied(json!({
+            "metadata": {"labels": {"app.kubernetes.io/version": "v2.7.5"}}
+        })));
+
+        let out = collector.render(&ResourceState::new(), Utc::now());
+        assert!(out.contains("flux9s_watcher_degraded{watcher=\"GitRepository\"} 0\n"));
+        assert!(out.contains("flux9s_watcher_degraded{watcher=\"HelmRelease\"} 1\n"));
+        assert!(out.contains("flux9s_watcher_forbidden{watcher=\"Bucket\"} 1\n"));
+        assert!(out.contains("flux9s_controller_pod_ready{pod=\"source-controller-abc\"} 1\n"));
+        assert!(out.contains("flux9s_controller_pod_restarts{pod=\"source-controller-abc\"} 3\n"));
+        assert!(out.contains("flux9s_flux_info{version=\"v2.7.5\"} 1\n"));
+
+        collector.observe(&WatchEvent::WatcherRecovered("HelmRelease".into()));
+        collector.observe(&WatchEvent::PodDeleted("source-controller-abc".into()));
+        let out = collector.render(&ResourceState::new(), Utc::now());
+        assert!(out.contains("flux9s_watcher_degraded{watcher=\"HelmRelease\"} 0\n"));
+        assert!(!out.contains("source-controller-abc"));
+    }
+
+    #[test]
+    fn label_values_are_escaped() {
+        assert_eq!(escape_label("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+}
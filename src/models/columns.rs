@@ -0,0 +1,394 @@
+//! User-defined list columns
+//!
+//! `columns:` in config maps a kind to a list of column entries that adjust
+//! the hard-coded [`FluxResourceKind::columns`] of the type-specific list:
+//!
+//! ```yaml
+//! columns:
+//!   HelmRelease:
+//!     - name: VALUES_FROM
+//!       path: .spec.valuesFrom[*].name
+//!     - name: PRUNE
+//!       hide: true
+//! ```
+//!
+//! - an entry with a `path` adds a column whose value is read from the object
+//!   with a JSONPath subset (`.field`, `['field']`, `[n]`, `[*]`, optionally
+//!   wrapped in `{...}` like `kubectl -o jsonpath`)
+//! - an entry with `hide: true` removes a column
+//! - an entry with only a `name` refers to a built-in column; naming any
+//!   built-in column makes the list the full column order, otherwise new
+//!   columns are inserted before AGE
+//!
+//! Keys are kind names or aliases (`hr`, plurals, CRD short names), so the
+//! same mechanism covers discovered [`ExtraKind`](super::extra_kinds::ExtraKind)s.
+
+use anyhow::{Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::models::FluxResourceKind;
+
+/// Columns configured per kind, as found under `columns:` in config
+pub type ColumnsConfig = HashMap<String, Vec<ColumnConfig>>;
+
+/// One configured column entry
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnConfig {
+    /// Header name (matched case-insensitively, shown upper-case)
+    pub name: String,
+    /// JSONPath of the value; unset refers to a built-in column
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Remove the column from the list
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hide: bool,
+}
+
+impl ColumnConfig {
+    /// Header name as shown in the list.
+    pub fn header(&self) -> String {
+        self.name.to_uppercase()
+    }
+}
+
+/// Columns that always come from the tracked status rather than the object,
+/// so a custom column cannot shadow them.
+const RESERVED: &[&str] = &[
+    "STATUS",
+    "NAMESPACE",
+    "NAME",
+    "SUSPENDED",
+    "READY",
+    "REVISION",
+    "MESSAGE",
+    "AGE",
+];
+
+/// The entries configured for `resource_type`, whichever name or alias the
+/// config used as the key. Entries of several matching keys are combined.
+pub fn columns_for<'a>(config: &'a ColumnsConfig, resource_type: &str) -> Vec<&'a ColumnConfig> {
+    let mut keys: Vec<&String> = config
+        .keys()
+        .filter(|key| resolve_kind(key).eq_ignore_ascii_case(resource_type))
+        .collect();
+    keys.sort();
+    keys.into_iter().flat_map(|key| &config[key]).collect()
+}
+
+/// Resolve a config key (kind, alias, plural or CRD short name) to a kind name.
+fn resolve_kind(key: &str) -> String {
+    FluxResourceKind::from_str_case_insensitive(key)
+        .map(|kind| kind.as_str().to_string())
+        .or_else(|| crate::models::extra_kinds::global().resolve_command(key))
+        .unwrap_or_else(|| key.to_string())
+}
+
+/// Apply configured entries to the default column list.
+pub fn apply_columns(defaults: &[&str], configured: &[&ColumnConfig]) -> Vec<String> {
+    let hidden: Vec<String> = configured
+        .iter()
+        .filter(|c| c.hide)
+        .map(|c| c.header())
+        .collect();
+    let is_builtin = |name: &str| defaults.iter().any(|d| d.eq_ignore_ascii_case(name));
+    let reorders = configured
+        .iter()
+        .any(|c| !c.hide && c.path.is_none() && is_builtin(&c.name));
+
+    let mut columns: Vec<String> = if reorders {
+        Vec::new()
+    } else {
+        defaults.iter().map(|d| d.to_string()).collect()
+    };
+    for column in configured.iter().filter(|c| !c.hide) {
+        let header = column.header();
+        if columns.contains(&header) || (column.path.is_none() && !is_builtin(&header)) {
+            continue;
+        }
+        // Without a reorder, new columns go before AGE so it stays last
+        match columns.iter().position(|c| c == "AGE") {
+            Some(age) if !reorders => columns.insert(age, header),
+            _ => columns.push(header),
+        }
+    }
+    columns.retain(|c| !hidden.contains(c));
+    columns
+}
+
+/// Values of the configured path columns, keyed by header. Invalid paths
+/// and paths that select nothing yield no entry (shown as `-`).
+pub fn extract_custom_fields(obj: &Value, configured: &[&ColumnConfig]) -> HashMap<String, String> {
+    configured
+        .iter()
+        .filter(|c| !c.hide)
+        .filter_map(|c| {
+            let path = JsonPath::parse(c.path.as_deref()?).ok()?;
+            let values = path.select(obj);
+            (!values.is_empty()).then(|| (c.header(), format_values(&values)))
+        })
+        .collect()
+}
+
+/// Check every entry: a name, no reserved name for a path column, a valid
+/// path, and for Flux kinds, that path-less entries name a built-in column.
+pub fn validate(config: &ColumnsConfig) -> Result<()> {
+    for (kind, columns) in config {
+        let defaults = FluxResourceKind::from_str_case_insensitive(kind).map(|k| {
+            let mut defaults = k.columns();
+            defaults.push("AGE");
+            defaults
+        });
+        for column in columns {
+            if column.name.trim().is_empty() {
+                bail!("columns.{}: a column has no name", kind);
+            }
+            match &column.path {
+                Some(path) => {
+                    if RESERVED.contains(&column.header().as_str()) {
+                        bail!(
+                            "columns.{}: '{}' is a built-in column and cannot take a path",
+                            kind,
+                            column.name
+                        );
+                    }
+                    JsonPath::parse(path).map_err(|e| {
+                        anyhow!(
+                            "columns.{}: invalid path for '{}': {}",
+                            kind,
+                            column.name,
+                            e
+                        )
+                    })?;
+                }
+                None => {
+                    if let Some(defaults) = &defaults {
+                        if !defaults
+                            .iter()
+                            .any(|d| d.eq_ignore_ascii_case(&column.name))
+                        {
+                            bail!(
+                                "columns.{}: '{}' is not a built-in column (use {}) and has no path",
+                                kind,
+                                column.name,
+                                defaults.join(", ")
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A parsed JSONPath (the subset `kubectl` users write for column values)
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPath {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Field(String),
+    Index(usize),
+    Wildcard,
+}
+
+impl JsonPath {
+    /// Parse `.spec.valuesFrom[*].name`, `{.metadata.labels['app.kubernetes.io/name']}`, ...
+    pub fn parse(path: &str) -> Result<Self> {
+        let path = path.trim();
+        let path = path
+            .strip_prefix('{')
+            .and_then(|p| p.strip_suffix('}'))
+            .unwrap_or(path);
+        let path = path.strip_prefix('$').unwrap_or(path);
+        if path.is_empty() {
+            bail!("empty path");
+        }
+
+        let mut segments = Vec::new();
+        let mut rest = path;
+        while !rest.is_empty() {
+            if let Some(after) = rest.strip_prefix('.') {
+                let end = after.find(['.', '[']).unwrap_or(after.len());
+                let field = &after[..end];
+                if field.is_empty() {
+                    bail!("empty field name in '{}'", path);
+                }
+                segments.push(if field == "*" {
+                    Segment::Wildcard
+                } else {
+                    Segment::Field(field.to_string())
+                });
+                rest = &after[end..];
+            } else if let Some(after) = rest.strip_prefix('[') {
+                let end = after
+                    .find(']')
+                    .ok_or_else(|| anyhow!("unclosed '[' in '{}'", path))?;
+                let inner = after[..end].trim();
+                segments.push(match inner {
+                    "*" => Segment::Wildcard,
+                    quoted
+                        if quoted.len() >= 2
+                            && (quoted.starts_with('\'') && quoted.ends_with('\'')
+                                || quoted.starts_with('"') && quoted.ends_with('"')) =>
+                    {
+                        Segment::Field(quoted[1..quoted.len() - 1].to_string())
+                    }
+                    index => Segment::Index(
+                        index
+                            .parse()
+                            .map_err(|_| anyhow!("invalid index '[{}]' in '{}'", index, path))?,
+                    ),
+                });
+                rest = &after[end + 1..];
+            } else {
+                bail!("expected '.' or '[' at '{}' in '{}'", rest, path);
+            }
+        }
+        Ok(Self { segments })
+    }
+
+    /// All values the path selects in `obj`, in document order.
+    pub fn select<'a>(&self, obj: &'a Value) -> Vec<&'a Value> {
+        let mut current = vec![obj];
+        for segment in &self.segments {
+            current = current
+                .into_iter()
+                .flat_map(|value| -> Vec<&'a Value> {
+                    match (segment, value) {
+                        (Segment::Field(field), Value::Object(map)) => {
+                            map.get(field).into_iter().collect()
+                        }
+                        (Segment::Index(index), Value::Array(items)) => {
+                            items.get(*index).into_iter().collect()
+                        }
+                        (Segment::Wildcard, Value::Array(items)) => items.iter().collect(),
+                        (Segment::Wildcard, Value::Object(map)) => map.values().collect(),
+                        _ => Vec::new(),
+                    }
+                })
+                .collect();
+        }
+        current.retain(|value| !value.is_null());
+        current
+    }
+}
+
+/// Join selected values with `,`: strings as-is, everything else as JSON.
+fn format_values(values: &[&Value]) -> String {
+    values
+        .iter()
+        .map(|value| match value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn column(name: &str, path: Option<&str>, hide: bool) -> ColumnConfig {
+        ColumnConfig {
+            name: name.to_string(),
+            path: path.map(str::to_string),
+            hide,
+        }
+    }
+
+    #[test]
+    fn json_path_selects_fields_indices_and_wildcards() {
+        let obj = json!({
+            "metadata": {"labels": {"app.kubernetes.io/name": "podinfo"}},
+            "spec": {
+                "valuesFrom": [
+                    {"kind": "ConfigMap", "name": "common"},
+                    {"kind": "Secret", "name": "db"}
+                ],
+                "install": {"remediation": {"retries": 3}}
+            }
+        });
+        let select = |path: &str| format_values(&JsonPath::parse(path).unwrap().select(&obj));
+
+        assert_eq!(select(".spec.valuesFrom[*].name"), "common,db");
+        assert_eq!(select("{.spec.valuesFrom[1].kind}"), "Secret");
+        assert_eq!(
+            select("$.metadata.labels['app.kubernetes.io/name']"),
+            "podinfo"
+        );
+        assert_eq!(select(".spec.install.remediation.retries"), "3");
+        assert!(
+            JsonPath::parse(".spec.missing")
+                .unwrap()
+                .select(&obj)
+                .is_empty()
+        );
+        assert!(JsonPath::parse(".spec[").is_err());
+        assert!(JsonPath::parse(".spec..name").is_err());
+        assert!(JsonPath::parse("spec").is_err());
+    }
+
+    #[test]
+    fn configured_columns_add_hide_and_reorder() {
+        let defaults = ["STATUS", "NAME", "CHART", "PRUNE", "AGE"];
+
+        let values_from = column("values_from", Some(".spec.valuesFrom[*].name"), false);
+        let prune = column("prune", None, true);
+        assert_eq!(
+            apply_columns(&defaults, &[&values_from, &prune]),
+            ["STATUS", "NAME", "CHART", "VALUES_FROM", "AGE"]
+        );
+
+        let name = column("NAME", None, false);
+        let chart = column("chart", None, false);
+        assert_eq!(
+            apply_columns(&defaults, &[&name, &values_from, &chart]),
+            ["NAME", "VALUES_FROM", "CHART"]
+        );
+    }
+
+    #[test]
+    fn keys_resolve_aliases() {
+        let config = ColumnsConfig::from([
+            ("hr".to_string(), vec![column("A", Some(".spec.a"), false)]),
+            (
+                "Kustomization".to_string(),
+                vec![column("B", Some(".spec.b"), false)],
+            ),
+        ]);
+        let hr = columns_for(&config, "HelmRelease");
+        assert_eq!(hr.len(), 1);
+        assert_eq!(hr[0].name, "A");
+        assert!(columns_for(&config, "GitRepository").is_empty());
+
+        let fields = extract_custom_fields(&json!({"spec": {"a": "x"}}), &hr);
+        assert_eq!(fields.get("A").map(String::as_str), Some("x"));
+    }
+
+    #[test]
+    fn validate_reports_bad_entries() {
+        let error = |kind: &str, c: ColumnConfig| {
+            validate(&ColumnsConfig::from([(kind.to_string(), vec![c])]))
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(error("hr", column("X", Some(".spec[x"), false)).contains("invalid path for 'X'"));
+        assert!(error("hr", column("READY", Some(".spec.x"), false)).contains("built-in column"));
+        assert!(error("hr", column("NOPE", None, false)).contains("not a built-in column"));
+        assert!(
+            validate(&ColumnsConfig::from([(
+                "Widget".to_string(),
+                vec![column("size", Some(".spec.size"), false)]
+            )]))
+            .is_ok()
+        );
+    }
+}
@@ -108,6 +108,9 @@ impl ExtraKind {
 #[derive(Debug, Clone, Default)]
 pub struct ExtraKindRegistry {
     kinds: Arc<RwLock<HashMap<String, ExtraKind>>>,
+    /// User aliases from the `aliases` config (lowercase name → one-word
+    /// command), resolved next to the CRD short names.
+    aliases: Arc<RwLock<HashMap<String, String>>>,
 }
 
 impl ExtraKindRegistry {
@@ -138,10 +141,35 @@ impl ExtraKindRegistry {
             .cloned()
     }
 
-    /// Resolve a `:` command token (kind, plural, or short name — case
-    /// insensitive) to the kind name.
+    /// Register user aliases (the `aliases` config). Aliases that expand to a
+    /// single word resolve like short names once that word names a
+    /// discovered kind, so `tf: terraform` works before and after discovery.
+    pub fn add_aliases(&self, aliases: &HashMap<String, String>) {
+        let mut registered = self.aliases.write().expect("extra kind registry poisoned");
+        for (name, expansion) in aliases {
+            let expansion = expansion.trim().trim_start_matches(':').trim();
+            if !expansion.is_empty() && !expansion.contains(char::is_whitespace) {
+                registered.insert(name.to_lowercase(), expansion.to_lowercase());
+            }
+        }
+    }
+
+    /// Resolve a `:` command token (kind, plural, short name or user alias —
+    /// case insensitive) to the kind name.
     pub fn resolve_command(&self, token: &str) -> Option<String> {
         let token = token.to_lowercase();
+        self.find_kind(&token).or_else(|| {
+            let target = self
+                .aliases
+                .read()
+                .expect("extra kind registry poisoned")
+                .get(&token)
+                .cloned()?;
+            self.find_kind(&target)
+        })
+    }
+
+    fn find_kind(&self, token: &str) -> Option<String> {
         self.kinds
             .read()
             .expect("extra kind registry poisoned")
@@ -285,6 +313,25 @@ mod tests {
         assert!(registry.is_empty());
     }
 
+    #[test]
+    fn user_aliases_resolve_like_short_names() {
+        let registry = ExtraKindRegistry::default();
+        registry.add_aliases(&HashMap::from([
+            ("gadget".to_string(), "wd".to_string()),
+            ("broken".to_string(), "wd status:failed".to_string()),
+        ]));
+        // Nothing to resolve to until the kind is discovered
+        assert!(registry.resolve_command("gadget").is_none());
+
+        registry.insert(ExtraKind::from_crd(&crd("Widget", "Namespaced", Some("v1"))).unwrap());
+        assert_eq!(
+            registry.resolve_command("Gadget").as_deref(),
+            Some("Widget")
+        );
+        // Multi-word aliases are commands, not kind names
+        assert!(registry.resolve_command("broken").is_none());
+    }
+
     #[test]
     fn clear_forgets_all_kinds() {
         // Context switches clear the registry via ResourceWatcher::stop() so
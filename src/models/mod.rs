@@ -13,6 +13,9 @@ pub mod _generated;
 // Manual extensions
 pub mod extensions;
 
+// User-defined list columns
+pub mod columns;
+
 // Dynamically discovered extra resource kinds (#197)
 pub mod extra_kinds;
 
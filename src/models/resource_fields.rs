@@ -3,6 +3,7 @@
 //! These are thin wrappers that delegate to FluxResourceKind methods.
 
 use crate::models::FluxResourceKind;
+use crate::models::columns::{self, ColumnsConfig};
 use crate::models::flux_resource_kind::field_names;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -33,3 +34,25 @@ pub fn get_resource_type_columns(resource_type: &str) -> Vec<&'static str> {
         ],
     }
 }
+
+/// Extract display fields plus the custom columns configured for the type
+pub fn extract_fields_with_columns(
+    resource_type: &str,
+    obj: &Value,
+    columns: &ColumnsConfig,
+) -> HashMap<String, String> {
+    let mut fields = extract_resource_specific_fields(resource_type, obj);
+    let configured = columns::columns_for(columns, resource_type);
+    if !configured.is_empty() {
+        fields.extend(columns::extract_custom_fields(obj, &configured));
+    }
+    fields
+}
+
+/// Column headers of the type-specific list (including AGE) with the
+/// configured columns added, hidden or reordered
+pub fn get_list_columns(resource_type: &str, columns: &ColumnsConfig) -> Vec<String> {
+    let mut defaults = get_resource_type_columns(resource_type);
+    defaults.push("AGE");
+    columns::apply_columns(&defaults, &columns::columns_for(columns, resource_type))
+}
@@ -800,6 +800,47 @@ pub async fn apply_resource_yaml(
     namespace: &str,
     name: &str,
     yaml_str: &str,
+) -> anyhow::Result<()> {
+    let params = PatchParams::apply("flux9s").force();
+    server_side_apply(client, resource_type, namespace, name, yaml_str, &params).await?;
+    tracing::info!(
+        "Successfully applied {}/{} in namespace {} via SSA",
+        resource_type,
+        name,
+        namespace
+    );
+    Ok(())
+}
+
+/// Dry-run [`apply_resource_yaml`]: the server runs the full apply — schema
+/// validation and admission webhooks included — without persisting anything,
+/// so an edit can be previewed before it is saved.
+pub async fn dry_run_resource_yaml(
+    client: &kube::Client,
+    resource_type: &str,
+    namespace: &str,
+    name: &str,
+    yaml_str: &str,
+) -> anyhow::Result<()> {
+    let mut params = PatchParams::apply("flux9s").force();
+    params.dry_run = true;
+    server_side_apply(client, resource_type, namespace, name, yaml_str, &params).await?;
+    tracing::debug!(
+        "Dry-run apply of {}/{} in namespace {} passed",
+        resource_type,
+        name,
+        namespace
+    );
+    Ok(())
+}
+
+async fn server_side_apply(
+    client: &kube::Client,
+    resource_type: &str,
+    namespace: &str,
+    name: &str,
+    yaml_str: &str,
+    params: &PatchParams,
 ) -> anyhow::Result<()> {
     use anyhow::Context as _;
 
@@ -813,8 +854,7 @@ pub async fn apply_resource_yaml(
 
     let api = get_resource_api(client, resource_type, namespace, name).await?;
 
-    let params = PatchParams::apply("flux9s").force();
-    api.patch(name, &params, &Patch::Apply(&value))
+    api.patch(name, params, &Patch::Apply(&value))
         .await
         .map_err(|e| {
             // Surface a user-friendly message for 409 Conflict (concurrent modification)
@@ -827,13 +867,6 @@ pub async fn apply_resource_yaml(
                 anyhow::anyhow!("Failed to apply resource: {}", msg)
             }
         })?;
-
-    tracing::info!(
-        "Successfully applied {}/{} in namespace {} via SSA",
-        resource_type,
-        name,
-        namespace
-    );
     Ok(())
 }
 
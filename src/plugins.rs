@@ -0,0 +1,303 @@
+//! Custom commands on the selected resource
+//!
+//! Plugins declared under `plugins:` in config (modelled on k9s
+//! `plugins.yaml`) bind a shortcut to a local command that runs against the
+//! resource selected in the TUI:
+//!
+//! ```yaml
+//! plugins:
+//!   flux-diff:
+//!     shortcut: ctrl+k
+//!     description: flux diff
+//!     scopes: [ks]
+//!     command: sh
+//!     args: [-c, "flux diff ks $NAME -n $NAMESPACE --path ./clusters/$CONTEXT | less"]
+//! ```
+//!
+//! `scopes` lists kinds (names or aliases, discovered kinds included) and
+//! views ([`VIEW_SCOPES`]); a plugin applies when the selected resource's kind
+//! and the current view each match, an empty list of either matching
+//! everything. `all` matches everything.
+//!
+//! The command runs directly, not through a shell, with these placeholders
+//! replaced in `command` and `args`:
+//!
+//! | Placeholder | Value |
+//! |-------------|-------|
+//! | `$NAME` / `$NAMESPACE` / `$KIND` | the selected resource |
+//! | `$CONTEXT` | kubeconfig context |
+//! | `$REVISION` | applied/artifact revision, empty when unknown |
+//!
+//! Foreground plugins take over the terminal like the editor does; with
+//! `background: true` the command runs detached and flux9s stays on screen.
+
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::process::{Command, Stdio};
+
+use crate::models::FluxResourceKind;
+use crate::watcher::ResourceInfo;
+
+/// Plugins by name, as found under `plugins:` in config
+pub type PluginsConfig = HashMap<String, PluginConfig>;
+
+/// View names usable in `scopes`
+pub const VIEW_SCOPES: &[&str] = &[
+    "list",
+    "favorites",
+    "events",
+    "timeline",
+    "graph",
+    "detail",
+    "yaml",
+    "describe",
+    "trace",
+    "history",
+    "diff",
+    "release",
+];
+
+/// One configured plugin
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginConfig {
+    /// Key that runs the plugin, e.g. `ctrl+k` or `Shift-P` (k9s spelling)
+    #[serde(alias = "shortCut")]
+    pub shortcut: String,
+    /// Shown in the help view; defaults to the plugin name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Kinds and views the plugin applies to; empty applies everywhere
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scopes: Vec<String>,
+    /// Program to run
+    pub command: String,
+    /// Arguments, with placeholders replaced
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    /// Run detached instead of suspending the TUI
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub background: bool,
+}
+
+impl PluginConfig {
+    /// Whether the plugin applies to a resource of `kind` shown in `view`
+    /// (one of [`VIEW_SCOPES`]).
+    pub fn applies_to(&self, kind: &str, view: &str) -> bool {
+        if self
+            .scopes
+            .iter()
+            .any(|scope| scope.eq_ignore_ascii_case("all"))
+        {
+            return true;
+        }
+        let (views, kinds): (Vec<&String>, Vec<&String>) =
+            self.scopes.iter().partition(|scope| is_view_scope(scope));
+        let view_matches = views.is_empty() || views.iter().any(|v| v.eq_ignore_ascii_case(view));
+        let kind_matches = kinds.is_empty()
+            || kinds
+                .iter()
+                .any(|k| resolve_kind(k).eq_ignore_ascii_case(kind));
+        view_matches && kind_matches
+    }
+
+    /// Program and arguments for `info`, placeholders replaced.
+    pub fn command_line(&self, info: &ResourceInfo, context: &str) -> (String, Vec<String>) {
+        let program = expand(&self.command, info, context);
+        let args = self
+            .args
+            .iter()
+            .map(|arg| expand(arg, info, context))
+            .collect();
+        (program, args)
+    }
+}
+
+fn is_view_scope(scope: &str) -> bool {
+    VIEW_SCOPES.iter().any(|v| v.eq_ignore_ascii_case(scope))
+}
+
+/// Resolve a scope (kind, alias, plural or CRD short name) to a kind name.
+fn resolve_kind(scope: &str) -> String {
+    FluxResourceKind::from_str_case_insensitive(scope)
+        .map(|kind| kind.as_str().to_string())
+        .or_else(|| crate::models::extra_kinds::global().resolve_command(scope))
+        .unwrap_or_else(|| scope.to_string())
+}
+
+/// Replace `$NAME`, `$NAMESPACE`, `$KIND`, `$CONTEXT` and `$REVISION` (also
+/// written `${NAME}`). Other `$` sequences are kept, so shell variables in a
+/// `sh -c` argument still reach the shell.
+pub fn expand(template: &str, info: &ResourceInfo, context: &str) -> String {
+    let value = |name: &str| match name {
+        "NAME" => Some(info.name.clone()),
+        "NAMESPACE" => Some(info.namespace.clone()),
+        "KIND" => Some(info.resource_type.clone()),
+        "CONTEXT" => Some(context.to_string()),
+        "REVISION" => Some(info.revision.clone().unwrap_or_default()),
+        _ => None,
+    };
+
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        let after = &rest[pos + 1..];
+        let (name, consumed) = match after.strip_prefix('{') {
+            Some(braced) => match braced.find('}') {
+                Some(end) => (&braced[..end], end + 2),
+                None => ("", 0),
+            },
+            None => {
+                let end = after
+                    .find(|c: char| !(c.is_ascii_uppercase() || c == '_'))
+                    .unwrap_or(after.len());
+                (&after[..end], end)
+            }
+        };
+        match value(name) {
+            Some(v) => {
+                out.push_str(&v);
+                rest = &after[consumed..];
+            }
+            None => {
+                out.push('$');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Check the parts of each plugin that do not depend on the TUI keymap.
+/// Shortcuts are checked against the keymap by the TUI.
+pub fn validate(plugins: &PluginsConfig) -> Result<()> {
+    let mut names: Vec<&String> = plugins.keys().collect();
+    names.sort();
+    for name in names {
+        let plugin = &plugins[name];
+        if plugin.shortcut.trim().is_empty() {
+            bail!("plugins.{}: no shortcut", name);
+        }
+        if plugin.command.trim().is_empty() {
+            bail!("plugins.{}: no command", name);
+        }
+    }
+    Ok(())
+}
+
+/// Start a background plugin without waiting on it; a reaper thread collects
+/// the exit status so failures end up in the log rather than as zombies.
+pub fn spawn_background(name: &str, program: &str, args: &[String]) -> Result<()> {
+    // Detached stdio: inherited handles would draw over the TUI
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    let name = name.to_string();
+    std::thread::spawn(move || match child.wait() {
+        Ok(status) if !status.success() => {
+            tracing::warn!("Plugin '{}' exited with {}", name, status);
+        }
+        Ok(_) => {}
+        Err(e) => tracing::warn!("Failed to wait for plugin '{}': {}", name, e),
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info() -> ResourceInfo {
+        ResourceInfo {
+            name: "podinfo".to_string(),
+            namespace: "apps".to_string(),
+            resource_type: "HelmRelease".to_string(),
+            age: None,
+            suspended: Some(false),
+            ready: Some(true),
+            message: None,
+            revision: Some("6.5.0".to_string()),
+            labels: Default::default(),
+            annotations: Default::default(),
+            last_reconciled: None,
+            reconciliation_history: Vec::new(),
+        }
+    }
+
+    fn plugin(scopes: &[&str]) -> PluginConfig {
+        PluginConfig {
+            shortcut: "p".to_string(),
+            scopes: scopes.iter().map(|s| s.to_string()).collect(),
+            command: "echo".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn placeholders_expand_and_other_variables_are_kept() {
+        let info = info();
+        assert_eq!(
+            expand(
+                "$KIND/$NAMESPACE/$NAME@$REVISION on ${CONTEXT}",
+                &info,
+                "prod"
+            ),
+            "HelmRelease/apps/podinfo@6.5.0 on prod"
+        );
+        assert_eq!(
+            expand("$HOME $NAMEX $ ${NAME", &info, "prod"),
+            "$HOME $NAMEX $ ${NAME"
+        );
+        let (program, args) = PluginConfig {
+            command: "flux".to_string(),
+            args: vec!["diff".to_string(), "hr".to_string(), "$NAME".to_string()],
+            ..plugin(&[])
+        }
+        .command_line(&info, "prod");
+        assert_eq!(program, "flux");
+        assert_eq!(args, vec!["diff", "hr", "podinfo"]);
+    }
+
+    #[test]
+    fn scopes_match_kinds_and_views() {
+        assert!(plugin(&[]).applies_to("HelmRelease", "yaml"));
+        assert!(plugin(&["all"]).applies_to("Kustomization", "list"));
+        assert!(plugin(&["hr"]).applies_to("HelmRelease", "detail"));
+        assert!(!plugin(&["hr"]).applies_to("Kustomization", "detail"));
+        assert!(plugin(&["list", "favorites"]).applies_to("Kustomization", "favorites"));
+        assert!(!plugin(&["list"]).applies_to("Kustomization", "graph"));
+        assert!(plugin(&["helmreleases", "list"]).applies_to("HelmRelease", "list"));
+        assert!(!plugin(&["helmreleases", "list"]).applies_to("HelmRelease", "yaml"));
+    }
+
+    #[test]
+    fn plugins_parse_k9s_spelling_and_validate() {
+        let plugins: PluginsConfig = serde_yaml::from_str(
+            "diff:\n  shortCut: Shift-D\n  scopes: [ks]\n  command: flux\n  args: [diff, ks, $NAME]\n",
+        )
+        .unwrap();
+        assert_eq!(plugins["diff"].shortcut, "Shift-D");
+        assert!(!plugins["diff"].background);
+        assert!(validate(&plugins).is_ok());
+
+        let missing = PluginsConfig::from([(
+            "x".to_string(),
+            PluginConfig {
+                command: " ".to_string(),
+                ..plugin(&[])
+            },
+        )]);
+        assert!(
+            validate(&missing)
+                .unwrap_err()
+                .to_string()
+                .contains("no command")
+        );
+    }
+}
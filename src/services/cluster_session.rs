@@ -5,15 +5,20 @@
 //! This is the primary entry point for using flux9s as a library.
 
 use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
+use std::sync::RwLock;
+use std::time::Duration;
 use tokio::sync::mpsc;
 
 use crate::config::schema::Config;
 use crate::constants::MAX_RECONCILIATION_HISTORY;
+use crate::hooks::HookRunner;
 use crate::models::FluxResourceKind;
 use crate::watcher::{
-    ResourceInfo, ResourceState, ResourceWatcher, WatchEvent, extract_annotations, extract_labels,
-    extract_reconciliation_info, extract_status_fields, resource_key,
+    ResourceInfo, ResourceState, ResourceWatcher, WatchEvent, WatchRecord, extract_annotations,
+    extract_labels, extract_reconciliation_info, extract_status_fields, resolve_ready,
+    resource_key,
 };
 
 /// A headless session connected to a single Kubernetes cluster.
@@ -47,8 +52,14 @@ pub struct ClusterSession {
     namespace: Option<String>,
     controller_namespace: String,
     state: ResourceState,
+    /// Full objects from the last `Applied` event, keyed like `state`. Kept so
+    /// callers can extract per-kind columns and status fields `ResourceInfo`
+    /// doesn't carry.
+    objects: RwLock<HashMap<String, serde_json::Value>>,
     watcher: ResourceWatcher,
     event_rx: mpsc::UnboundedReceiver<WatchEvent>,
+    /// Status-transition hooks from config, fired by `apply_event`
+    hooks: HookRunner,
 }
 
 impl ClusterSession {
@@ -90,20 +101,57 @@ impl ClusterSession {
             namespace,
             controller_namespace: controller_namespace.to_string(),
             state,
+            objects: RwLock::new(HashMap::new()),
             watcher,
             event_rx,
+            hooks: HookRunner::new(config.hooks.clone()),
         })
     }
 
     /// Connect using the default kubeconfig and configuration.
     pub async fn connect_default(config: &Config) -> Result<Self> {
-        let client = crate::kube::create_client()
-            .await
-            .context("Failed to create Kubernetes client")?;
+        let namespace = Self::configured_namespace(config).await;
+        Self::connect_in_namespace(None, namespace, config).await
+    }
 
-        let context = crate::kube::get_context()
-            .await
-            .context("Failed to get current context")?;
+    /// Connect using a specific kubeconfig file path.
+    pub async fn connect_from_kubeconfig(path: &Path, config: &Config) -> Result<Self> {
+        let namespace = Self::configured_namespace(config).await;
+        Self::connect_in_namespace(Some(path), namespace, config).await
+    }
+
+    /// Connect with an explicit namespace scope, ignoring `defaultNamespace`.
+    ///
+    /// `namespace = None` watches all namespaces. Uses the kubeconfig at
+    /// `kubeconfig` when given, otherwise the default kubeconfig (or the
+    /// in-cluster config).
+    pub async fn connect_in_namespace(
+        kubeconfig: Option<&Path>,
+        namespace: Option<String>,
+        config: &Config,
+    ) -> Result<Self> {
+        let (client, context) = match kubeconfig {
+            Some(path) => {
+                let client = crate::kube::create_client_from_kubeconfig_path(path)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Failed to create client from kubeconfig: {}",
+                            path.display()
+                        )
+                    })?;
+                (client, crate::kube::get_context_from_kubeconfig_path(path)?)
+            }
+            None => {
+                let client = crate::kube::create_client()
+                    .await
+                    .context("Failed to create Kubernetes client")?;
+                let context = crate::kube::get_context()
+                    .await
+                    .context("Failed to get current context")?;
+                (client, context)
+            }
+        };
 
         // Verify the API server is actually reachable before starting watchers.
         crate::kube::check_connectivity(
@@ -114,15 +162,6 @@ impl ClusterSession {
         .map_err(|e| anyhow::anyhow!("{}", e))
         .context("Failed to connect to the Kubernetes API server")?;
 
-        let namespace = if config.default_namespace.is_empty()
-            || config.default_namespace == "all"
-            || config.default_namespace == "-A"
-        {
-            crate::kube::get_default_namespace().await
-        } else {
-            Some(config.default_namespace.clone())
-        };
-
         let state = ResourceState::new();
         let (mut watcher, event_rx) = ResourceWatcher::new(
             client.clone(),
@@ -141,63 +180,24 @@ impl ClusterSession {
             namespace,
             controller_namespace: config.default_controller_namespace.clone(),
             state,
+            objects: RwLock::new(HashMap::new()),
             watcher,
             event_rx,
+            hooks: HookRunner::new(config.hooks.clone()),
         })
     }
 
-    /// Connect using a specific kubeconfig file path.
-    pub async fn connect_from_kubeconfig(path: &Path, config: &Config) -> Result<Self> {
-        let client = crate::kube::create_client_from_kubeconfig_path(path)
-            .await
-            .with_context(|| {
-                format!(
-                    "Failed to create client from kubeconfig: {}",
-                    path.display()
-                )
-            })?;
-
-        let context = crate::kube::get_context_from_kubeconfig_path(path)?;
-
-        // Verify the API server is actually reachable before starting watchers.
-        crate::kube::check_connectivity(
-            &client,
-            crate::kube::resolve_connect_timeout(config.connect_timeout_seconds),
-        )
-        .await
-        .map_err(|e| anyhow::anyhow!("{}", e))
-        .context("Failed to connect to the Kubernetes API server")?;
-
-        let namespace = if config.default_namespace.is_empty()
+    /// Resolve the namespace scope from `defaultNamespace`: empty, `all` and
+    /// `-A` defer to [`crate::kube::get_default_namespace`].
+    async fn configured_namespace(config: &Config) -> Option<String> {
+        if config.default_namespace.is_empty()
             || config.default_namespace == "all"
             || config.default_namespace == "-A"
         {
             crate::kube::get_default_namespace().await
         } else {
             Some(config.default_namespace.clone())
-        };
-
-        let state = ResourceState::new();
-        let (mut watcher, event_rx) = ResourceWatcher::new(
-            client.clone(),
-            namespace.clone(),
-            config.default_controller_namespace.clone(),
-            false, // headless sessions don't run CRD discovery
-        );
-
-        watcher
-            .watch_all()
-            .context("Failed to start resource watchers")?;
-
-        Ok(Self {
-            client,
-            context,
-            namespace,
-            controller_namespace: config.default_controller_namespace.clone(),
-            state,
-            watcher,
-            event_rx,
-        })
+        }
     }
 
     /// Returns a reference to the underlying Kubernetes client.
@@ -225,6 +225,16 @@ impl ClusterSession {
         self.state.all()
     }
 
+    /// Returns the full object last seen for a resource key
+    /// (`resource_type:namespace:name`), if any.
+    pub fn object(&self, key: &str) -> Option<serde_json::Value> {
+        self.objects
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(key)
+            .cloned()
+    }
+
     /// Wait for and return the next watch event.
     ///
     /// Returns `None` if the watcher channel is closed.
@@ -239,11 +249,35 @@ impl ClusterSession {
         self.event_rx.try_recv().ok()
     }
 
+    /// Wait for the next event that has a [`WatchRecord`], apply it to the
+    /// state, and return the record.
+    ///
+    /// Events without a serialized form (controller pods and deployments) are
+    /// applied and skipped. Returns `None` if the watcher channel is closed.
+    pub async fn next_record(&mut self) -> Option<WatchRecord> {
+        loop {
+            let event = self.event_rx.recv().await?;
+            let record = WatchRecord::from_event(&event, chrono::Utc::now());
+            self.apply_event(event);
+            if record.is_some() {
+                return record;
+            }
+        }
+    }
+
+    /// Start streaming Kubernetes Events for the session's namespace scope.
+    /// They arrive as `KubeEventApplied`/`KubeEventDeleted`; not started by
+    /// default because Events churn far more than Flux resources.
+    pub fn watch_kube_events(&mut self) -> Result<()> {
+        self.watcher.watch_kube_events()
+    }
+
     /// Apply a watch event to the internal state.
     ///
     /// This processes `Applied` and `Deleted` events, updating the resource
-    /// state accordingly. Pod and Deployment events are ignored (they are
-    /// TUI-specific for controller status display).
+    /// state accordingly and firing any configured hooks. Pod and Deployment
+    /// events are ignored (they are TUI-specific for controller status
+    /// display).
     pub fn apply_event(&self, event: WatchEvent) {
         match event {
             WatchEvent::Applied(resource_type, ns, name, obj_json) => {
@@ -264,24 +298,12 @@ impl ClusterSession {
                 let (suspended, ready, message, revision) = extract_status_fields(&obj_json);
 
                 // Stateless resources have no status.conditions — mark as ready
-                let ready = if ready.is_none() {
-                    if let Some(kind) = FluxResourceKind::parse_optional(&resource_type) {
-                        if kind.is_stateless() {
-                            Some(true)
-                        } else {
-                            ready
-                        }
-                    } else {
-                        ready
-                    }
-                } else {
-                    ready
-                };
+                let ready = resolve_ready(&resource_type, ready);
 
                 let labels = extract_labels(&obj_json);
                 let annotations = extract_annotations(&obj_json);
 
-                let mut history = if let Some(existing) = existing_info {
+                let mut history = if let Some(existing) = &existing_info {
                     existing.reconciliation_history.clone()
                 } else {
                     Vec::new()
@@ -296,32 +318,43 @@ impl ClusterSession {
                     }
                 }
 
-                self.state.upsert(
-                    key,
-                    ResourceInfo {
-                        name,
-                        namespace: ns,
-                        resource_type,
-                        age: crate::watcher::extract_creation_timestamp(&obj_json),
-                        suspended,
-                        ready,
-                        message,
-                        revision,
-                        labels,
-                        annotations,
-                        last_reconciled: reconciliation_event.as_ref().map(|e| e.timestamp),
-                        reconciliation_history: history,
-                    },
-                );
+                let info = ResourceInfo {
+                    name,
+                    namespace: ns,
+                    resource_type,
+                    age: crate::watcher::extract_creation_timestamp(&obj_json),
+                    suspended,
+                    ready,
+                    message,
+                    revision,
+                    labels,
+                    annotations,
+                    last_reconciled: reconciliation_event.as_ref().map(|e| e.timestamp),
+                    reconciliation_history: history,
+                };
+                self.hooks
+                    .fire(&self.context, existing_info.as_ref(), &info);
+                self.state.upsert(key.clone(), info);
+                self.objects
+                    .write()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner)
+                    .insert(key, obj_json);
             }
             WatchEvent::Deleted(resource_type, ns, name) => {
                 let key = resource_key(&ns, &name, &resource_type);
                 self.state.remove(&key);
+                self.objects
+                    .write()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner)
+                    .remove(&key);
             }
             // Pod/Deployment events are TUI-specific (controller status bar)
             WatchEvent::Error(msg) => {
                 tracing::warn!("Watch event error: {}", msg);
             }
+            WatchEvent::InitialSyncDone(name) => {
+                tracing::debug!("Initial sync done: {}", name);
+            }
             WatchEvent::WatcherDegraded(name) => {
                 tracing::warn!("Watcher degraded (retrying with backoff): {}", name);
             }
@@ -353,6 +386,37 @@ impl ClusterSession {
         count
     }
 
+    /// Apply events until every Flux kind has finished its initial list, or
+    /// `timeout` elapses.
+    ///
+    /// Kinds whose CRD is absent or whose watch is forbidden by RBAC count as
+    /// synced (they will never deliver anything). Returns the kinds that had
+    /// not synced when the wait ended, sorted — empty means the snapshot is
+    /// complete.
+    pub async fn wait_for_initial_sync(&mut self, timeout: Duration) -> Vec<String> {
+        let mut pending: HashSet<String> = FluxResourceKind::all()
+            .iter()
+            .map(|kind| kind.as_str().to_string())
+            .collect();
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        while !pending.is_empty() {
+            let event = match tokio::time::timeout_at(deadline, self.event_rx.recv()).await {
+                Ok(Some(event)) => event,
+                // Channel closed or deadline reached
+                Ok(None) | Err(_) => break,
+            };
+            if let WatchEvent::InitialSyncDone(name) | WatchEvent::WatcherForbidden(name) = &event {
+                pending.remove(name);
+            }
+            self.apply_event(event);
+        }
+
+        let mut pending: Vec<String> = pending.into_iter().collect();
+        pending.sort();
+        pending
+    }
+
     /// Switch to a different cluster context.
     ///
     /// Creates a new client, checks connectivity, and restarts watchers.
@@ -390,6 +454,7 @@ impl ClusterSession {
         // Succeeded! Now we can safely stop the old watcher, clear state, and update fields.
         self.watcher.stop();
         self.state.clear();
+        self.clear_objects();
 
         self.client = new_client;
         self.context = context.to_string();
@@ -403,10 +468,18 @@ impl ClusterSession {
     /// Change the namespace filter and restart watchers.
     pub fn set_namespace(&mut self, namespace: Option<String>) -> Result<()> {
         self.state.clear();
+        self.clear_objects();
         self.watcher.set_namespace(namespace.clone())?;
         self.namespace = namespace;
         Ok(())
     }
+
+    fn clear_objects(&self) {
+        self.objects
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clear();
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,396 @@
+//! Persisted reconciliation history
+//!
+//! Only a few Flux kinds keep their own `status.history` (see
+//! [`FluxResourceKind::history_supported_types`](crate::models::FluxResourceKind::history_supported_types)).
+//! flux9s records the status changes it observes for every watched resource
+//! as [`ReconciliationEvent`]s and keeps them per context in
+//! `history/<context>.json` under the data dir, so a restart doesn't wipe
+//! what was seen. The history view falls back to them for kinds without
+//! `status.history` (sources, image automation, notification kinds), and the
+//! `:timeline` view merges them across resources.
+//!
+//! An event is recorded when a resource's Ready status or revision differs
+//! from the last recorded event; in-progress states (Ready `Unknown`) are
+//! skipped. Events older than the retention window, and
+//! anything past [`MAX_PERSISTED_HISTORY`] per resource, are pruned on load
+//! and on save.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::config::paths;
+use crate::constants::{HISTORY_FLUSH_INTERVAL_SECS, MAX_PERSISTED_HISTORY};
+use crate::watcher::ReconciliationEvent;
+
+const HISTORY_FILE_VERSION: u32 = 1;
+
+/// On-disk layout of a context's history file
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct HistoryFile {
+    version: u32,
+    /// Events per resource key, oldest first
+    #[serde(default)]
+    resources: BTreeMap<String, Vec<ReconciliationEvent>>,
+}
+
+/// Observed reconciliation history for one context
+#[derive(Debug)]
+pub struct HistoryStore {
+    /// `None` keeps history in memory only
+    path: Option<PathBuf>,
+    retention_days: u32,
+    resources: BTreeMap<String, Vec<ReconciliationEvent>>,
+    dirty: bool,
+    last_flush: Instant,
+    /// Bumped on every recorded event
+    generation: u64,
+}
+
+impl Default for HistoryStore {
+    fn default() -> Self {
+        Self::in_memory()
+    }
+}
+
+impl HistoryStore {
+    /// A store that records events but never touches disk.
+    pub fn in_memory() -> Self {
+        Self {
+            path: None,
+            retention_days: 0,
+            resources: BTreeMap::new(),
+            dirty: false,
+            last_flush: Instant::now(),
+            generation: 0,
+        }
+    }
+
+    /// Load the history for `context` from the data dir. A retention of `0`
+    /// disables persistence; a missing or unreadable file starts empty.
+    pub fn load(context: &str, retention_days: u32) -> Self {
+        if retention_days == 0 {
+            return Self::in_memory();
+        }
+        Self::load_from(paths::history_path(context), retention_days)
+    }
+
+    /// Load the history stored at `path`.
+    pub fn load_from(path: PathBuf, retention_days: u32) -> Self {
+        let resources = match std::fs::read_to_string(&path) {
+            Ok(contents) => match serde_json::from_str::<HistoryFile>(&contents) {
+                Ok(file) => file.resources,
+                Err(e) => {
+                    tracing::warn!("Ignoring unreadable history {}: {}", path.display(), e);
+                    BTreeMap::new()
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => {
+                tracing::warn!("Failed to read history {}: {}", path.display(), e);
+                BTreeMap::new()
+            }
+        };
+        let mut store = Self {
+            path: Some(path),
+            retention_days,
+            resources,
+            dirty: false,
+            last_flush: Instant::now(),
+            generation: 0,
+        };
+        store.prune(Utc::now());
+        store
+    }
+
+    /// Observed events for a resource key, oldest first.
+    pub fn events(&self, key: &str) -> &[ReconciliationEvent] {
+        self.resources.get(key).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Every resource key with observed events.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[ReconciliationEvent])> {
+        self.resources
+            .iter()
+            .map(|(key, events)| (key.as_str(), events.as_slice()))
+    }
+
+    /// Record the state of a resource from an `Applied` event. Returns `true`
+    /// if a new event was recorded.
+    pub fn observe(
+        &mut self,
+        key: &str,
+        resource_type: &str,
+        obj: &serde_json::Value,
+        now: DateTime<Utc>,
+    ) -> bool {
+        let Some(event) = observed_event(resource_type, obj, now) else {
+            return false;
+        };
+        let events = self.resources.entry(key.to_string()).or_default();
+        if events
+            .last()
+            .is_some_and(|last| last.status == event.status && last.revision == event.revision)
+        {
+            return false;
+        }
+        events.push(event);
+        if events.len() > MAX_PERSISTED_HISTORY {
+            let excess = events.len() - MAX_PERSISTED_HISTORY;
+            events.drain(..excess);
+        }
+        self.dirty = self.path.is_some();
+        self.generation += 1;
+        true
+    }
+
+    /// Changes whenever an event is recorded, so views derived from the
+    /// history know when to rebuild.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Write the history if it changed and the flush interval has passed.
+    pub fn flush_if_due(&mut self) {
+        if self.dirty
+            && self.last_flush.elapsed() >= Duration::from_secs(HISTORY_FLUSH_INTERVAL_SECS)
+        {
+            self.flush();
+        }
+    }
+
+    /// Write the history if it changed. Failures are logged: losing history
+    /// must never interrupt the session.
+    pub fn flush(&mut self) {
+        if !self.dirty {
+            return;
+        }
+        if let Err(e) = self.save() {
+            tracing::warn!("Failed to persist reconciliation history: {:#}", e);
+        }
+    }
+
+    /// Prune and write the history to disk.
+    pub fn save(&mut self) -> Result<()> {
+        self.last_flush = Instant::now();
+        let Some(path) = self.path.clone() else {
+            return Ok(());
+        };
+        self.prune(Utc::now());
+        if let Some(parent) = path.parent() {
+            paths::ensure_dir(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let file = HistoryFile {
+            version: HISTORY_FILE_VERSION,
+            resources: self.resources.clone(),
+        };
+        let json = serde_json::to_string(&file)?;
+        // Write then rename so a crash mid-write can't truncate the file
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, json).with_context(|| format!("Failed to write {}", tmp.display()))?;
+        std::fs::rename(&tmp, &path)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Drop events outside the retention window and past the per-resource cap.
+    fn prune(&mut self, now: DateTime<Utc>) {
+        let cutoff = now - chrono::Duration::days(i64::from(self.retention_days));
+        let persistent = self.path.is_some();
+        self.resources.retain(|_, events| {
+            if persistent {
+                events.retain(|e| e.timestamp >= cutoff);
+            }
+            if events.len() > MAX_PERSISTED_HISTORY {
+                let excess = events.len() - MAX_PERSISTED_HISTORY;
+                events.drain(..excess);
+            }
+            !events.is_empty()
+        });
+    }
+}
+
+/// The event describing the current state of a resource object, or `None`
+/// while a reconciliation is in progress (Ready is `Unknown`): only outcomes
+/// are recorded, so every reconcile doesn't add an in-progress entry.
+///
+/// The timestamp is the most specific status time the kind reports, falling
+/// back to the observation time.
+pub fn observed_event(
+    resource_type: &str,
+    obj: &serde_json::Value,
+    now: DateTime<Utc>,
+) -> Option<ReconciliationEvent> {
+    let condition = ready_condition(obj);
+    let status = match condition
+        .and_then(|c| c.get("status"))
+        .and_then(|s| s.as_str())
+    {
+        Some("True") => "Success",
+        Some("False") => "Failed",
+        Some(_) => return None,
+        // No Ready condition: stateless kinds count as ready
+        None => match crate::watcher::resolve_ready(resource_type, None) {
+            Some(true) => "Success",
+            _ => "Unknown",
+        },
+    };
+    Some(ReconciliationEvent {
+        timestamp: status_time(obj).unwrap_or(now),
+        revision: observed_revision(obj),
+        status: status.to_string(),
+        message: condition
+            .and_then(|c| c.get("message"))
+            .and_then(|m| m.as_str())
+            .map(str::to_string),
+    })
+}
+
+fn ready_condition(obj: &serde_json::Value) -> Option<&serde_json::Value> {
+    obj.pointer("/status/conditions")?
+        .as_array()?
+        .iter()
+        .find(|c| c.get("type").and_then(|t| t.as_str()) == Some("Ready"))
+}
+
+/// Revision a resource is at: the applied revision for appliers, the chart
+/// version for HelmReleases, the artifact revision for sources, the latest
+/// image or pushed commit for image kinds.
+fn observed_revision(obj: &serde_json::Value) -> Option<String> {
+    let status = obj.get("status")?;
+    [
+        "/lastAppliedRevision",
+        "/lastAttemptedRevision",
+        "/artifact/revision",
+        "/latestImage",
+        "/lastPushCommit",
+    ]
+    .iter()
+    .find_map(|pointer| status.pointer(pointer).and_then(|v| v.as_str()))
+    .map(str::to_string)
+}
+
+fn status_time(obj: &serde_json::Value) -> Option<DateTime<Utc>> {
+    if let Some(event) = crate::watcher::extract_reconciliation_info(obj) {
+        return Some(event.timestamp);
+    }
+    let status = obj.get("status")?;
+    let ready_transition = ready_condition(obj).and_then(|c| c.get("lastTransitionTime"));
+    [
+        status.pointer("/artifact/lastUpdateTime"),
+        status.pointer("/lastScanResult/scanTime"),
+        status.get("lastAutomationRunTime"),
+        ready_transition,
+    ]
+    .into_iter()
+    .flatten()
+    .find_map(|v| v.as_str())
+    .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+    .map(|t| t.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 16, 9, 30, 0).unwrap()
+    }
+
+    fn git_repo(revision: &str, ready: &str, updated: &str) -> serde_json::Value {
+        json!({
+            "kind": "GitRepository",
+            "status": {
+                "artifact": {"revision": revision, "lastUpdateTime": updated},
+                "conditions": [{
+                    "type": "Ready",
+                    "status": ready,
+                    "message": format!("stored artifact for revision '{}'", revision),
+                    "lastTransitionTime": "2026-10-01T00:00:00Z"
+                }]
+            }
+        })
+    }
+
+    #[test]
+    fn observed_event_reads_source_status() {
+        let event = observed_event(
+            "GitRepository",
+            &git_repo("main@sha1:abc", "True", "2026-10-15T12:00:00Z"),
+            now(),
+        )
+        .unwrap();
+        assert_eq!(event.status, "Success");
+        assert_eq!(event.revision.as_deref(), Some("main@sha1:abc"));
+        assert_eq!(
+            event.timestamp,
+            Utc.with_ymd_and_hms(2026, 10, 15, 12, 0, 0).unwrap()
+        );
+
+        // Stateless kinds without a status are recorded at observation time
+        let alert = observed_event("Alert", &json!({"kind": "Alert", "spec": {}}), now()).unwrap();
+        assert_eq!(alert.status, "Success");
+        assert_eq!(alert.timestamp, now());
+
+        // In-progress reconciliations aren't outcomes
+        let progressing = git_repo("main@sha1:abc", "Unknown", "2026-10-15T12:00:00Z");
+        assert!(observed_event("GitRepository", &progressing, now()).is_none());
+    }
+
+    #[test]
+    fn observe_records_only_changes() {
+        let mut store = HistoryStore::in_memory();
+        let key = "GitRepository:flux-system:repo";
+        let v1 = git_repo("main@sha1:abc", "True", "2026-10-15T12:00:00Z");
+        assert!(store.observe(key, "GitRepository", &v1, now()));
+        assert!(!store.observe(key, "GitRepository", &v1, now()));
+        let failed = git_repo("main@sha1:abc", "False", "2026-10-15T12:00:00Z");
+        assert!(store.observe(key, "GitRepository", &failed, now()));
+        let v2 = git_repo("main@sha1:def", "True", "2026-10-15T13:00:00Z");
+        assert!(store.observe(key, "GitRepository", &v2, now()));
+        let statuses: Vec<_> = store
+            .events(key)
+            .iter()
+            .map(|e| e.status.as_str())
+            .collect();
+        assert_eq!(statuses, ["Success", "Failed", "Success"]);
+        assert_eq!(store.iter().count(), 1);
+    }
+
+    #[test]
+    fn history_round_trips_and_prunes_expired_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ctx.json");
+        let key = "Bucket:flux-system:assets";
+
+        let mut store = HistoryStore::load_from(path.clone(), 30);
+        store.observe(
+            key,
+            "Bucket",
+            &git_repo("sha256:old", "True", "2020-01-01T00:00:00Z"),
+            now(),
+        );
+        store.observe(
+            key,
+            "Bucket",
+            &git_repo("sha256:new", "True", &Utc::now().to_rfc3339()),
+            now(),
+        );
+        store.save().unwrap();
+
+        let reloaded = HistoryStore::load_from(path, 30);
+        let revisions: Vec<_> = reloaded
+            .events(key)
+            .iter()
+            .map(|e| e.revision.as_deref())
+            .collect();
+        assert_eq!(revisions, [Some("sha256:new")]);
+    }
+}
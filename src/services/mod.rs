@@ -5,7 +5,11 @@
 //! results via channels, keeping the TUI layer focused on presentation.
 
 pub mod cluster_session;
+pub mod history_store;
+pub mod multi_cluster;
 pub mod resource_service;
 
 pub use cluster_session::ClusterSession;
+pub use history_store::HistoryStore;
+pub use multi_cluster::{ClusterHealth, ClusterResource, MultiClusterSession};
 pub use resource_service::ResourceService;
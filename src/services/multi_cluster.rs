@@ -0,0 +1,297 @@
+//! Several clusters watched at once
+//!
+//! `MultiClusterSession` holds one [`ClusterSession`] per kubeconfig context
+//! and merges their state, tagging every resource with the context it came
+//! from. Contexts that fail to connect are kept as failures instead of
+//! failing the whole set, so one unreachable cluster doesn't hide the rest.
+
+use anyhow::Result;
+use std::collections::HashMap;
+
+use crate::config::schema::Config;
+use crate::services::ClusterSession;
+use crate::watcher::ResourceInfo;
+
+/// A resource and the context whose session watches it
+#[derive(Debug, Clone)]
+pub struct ClusterResource {
+    pub context: String,
+    pub info: ResourceInfo,
+}
+
+/// Resource health of one cluster in a [`MultiClusterSession`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClusterHealth {
+    pub context: String,
+    pub total: usize,
+    pub healthy: usize,
+    /// Not ready (`ready == false`), suspended or not
+    pub failed: usize,
+    pub suspended: usize,
+    /// Why the context could not be connected; counts are zero when set
+    pub error: Option<String>,
+}
+
+impl ClusterHealth {
+    /// Summarize `resources`, all from `context`.
+    pub fn from_resources<'a>(
+        context: &str,
+        resources: impl IntoIterator<Item = &'a ResourceInfo>,
+    ) -> Self {
+        let mut health = Self {
+            context: context.to_string(),
+            ..Self::default()
+        };
+        for r in resources {
+            health.total += 1;
+            if r.is_healthy() {
+                health.healthy += 1;
+            }
+            if r.ready == Some(false) {
+                health.failed += 1;
+            }
+            if r.effective_suspended() {
+                health.suspended += 1;
+            }
+        }
+        health
+    }
+
+    /// Share of healthy resources in percent; 100 for an empty cluster.
+    pub fn percentage(&self) -> f64 {
+        if self.total == 0 {
+            return 100.0;
+        }
+        self.healthy as f64 / self.total as f64 * 100.0
+    }
+}
+
+/// One [`ClusterSession`] per context, watched together.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use flux9s::services::MultiClusterSession;
+/// use flux9s::config::schema::Config;
+///
+/// # async fn example() -> anyhow::Result<()> {
+/// let config = Config::default();
+/// let contexts = vec!["prod-eu-1".to_string(), "prod-us-1".to_string()];
+/// let mut clusters = MultiClusterSession::connect(&contexts, None, &config).await;
+///
+/// clusters.drain_events();
+/// for health in clusters.health() {
+///     println!("{}: {:.1}% healthy", health.context, health.percentage());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct MultiClusterSession {
+    /// Connected sessions, in the order the contexts were given
+    sessions: Vec<ClusterSession>,
+    /// Contexts that failed to connect, with the error
+    failures: Vec<(String, String)>,
+}
+
+impl MultiClusterSession {
+    /// Connect to every context concurrently, watching `namespace` (`None`
+    /// for all namespaces) in each. Duplicate contexts are connected once.
+    pub async fn connect(contexts: &[String], namespace: Option<String>, config: &Config) -> Self {
+        let mut unique: Vec<&String> = Vec::new();
+        for context in contexts {
+            if !unique.contains(&context) {
+                unique.push(context);
+            }
+        }
+
+        let results = futures::future::join_all(unique.iter().map(|context| {
+            ClusterSession::connect(
+                context,
+                namespace.clone(),
+                &config.default_controller_namespace,
+                config,
+            )
+        }))
+        .await;
+
+        let mut sessions = Vec::new();
+        let mut failures = Vec::new();
+        for (context, result) in unique.into_iter().zip(results) {
+            match result {
+                Ok(session) => sessions.push(session),
+                Err(e) => {
+                    tracing::warn!("Failed to connect to context '{}': {:#}", context, e);
+                    failures.push((context.clone(), format!("{:#}", e)));
+                }
+            }
+        }
+        Self { sessions, failures }
+    }
+
+    /// Contexts of the connected sessions.
+    pub fn contexts(&self) -> Vec<&str> {
+        self.sessions.iter().map(ClusterSession::context).collect()
+    }
+
+    /// Contexts that failed to connect, with the error.
+    pub fn failures(&self) -> &[(String, String)] {
+        &self.failures
+    }
+
+    /// Whether no context connected.
+    pub fn is_empty(&self) -> bool {
+        self.sessions.is_empty()
+    }
+
+    /// The session watching `context`.
+    pub fn session(&self, context: &str) -> Option<&ClusterSession> {
+        self.sessions.iter().find(|s| s.context() == context)
+    }
+
+    /// Client of the session watching `context`, for operations on its
+    /// resources.
+    pub fn client(&self, context: &str) -> Option<&kube::Client> {
+        self.session(context).map(ClusterSession::client)
+    }
+
+    /// Apply the pending events of every session. Returns the number of
+    /// events processed.
+    pub fn drain_events(&mut self) -> usize {
+        self.sessions
+            .iter_mut()
+            .map(ClusterSession::drain_events)
+            .sum()
+    }
+
+    /// Every resource of every cluster, tagged with its context.
+    pub fn snapshot(&self) -> Vec<ClusterResource> {
+        self.sessions
+            .iter()
+            .flat_map(|session| {
+                session.snapshot().into_iter().map(|info| ClusterResource {
+                    context: session.context().to_string(),
+                    info,
+                })
+            })
+            .collect()
+    }
+
+    /// Health of each cluster, failed contexts last.
+    pub fn health(&self) -> Vec<ClusterHealth> {
+        self.health_of(&self.snapshot())
+    }
+
+    /// Health of each cluster counting only `resources`, for a merged list
+    /// that has already been narrowed (e.g. by namespace). Failed contexts
+    /// come last.
+    pub fn health_of(&self, resources: &[ClusterResource]) -> Vec<ClusterHealth> {
+        let mut health = health_by_context(&self.contexts(), resources);
+        health.extend(self.failures.iter().map(|(context, error)| ClusterHealth {
+            context: context.clone(),
+            error: Some(error.clone()),
+            ..ClusterHealth::default()
+        }));
+        health
+    }
+
+    /// Change the namespace scope of every session, restarting its watchers.
+    pub fn set_namespace(&mut self, namespace: Option<String>) -> Result<()> {
+        for session in &mut self.sessions {
+            session.set_namespace(namespace.clone())?;
+        }
+        Ok(())
+    }
+}
+
+/// Group `resources` by context into [`ClusterHealth`] entries, in the order
+/// of `contexts`.
+fn health_by_context(contexts: &[&str], resources: &[ClusterResource]) -> Vec<ClusterHealth> {
+    let mut grouped: HashMap<&str, Vec<&ResourceInfo>> = HashMap::new();
+    for r in resources {
+        grouped.entry(r.context.as_str()).or_default().push(&r.info);
+    }
+    contexts
+        .iter()
+        .map(|context| {
+            ClusterHealth::from_resources(
+                context,
+                grouped.get(context).into_iter().flatten().copied(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource(
+        context: &str,
+        name: &str,
+        ready: Option<bool>,
+        suspended: bool,
+    ) -> ClusterResource {
+        ClusterResource {
+            context: context.to_string(),
+            info: ResourceInfo {
+                name: name.to_string(),
+                namespace: "apps".to_string(),
+                resource_type: "HelmRelease".to_string(),
+                age: None,
+                suspended: Some(suspended),
+                ready,
+                message: None,
+                revision: None,
+                labels: HashMap::new(),
+                annotations: HashMap::new(),
+                last_reconciled: None,
+                reconciliation_history: vec![],
+            },
+        }
+    }
+
+    #[test]
+    fn health_counts_per_context() {
+        let resources = [
+            resource("prod", "a", Some(true), false),
+            resource("prod", "b", Some(false), false),
+            resource("prod", "c", Some(true), true),
+            resource("staging", "a", Some(true), false),
+        ];
+        let health = health_by_context(&["prod", "staging", "dev"], &resources);
+
+        assert_eq!(health[0].context, "prod");
+        assert_eq!(
+            (
+                health[0].total,
+                health[0].healthy,
+                health[0].failed,
+                health[0].suspended
+            ),
+            (3, 1, 1, 1)
+        );
+        assert!((health[0].percentage() - 100.0 / 3.0).abs() < 0.01);
+        assert_eq!(health[1].percentage(), 100.0);
+        assert_eq!(health[2].total, 0, "contexts without resources are kept");
+        assert_eq!(health[2].percentage(), 100.0);
+    }
+
+    #[tokio::test]
+    async fn failed_contexts_are_reported_not_fatal() {
+        let config = Config {
+            connect_timeout_seconds: 1,
+            ..Config::default()
+        };
+        let contexts = vec![
+            "nonexistent-context-12345".to_string(),
+            "nonexistent-context-12345".to_string(),
+        ];
+        let clusters = MultiClusterSession::connect(&contexts, None, &config).await;
+        assert!(clusters.is_empty());
+        assert_eq!(clusters.failures().len(), 1, "duplicates connect once");
+        let health = clusters.health();
+        assert_eq!(health.len(), 1);
+        assert!(health[0].error.is_some());
+        assert!(clusters.client("nonexistent-context-12345").is_none());
+    }
+}
@@ -0,0 +1,395 @@
+//! Export a [`ResourceGraph`] as Graphviz DOT, Mermaid, or JSON
+//!
+//! The graph view lays nodes out for the terminal; these exporters carry the
+//! same nodes and edges into design docs and PR descriptions. Aggregate nodes
+//! (workload and resource groups) are expanded into their members so the
+//! diagrams say what the TUI shows after drilling in.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::kube::inventory::InventoryEntry;
+use crate::kube::workloads::WorkloadRef;
+use crate::trace::graph::{GraphNode, NodeType, RelationshipType, ResourceGraph};
+
+/// Output format for an exported graph
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz DOT (`dot -Tsvg graph.dot`)
+    Dot,
+    /// Mermaid flowchart (renders inline on GitHub)
+    Mermaid,
+    /// JSON nodes and edges
+    Json,
+}
+
+impl GraphFormat {
+    /// Every format, in the order offered to the user.
+    pub const ALL: [GraphFormat; 3] = [GraphFormat::Dot, GraphFormat::Mermaid, GraphFormat::Json];
+
+    /// Name used on the command line and in the export menu.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GraphFormat::Dot => "dot",
+            GraphFormat::Mermaid => "mermaid",
+            GraphFormat::Json => "json",
+        }
+    }
+
+    /// File extension for exported files.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            GraphFormat::Dot => "dot",
+            GraphFormat::Mermaid => "mmd",
+            GraphFormat::Json => "json",
+        }
+    }
+}
+
+impl std::str::FromStr for GraphFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        GraphFormat::ALL
+            .into_iter()
+            .find(|format| format.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unknown graph format '{}'. Valid formats: dot, mermaid, json",
+                    s
+                )
+            })
+    }
+}
+
+impl std::fmt::Display for GraphFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl NodeType {
+    /// Stable name used in exported graphs.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NodeType::Object => "object",
+            NodeType::Chain => "chain",
+            NodeType::Source => "source",
+            NodeType::Upstream => "upstream",
+            NodeType::FluxResource => "fluxResource",
+            NodeType::Workload => "workload",
+            NodeType::WorkloadGroup => "workloadGroup",
+            NodeType::ResourceGroup => "resourceGroup",
+        }
+    }
+}
+
+impl RelationshipType {
+    /// Stable name used in exported graphs.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RelationshipType::ManagedBy => "managedBy",
+            RelationshipType::SourcedFrom => "sourcedFrom",
+            RelationshipType::Owns => "owns",
+        }
+    }
+
+    /// Edge label read in the edge's direction (`from` → `to`): sources point
+    /// at what they feed, managers at what they manage, owners at children.
+    fn edge_label(&self) -> &'static str {
+        match self {
+            RelationshipType::ManagedBy => "manages",
+            RelationshipType::SourcedFrom => "sources",
+            RelationshipType::Owns => "owns",
+        }
+    }
+}
+
+/// Render a graph in the requested format.
+pub fn export_graph(graph: &ResourceGraph, format: GraphFormat) -> Result<String> {
+    match format {
+        GraphFormat::Dot => Ok(to_dot(graph)),
+        GraphFormat::Mermaid => Ok(to_mermaid(graph)),
+        GraphFormat::Json => serde_json::to_string_pretty(&JsonGraph::from(graph))
+            .map(|s| s + "\n")
+            .context("Failed to serialize graph to JSON"),
+    }
+}
+
+/// Default file name for an export: `<kind>-<namespace>-<name>.<ext>`, taken
+/// from the object node (the resource the graph was built for).
+pub fn export_file_name(graph: &ResourceGraph, format: GraphFormat) -> String {
+    let stem = graph
+        .object_node_index()
+        .and_then(|idx| graph.nodes.get(idx))
+        .map(|node| {
+            format!(
+                "{}-{}-{}",
+                node.kind.to_lowercase(),
+                node.namespace,
+                node.name
+            )
+        })
+        .unwrap_or_else(|| "graph".to_string());
+    // Names and namespaces are DNS labels, but guard against path separators
+    let stem: String = stem
+        .chars()
+        .map(|c| if c == '/' || c == '\\' { '_' } else { c })
+        .collect();
+    format!("{}.{}", stem, format.extension())
+}
+
+/// Write an export into `dir` as `file_name`, or as `<stem>-1.<ext>`,
+/// `<stem>-2.<ext>`, ... when that file already exists. Returns the path
+/// written; existing files are never overwritten.
+pub fn write_new_export(dir: &Path, file_name: &str, contents: &str) -> Result<PathBuf> {
+    let (stem, ext) = file_name.rsplit_once('.').unwrap_or((file_name, ""));
+    for attempt in 0..1000 {
+        let candidate = match (attempt, ext) {
+            (0, _) => file_name.to_string(),
+            (n, "") => format!("{}-{}", stem, n),
+            (n, ext) => format!("{}-{}.{}", stem, n, ext),
+        };
+        let path = dir.join(candidate);
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(mut file) => {
+                file.write_all(contents.as_bytes())
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+                return Ok(path);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            Err(e) => {
+                return Err(e).with_context(|| format!("Failed to create {}", path.display()));
+            }
+        }
+    }
+    anyhow::bail!(
+        "Too many existing exports named {} in {}",
+        file_name,
+        dir.display()
+    )
+}
+
+/// Graphviz DOT, top to bottom, with nodes filled by readiness.
+pub fn to_dot(graph: &ResourceGraph) -> String {
+    let mut out = String::from("digraph flux {\n");
+    out.push_str("  rankdir=TB;\n");
+    out.push_str("  node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n");
+    out.push_str("  edge [fontname=\"Helvetica\", fontsize=10];\n");
+    for node in &graph.nodes {
+        out.push_str(&format!(
+            "  \"{}\" [label=\"{}\", fillcolor=\"{}\"];\n",
+            dot_escape(&node.id),
+            dot_escape(&node_label_lines(node).join("\n")),
+            status_fill(node.ready),
+        ));
+    }
+    for edge in &graph.edges {
+        out.push_str(&format!(
+            "  \"{}\" -> \"{}\" [label=\"{}\"];\n",
+            dot_escape(&edge.from),
+            dot_escape(&edge.to),
+            edge.relationship.edge_label(),
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Mermaid flowchart. Node IDs are positional (`n0`, `n1`, ...) because graph
+/// IDs contain characters Mermaid does not accept.
+pub fn to_mermaid(graph: &ResourceGraph) -> String {
+    let mermaid_id = |id: &str| graph.node_index.get(id).map(|idx| format!("n{}", idx));
+
+    let mut out = String::from("flowchart TD\n");
+    for (idx, node) in graph.nodes.iter().enumerate() {
+        out.push_str(&format!(
+            "  n{}[\"{}\"]\n",
+            idx,
+            node_label_lines(node)
+                .iter()
+                .map(|line| mermaid_escape(line))
+                .collect::<Vec<_>>()
+                .join("<br/>")
+        ));
+    }
+    for edge in &graph.edges {
+        if let (Some(from), Some(to)) = (mermaid_id(&edge.from), mermaid_id(&edge.to)) {
+            out.push_str(&format!(
+                "  {} -->|{}| {}\n",
+                from,
+                edge.relationship.edge_label(),
+                to
+            ));
+        }
+    }
+
+    let class_members = |ready: Option<bool>| -> Vec<String> {
+        (0..graph.nodes.len())
+            .filter(|&idx| graph.nodes[idx].ready == ready)
+            .map(|idx| format!("n{}", idx))
+            .collect()
+    };
+    for (class, ready, fill) in [
+        ("ready", Some(true), status_fill(Some(true))),
+        ("notReady", Some(false), status_fill(Some(false))),
+    ] {
+        let members = class_members(ready);
+        if !members.is_empty() {
+            out.push_str(&format!("  classDef {} fill:{}\n", class, fill));
+            out.push_str(&format!("  class {} {}\n", members.join(","), class));
+        }
+    }
+    out
+}
+
+/// Label lines shared by DOT and Mermaid: kind and name, then either the
+/// description or, for groups, one line per member.
+fn node_label_lines(node: &GraphNode) -> Vec<String> {
+    let mut lines = match node.node_type {
+        NodeType::WorkloadGroup | NodeType::ResourceGroup => vec![node.name.clone()],
+        _ => vec![node.kind.clone(), node.name.clone()],
+    };
+    let members = group_members(node);
+    if !members.is_empty() {
+        lines.extend(members.iter().map(GroupMember::label));
+    } else if let Some(desc) = &node.description {
+        lines.extend(desc.lines().map(str::to_string));
+    }
+    lines
+}
+
+fn status_fill(ready: Option<bool>) -> &'static str {
+    match ready {
+        Some(true) => "#d4edda",
+        Some(false) => "#f8d7da",
+        None => "#e2e3e5",
+    }
+}
+
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn mermaid_escape(s: &str) -> String {
+    s.replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+}
+
+/// A resource inside a workload or resource group node
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct GroupMember {
+    kind: String,
+    namespace: String,
+    name: String,
+}
+
+impl GroupMember {
+    fn label(&self) -> String {
+        format!("{}/{}", self.kind, self.name)
+    }
+}
+
+/// Decode the members encoded in a group node's description.
+fn group_members(node: &GraphNode) -> Vec<GroupMember> {
+    let Some(desc) = &node.description else {
+        return Vec::new();
+    };
+    match node.node_type {
+        NodeType::WorkloadGroup => desc
+            .lines()
+            .filter_map(WorkloadRef::parse_graph_line)
+            .map(|w| GroupMember {
+                kind: w.kind,
+                namespace: w.namespace,
+                name: w.name,
+            })
+            .collect(),
+        NodeType::ResourceGroup => desc
+            .lines()
+            .filter_map(InventoryEntry::parse_graph_line)
+            .map(|e| GroupMember {
+                kind: e.kind,
+                namespace: e.namespace,
+                name: e.name,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[derive(Serialize)]
+struct JsonGraph<'a> {
+    nodes: Vec<JsonNode<'a>>,
+    edges: Vec<JsonEdge<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonNode<'a> {
+    id: &'a str,
+    kind: &'a str,
+    name: &'a str,
+    namespace: &'a str,
+    #[serde(rename = "type")]
+    node_type: &'static str,
+    ready: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    members: Vec<GroupMember>,
+}
+
+#[derive(Serialize)]
+struct JsonEdge<'a> {
+    from: &'a str,
+    to: &'a str,
+    relationship: &'static str,
+}
+
+impl<'a> From<&'a ResourceGraph> for JsonGraph<'a> {
+    fn from(graph: &'a ResourceGraph) -> Self {
+        let nodes = graph
+            .nodes
+            .iter()
+            .map(|node| {
+                let members = group_members(node);
+                JsonNode {
+                    id: &node.id,
+                    kind: &node.kind,
+                    name: &node.name,
+                    namespace: &node.namespace,
+                    node_type: node.node_type.as_str(),
+                    ready: node.ready,
+                    // Group descriptions are an internal encoding; members replace them
+                    description: if members.is_empty() {
+                        node.description.as_deref()
+                    } else {
+                        None
+                    },
+                    members,
+                }
+            })
+            .collect();
+        let edges = graph
+            .edges
+            .iter()
+            .map(|edge| JsonEdge {
+                from: &edge.from,
+                to: &edge.to,
+                relationship: edge.relationship.as_str(),
+            })
+            .collect();
+        JsonGraph { nodes, edges }
+    }
+}
@@ -768,32 +768,7 @@ async fn extract_helmrelease_inventory_from_secrets(
         .and_then(|data| data.get("release"))
         .ok_or_else(|| anyhow::anyhow!("Secret missing 'release' key"))?;
 
-    // Base64 decode
-    use base64::Engine;
-    let decoded = base64::engine::general_purpose::STANDARD
-        .decode(&release_data.0)
-        .context("Failed to decode base64 release data")?;
-
-    // Check for gzip magic bytes (0x1f, 0x8b, 0x08)
-    let is_gzipped =
-        decoded.len() >= 3 && decoded[0] == 0x1f && decoded[1] == 0x8b && decoded[2] == 0x08;
-
-    // Decompress if gzipped
-    let decompressed = if is_gzipped {
-        use std::io::Read;
-        let mut decoder = flate2::read::GzDecoder::new(&decoded[..]);
-        let mut buf = Vec::new();
-        decoder
-            .read_to_end(&mut buf)
-            .context("Failed to decompress gzip release data")?;
-        buf
-    } else {
-        decoded
-    };
-
-    // JSON unmarshal
-    let release_json: serde_json::Value =
-        serde_json::from_slice(&decompressed).context("Failed to parse release JSON")?;
+    let release_json = crate::kube::helm::decode_release(&release_data.0)?;
 
     // Extract manifest from release JSON
     // Helm release JSON structure: { "manifest": "---\nkind: Deployment\n..." }
@@ -5,11 +5,17 @@
 //! Kustomization or HelmRelease, then resolves their sources.
 
 mod core;
+mod export;
+mod format;
 mod graph;
 mod graph_builder;
 mod models;
 
 pub use core::trace_object;
+pub use export::{
+    GraphFormat, export_file_name, export_graph, to_dot, to_mermaid, write_new_export,
+};
+pub use format::format_trace_result;
 pub use graph_builder::{build_resource_graph, is_resource_type_with_graph};
 // These types are exported for library consumers (tests, etc.)
 #[allow(unused_imports)] // Exported for external use
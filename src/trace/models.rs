@@ -1,7 +1,10 @@
 //! Data structures for trace results
 
+use serde::{Deserialize, Serialize};
+
 /// Trace result showing the ownership chain
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TraceResult {
     /// The original object being traced
     pub object: TraceNode,
@@ -12,7 +15,8 @@ pub struct TraceResult {
 }
 
 /// A node in the trace chain
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TraceNode {
     pub kind: String,
     pub name: String,
@@ -22,9 +26,9 @@ pub struct TraceNode {
 }
 
 /// Status information from a Flux resource
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TraceStatus {
-    #[allow(dead_code)] // Used in format_trace_result which may be used for debugging
     pub ready: Option<bool>,
     pub message: Option<String>,
     pub last_reconciled: Option<String>,
@@ -32,7 +36,8 @@ pub struct TraceStatus {
 }
 
 /// Spec information from a Flux resource
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TraceSpec {
     pub path: Option<String>,          // For Kustomization
     pub url: Option<String>,           // For GitRepository, OCIRepository
@@ -41,7 +46,8 @@ pub struct TraceSpec {
 }
 
 /// Source reference from Kustomization or HelmRelease
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SourceRef {
     pub kind: String,
     pub name: String,
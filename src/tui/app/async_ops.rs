@@ -5,9 +5,11 @@
 //! [`AsyncOperationState`](super::state::AsyncOperationState); the main loop
 //! dispatches and polls them directly. This module keeps only the flows with
 //! extra semantics: mutating operations (registry validation, success message
-//! bookkeeping) and the graph result hook (initial keyboard focus).
+//! bookkeeping), the edit flow (preview diff, server-side dry-run, apply) and
+//! the graph result hook (initial keyboard focus).
 
 use super::core::App;
+use super::state::EditPreview;
 use crate::watcher::ResourceKey;
 
 /// Request to save edited resource YAML via Server Side Apply
@@ -42,7 +44,11 @@ impl App {
     /// Trigger operation execution if pending
     pub fn trigger_operation_execution(&mut self) -> Option<OperationRequest> {
         let pending = self.async_state.operation.pending()?;
-        let client = self.kube_client.as_ref()?;
+        // Rows of the `:clusters` view run with their own context's client
+        let client = match pending.context.as_deref() {
+            Some(context) => self.clusters.as_ref()?.client(context)?,
+            None => self.kube_client.as_ref()?,
+        };
         self.operation_registry
             .get_by_keybinding(pending.operation_key)?;
 
@@ -109,32 +115,98 @@ impl App {
         None
     }
 
+    /// The document to open in the editor: the freshly fetched resource the
+    /// first time (recorded as the preview baseline), afterwards the user's
+    /// kept edit.
+    pub fn take_edit_document(&mut self) -> Option<String> {
+        if let Some(full_yaml_json) = self.async_state.edit_full_yaml.take() {
+            // Present the same trimmed document the YAML view shows, not the
+            // raw API response (no managedFields, no status, no explicit nulls).
+            let edit_doc = crate::tui::views::prepare_edit_document(&full_yaml_json);
+            let yaml_str = serde_yaml::to_string(&edit_doc).unwrap_or_else(|_| "{}".to_string());
+            self.async_state.edit_original = Some(yaml_str.clone());
+            return Some(yaml_str);
+        }
+        self.async_state.edit_buffer.clone()
+    }
+
+    /// Handle the editor closing: show the diff against the fetched document
+    /// and start a server-side dry-run, or cancel when nothing changed.
+    pub fn on_editor_closed(&mut self, edited: String) {
+        let original = self.async_state.edit_original.clone().unwrap_or_default();
+        if edited.trim() == original.trim() {
+            self.end_edit();
+            self.set_status_message(("Edit cancelled (no changes)".to_string(), false));
+            return;
+        }
+        self.async_state.edit_preview = Some(EditPreview {
+            lines: crate::kube::diff::unified_diff(&original, &edited, "fetched", "edited"),
+            dry_run: None,
+        });
+        self.async_state.edit_dry_run.request(edited.clone());
+        self.async_state.edit_buffer = Some(edited);
+        self.view_state.edit_scroll_offset = 0;
+    }
+
+    /// Store the dry-run outcome in the preview.
+    pub fn set_edit_dry_run_result(&mut self, result: anyhow::Result<()>) {
+        if let Some(preview) = self.async_state.edit_preview.as_mut() {
+            preview.dry_run = Some(result.map_err(|e| e.to_string()));
+        }
+    }
+
+    /// Apply the previewed edit, once its dry-run passed.
+    pub fn apply_edit_preview(&mut self) {
+        let Some(preview) = &self.async_state.edit_preview else {
+            return;
+        };
+        match &preview.dry_run {
+            Some(Ok(())) => {
+                self.async_state.edit_save_pending = self.async_state.edit_buffer.clone();
+                self.async_state.edit_preview = None;
+            }
+            Some(Err(_)) => self.set_status_message((
+                "Dry-run failed — press 'e' to fix the edit".to_string(),
+                true,
+            )),
+            None => self.set_status_message(("Dry-run still running...".to_string(), false)),
+        }
+    }
+
+    /// Re-open the editor on the kept edit, dropping the stale preview.
+    pub fn reopen_edit(&mut self) {
+        self.async_state.edit_preview = None;
+        self.async_state.edit_dry_run.clear();
+        self.async_state.edit_editor_launched = false;
+    }
+
+    /// Clear all edit state and return to the view `e` was pressed from.
+    pub fn end_edit(&mut self) {
+        self.async_state.edit_pending = None;
+        self.async_state.edit_full_yaml = None;
+        self.async_state.edit_save_pending = None;
+        self.async_state.edit_save_result_rx = None;
+        self.async_state.edit_error_message = None;
+        self.async_state.edit_editor_launched = false;
+        self.async_state.clear_edit_preview();
+        self.view_state.current_view = self.async_state.edit_return_view;
+        self.async_state.edit_return_view = super::state::View::ResourceList;
+    }
+
     /// Handle the SSA apply result, updating status and returning to list view on success.
     pub fn set_edit_save_result(&mut self, result: anyhow::Result<()>) {
         match result {
             Ok(_) => {
                 tracing::info!("Resource edit applied successfully via SSA");
-                // Clear all edit state
-                self.async_state.edit_pending = None;
-                self.async_state.edit_full_yaml = None;
-                self.async_state.edit_save_pending = None;
-                self.async_state.edit_error_message = None;
-                self.async_state.edit_editor_launched = false;
                 // Return to origin view (wherever 'e' was pressed from)
-                self.view_state.current_view = self.async_state.edit_return_view;
-                self.async_state.edit_return_view = super::state::View::ResourceList;
+                self.end_edit();
                 self.set_status_message(("Resource saved successfully".to_string(), false));
             }
             Err(e) => {
                 tracing::warn!("Resource edit SSA apply failed: {}", e);
                 let msg = e.to_string();
+                self.end_edit();
                 self.async_state.edit_error_message = Some(msg.clone());
-                self.async_state.edit_pending = None;
-                self.async_state.edit_full_yaml = None;
-                self.async_state.edit_save_pending = None;
-                self.async_state.edit_editor_launched = false;
-                self.view_state.current_view = self.async_state.edit_return_view;
-                self.async_state.edit_return_view = super::state::View::ResourceList;
                 self.set_status_message((format!("Save failed: {}", msg), true));
             }
         }
@@ -208,6 +280,14 @@ mod tests {
             connect_timeout_seconds: crate::kube::health::DEFAULT_CONNECT_TIMEOUT_SECS,
             discover_flux_resources: false,
             editor: None,
+            hooks: vec![],
+            history_retention_days: 0,
+            views: HashMap::new(),
+            columns: HashMap::new(),
+            keymap: HashMap::new(),
+            plugins: HashMap::new(),
+            aliases: HashMap::new(),
+            clusters: Vec::new(),
         };
         let theme = Theme::default();
         App::new(state, "test-context".to_string(), None, config, theme)
@@ -299,4 +379,67 @@ mod tests {
 
         assert_eq!(app.view_state.current_view, View::ResourceYAML);
     }
+
+    #[test]
+    fn test_editor_closed_without_changes_cancels_edit() {
+        let mut app = create_test_app();
+        set_edit_in_progress(&mut app);
+        app.async_state.edit_save_pending = None;
+        let original = app.take_edit_document().unwrap();
+
+        app.on_editor_closed(format!("{}\n", original));
+
+        assert!(app.async_state.edit_pending.is_none());
+        assert!(app.async_state.edit_preview.is_none());
+        assert_eq!(app.view_state.current_view, View::ResourceList);
+    }
+
+    #[test]
+    fn test_editor_closed_with_changes_previews_and_dry_runs() {
+        let mut app = create_test_app();
+        set_edit_in_progress(&mut app);
+        app.async_state.edit_save_pending = None;
+        app.take_edit_document().unwrap();
+
+        app.on_editor_closed("apiVersion: v2\n".to_string());
+
+        let preview = app.async_state.edit_preview.as_ref().unwrap();
+        assert!(preview.dry_run.is_none());
+        assert!(
+            preview
+                .lines
+                .iter()
+                .any(|line| line.text == "+apiVersion: v2")
+        );
+        assert_eq!(
+            app.async_state.edit_dry_run.pending().map(String::as_str),
+            Some("apiVersion: v2\n")
+        );
+        assert_eq!(app.view_state.current_view, View::ResourceEdit);
+
+        // A failed dry-run blocks the apply
+        app.set_edit_dry_run_result(Err(anyhow::anyhow!("admission webhook denied")));
+        app.apply_edit_preview();
+        assert!(app.async_state.edit_save_pending.is_none());
+        assert!(app.ui_state.status_message.as_ref().unwrap().1);
+
+        // Re-opening the editor keeps the edit and drops the stale preview
+        app.reopen_edit();
+        assert!(app.async_state.edit_preview.is_none());
+        assert!(!app.async_state.edit_editor_launched);
+        assert_eq!(
+            app.take_edit_document().as_deref(),
+            Some("apiVersion: v2\n")
+        );
+
+        // A passing dry-run queues the real apply
+        app.on_editor_closed("apiVersion: v3\n".to_string());
+        app.set_edit_dry_run_result(Ok(()));
+        app.apply_edit_preview();
+        assert_eq!(
+            app.async_state.edit_save_pending.as_deref(),
+            Some("apiVersion: v3\n")
+        );
+        assert!(app.async_state.edit_preview.is_none());
+    }
 }
@@ -0,0 +1,369 @@
+//! Multi-select and bulk operations
+//!
+//! Rows of the resource list and favorites views can be marked (Space,
+//! Ctrl+a, `*`). While any visible row is marked, the operation keys act on
+//! the marked rows instead of the selected one: the targets go through the
+//! [`OperationRegistry`](crate::tui::OperationRegistry) behind one aggregated
+//! confirmation, and the per-item outcome is shown as a summary. Failed items
+//! stay marked so a retry is a single key press.
+
+use super::core::App;
+use super::state::{BulkOperation, BulkResult, View};
+use crate::watcher::{ResourceKey, resource_key};
+use crossterm::event::{KeyCode, KeyEvent};
+use futures::StreamExt;
+
+/// How many operations of a bulk run are in flight at once.
+const BULK_CONCURRENCY: usize = 4;
+
+/// Request to run a confirmed bulk operation
+pub struct BulkRequest {
+    pub operation: BulkOperation,
+    /// Kubernetes client to use for API calls
+    pub client: kube::Client,
+    /// Channel to send the per-item outcome back
+    pub tx: tokio::sync::oneshot::Sender<anyhow::Result<BulkResult>>,
+}
+
+/// Run `operation` on every target, a few at a time, collecting each outcome.
+pub async fn run_bulk_operation(client: kube::Client, operation: BulkOperation) -> BulkResult {
+    let registry = crate::tui::OperationRegistry::new();
+    let op_key = operation.operation_key;
+    let outcomes = futures::stream::iter(operation.targets)
+        .map(|rk| {
+            let registry = &registry;
+            let client = &client;
+            async move {
+                let outcome = match registry.get_by_keybinding(op_key) {
+                    Some(op) => op
+                        .execute(client, &rk.resource_type, &rk.namespace, &rk.name)
+                        .await
+                        .map_err(|e| e.to_string()),
+                    None => Err("Unknown operation".to_string()),
+                };
+                if let Err(ref e) = outcome {
+                    tracing::warn!("Bulk operation '{}' failed on {}: {}", op_key, rk, e);
+                }
+                (rk, outcome)
+            }
+        })
+        .buffered(BULK_CONCURRENCY)
+        .collect()
+        .await;
+    BulkResult {
+        operation_key: op_key,
+        outcomes,
+        skipped: operation.skipped,
+    }
+}
+
+impl App {
+    /// Whether rows can be marked in the current view.
+    pub(crate) fn is_markable_view(&self) -> bool {
+        matches!(
+            self.view_state.current_view,
+            View::ResourceList | View::ResourceFavorites
+        )
+    }
+
+    /// Marked resources visible in the current list, in display order.
+    ///
+    /// Marks outside the current filter are kept but never acted on.
+    pub(crate) fn marked_resources(&self) -> Vec<ResourceKey> {
+        self.get_filtered_resources()
+            .into_iter()
+            .filter(|r| {
+                self.selection_state.marked.contains(&resource_key(
+                    &r.namespace,
+                    &r.name,
+                    &r.resource_type,
+                ))
+            })
+            .map(|r| ResourceKey::new(r.resource_type, r.namespace, r.name))
+            .collect()
+    }
+
+    /// Mark or unmark the selected row.
+    pub(crate) fn toggle_mark_selected(&mut self) {
+        if let Some(r) = self.get_current_resource() {
+            let key = resource_key(&r.namespace, &r.name, &r.resource_type);
+            if !self.selection_state.marked.remove(&key) {
+                self.selection_state.marked.insert(key);
+            }
+        }
+    }
+
+    /// Mark every row that passes the current filter.
+    pub(crate) fn mark_all_filtered(&mut self) {
+        let resources = self.get_filtered_resources();
+        let count = resources.len();
+        self.selection_state.marked.extend(
+            resources
+                .iter()
+                .map(|r| resource_key(&r.namespace, &r.name, &r.resource_type)),
+        );
+        self.set_status_message((format!("Marked {} resources", count), false));
+    }
+
+    /// Invert the marks of the rows that pass the current filter.
+    pub(crate) fn invert_marks(&mut self) {
+        for r in self.get_filtered_resources() {
+            let key = resource_key(&r.namespace, &r.name, &r.resource_type);
+            if !self.selection_state.marked.remove(&key) {
+                self.selection_state.marked.insert(key);
+            }
+        }
+    }
+
+    /// Drop all marks.
+    pub(crate) fn clear_marks(&mut self) {
+        self.selection_state.marked.clear();
+    }
+
+    /// Queue the aggregated confirmation for running `op_key` on the marked
+    /// rows. Rows the operation is not valid for are listed as skipped.
+    pub(crate) fn start_bulk_operation(&mut self, op_key: char) {
+        let Some(operation) = self.operation_registry.get_by_keybinding(op_key) else {
+            return;
+        };
+        let (targets, skipped): (Vec<_>, Vec<_>) = self
+            .marked_resources()
+            .into_iter()
+            .partition(|rk| operation.is_valid_for(&rk.resource_type));
+        if targets.is_empty() {
+            let message = format!(
+                "Operation '{}' is not valid for any marked resource",
+                operation.name()
+            );
+            self.set_status_message((message, true));
+            return;
+        }
+        if self.config.read_only {
+            self.set_status_message((
+                crate::constants::READ_ONLY_WRITE_ACTION_MESSAGE.to_string(),
+                true,
+            ));
+            return;
+        }
+        // Always confirm: even a reconcile is worth a second look at twenty rows.
+        self.async_state.bulk_confirmation = Some(BulkOperation {
+            operation_key: op_key,
+            targets,
+            skipped,
+        });
+    }
+
+    /// Keys of the bulk confirmation dialog: y/Y runs, n/N/Esc cancels.
+    pub(crate) fn handle_bulk_confirmation_key(&mut self, key: KeyEvent) -> Option<bool> {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                let pending = self.async_state.bulk_confirmation.take()?;
+                if self.config.read_only {
+                    self.set_status_message((
+                        crate::constants::READ_ONLY_WRITE_ACTION_MESSAGE.to_string(),
+                        true,
+                    ));
+                    return None;
+                }
+                if self.kube_client.is_none() {
+                    self.set_status_message(("Not connected to a cluster".to_string(), true));
+                    return None;
+                }
+                let name = self
+                    .operation_registry
+                    .get_by_keybinding(pending.operation_key)
+                    .map(|op| op.name())
+                    .unwrap_or("Operation");
+                let message = format!("{} {} resources...", name, pending.targets.len());
+                self.set_status_message((message, false));
+                self.async_state.bulk.request(pending);
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                self.async_state.bulk_confirmation = None;
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Take the queued bulk operation for the main loop to run.
+    pub fn trigger_bulk_execution(&mut self) -> Option<BulkRequest> {
+        self.async_state.bulk.pending()?;
+        let client = self.kube_client.clone()?;
+        let (operation, tx) = self.async_state.bulk.dispatch()?;
+        Some(BulkRequest {
+            operation,
+            client,
+            tx,
+        })
+    }
+
+    /// Show the per-item summary and keep only the failed rows marked, so
+    /// retrying them is one key press.
+    pub fn set_bulk_result(&mut self, result: BulkResult) {
+        self.selection_state.marked = result.failed().map(|rk| rk.to_key_string()).collect();
+        let name = self
+            .operation_registry
+            .get_by_keybinding(result.operation_key)
+            .map(|op| op.name())
+            .unwrap_or("Operation");
+        let failed = result.outcomes.len() - result.succeeded_count();
+        let message = format!(
+            "{}: {} succeeded, {} failed",
+            name,
+            result.succeeded_count(),
+            failed
+        );
+        self.set_status_message((message, failed > 0));
+        self.async_state.bulk_summary = Some(result.clone());
+        self.async_state.bulk.set_result(result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, UiConfig};
+    use crate::tui::Theme;
+    use crate::watcher::{ResourceInfo, ResourceState};
+    use crossterm::event::{KeyEventKind, KeyEventState, KeyModifiers};
+    use std::collections::HashMap;
+
+    fn create_test_app(read_only: bool) -> App {
+        let config = Config {
+            read_only,
+            ui: UiConfig {
+                splashless: true,
+                ..UiConfig::default()
+            },
+            ..Config::default()
+        };
+        App::new(
+            ResourceState::new(),
+            "test-context".to_string(),
+            None,
+            config,
+            Theme::default(),
+        )
+    }
+
+    fn add_resource(app: &mut App, resource_type: &str, name: &str) {
+        let resource = ResourceInfo {
+            name: name.to_string(),
+            namespace: "flux-system".to_string(),
+            resource_type: resource_type.to_string(),
+            age: None,
+            suspended: Some(false),
+            ready: Some(true),
+            message: None,
+            revision: None,
+            labels: HashMap::new(),
+            annotations: HashMap::new(),
+            last_reconciled: None,
+            reconciliation_history: vec![],
+        };
+        app.state.upsert(
+            resource_key(&resource.namespace, &resource.name, &resource.resource_type),
+            resource,
+        );
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }
+    }
+
+    #[test]
+    fn mark_all_and_invert_follow_the_filter() {
+        let mut app = create_test_app(false);
+        add_resource(&mut app, "Kustomization", "apps");
+        add_resource(&mut app, "Kustomization", "infra");
+        add_resource(&mut app, "HelmRelease", "podinfo");
+        app.view_state.current_view = View::ResourceList;
+
+        app.mark_all_filtered();
+        assert_eq!(app.marked_resources().len(), 3);
+
+        app.clear_marks();
+        app.view_state.selected_resource_type = Some("Kustomization".to_string());
+        app.mark_all_filtered();
+        assert_eq!(app.marked_resources().len(), 2);
+
+        app.view_state.selected_resource_type = None;
+        app.invert_marks();
+        assert_eq!(
+            app.marked_resources(),
+            vec![ResourceKey::new("HelmRelease", "flux-system", "podinfo")]
+        );
+    }
+
+    #[test]
+    fn bulk_operation_confirms_once_and_skips_invalid_kinds() {
+        let mut app = create_test_app(false);
+        add_resource(&mut app, "Kustomization", "apps");
+        add_resource(&mut app, "GitRepository", "flux-system");
+        app.view_state.current_view = View::ResourceList;
+        app.mark_all_filtered();
+
+        // Reconcile with source only applies to Kustomizations and HelmReleases
+        app.start_bulk_operation('W');
+        let pending = app.async_state.bulk_confirmation.clone().unwrap();
+        assert_eq!(
+            pending.targets,
+            vec![ResourceKey::new("Kustomization", "flux-system", "apps")]
+        );
+        assert_eq!(
+            pending.skipped,
+            vec![ResourceKey::new(
+                "GitRepository",
+                "flux-system",
+                "flux-system"
+            )]
+        );
+
+        app.handle_bulk_confirmation_key(key(KeyCode::Esc));
+        assert!(app.async_state.bulk_confirmation.is_none());
+        assert!(app.async_state.bulk.pending().is_none());
+    }
+
+    #[test]
+    fn bulk_operation_blocked_in_read_only_mode() {
+        let mut app = create_test_app(true);
+        add_resource(&mut app, "Kustomization", "apps");
+        app.view_state.current_view = View::ResourceList;
+        app.mark_all_filtered();
+
+        app.start_bulk_operation('s');
+        assert!(app.async_state.bulk_confirmation.is_none());
+        let (msg, is_error) = app.ui_state.status_message.clone().unwrap();
+        assert!(is_error);
+        assert_eq!(msg, crate::constants::READ_ONLY_WRITE_ACTION_MESSAGE);
+    }
+
+    #[test]
+    fn bulk_result_keeps_only_failed_items_marked() {
+        let mut app = create_test_app(false);
+        add_resource(&mut app, "HelmRelease", "a");
+        add_resource(&mut app, "HelmRelease", "b");
+        app.view_state.current_view = View::ResourceList;
+        app.mark_all_filtered();
+
+        let a = ResourceKey::new("HelmRelease", "flux-system", "a");
+        let b = ResourceKey::new("HelmRelease", "flux-system", "b");
+        app.set_bulk_result(BulkResult {
+            operation_key: 'r',
+            outcomes: vec![(a, Ok(())), (b.clone(), Err("timeout".to_string()))],
+            skipped: vec![],
+        });
+
+        assert_eq!(app.marked_resources(), vec![b]);
+        assert!(app.async_state.bulk_summary.is_some());
+        let (msg, is_error) = app.ui_state.status_message.clone().unwrap();
+        assert!(is_error);
+        assert_eq!(msg, "Resume: 1 succeeded, 1 failed");
+    }
+}
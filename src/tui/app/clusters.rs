@@ -0,0 +1,317 @@
+//! Multi-cluster view
+//!
+//! `:clusters` connects a [`MultiClusterSession`] to several kubeconfig
+//! contexts and shows their resources in one list. The sessions run beside
+//! the current context's watcher: `:ctx` keeps them, and leaving the view
+//! keeps them too so `:clusters` reopens it without reconnecting. The main
+//! loop connects them in a background task (see
+//! [`AsyncOperationState::clusters`](super::state::AsyncOperationState::clusters)).
+
+use super::core::App;
+use super::state::{HealthFilter, PendingOperation, SortField, View};
+use crate::services::{ClusterHealth, ClusterResource, MultiClusterSession};
+use crate::tui::commands;
+use std::collections::HashMap;
+
+impl App {
+    /// `:clusters [ctx…|all|off]` — watch several contexts together.
+    ///
+    /// Contexts are separated by spaces or commas; `all` takes every
+    /// kubeconfig context. Without arguments, reopens the running sessions or
+    /// connects the configured `clusters`. `off` disconnects them.
+    pub(super) fn cmd_clusters(&mut self, cmd: &str) {
+        let contexts: Vec<String> = match commands::extract_command_arg(cmd, "clusters").as_deref()
+        {
+            Some("off") => {
+                self.clusters = None;
+                self.async_state.clusters.clear();
+                if self.view_state.current_view == View::Clusters {
+                    self.view_state.current_view = View::ResourceList;
+                    self.reset_list_position();
+                    self.invalidate_layout_cache();
+                }
+                self.set_status_message(("Multi-cluster sessions closed".to_string(), false));
+                return;
+            }
+            Some("all") => match crate::kube::list_contexts() {
+                Ok(contexts) => contexts,
+                Err(e) => {
+                    self.set_status_message((format!("Failed to list contexts: {}", e), true));
+                    return;
+                }
+            },
+            Some(list) => list
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|context| !context.is_empty())
+                .map(str::to_string)
+                .collect(),
+            None if self.clusters.is_some() => {
+                self.open_clusters_view();
+                return;
+            }
+            None => self.config.clusters.clone(),
+        };
+
+        if contexts.is_empty() {
+            self.set_status_message((
+                "No clusters configured. Usage: :clusters <ctx> [ctx…] | all".to_string(),
+                true,
+            ));
+            return;
+        }
+        self.set_status_message((
+            format!("Connecting to {} clusters...", contexts.len()),
+            false,
+        ));
+        self.async_state.clusters.request(contexts);
+    }
+
+    /// Store the connected sessions and open the view, reporting contexts
+    /// that could not be reached.
+    pub fn set_clusters(&mut self, clusters: MultiClusterSession) {
+        let connected = clusters.contexts().len();
+        let failures = clusters.failures();
+        let message = if failures.is_empty() {
+            (format!("Watching {} clusters", connected), false)
+        } else {
+            let unreachable: Vec<&str> = failures.iter().map(|(ctx, _)| ctx.as_str()).collect();
+            (
+                format!(
+                    "Watching {} clusters; unreachable: {}",
+                    connected,
+                    unreachable.join(", ")
+                ),
+                true,
+            )
+        };
+        self.clusters = Some(clusters);
+        self.open_clusters_view();
+        self.set_status_message(message);
+    }
+
+    fn open_clusters_view(&mut self) {
+        self.view_state.current_view = View::Clusters;
+        self.reset_list_position();
+        self.invalidate_layout_cache(); // The clusters header has its own height
+    }
+
+    /// Apply the pending events of the multi-cluster sessions. Returns the
+    /// number of events processed.
+    pub fn drain_cluster_events(&mut self) -> usize {
+        self.clusters
+            .as_mut()
+            .map_or(0, MultiClusterSession::drain_events)
+    }
+
+    /// Resources of every cluster within the namespace and resource type
+    /// scope, before the filter query and health filter.
+    pub(crate) fn scoped_cluster_resources(&self) -> Vec<ClusterResource> {
+        let Some(ref clusters) = self.clusters else {
+            return Vec::new();
+        };
+        let mut rows = clusters.snapshot();
+        rows.retain(|r| self.in_namespace_scope(&r.info.namespace));
+        if let Some(ref resource_type) = self.view_state.selected_resource_type {
+            rows.retain(|r| r.info.resource_type == *resource_type);
+        }
+        rows
+    }
+
+    /// Rows of the `:clusters` view: the scoped resources narrowed by the `/`
+    /// query (whose `ctx:` terms match each row's own context) and the health
+    /// filter, grouped by context.
+    pub(crate) fn filtered_cluster_resources(&self) -> Vec<ClusterResource> {
+        let mut rows = self.scoped_cluster_resources();
+
+        if !self.view_state.filter.is_empty() {
+            match crate::watcher::FilterQuery::parse(&self.view_state.filter) {
+                Ok(query) => {
+                    let now = chrono::Utc::now();
+                    // Column values come from the current context's objects
+                    let no_columns = HashMap::new();
+                    rows.retain(|r| {
+                        query.matches_in_context(&r.info, &r.context, &no_columns, now)
+                    });
+                }
+                Err(_) => rows.clear(),
+            }
+        }
+
+        match self.view_state.health_filter {
+            HealthFilter::Healthy => rows.retain(|r| r.info.is_healthy()),
+            HealthFilter::Unhealthy => rows.retain(|r| !r.info.is_healthy()),
+            HealthFilter::All => {}
+        }
+
+        let sort_field = &self.view_state.sort_field;
+        let sort_reverse = self.view_state.sort_reverse;
+        let no_values = HashMap::new();
+        rows.sort_by(|a, b| {
+            let by_field =
+                || super::core::compare_by_sort_field(&a.info, &b.info, sort_field, &no_values);
+            let ord = if *sort_field == SortField::Default {
+                a.context.cmp(&b.context).then_with(by_field)
+            } else {
+                by_field().then_with(|| a.context.cmp(&b.context))
+            };
+            if sort_reverse { ord.reverse() } else { ord }
+        });
+        rows
+    }
+
+    /// Health of each cluster over the scoped resources, unreachable
+    /// contexts last.
+    pub(crate) fn cluster_health(&self) -> Vec<ClusterHealth> {
+        self.clusters
+            .as_ref()
+            .map(|clusters| clusters.health_of(&self.scoped_cluster_resources()))
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn selected_cluster_resource(&self) -> Option<ClusterResource> {
+        self.filtered_cluster_resources()
+            .into_iter()
+            .nth(self.view_state.selected_index)
+    }
+
+    /// Enter on a `:clusters` row: open its detail when it belongs to the
+    /// current context, else switch to its context with the list filtered
+    /// down to it.
+    pub(crate) fn open_selected_cluster_resource(&mut self) {
+        let Some(row) = self.selected_cluster_resource() else {
+            return;
+        };
+        let info = row.info;
+        if row.context == self.context {
+            self.view_state.previous_list_view = View::Clusters;
+            self.view_state.detail_back_view = None;
+            self.selection_state.selected_resource_key = Some(crate::watcher::resource_key(
+                &info.namespace,
+                &info.name,
+                &info.resource_type,
+            ));
+            self.view_state.current_view = View::ResourceDetail;
+            return;
+        }
+
+        self.pending_context_switch = Some(row.context.clone());
+        self.view_state.current_view = View::ResourceList;
+        self.view_state.filter = format!("ns:{} name:{}", info.namespace, info.name);
+        self.reset_list_position();
+        self.invalidate_layout_cache();
+        self.set_status_message((format!("Switching to context '{}'...", row.context), false));
+    }
+
+    /// Operation keys in the `:clusters` view act on the selected row through
+    /// its own context's client.
+    pub(super) fn handle_cluster_operation_key(&mut self, op_key: char) {
+        let Some(row) = self.selected_cluster_resource() else {
+            return;
+        };
+        let Some(operation) = self.operation_registry.get_by_keybinding(op_key) else {
+            return;
+        };
+        let resource = &row.info;
+        if !operation.is_valid_for(&resource.resource_type) {
+            self.set_status_message((
+                format!(
+                    "Operation '{}' is not valid for {}",
+                    operation.name(),
+                    resource.resource_type
+                ),
+                true,
+            ));
+            return;
+        }
+
+        if self.config.read_only {
+            self.set_status_message((
+                crate::constants::READ_ONLY_WRITE_ACTION_MESSAGE.to_string(),
+                true,
+            ));
+            return;
+        }
+
+        let pending = PendingOperation::new(
+            resource.resource_type.clone(),
+            resource.namespace.clone(),
+            resource.name.clone(),
+            op_key,
+        )
+        .in_context(Some(row.context.clone()));
+        if operation.requires_confirmation() {
+            self.async_state.confirmation_pending = Some(pending);
+            return;
+        }
+
+        let feedback_msg = format!(
+            "{} {}/{} in {}...",
+            operation.name(),
+            resource.resource_type,
+            resource.name,
+            row.context
+        );
+        self.set_status_message((feedback_msg, false));
+        self.execute_operation(pending);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{Config, UiConfig};
+    use crate::tui::Theme;
+    use crate::tui::app::App;
+    use crate::tui::app::state::View;
+    use crate::watcher::ResourceState;
+
+    fn app(clusters: Vec<String>) -> App {
+        let config = Config {
+            clusters,
+            ui: UiConfig {
+                splashless: true,
+                ..UiConfig::default()
+            },
+            ..Config::default()
+        };
+        App::new(
+            ResourceState::new(),
+            "prod".to_string(),
+            None,
+            config,
+            Theme::default(),
+        )
+    }
+
+    #[test]
+    fn clusters_command_queues_contexts_for_the_main_loop() {
+        let mut app = app(vec!["prod-eu-1".to_string()]);
+        app.cmd_clusters("clusters");
+        assert_eq!(
+            app.async_state.clusters.pending(),
+            Some(&vec!["prod-eu-1".to_string()])
+        );
+
+        app.cmd_clusters("clusters eu-1, us-1 ap-1");
+        assert_eq!(
+            app.async_state.clusters.pending(),
+            Some(&vec![
+                "eu-1".to_string(),
+                "us-1".to_string(),
+                "ap-1".to_string()
+            ])
+        );
+        assert_eq!(app.current_view(), View::ResourceList);
+    }
+
+    #[test]
+    fn clusters_command_without_contexts_reports_usage() {
+        let mut app = app(Vec::new());
+        app.cmd_clusters("clusters");
+        assert!(app.async_state.clusters.pending().is_none());
+        let (message, is_error) = app.ui_state.status_message.clone().unwrap();
+        assert!(is_error);
+        assert!(message.contains("Usage: :clusters"));
+        assert!(app.filtered_cluster_resources().is_empty());
+        assert!(app.cluster_health().is_empty());
+    }
+}
@@ -1,8 +1,8 @@
 //! Application state and main TUI logic
 
 use super::state::{
-    AsyncOperationState, ControllerPodState, HealthFilter, KubeEventStore, SelectionState, UIState,
-    View, ViewState,
+    AsyncOperationState, ControllerPodState, HealthFilter, KubeEventStore, SelectionState,
+    SortField, UIState, View, ViewState,
 };
 use crate::tui::{OperationRegistry, Theme};
 use crate::watcher::ResourceState;
@@ -17,6 +17,11 @@ pub struct App {
     pub(crate) theme: Theme,
     pub(crate) context: String,
     pub(crate) namespace: Option<String>,
+    /// Namespaces watched when several are (`:ns a,b` or a label selector);
+    /// `namespace` is `None` then. Empty otherwise.
+    pub(crate) namespaces: Vec<String>,
+    /// Label selector `namespaces` was resolved from
+    pub(crate) namespace_selector: Option<String>,
 
     // Organized state
     pub(crate) view_state: ViewState,
@@ -30,6 +35,10 @@ pub struct App {
     pub(crate) kube_client: Option<kube::Client>,
     pub(crate) operation_registry: OperationRegistry,
     pub(crate) namespace_hotkeys: Vec<String>,
+    /// Effective keybindings (`keymap` config applied to the built-in keys)
+    pub(crate) keymap: crate::tui::keybindings::Keymap,
+    /// `:` command aliases from config, empty when the config is invalid
+    pub(crate) aliases: HashMap<String, String>,
     pub(crate) pending_context_switch: Option<String>,
     pub(crate) controller_pods: ControllerPodState,
     /// Live Kubernetes events feed (populated while the events view is open).
@@ -48,6 +57,19 @@ pub struct App {
     /// Drives the contextual "restricted" empty-state (#210). Distinct from
     /// missing CRDs, which stay silent so sparse clusters aren't noisy.
     pub(crate) forbidden_watchers: HashSet<String>,
+    /// Observed reconciliation history for kinds without `status.history`,
+    /// persisted per context.
+    pub(crate) history: crate::services::HistoryStore,
+    /// `:timeline` entries in the namespace scope (see [`App::refresh_timeline`])
+    pub(crate) timeline: crate::tui::timeline::TimelineCache,
+    /// Sessions behind the `:clusters` view, one per context
+    pub(crate) clusters: Option<crate::services::MultiClusterSession>,
+    /// Config files the config was merged from, lowest precedence first;
+    /// `:view save` and `:view delete` write back to them
+    pub(crate) config_layers: Vec<std::path::PathBuf>,
+    /// Invalid `keymap`, `plugins` or `aliases` sections that were ignored,
+    /// shown with the startup config warning
+    pub(crate) config_errors: Vec<String>,
 }
 
 impl App {
@@ -65,6 +87,9 @@ impl App {
                 config.ui.splashless
             );
         }
+        let mut config_errors = Vec::new();
+        let keymap = Self::build_keymap(&config, &mut config_errors);
+        let aliases = Self::build_aliases(&config, &mut config_errors);
 
         Self {
             // Core data
@@ -73,6 +98,8 @@ impl App {
             theme,
             context,
             namespace,
+            namespaces: Vec::new(),
+            namespace_selector: None,
 
             // Organized state
             view_state: ViewState::default(),
@@ -80,6 +107,7 @@ impl App {
                 selected_resource_key: None,
                 favorites: config.favorites.iter().cloned().collect(),
                 favorites_pending_save: false,
+                marked: HashSet::new(),
             },
             ui_state: UIState::new(show_splash),
             async_state: AsyncOperationState::default(),
@@ -90,6 +118,8 @@ impl App {
             kube_client: None,
             operation_registry: OperationRegistry::new(),
             namespace_hotkeys: Self::build_namespace_hotkeys(&config, Vec::new()),
+            keymap,
+            aliases,
             pending_context_switch: None,
             controller_pods: ControllerPodState::default(),
             kube_events: KubeEventStore::default(),
@@ -98,6 +128,11 @@ impl App {
             log_path: None,
             degraded_watchers: HashSet::new(),
             forbidden_watchers: HashSet::new(),
+            history: crate::services::HistoryStore::in_memory(),
+            timeline: crate::tui::timeline::TimelineCache::default(),
+            clusters: None,
+            config_layers: crate::config::ConfigLoader::layer_paths(None, None),
+            config_errors,
         }
     }
 
@@ -198,6 +233,12 @@ impl App {
         self.log_path = path;
     }
 
+    /// Set the config files the config was merged from (see
+    /// [`ConfigLoader::layer_paths`](crate::config::ConfigLoader::layer_paths)).
+    pub fn set_config_layers(&mut self, layers: Vec<std::path::PathBuf>) {
+        self.config_layers = layers;
+    }
+
     /// Render the connection error view inside the specified area, if in a failed state.
     pub(crate) fn render_connection_error_screen(
         &self,
@@ -217,6 +258,49 @@ impl App {
         }
     }
 
+    /// Build the effective keymap with the plugin shortcuts, falling back to
+    /// the built-in keys when the `keymap` or `plugins` config is invalid
+    /// (`config validate` reports the error).
+    fn build_keymap(
+        config: &crate::config::Config,
+        errors: &mut Vec<String>,
+    ) -> crate::tui::keybindings::Keymap {
+        use crate::tui::keybindings::Keymap;
+        let mut ignore = |section: &str, e: anyhow::Error| {
+            tracing::warn!("Ignoring {} config: {}", section, e);
+            errors.push(format!("Ignoring {} config: {}", section, e));
+        };
+        let keymap = Keymap::from_config(&config.keymap).unwrap_or_else(|e| {
+            ignore("keymap", e);
+            Keymap::default()
+        });
+        keymap
+            .clone()
+            .with_plugins(&config.plugins)
+            .unwrap_or_else(|e| {
+                ignore("plugins", e);
+                keymap
+            })
+    }
+
+    /// Validate the `aliases` config and register the one-word ones with the
+    /// discovered-kind registry, ignoring all aliases when one is invalid
+    /// (`config validate` reports the error).
+    fn build_aliases(
+        config: &crate::config::Config,
+        errors: &mut Vec<String>,
+    ) -> HashMap<String, String> {
+        if let Err(e) = crate::tui::commands::validate_aliases(&config.aliases) {
+            tracing::warn!("Ignoring aliases config: {}", e);
+            errors.push(format!("Ignoring aliases config: {}", e));
+            return HashMap::new();
+        }
+        if !config.aliases.is_empty() {
+            crate::models::extra_kinds::global().add_aliases(&config.aliases);
+        }
+        config.aliases.clone()
+    }
+
     /// Build namespace hotkeys from config and discovered namespaces
     ///
     /// If config.namespace_hotkeys is non-empty, use it (validated to max 10 items).
@@ -377,8 +461,8 @@ impl App {
     pub fn set_watcher(&mut self, watcher: crate::watcher::ResourceWatcher) {
         self.watcher = Some(watcher);
         // A replacement watcher (context switch) starts without the lazily
-        // started events watch — rearm it if the events view is showing.
-        if self.view_state.current_view == View::EventList {
+        // started events watch — rearm it if an events-backed view is showing.
+        if self.view_state.current_view.uses_kube_events() {
             self.start_kube_events_watch();
         }
     }
@@ -408,9 +492,7 @@ impl App {
     /// always shows the whole scope.
     pub(crate) fn pulse_resources(&self) -> Vec<crate::watcher::ResourceInfo> {
         let mut resources = self.state.all();
-        if let Some(ref namespace) = self.namespace {
-            resources.retain(|r| r.namespace == *namespace);
-        }
+        resources.retain(|r| self.in_namespace_scope(&r.namespace));
         resources
     }
 
@@ -467,12 +549,120 @@ impl App {
             .collect()
     }
 
+    /// Rebuild the scoped timeline if the history, the events or the
+    /// namespace scope changed since it was built. Called when `:timeline`
+    /// opens and on each of its frames and key presses, so the accessors
+    /// below only read the cache; other views never pay for it.
+    pub(crate) fn refresh_timeline(&mut self) {
+        let key = (
+            self.history.generation(),
+            self.kube_events.generation(),
+            self.namespace.clone(),
+            self.namespaces.clone(),
+        );
+        if self.timeline.is_current(&key) {
+            return;
+        }
+        let mut entries =
+            crate::tui::timeline::build_timeline(&self.history, self.kube_events.sorted_events());
+        entries.retain(|entry| self.in_namespace_scope(&entry.namespace));
+        self.timeline.store(key, entries);
+    }
+
+    /// The reconciliation timeline in the current namespace scope, newest
+    /// first. The observed history spans every namespace of the context, so
+    /// it is scoped here rather than by the watchers.
+    pub(crate) fn scoped_timeline(&self) -> &[crate::tui::timeline::TimelineEntry] {
+        &self.timeline.entries
+    }
+
+    /// The scoped timeline limited to the `:timeline` kinds and filtered by
+    /// the list filter (matches change, kind, namespace, name, revision and
+    /// message).
+    pub(crate) fn filtered_timeline(&self) -> Vec<crate::tui::timeline::TimelineEntry> {
+        let filter = self.view_state.filter.to_lowercase();
+        let kinds = &self.view_state.timeline_kinds;
+        self.scoped_timeline()
+            .iter()
+            .filter(|entry| kinds.is_empty() || kinds.contains(&entry.kind))
+            .filter(|entry| {
+                if filter.is_empty() {
+                    return true;
+                }
+                [
+                    entry.change.label(),
+                    entry.kind.as_str(),
+                    entry.namespace.as_str(),
+                    entry.name.as_str(),
+                    entry.revision.as_deref().unwrap_or_default(),
+                    entry.message.as_str(),
+                ]
+                .iter()
+                .any(|text| text.to_lowercase().contains(&filter))
+            })
+            .cloned()
+            .collect()
+    }
+
     pub fn set_context(&mut self, context: String) {
+        self.load_history(&context);
         self.context = context;
     }
 
+    /// Persist the current context's observed history and load `context`'s.
+    fn load_history(&mut self, context: &str) {
+        self.history.flush();
+        self.history =
+            crate::services::HistoryStore::load(context, self.config.history_retention_days);
+        self.timeline.invalidate();
+    }
+
     pub fn set_namespace(&mut self, namespace: Option<String>) {
         self.namespace = namespace;
+        self.namespaces.clear();
+        self.namespace_selector = None;
+    }
+
+    /// Whether `namespace` is within the watched namespace scope.
+    pub(crate) fn in_namespace_scope(&self, namespace: &str) -> bool {
+        match self.namespace {
+            Some(ref current) => current == namespace,
+            None => self.namespaces.is_empty() || self.namespaces.iter().any(|ns| ns == namespace),
+        }
+    }
+
+    /// Namespaces the watchers cover; empty for all namespaces.
+    pub(crate) fn watched_namespaces(&self) -> Vec<String> {
+        match self.namespace {
+            Some(ref namespace) => vec![namespace.clone()],
+            None => self.namespaces.clone(),
+        }
+    }
+
+    /// The namespace scope for the header: `None` for all namespaces, else
+    /// the namespace, the comma-separated set, or the label selector with
+    /// the number of namespaces it matched.
+    pub(crate) fn namespace_display(&self) -> Option<String> {
+        match self.namespace_selector {
+            Some(ref selector) => Some(format!(
+                "{} ({})",
+                selector,
+                self.watched_namespaces().len()
+            )),
+            None if self.namespace.is_none() && !self.namespaces.is_empty() => {
+                Some(self.namespaces.join(","))
+            }
+            None => self.namespace.clone(),
+        }
+    }
+
+    /// The namespace scope as typed after `:ns`, for saved views.
+    pub(crate) fn namespace_arg(&self) -> String {
+        match self.namespace_selector {
+            Some(ref selector) => selector.clone(),
+            None if self.namespace.is_none() && self.namespaces.is_empty() => "all".to_string(),
+            None => self.watched_namespaces().join(","),
+        }
     }
 
     pub fn namespace(&self) -> &Option<String> {
@@ -486,8 +676,11 @@ impl App {
 
     /// Update the app with a new context after successful switch
     pub fn complete_context_switch(&mut self, context: String, namespace: Option<String>) {
+        self.load_history(&context);
         self.context = context;
         self.namespace = namespace;
+        self.namespaces.clear();
+        self.namespace_selector = None;
         self.state.clear();
         self.resource_objects.clear();
         self.controller_pods.clear();
@@ -504,8 +697,7 @@ impl App {
     /// Cycle the sort for the resource list: ascending → descending → default.
     ///
     /// Pressing a different sort key switches to that field (ascending).
-    pub(crate) fn toggle_sort(&mut self, field: crate::tui::app::state::SortField) {
-        use crate::tui::app::state::SortField;
+    pub(crate) fn toggle_sort(&mut self, field: SortField) {
         if self.view_state.sort_field == field {
             if self.view_state.sort_reverse {
                 self.view_state.sort_field = SortField::Default;
@@ -522,7 +714,7 @@ impl App {
 
         let msg = match self.view_state.sort_field {
             SortField::Default => "Sort: default (namespace/type/name)".to_string(),
-            f => format!(
+            ref f => format!(
                 "Sort: {}{}",
                 f.display_name(),
                 if self.view_state.sort_reverse {
@@ -650,12 +842,23 @@ impl App {
                     event.involved_name.clone(),
                 ))
             }
+            View::Timeline => {
+                let entries = self.filtered_timeline();
+                let entry = entries.get(self.view_state.selected_index)?;
+                Some(ResourceKey::new(
+                    entry.kind.clone(),
+                    entry.namespace.clone(),
+                    entry.name.clone(),
+                ))
+            }
             View::ResourceGraph => self.focused_graph_node_target(),
             View::ResourceDetail
             | View::ResourceDescribe
             | View::ResourceYAML
             | View::ResourceTrace
-            | View::ResourceHistory => self
+            | View::ResourceHistory
+            | View::ResourceDiff
+            | View::ResourceRelease => self
                 .selection_state
                 .selected_resource_key
                 .as_deref()
@@ -669,6 +872,9 @@ impl App {
             | View::Pulse
             | View::ResourceEdit
             | View::Help => None,
+            // Rows belong to other contexts' sessions; operations there are
+            // routed by `handle_cluster_operation_key`.
+            View::Clusters => None,
         }
     }
 
@@ -680,7 +886,7 @@ impl App {
         // live events feed keeps its watcher).
         if matches!(
             self.view_state.current_view,
-            View::ResourceList | View::ResourceFavorites | View::EventList
+            View::ResourceList | View::ResourceFavorites | View::EventList | View::Timeline
         ) {
             self.view_state.previous_list_view = self.view_state.current_view;
         }
@@ -762,6 +968,29 @@ impl App {
         }
     }
 
+    /// Apply the `/` filter query. An invalid query matches nothing — the
+    /// footer reports the error — so a typo never widens the list that bulk
+    /// operations act on.
+    pub(crate) fn apply_filter_query(&self, resources: &mut Vec<crate::watcher::ResourceInfo>) {
+        if self.view_state.filter.is_empty() {
+            return;
+        }
+        match crate::watcher::FilterQuery::parse(&self.view_state.filter) {
+            Ok(query) => {
+                let now = chrono::Utc::now();
+                let no_columns = HashMap::new();
+                resources.retain(|r| {
+                    if query.uses_columns() {
+                        query.matches_in_context(r, &self.context, &self.column_fields(r), now)
+                    } else {
+                        query.matches_in_context(r, &self.context, &no_columns, now)
+                    }
+                });
+            }
+            Err(_) => resources.clear(),
+        }
+    }
+
     pub(crate) fn get_filtered_resources(&self) -> Vec<crate::watcher::ResourceInfo> {
         let mut resources = if let Some(ref resource_type) = self.view_state.selected_resource_type
         {
@@ -770,9 +999,7 @@ impl App {
             self.state.all()
         };
 
-        if let Some(ref namespace) = self.namespace {
-            resources.retain(|r| r.namespace == *namespace);
-        }
+        resources.retain(|r| self.in_namespace_scope(&r.namespace));
 
         if self.view_state.current_view == View::ResourceFavorites {
             resources.retain(|r| {
@@ -781,40 +1008,7 @@ impl App {
             });
         }
 
-        if !self.view_state.filter.is_empty() {
-            if let Some(label_filter) = self.view_state.filter.strip_prefix("label:") {
-                if label_filter.is_empty() {
-                    resources.retain(|r| !r.labels.is_empty());
-                } else if let Some((key, value)) = label_filter.split_once('=') {
-                    resources.retain(|r| {
-                        r.labels
-                            .iter()
-                            .any(|(k, v)| k.starts_with(key) && v.starts_with(value))
-                    });
-                } else {
-                    resources.retain(|r| r.labels.keys().any(|k| k.starts_with(label_filter)));
-                }
-            } else if let Some(ann_filter) = self
-                .view_state
-                .filter
-                .strip_prefix("ann:")
-                .or_else(|| self.view_state.filter.strip_prefix("annotations:"))
-            {
-                if ann_filter.is_empty() {
-                    resources.retain(|r| !r.annotations.is_empty());
-                } else if let Some((key, value)) = ann_filter.split_once('=') {
-                    resources.retain(|r| {
-                        r.annotations
-                            .iter()
-                            .any(|(k, v)| k.starts_with(key) && v.starts_with(value))
-                    });
-                } else {
-                    resources.retain(|r| r.annotations.keys().any(|k| k.starts_with(ann_filter)));
-                }
-            } else {
-                resources.retain(|r| r.name.contains(&self.view_state.filter));
-            }
-        }
+        self.apply_filter_query(&mut resources);
 
         match self.view_state.health_filter {
             HealthFilter::Healthy => {
@@ -826,8 +1020,20 @@ impl App {
             HealthFilter::All => {}
         }
 
-        let sort_field = self.view_state.sort_field;
+        let sort_field = &self.view_state.sort_field;
         let sort_reverse = self.view_state.sort_reverse;
+        // Column values come from the stored objects; extract them once
+        let column_values: HashMap<String, String> = match sort_field {
+            SortField::Column(column) => resources
+                .iter()
+                .filter_map(|r| {
+                    let value = self.column_fields(r).remove(column)?;
+                    let key = crate::watcher::resource_key(&r.namespace, &r.name, &r.resource_type);
+                    Some((key, value))
+                })
+                .collect(),
+            _ => HashMap::new(),
+        };
         resources.sort_by(|a, b| {
             let a_key = crate::watcher::resource_key(&a.namespace, &a.name, &a.resource_type);
             let b_key = crate::watcher::resource_key(&b.namespace, &b.name, &b.resource_type);
@@ -839,7 +1045,7 @@ impl App {
                 (true, false) => std::cmp::Ordering::Less,
                 (false, true) => std::cmp::Ordering::Greater,
                 _ => {
-                    let ord = compare_by_sort_field(a, b, sort_field);
+                    let ord = compare_by_sort_field(a, b, sort_field, &column_values);
                     if sort_reverse { ord.reverse() } else { ord }
                 }
             }
@@ -847,15 +1053,35 @@ impl App {
 
         resources
     }
+
+    /// List column values of a resource (built-in and configured columns),
+    /// for `col:` filter terms and `:sort <column>`.
+    pub(crate) fn column_fields(
+        &self,
+        r: &crate::watcher::ResourceInfo,
+    ) -> HashMap<String, String> {
+        let key = crate::watcher::resource_key(&r.namespace, &r.name, &r.resource_type);
+        self.resource_objects
+            .get(&key)
+            .map(|obj| {
+                crate::models::resource_fields::extract_fields_with_columns(
+                    &r.resource_type,
+                    obj,
+                    &self.config.columns,
+                )
+            })
+            .unwrap_or_default()
+    }
 }
 
-/// Compare two resources by the given sort field (ascending).
-fn compare_by_sort_field(
+/// Compare two resources by the given sort field (ascending). `column_values`
+/// holds the values of a [`SortField::Column`] sort, keyed by resource key.
+pub(super) fn compare_by_sort_field(
     a: &crate::watcher::ResourceInfo,
     b: &crate::watcher::ResourceInfo,
-    field: crate::tui::app::state::SortField,
+    field: &SortField,
+    column_values: &HashMap<String, String>,
 ) -> std::cmp::Ordering {
-    use crate::tui::app::state::SortField;
     use std::cmp::Ordering;
     match field {
         SortField::Default => a
@@ -897,6 +1123,23 @@ fn compare_by_sort_field(
                 .then_with(|| a.namespace.cmp(&b.namespace))
                 .then_with(|| a.name.cmp(&b.name))
         }
+        SortField::Column(_) => {
+            // Resources without a value sort last
+            let value = |r: &crate::watcher::ResourceInfo| {
+                column_values.get(&crate::watcher::resource_key(
+                    &r.namespace,
+                    &r.name,
+                    &r.resource_type,
+                ))
+            };
+            match (value(a), value(b)) {
+                (Some(x), Some(y)) => x.cmp(y),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+            .then_with(|| a.name.cmp(&b.name))
+        }
     }
 }
 
@@ -1000,6 +1243,14 @@ mod tests {
             default_resource_filter: None,
             connect_timeout_seconds: crate::kube::health::DEFAULT_CONNECT_TIMEOUT_SECS,
             editor: None,
+            hooks: vec![],
+            history_retention_days: 0,
+            views: HashMap::new(),
+            columns: HashMap::new(),
+            keymap: HashMap::new(),
+            plugins: HashMap::new(),
+            aliases: HashMap::new(),
+            clusters: Vec::new(),
         };
         let theme = Theme::default();
         App::new(state, "test-context".to_string(), None, config, theme)
@@ -1154,6 +1405,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_filter_query_combines_with_health_filter() {
+        let mut app = create_test_app();
+        for resource in [
+            make_resource("podinfo", "apps", "HelmRelease", Some(false), None, None),
+            make_resource("redis", "apps", "HelmRelease", Some(true), None, None),
+            make_resource(
+                "apps",
+                "flux-system",
+                "Kustomization",
+                Some(false),
+                None,
+                None,
+            ),
+        ] {
+            let key = crate::watcher::resource_key(
+                &resource.namespace,
+                &resource.name,
+                &resource.resource_type,
+            );
+            app.state.upsert(key, resource);
+        }
+        let names = |app: &App| -> Vec<String> {
+            app.get_filtered_resources()
+                .into_iter()
+                .map(|r| r.name)
+                .collect()
+        };
+
+        app.view_state.filter = "kind:hr".to_string();
+        assert_eq!(names(&app), vec!["podinfo", "redis"]);
+
+        app.view_state.health_filter = HealthFilter::Unhealthy;
+        assert_eq!(names(&app), vec!["podinfo"]);
+
+        // An invalid query matches nothing rather than everything
+        app.view_state.health_filter = HealthFilter::All;
+        app.view_state.filter = "status:sideways".to_string();
+        assert!(names(&app).is_empty());
+    }
+
     #[test]
     fn test_toggle_sort_cycles_asc_desc_default() {
         use crate::tui::app::state::SortField;
@@ -1202,11 +1494,11 @@ mod tests {
         let unknown = make_resource("unknown", "ns", "Kustomization", None, None, None);
 
         assert_eq!(
-            compare_by_sort_field(&old, &new, SortField::Age),
+            compare_by_sort_field(&old, &new, &SortField::Age, &HashMap::new()),
             Ordering::Less
         );
         assert_eq!(
-            compare_by_sort_field(&new, &unknown, SortField::Age),
+            compare_by_sort_field(&new, &unknown, &SortField::Age, &HashMap::new()),
             Ordering::Less
         );
     }
@@ -1220,11 +1512,11 @@ mod tests {
         let ready = make_resource("c", "ns", "Kustomization", Some(true), Some(false), None);
 
         assert_eq!(
-            compare_by_sort_field(&failing, &suspended, SortField::Status),
+            compare_by_sort_field(&failing, &suspended, &SortField::Status, &HashMap::new()),
             Ordering::Less
         );
         assert_eq!(
-            compare_by_sort_field(&suspended, &ready, SortField::Status),
+            compare_by_sort_field(&suspended, &ready, &SortField::Status, &HashMap::new()),
             Ordering::Less
         );
     }
@@ -1239,4 +1531,23 @@ mod tests {
         assert_eq!(app.context, "new-context");
         assert_eq!(app.namespace(), &Some("new-namespace".to_string()));
     }
+
+    #[test]
+    fn invalid_keymap_config_is_reported_as_config_error() {
+        let mut config = create_test_app().config.clone();
+        config
+            .keymap
+            .insert("no-such-action".to_string(), "z".to_string());
+        let app = App::new(
+            ResourceState::new(),
+            "test-context".to_string(),
+            None,
+            config,
+            Theme::default(),
+        );
+        assert_eq!(app.config_errors.len(), 1);
+        assert!(app.config_errors[0].starts_with("Ignoring keymap config:"));
+        assert!(app.config_errors[0].contains("no-such-action"));
+        assert!(create_test_app().config_errors.is_empty());
+    }
 }
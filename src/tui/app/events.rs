@@ -26,10 +26,14 @@ const COMMAND_TABLE: &[(fn(&str) -> bool, CommandHandler)] = &[
     (commands::is_unhealthy_command, App::cmd_filter_unhealthy),
     (commands::is_favorites_command, App::cmd_show_favorites),
     (commands::is_events_command, App::cmd_show_events),
+    (commands::is_timeline_command, App::cmd_show_timeline),
     (commands::is_pulse_command, App::cmd_show_pulse),
     (commands::is_logs_command, App::cmd_show_logs),
     (commands::is_all_command, App::cmd_show_all),
     (commands::is_discover_command, App::cmd_toggle_discover),
+    (commands::is_view_command, App::cmd_view),
+    (commands::is_sort_command, App::cmd_sort),
+    (commands::is_clusters_command, App::cmd_clusters),
 ];
 
 impl App {
@@ -46,15 +50,21 @@ impl App {
         // of free-scrolling; the renderer scrolls to keep the focused node on screen.
         if view == View::ResourceGraph {
             self.move_graph_focus(true);
+        } else if self.release_cursor_active() {
+            self.move_release_cursor(amount as isize);
         } else if let Some(offset) = view.scroll_offset_mut(&mut self.view_state) {
             *offset += amount;
         } else {
             let max_index = if view == View::EventList {
                 self.filtered_kube_events().len().saturating_sub(1)
+            } else if view == View::Timeline {
+                self.filtered_timeline().len().saturating_sub(1)
             } else if view == View::WorkloadList {
                 self.view_state.workload_rows.len().saturating_sub(1)
             } else if view == View::InventoryList {
                 self.view_state.inventory_rows.len().saturating_sub(1)
+            } else if view == View::Clusters {
+                self.filtered_cluster_resources().len().saturating_sub(1)
             } else {
                 self.get_filtered_resources().len().saturating_sub(1)
             };
@@ -73,6 +83,8 @@ impl App {
         }
         if view == View::ResourceGraph {
             self.move_graph_focus(false);
+        } else if self.release_cursor_active() {
+            self.move_release_cursor(-(amount as isize));
         } else if let Some(offset) = view.scroll_offset_mut(&mut self.view_state) {
             *offset = offset.saturating_sub(amount);
         } else {
@@ -89,6 +101,9 @@ impl App {
     /// None for normal continuation
     pub fn handle_key(&mut self, key: KeyEvent) -> Option<bool> {
         // Return Some(true) to quit, Some(false) to continue, None for no action
+        if self.view_state.current_view == View::Timeline {
+            self.refresh_timeline();
+        }
 
         // If splash is showing, dismiss it immediately on any keypress
         if self.ui_state.show_splash {
@@ -102,6 +117,13 @@ impl App {
         if self.async_state.confirmation_pending.is_some() {
             return self.handle_confirmation_key(key);
         }
+        if self.async_state.bulk_confirmation.is_some() {
+            return self.handle_bulk_confirmation_key(key);
+        }
+        // Any key dismisses the bulk operation summary
+        if self.async_state.bulk_summary.take().is_some() {
+            return None;
+        }
 
         // Handle quit confirmation dialog (shown when q/Esc is pressed at top level)
         if self.ui_state.show_quit_confirm {
@@ -113,6 +135,17 @@ impl App {
             return self.handle_submenu_key(key);
         }
 
+        // Apply the `keymap` config: rewrite a remapped key into the built-in
+        // key matched below. Text input modes take keys literally.
+        let key = if self.ui_state.command_mode
+            || self.view_state.filter_mode
+            || self.view_state.text_search.input_mode
+        {
+            key
+        } else {
+            self.keymap.translate(key)?
+        };
+
         // Handle connection error state keys
         if self.has_connection_error() {
             // Check status message timeout
@@ -224,6 +257,38 @@ impl App {
             return self.handle_text_search_key(key);
         }
 
+        // Edit preview: apply or re-open the editor; scrolling and Esc fall
+        // through to the regular handling below.
+        if self.view_state.current_view == View::ResourceEdit
+            && self.async_state.edit_preview.is_some()
+        {
+            match key.code {
+                crossterm::event::KeyCode::Char('y') | crossterm::event::KeyCode::Enter => {
+                    self.apply_edit_preview();
+                    return None;
+                }
+                crossterm::event::KeyCode::Char('e') => {
+                    self.reopen_edit();
+                    return None;
+                }
+                _ => {}
+            }
+        }
+
+        // Plugin shortcuts (`plugins` config)
+        if let Some(plugin) = self.keymap.plugin_for(&key) {
+            let name = plugin.name.clone();
+            self.run_plugin(&name);
+            return None;
+        }
+
+        // Saved view hotkeys (F1-F12)
+        if let crossterm::event::KeyCode::F(n) = key.code {
+            if self.apply_view_hotkey(n) {
+                return None;
+            }
+        }
+
         // Handle namespace hotkeys (0-9)
         if let crossterm::event::KeyCode::Char(c) = key.code {
             if c.is_ascii_digit() {
@@ -236,33 +301,15 @@ impl App {
                         Some(ns_name.clone())
                     };
 
-                    // Update namespace and restart watchers if changed
-                    if self.namespace != new_namespace {
-                        self.namespace = new_namespace.clone();
-
-                        self.state.clear();
-                        self.resource_objects.clear();
-                        self.controller_pods.clear();
-                        // Restarted watchers start clean; stale degraded/forbidden
-                        // state from the old set would otherwise never clear.
-                        self.degraded_watchers.clear();
-                        self.forbidden_watchers.clear();
-                        if let Some(ref mut watcher) = self.watcher {
-                            if let Err(e) = watcher.set_namespace(new_namespace) {
-                                self.set_status_message((
-                                    format!("Failed to switch namespace: {}", e),
-                                    true,
-                                ));
-                            } else {
-                                self.set_status_message((
-                                    format!("Switched to namespace: {}", ns_name),
-                                    false,
-                                ));
-                            }
-                        }
-
-                        self.view_state.selected_index = 0;
-                        self.view_state.scroll_offset = 0;
+                    // Update namespace and restart watchers if changed (a
+                    // namespace set also shows as no single namespace)
+                    if self.namespace != new_namespace || !self.namespaces.is_empty() {
+                        // A failed restart replaces this with the error
+                        self.set_status_message((
+                            format!("Switched to namespace: {}", ns_name),
+                            false,
+                        ));
+                        self.switch_namespace(new_namespace);
                     }
                     return None;
                 }
@@ -292,6 +339,10 @@ impl App {
                     self.handle_operation_key('d');
                     return None;
                 }
+                crossterm::event::KeyCode::Char('a') if self.is_markable_view() => {
+                    self.mark_all_filtered();
+                    return None;
+                }
                 _ => {}
             }
         }
@@ -331,6 +382,11 @@ impl App {
                     self.view_state.text_search.clear();
                     return None;
                 }
+                // In a list with marked rows, Esc drops the marks first
+                if self.is_markable_view() && !self.marked_resources().is_empty() {
+                    self.clear_marks();
+                    return None;
+                }
                 // Navigate back a level, closer to k9s behaviour where Esc never
                 // exits directly. At the top-level view a confirmation dialog is shown.
                 return self.navigate_back_or_confirm_quit();
@@ -351,6 +407,20 @@ impl App {
                 };
                 self.handle_operation_key(op_key);
             }
+            // Pick revisions to diff in the release inspector
+            crossterm::event::KeyCode::Char(' ')
+                if self.view_state.current_view == View::ResourceRelease =>
+            {
+                self.toggle_release_mark();
+            }
+            // Mark rows for a bulk operation
+            crossterm::event::KeyCode::Char(' ') if self.is_markable_view() => {
+                self.toggle_mark_selected();
+                self.scroll_down(1);
+            }
+            crossterm::event::KeyCode::Char('*') if self.is_markable_view() => {
+                self.invert_marks();
+            }
             crossterm::event::KeyCode::Char('t') => {
                 // Trace command - works from list, favorites, and detail view
                 if let Some(resource) = self.get_current_resource() {
@@ -433,6 +503,35 @@ impl App {
                     self.view_state.current_view = View::ResourceDescribe;
                 }
             }
+            crossterm::event::KeyCode::Char('D')
+                if self.view_state.current_view == View::ResourceRelease =>
+            {
+                self.diff_release_revisions();
+            }
+            crossterm::event::KeyCode::Char('D') => {
+                // Diff live spec vs applied - trigger async fetch
+                if let Some(key) = self.prepare_selected_resource_key_for_nested_view() {
+                    self.async_state.diff.request(key);
+                    self.view_state.diff_scroll_offset = 0;
+                    self.view_state.text_search.clear();
+                    self.view_state.current_view = View::ResourceDiff;
+                }
+            }
+            crossterm::event::KeyCode::Char('H') => {
+                // Inspect the Helm release behind a HelmRelease - async fetch
+                self.open_release_view();
+            }
+            // Cycle the release inspector's tabs
+            crossterm::event::KeyCode::Tab | crossterm::event::KeyCode::BackTab
+                if self.view_state.current_view == View::ResourceRelease =>
+            {
+                let step = if key.code == crossterm::event::KeyCode::Tab {
+                    1
+                } else {
+                    -1
+                };
+                self.cycle_release_tab(step);
+            }
             crossterm::event::KeyCode::Char('e') => {
                 if self.config.read_only {
                     self.set_status_message((
@@ -464,10 +563,14 @@ impl App {
                 // Drill into the focused graph node's resource.
                 self.navigate_to_focused_graph_node();
             }
-            crossterm::event::KeyCode::Enter if self.view_state.current_view == View::EventList => {
-                // Jump to the event's involved resource when flux9s watches it.
+            crossterm::event::KeyCode::Enter if self.view_state.current_view.uses_kube_events() => {
+                // Jump to the event's (or timeline entry's) resource when
+                // flux9s watches it.
                 self.navigate_to_selected_event_resource();
             }
+            crossterm::event::KeyCode::Enter if self.view_state.current_view == View::Clusters => {
+                self.open_selected_cluster_resource();
+            }
             crossterm::event::KeyCode::Enter
                 if self.view_state.current_view == View::WorkloadList =>
             {
@@ -571,7 +674,8 @@ impl App {
                         .and_then(|s| s.get("history"))
                         .and_then(|h| h.as_array())
                         .map(|arr| !arr.is_empty())
-                        .unwrap_or(false);
+                        .unwrap_or(false)
+                        || !self.history.events(&key).is_empty();
                     let is_kustomization = matches!(
                         FluxResourceKind::parse_optional(&resource.resource_type),
                         Some(FluxResourceKind::Kustomization)
@@ -597,7 +701,7 @@ impl App {
                                     .map(|k| k.as_str().to_string())
                                     .collect();
                             format!(
-                                "Resource '{}' does not have reconciliation history. History is only available for: {}",
+                                "No reconciliation history observed for '{}' yet. status.history is only available for: {}",
                                 resource.name,
                                 supported_types.join(", ")
                             )
@@ -608,6 +712,16 @@ impl App {
                     self.set_status_message(("No resource selected".to_string(), true));
                 }
             }
+            crossterm::event::KeyCode::Char('x')
+                if self.view_state.current_view == View::ResourceGraph =>
+            {
+                self.open_graph_export_menu();
+            }
+            crossterm::event::KeyCode::Char('x')
+                if self.view_state.current_view == View::ResourceRelease =>
+            {
+                self.toggle_release_secrets();
+            }
             crossterm::event::KeyCode::Char('g') => {
                 // View resource graph - works from list, favorites, and detail view
                 if let Some(resource) = self.get_current_resource() {
@@ -625,7 +739,7 @@ impl App {
 
                     // Save current view as previous list view before navigating
                     if self.view_state.current_view.is_list_view()
-                        || self.view_state.current_view == View::EventList
+                        || self.view_state.current_view.uses_kube_events()
                     {
                         self.view_state.previous_list_view = self.view_state.current_view;
                     }
@@ -654,14 +768,7 @@ impl App {
                 // Backspace goes back (same as Escape for detail view)
                 if self.view_state.current_view == View::ResourceEdit {
                     // Cancel edit — clear all edit state
-                    self.async_state.edit_pending = None;
-                    self.async_state.edit_full_yaml = None;
-                    self.async_state.edit_save_pending = None;
-                    self.async_state.edit_save_result_rx = None;
-                    self.async_state.edit_error_message = None;
-                    self.async_state.edit_editor_launched = false;
-                    self.view_state.current_view = self.async_state.edit_return_view;
-                    self.async_state.edit_return_view = View::ResourceList;
+                    self.end_edit();
                 } else if self.view_state.current_view.is_nested_view() {
                     // Mirror Esc: return to the graph if we came from there,
                     // otherwise to the previous list view.
@@ -675,7 +782,7 @@ impl App {
                 } else if self.view_state.current_view == View::ResourceFavorites {
                     self.view_state.current_view = View::ResourceList;
                     self.selection_state.selected_resource_key = None;
-                } else if self.view_state.current_view == View::EventList {
+                } else if self.view_state.current_view.uses_kube_events() {
                     self.stop_kube_events_watch();
                     self.view_state.current_view = View::ResourceList;
                     self.selection_state.selected_resource_key = None;
@@ -727,6 +834,9 @@ impl App {
                 if !self.view_state.filter.is_empty() {
                     self.invalidate_layout_cache();
                 }
+                if let Err(e) = crate::watcher::FilterQuery::parse(&self.view_state.filter) {
+                    self.set_status_message((format!("Invalid filter: {}", e), true));
+                }
                 None
             }
             crossterm::event::KeyCode::Backspace => {
@@ -932,6 +1042,8 @@ impl App {
                                 Some(value.clone())
                             };
                             self.switch_namespace(new_namespace);
+                        } else if command == "view" {
+                            self.apply_saved_view(&value);
                         } else if command == "logs" {
                             self.open_log_view(&value);
                         } else if command == "pod-logs" {
@@ -940,6 +1052,8 @@ impl App {
                                 let (namespace, pod) = (namespace.to_string(), pod.to_string());
                                 self.open_pod_logs(&namespace, &pod);
                             }
+                        } else if command == "graph-export" {
+                            self.export_current_graph(&value);
                         } else if command == "skin" {
                             // Change theme (already previewed, so just confirm)
                             match self.set_theme(&value) {
@@ -1044,6 +1158,53 @@ impl App {
         ))
     }
 
+    /// Offer the export formats for the graph on screen (`x` in the graph view).
+    fn open_graph_export_menu(&mut self) {
+        if self.async_state.graph.result().is_none() {
+            self.set_status_message(("No graph to export yet".to_string(), true));
+            return;
+        }
+        let items = crate::trace::GraphFormat::ALL
+            .iter()
+            .map(|format| {
+                crate::tui::submenu::SubmenuItem::with_display(
+                    format.as_str().to_string(),
+                    format!("{} (.{})", format.as_str(), format.extension()),
+                )
+            })
+            .collect();
+        self.view_state.submenu_state = Some(
+            crate::tui::submenu::SubmenuState::new("graph-export".to_string(), items)
+                .with_title("Export Graph".to_string())
+                .with_help("j/k: Navigate | Enter: Write file | Esc: Cancel".to_string()),
+        );
+    }
+
+    /// Write the graph on screen to the working directory in `format`, next to
+    /// (never over) an existing file of the same name.
+    fn export_current_graph(&mut self, format: &str) {
+        let Ok(format) = format.parse::<crate::trace::GraphFormat>() else {
+            return;
+        };
+        let Some(graph) = self.async_state.graph.result() else {
+            self.set_status_message(("No graph to export yet".to_string(), true));
+            return;
+        };
+        let file_name = crate::trace::export_file_name(graph, format);
+        let written = crate::trace::export_graph(graph, format).and_then(|rendered| {
+            let dir = std::env::current_dir()?;
+            crate::trace::write_new_export(&dir, &file_name, &rendered)
+        });
+        match written {
+            Ok(path) => {
+                self.set_status_message((format!("Graph exported to {}", path.display()), false));
+            }
+            Err(e) => {
+                self.set_status_message((format!("Failed to export graph: {}", e), true));
+            }
+        }
+    }
+
     /// Open the detail view for the focused graph node when it maps to a watched
     /// resource. Aggregate nodes (workload/resource groups) and external upstream
     /// URLs are not directly navigable and just show a hint instead.
@@ -1158,6 +1319,8 @@ impl App {
             | View::ResourceYAML
             | View::ResourceTrace
             | View::ResourceHistory
+            | View::ResourceDiff
+            | View::ResourceRelease
             | View::ResourceGraph => {
                 // If we drilled into this detail view from the graph, return to
                 // the graph; otherwise go back to the previous list view
@@ -1173,21 +1336,14 @@ impl App {
             }
             View::ResourceEdit => {
                 // Cancel edit — clear all edit state and return to origin view
-                self.async_state.edit_pending = None;
-                self.async_state.edit_full_yaml = None;
-                self.async_state.edit_save_pending = None;
-                self.async_state.edit_save_result_rx = None;
-                self.async_state.edit_error_message = None;
-                self.async_state.edit_editor_launched = false;
-                self.view_state.current_view = self.async_state.edit_return_view;
-                self.async_state.edit_return_view = View::ResourceList;
+                self.end_edit();
                 None
             }
             View::ResourceFavorites => {
                 self.view_state.current_view = View::ResourceList;
                 None
             }
-            View::EventList => {
+            View::EventList | View::Timeline => {
                 self.stop_kube_events_watch();
                 self.view_state.current_view = View::ResourceList;
                 None
@@ -1220,6 +1376,13 @@ impl App {
                 self.view_state.current_view = View::ResourceList;
                 None
             }
+            View::Clusters => {
+                // The sessions keep running so `:clusters` reopens instantly
+                self.view_state.current_view = View::ResourceList;
+                self.reset_list_position();
+                self.invalidate_layout_cache();
+                None
+            }
             View::Help => {
                 self.view_state.current_view = View::ResourceList;
                 None
@@ -1248,36 +1411,30 @@ impl App {
         }
     }
 
-    /// Open the detail view for the resource the selected event is about,
-    /// when it is a Flux resource flux9s is watching. Back returns to the
-    /// events feed (the events watcher keeps running meanwhile).
+    /// Open the detail view for the resource the selected event (or timeline
+    /// entry) is about, when it is a Flux resource flux9s is watching. Back
+    /// returns to the feed (the events watcher keeps running meanwhile).
     fn navigate_to_selected_event_resource(&mut self) {
-        let events = self.filtered_kube_events();
-        let Some(event) = events.get(self.view_state.selected_index) else {
+        let Some(target) = self.view_target() else {
             return;
         };
 
-        let key = crate::watcher::resource_key(
-            &event.involved_namespace,
-            &event.involved_name,
-            &event.involved_kind,
-        );
+        let key = target.to_key_string();
         if self.state.get(&key).is_none() {
             // Not in the watch state: outside the namespace scope, a non-Flux
             // kind, or its watcher isn't running. Name the namespace so a
             // scope mismatch is visible, and point at the keys that still work.
             self.set_status_message((
                 format!(
-                    "{} (ns: {}) is not in the watched resources — press y/d to view it",
-                    event.object_label(),
-                    event.involved_namespace
+                    "{}/{} (ns: {}) is not in the watched resources — press y/d to view it",
+                    target.resource_type, target.name, target.namespace
                 ),
                 false,
             ));
             return;
         }
 
-        self.view_state.previous_list_view = View::EventList;
+        self.view_state.previous_list_view = self.view_state.current_view;
         self.view_state.detail_back_view = None;
         self.selection_state.selected_resource_key = Some(key);
         self.view_state.current_view = View::ResourceDetail;
@@ -1322,12 +1479,12 @@ impl App {
         }
     }
 
-    fn prepare_selected_resource_key_for_nested_view(&mut self) -> Option<ResourceKey> {
+    pub(super) fn prepare_selected_resource_key_for_nested_view(&mut self) -> Option<ResourceKey> {
         let rk = self.view_target()?;
         match self.view_state.current_view {
             // Root list-style views: remember where Back should return to and
             // drop any stale graph back-target from an earlier drill-down.
-            View::ResourceList | View::ResourceFavorites | View::EventList => {
+            View::ResourceList | View::ResourceFavorites | View::EventList | View::Timeline => {
                 self.view_state.previous_list_view = self.view_state.current_view;
                 self.view_state.detail_back_view = None;
             }
@@ -1342,6 +1499,15 @@ impl App {
     }
 
     fn handle_operation_key(&mut self, op_key: char) {
+        if self.view_state.current_view == View::Clusters {
+            self.handle_cluster_operation_key(op_key);
+            return;
+        }
+        // With marked rows, operations apply to the marks instead of the selection
+        if self.is_markable_view() && !self.marked_resources().is_empty() {
+            self.start_bulk_operation(op_key);
+            return;
+        }
         if let Some(resource) = self.get_current_resource() {
             if let Some(operation) = self.operation_registry.get_by_keybinding(op_key) {
                 if !operation.is_valid_for(&resource.resource_type) {
@@ -1388,12 +1554,12 @@ impl App {
                     )
                 };
                 self.set_status_message((feedback_msg, false));
-                self.execute_operation(
-                    &resource.resource_type,
-                    &resource.namespace,
-                    &resource.name,
+                self.execute_operation(PendingOperation::new(
+                    resource.resource_type.clone(),
+                    resource.namespace.clone(),
+                    resource.name.clone(),
                     op_key,
-                );
+                ));
             }
         }
     }
@@ -1414,12 +1580,7 @@ impl App {
                     // Confirm operation - clone data before clearing pending state
                     let pending_clone = pending.clone();
                     self.async_state.confirmation_pending = None;
-                    self.execute_operation(
-                        &pending_clone.resource_type,
-                        &pending_clone.namespace,
-                        &pending_clone.name,
-                        pending_clone.operation_key,
-                    );
+                    self.execute_operation(pending_clone);
                 }
                 crossterm::event::KeyCode::Char('n')
                 | crossterm::event::KeyCode::Char('N')
@@ -1433,13 +1594,8 @@ impl App {
         None
     }
 
-    fn execute_operation(
-        &mut self,
-        resource_type: &str,
-        namespace: &str,
-        name: &str,
-        op_key: char,
-    ) {
+    pub(crate) fn execute_operation(&mut self, operation: PendingOperation) {
+        let op_key = operation.operation_key;
         // Check readonly mode - prevent modification operations
         if self.config.read_only && self.operation_registry.get_by_keybinding(op_key).is_some() {
             // All operations are modifications, so block them all in readonly mode
@@ -1450,15 +1606,16 @@ impl App {
             return;
         }
 
-        if self.operation_registry.get_by_keybinding(op_key).is_some() && self.kube_client.is_some()
-        {
+        let has_client = match operation.context.as_deref() {
+            Some(context) => self
+                .clusters
+                .as_ref()
+                .is_some_and(|clusters| clusters.client(context).is_some()),
+            None => self.kube_client.is_some(),
+        };
+        if self.operation_registry.get_by_keybinding(op_key).is_some() && has_client {
             // Mark operation as pending - will be executed in main loop
-            self.async_state.operation.request(PendingOperation::new(
-                resource_type.to_string(),
-                namespace.to_string(),
-                name.to_string(),
-                op_key,
-            ));
+            self.async_state.operation.request(operation);
         }
     }
 
@@ -1510,7 +1667,7 @@ impl App {
 
         // Use centralized command registry to find matches
         // This prioritizes CRD commands over app commands
-        let matches = crate::tui::commands::find_matching_commands(&cmd_lower);
+        let matches = crate::tui::commands::find_matching_commands(&cmd_lower, &self.aliases);
 
         if matches.is_empty() {
             return;
@@ -1534,8 +1691,10 @@ impl App {
     /// message. Returns `Some(true)` only to quit.
     fn execute_command(&mut self) -> Option<bool> {
         // Own the command string so the per-command handlers can take `&mut self`
-        // without conflicting with a borrow of the command buffer.
+        // without conflicting with a borrow of the command buffer. Configured
+        // aliases expand before any parsing.
         let cmd = self.ui_state.command_buffer.trim().to_string();
+        let cmd = commands::expand_alias(&cmd, &self.aliases).unwrap_or(cmd);
         let cmd_lower = cmd.to_lowercase();
 
         if commands::is_help_command(&cmd_lower) {
@@ -1571,16 +1730,22 @@ impl App {
         }
 
         // Fallback: a resource-type command (e.g. `:ks`, `:hr`), else a
-        // dynamically discovered kind (#197), else unknown.
-        if let Some(display_name) = crate::watcher::get_display_name_for_command(&cmd_lower) {
-            self.view_state.selected_resource_type = Some(display_name.to_string());
-            self.reset_list_position();
-            self.invalidate_layout_cache(); // Resource type filter affects header display
-        } else if let Some(kind) = crate::models::extra_kinds::global().resolve_command(&cmd_lower)
-        {
+        // dynamically discovered kind (#197), else unknown. Words after the
+        // kind become the `/` filter query (`:hr status:failed`).
+        let (kind_cmd, query) = cmd
+            .split_once(char::is_whitespace)
+            .map(|(kind, query)| (kind.to_lowercase(), query.trim()))
+            .unwrap_or((cmd_lower.clone(), ""));
+        let kind = crate::watcher::get_display_name_for_command(&kind_cmd)
+            .map(str::to_string)
+            .or_else(|| crate::models::extra_kinds::global().resolve_command(&kind_cmd));
+        if let Some(kind) = kind {
             self.view_state.selected_resource_type = Some(kind);
+            if !query.is_empty() {
+                self.view_state.filter = query.to_string();
+            }
             self.reset_list_position();
-            self.invalidate_layout_cache();
+            self.invalidate_layout_cache(); // Resource type filter affects header display
         } else if !cmd.is_empty() {
             self.set_status_message((
                 format!(
@@ -1596,14 +1761,33 @@ impl App {
 
     /// Reset the list selection and scroll to the top. Shared by the commands
     /// that change what the list shows (namespace, filters, resource type).
-    fn reset_list_position(&mut self) {
+    pub(crate) fn reset_list_position(&mut self) {
         self.view_state.selected_index = 0;
         self.view_state.scroll_offset = 0;
     }
 
+    /// `:sort <column>` sorts the list by any column, including configured
+    /// custom columns, cycling like the shift-key sorts; `:sort` restores the
+    /// default order.
+    fn cmd_sort(&mut self, cmd: &str) {
+        use crate::tui::app::state::SortField;
+        match commands::extract_command_arg(cmd, "sort") {
+            Some(column) => self.toggle_sort(SortField::from_column(&column)),
+            None => {
+                self.view_state.sort_field = SortField::Default;
+                self.view_state.sort_reverse = false;
+                self.reset_list_position();
+                self.set_status_message(("Sort: default (namespace/type/name)".to_string(), false));
+            }
+        }
+    }
+
     /// Toggle read-only mode and reload the matching skin.
     fn cmd_toggle_readonly(&mut self) {
         self.config.read_only = !self.config.read_only;
+        if self.config.read_only {
+            self.redact_release_secrets();
+        }
         let status = if self.config.read_only {
             "enabled"
         } else {
@@ -1804,16 +1988,18 @@ impl App {
         self.set_status_message((format!("Switching to context '{}'...", ctx), false));
     }
 
-    /// `:ns [name|all]` — switch the watched namespace, restarting watchers.
+    /// `:ns [name|all|ns1,ns2|selector]` — switch the watched namespace
+    /// scope, restarting watchers.
     ///
     /// Without an argument, opens the searchable namespace picker submenu;
-    /// `:ns <name>` (or `:ns all` / `:ns -A`) switches directly.
+    /// `:ns <name>` (or `:ns all` / `:ns -A`) switches directly. A comma list
+    /// watches several namespaces and a label selector (`:ns team=payments`)
+    /// the namespaces it matches, resolved by the main loop.
     fn cmd_switch_namespace(&mut self, cmd: &str) {
         let ns = commands::extract_command_arg(cmd, "namespace")
             .or_else(|| commands::extract_command_arg(cmd, "ns"));
-        match ns.as_deref() {
-            Some("all") | Some("-A") => self.switch_namespace(None),
-            Some(name) => self.switch_namespace(Some(name.to_string())),
+        match ns {
+            Some(arg) => self.apply_namespace_arg(&arg),
             None => {
                 // No argument: open the searchable picker (same reusable submenu
                 // as :ctx / :skin) instead of listing options in the bars.
@@ -1828,11 +2014,48 @@ impl App {
         }
     }
 
+    /// Switch to the namespace scope written as after `:ns` (see
+    /// [`NamespaceScope::parse`](crate::watcher::NamespaceScope::parse)).
+    /// Shared by `:ns` and saved views.
+    pub(crate) fn apply_namespace_arg(&mut self, arg: &str) {
+        use crate::watcher::NamespaceScope;
+        match NamespaceScope::parse(arg) {
+            NamespaceScope::All => self.switch_namespace(None),
+            NamespaceScope::Namespaces(namespaces) => self.switch_namespaces(namespaces, None),
+            NamespaceScope::Selector(selector) => {
+                self.set_status_message((
+                    format!("Resolving namespaces matching '{}'...", selector),
+                    false,
+                ));
+                self.async_state.namespace_selector.request(selector);
+            }
+        }
+    }
+
     /// Switch the watched namespace, restarting watchers. `None` watches all
     /// namespaces. Shared by `:ns <name>` and the namespace picker submenu.
-    fn switch_namespace(&mut self, new_namespace: Option<String>) {
-        if self.namespace != new_namespace {
-            self.namespace = new_namespace.clone();
+    pub(crate) fn switch_namespace(&mut self, new_namespace: Option<String>) {
+        self.switch_namespaces(new_namespace.into_iter().collect(), None);
+    }
+
+    /// Watch a set of namespaces, one watcher per namespace, restarting
+    /// watchers; an empty set watches all namespaces. `selector` is the label
+    /// selector the set was resolved from, shown in the header.
+    pub(crate) fn switch_namespaces(
+        &mut self,
+        mut namespaces: Vec<String>,
+        selector: Option<String>,
+    ) {
+        let new_namespace = if namespaces.len() == 1 {
+            namespaces.pop()
+        } else {
+            None
+        };
+        self.namespace_selector = selector;
+
+        if self.namespace != new_namespace || self.namespaces != namespaces {
+            self.namespace = new_namespace;
+            self.namespaces = namespaces;
 
             // Clear state; the restarted watchers repopulate it. Stale degraded
             // state from the old watcher set would otherwise never clear.
@@ -1843,8 +2066,9 @@ impl App {
             self.degraded_watchers.clear();
             self.forbidden_watchers.clear();
 
+            let watched = self.watched_namespaces();
             if let Some(ref mut watcher) = self.watcher {
-                if let Err(e) = watcher.set_namespace(new_namespace) {
+                if let Err(e) = watcher.set_namespaces(watched) {
                     tracing::warn!("Failed to switch namespace: {}", e);
                     self.set_status_message((format!("Failed to switch namespace: {}", e), true));
                 }
@@ -1854,6 +2078,31 @@ impl App {
         self.reset_list_position();
     }
 
+    /// Apply the namespaces a `:ns <selector>` resolved to. A selector that
+    /// matches nothing leaves the scope unchanged.
+    pub fn on_namespace_selector_resolved(
+        &mut self,
+        result: anyhow::Result<(String, Vec<String>)>,
+    ) {
+        match result {
+            Ok((selector, namespaces)) if namespaces.is_empty() => {
+                self.set_status_message((format!("No namespaces match '{}'", selector), true))
+            }
+            Ok((selector, namespaces)) => {
+                self.set_status_message((
+                    format!(
+                        "Watching {} namespaces matching '{}'",
+                        namespaces.len(),
+                        selector
+                    ),
+                    false,
+                ));
+                self.switch_namespaces(namespaces, Some(selector));
+            }
+            Err(e) => self.set_status_message((format!("{:#}", e), true)),
+        }
+    }
+
     /// `:healthy` — filter the list to healthy resources.
     fn cmd_filter_healthy(&mut self, _cmd: &str) {
         self.view_state.health_filter = HealthFilter::Healthy;
@@ -1882,6 +2131,34 @@ impl App {
         self.reset_list_position();
     }
 
+    /// `:timeline [kind...]` — open the cluster-wide reconciliation timeline,
+    /// optionally limited to kinds (display names or aliases, separated by
+    /// spaces or commas). Starts the events watcher for Warning events.
+    fn cmd_show_timeline(&mut self, cmd: &str) {
+        let mut kinds = Vec::new();
+        if let Some(arg) = commands::extract_command_arg(cmd, "timeline")
+            .or_else(|| commands::extract_command_arg(cmd, "tl"))
+        {
+            for name in arg.split([',', ' ']).filter(|s| !s.is_empty()) {
+                match crate::models::FluxResourceKind::from_str_case_insensitive(name) {
+                    Some(kind) => kinds.push(kind.as_str().to_string()),
+                    None => {
+                        self.set_status_message((
+                            format!("Unknown resource kind '{}'", name),
+                            true,
+                        ));
+                        return;
+                    }
+                }
+            }
+        }
+        self.view_state.timeline_kinds = kinds;
+        self.start_kube_events_watch();
+        self.view_state.current_view = View::Timeline;
+        self.refresh_timeline();
+        self.reset_list_position();
+    }
+
     /// `:pulse` — open the cluster pulse dashboard.
     fn cmd_show_pulse(&mut self, _cmd: &str) {
         self.view_state.pulse_scroll_offset = 0;
@@ -1932,7 +2209,7 @@ impl App {
         if self.view_state.current_view == View::ResourceFavorites {
             self.view_state.current_view = View::ResourceList;
         }
-        if self.view_state.current_view == View::EventList {
+        if self.view_state.current_view.uses_kube_events() {
             self.stop_kube_events_watch();
             self.view_state.current_view = View::ResourceList;
         }
@@ -1998,6 +2275,14 @@ mod tests {
             default_resource_filter: None,
             connect_timeout_seconds: crate::kube::health::DEFAULT_CONNECT_TIMEOUT_SECS,
             editor: None,
+            hooks: vec![],
+            history_retention_days: 0,
+            views: HashMap::new(),
+            columns: HashMap::new(),
+            keymap: HashMap::new(),
+            plugins: HashMap::new(),
+            aliases: HashMap::new(),
+            clusters: Vec::new(),
         };
         let theme = Theme::default();
         App::new(state, "test-context".to_string(), None, config, theme)
@@ -2045,6 +2330,124 @@ mod tests {
         assert!(app.async_state.confirmation_pending.is_none());
     }
 
+    #[test]
+    fn test_shift_d_opens_diff_view() {
+        let mut app = create_test_app(false);
+        add_resource(&mut app);
+        app.view_state.current_view = View::ResourceList;
+
+        let result = app.handle_key(make_key(KeyCode::Char('D')));
+
+        assert_eq!(result, None);
+        assert_eq!(app.view_state.current_view, View::ResourceDiff);
+        assert_eq!(
+            app.async_state
+                .diff
+                .pending()
+                .map(ResourceKey::to_key_string)
+                .as_deref(),
+            Some("Kustomization:flux-system:my-kustomization")
+        );
+
+        app.handle_key(make_key(KeyCode::Esc));
+        assert_eq!(app.view_state.current_view, View::ResourceList);
+    }
+
+    #[test]
+    fn test_shift_h_opens_release_view_for_helmreleases_only() {
+        use crate::tui::app::state::ReleaseTab;
+        let mut app = create_test_app(false);
+        add_resource(&mut app);
+        app.view_state.current_view = View::ResourceList;
+
+        app.handle_key(make_key(KeyCode::Char('H')));
+        assert_eq!(app.view_state.current_view, View::ResourceList);
+        assert!(app.async_state.release.pending().is_none());
+        assert!(app.ui_state.status_message.as_ref().unwrap().1);
+
+        let mut release = app.state.all()[0].clone();
+        release.resource_type = "HelmRelease".to_string();
+        release.name = "podinfo".to_string();
+        app.state.clear();
+        app.state.upsert(
+            resource_key(&release.namespace, &release.name, &release.resource_type),
+            release,
+        );
+        app.handle_key(make_key(KeyCode::Char('H')));
+        assert_eq!(app.view_state.current_view, View::ResourceRelease);
+        assert_eq!(
+            app.async_state
+                .release
+                .pending()
+                .map(ResourceKey::to_key_string)
+                .as_deref(),
+            Some("HelmRelease:flux-system:podinfo")
+        );
+
+        app.handle_key(make_key(KeyCode::BackTab));
+        assert_eq!(app.view_state.release.tab, ReleaseTab::Diff);
+        app.handle_key(make_key(KeyCode::Tab));
+        assert_eq!(app.view_state.release.tab, ReleaseTab::Values);
+
+        app.handle_key(make_key(KeyCode::Esc));
+        assert_eq!(app.view_state.current_view, View::ResourceList);
+    }
+
+    #[test]
+    fn test_marked_rows_route_operations_to_bulk_confirmation() {
+        let mut app = create_test_app(false);
+        add_resource(&mut app);
+        app.view_state.current_view = View::ResourceList;
+
+        app.handle_key(make_key(KeyCode::Char(' ')));
+        assert_eq!(app.marked_resources().len(), 1);
+
+        app.handle_key(make_key(KeyCode::Char('s')));
+        assert!(app.async_state.confirmation_pending.is_none());
+        assert!(app.async_state.bulk_confirmation.is_some());
+        app.handle_key(make_key(KeyCode::Char('n')));
+        assert!(app.async_state.bulk_confirmation.is_none());
+
+        // Esc drops the marks before it would prompt to quit
+        app.handle_key(make_key(KeyCode::Esc));
+        assert!(app.marked_resources().is_empty());
+        assert!(!app.ui_state.show_quit_confirm);
+    }
+
+    #[test]
+    fn test_h_opens_observed_history_for_kinds_without_status_history() {
+        let mut app = create_test_app(false);
+        let resource = ResourceInfo {
+            name: "podinfo".to_string(),
+            namespace: "flux-system".to_string(),
+            resource_type: "GitRepository".to_string(),
+            age: None,
+            suspended: Some(false),
+            ready: Some(true),
+            message: None,
+            revision: None,
+            labels: HashMap::new(),
+            annotations: HashMap::new(),
+            last_reconciled: None,
+            reconciliation_history: vec![],
+        };
+        let key = resource_key(&resource.namespace, &resource.name, &resource.resource_type);
+        app.state.upsert(key.clone(), resource);
+        app.view_state.current_view = View::ResourceList;
+
+        app.handle_key(make_key(KeyCode::Char('h')));
+        assert_eq!(app.view_state.current_view, View::ResourceList);
+
+        app.history.observe(
+            &key,
+            "GitRepository",
+            &serde_json::json!({"status": {"artifact": {"revision": "main@sha1:abc"}}}),
+            chrono::Utc::now(),
+        );
+        app.handle_key(make_key(KeyCode::Char('h')));
+        assert_eq!(app.view_state.current_view, View::ResourceHistory);
+    }
+
     #[test]
     fn test_ctrl_d_still_requires_delete_confirmation() {
         let mut app = create_test_app(false);
@@ -2185,6 +2588,94 @@ mod tests {
         assert!(!app.config.read_only);
     }
 
+    #[test]
+    fn keymap_remaps_keys_in_list_view() {
+        use crate::tui::app::state::SortField;
+        let mut app = create_test_app(false);
+        app.keymap = crate::tui::keybindings::Keymap::from_config(&HashMap::from([
+            ("sortAge".to_string(), "o".to_string()),
+            ("command".to_string(), ";".to_string()),
+        ]))
+        .unwrap();
+
+        app.handle_key(make_key(KeyCode::Char('o')));
+        assert_eq!(app.view_state.sort_field, SortField::Age);
+
+        // The old key no longer acts; the new one opens command mode, where
+        // keys are taken literally
+        app.handle_key(make_key(KeyCode::Char(':')));
+        assert!(!app.ui_state.command_mode);
+        app.handle_key(make_key(KeyCode::Char(';')));
+        assert!(app.ui_state.command_mode);
+        app.handle_key(make_key(KeyCode::Char('o')));
+        assert_eq!(app.ui_state.command_buffer, "o");
+    }
+
+    #[test]
+    fn plugin_shortcut_queues_command_for_selected_resource() {
+        let mut app = create_test_app(false);
+        add_resource(&mut app);
+        let plugin = |scopes: &[&str]| crate::plugins::PluginConfig {
+            shortcut: "ctrl+k".to_string(),
+            scopes: scopes.iter().map(|s| s.to_string()).collect(),
+            command: "flux".to_string(),
+            args: vec!["diff".to_string(), "ks".to_string(), "$NAME".to_string()],
+            ..Default::default()
+        };
+        app.config.plugins = HashMap::from([("diff".to_string(), plugin(&["hr"]))]);
+        app.keymap = app
+            .keymap
+            .clone()
+            .with_plugins(&app.config.plugins)
+            .unwrap();
+
+        // Out of scope: reported, nothing queued
+        app.handle_key(make_ctrl_key(KeyCode::Char('k')));
+        assert!(app.take_plugin_run().is_none());
+        assert!(app.ui_state.status_message.is_some());
+
+        app.config.plugins = HashMap::from([("diff".to_string(), plugin(&["ks", "list"]))]);
+        app.handle_key(make_ctrl_key(KeyCode::Char('k')));
+        let run = app.take_plugin_run().unwrap();
+        assert_eq!(run.program, "flux");
+        assert_eq!(run.args, vec!["diff", "ks", "my-kustomization"]);
+    }
+
+    #[test]
+    fn command_aliases_expand_before_parsing() {
+        let mut app = create_test_app(false);
+        app.aliases = HashMap::from([
+            ("broken".to_string(), "hr status:failed".to_string()),
+            ("ok".to_string(), "healthy".to_string()),
+        ]);
+
+        app.ui_state.command_buffer = "broken".to_string();
+        app.execute_command();
+        assert_eq!(
+            app.view_state.selected_resource_type.as_deref(),
+            Some("HelmRelease")
+        );
+        assert_eq!(app.view_state.filter, "status:failed");
+
+        app.ui_state.command_buffer = "ok".to_string();
+        app.execute_command();
+        assert_eq!(
+            app.view_state.health_filter,
+            crate::tui::app::state::HealthFilter::Healthy
+        );
+        assert!(
+            app.ui_state
+                .status_message
+                .as_ref()
+                .is_some_and(|(_, is_error)| !is_error)
+        );
+
+        // Tab completes aliases like built-in commands
+        app.ui_state.command_buffer = "bro".to_string();
+        app.autocomplete_command();
+        assert_eq!(app.ui_state.command_buffer, "broken");
+    }
+
     #[test]
     fn test_sort_keys_in_list_view() {
         use crate::tui::app::state::SortField;
@@ -2331,6 +2822,16 @@ mod tests {
         assert_eq!(app.view_state.graph_focus_index, Some(0));
     }
 
+    #[test]
+    fn graph_x_opens_export_menu() {
+        let mut app = app_on_graph();
+        app.handle_key(make_key(KeyCode::Char('x')));
+        let submenu = app.view_state.submenu_state.as_ref().unwrap();
+        assert_eq!(submenu.command, "graph-export");
+        let formats: Vec<_> = submenu.items.iter().map(|i| i.value.as_str()).collect();
+        assert_eq!(formats, vec!["dot", "mermaid", "json"]);
+    }
+
     #[test]
     fn graph_j_k_move_focus_in_visual_order() {
         let mut app = app_on_graph();
@@ -2439,6 +2940,101 @@ mod tests {
         assert_eq!(app.view_state.current_view, View::EventList);
     }
 
+    #[test]
+    fn timeline_is_rebuilt_only_when_its_inputs_change() {
+        let mut app = create_test_app(false);
+        let observe = |app: &mut App, name: &str, revision: &str| {
+            app.history.observe(
+                &format!("GitRepository:flux-system:{}", name),
+                "GitRepository",
+                &serde_json::json!({"status": {"artifact": {"revision": revision}}}),
+                chrono::Utc::now(),
+            );
+        };
+        observe(&mut app, "repo", "main@sha1:abc123");
+        app.refresh_timeline();
+        assert_eq!(app.scoped_timeline().len(), 1);
+
+        // Reads between refreshes see the cached entries
+        observe(&mut app, "other", "main@sha1:def456");
+        assert_eq!(app.scoped_timeline().len(), 1);
+        app.refresh_timeline();
+        assert_eq!(app.scoped_timeline().len(), 2);
+
+        // A namespace switch rescopes it
+        app.namespace = Some("apps".to_string());
+        app.refresh_timeline();
+        assert!(app.scoped_timeline().is_empty());
+    }
+
+    #[test]
+    fn timeline_is_built_only_while_its_view_is_open() {
+        let mut app = create_test_app(false);
+        app.history.observe(
+            "GitRepository:flux-system:repo",
+            "GitRepository",
+            &serde_json::json!({"status": {"artifact": {"revision": "main@sha1:abc123"}}}),
+            chrono::Utc::now(),
+        );
+        app.handle_key(make_key(KeyCode::Down));
+        assert!(app.scoped_timeline().is_empty());
+
+        app.ui_state.command_buffer = "timeline".to_string();
+        app.execute_command();
+        assert_eq!(app.scoped_timeline().len(), 1);
+    }
+
+    #[test]
+    fn timeline_command_filters_kinds_and_opens_resources() {
+        let mut app = create_test_app(false);
+        add_resource(&mut app);
+        let kustomization = serde_json::json!({"status": {
+            "lastAppliedRevision": "main@sha1:abc123",
+            "conditions": [{"type": "Ready", "status": "True"}]
+        }});
+        app.history.observe(
+            "Kustomization:flux-system:my-kustomization",
+            "Kustomization",
+            &kustomization,
+            chrono::Utc::now(),
+        );
+        app.history.observe(
+            "GitRepository:flux-system:repo",
+            "GitRepository",
+            &serde_json::json!({"status": {"artifact": {"revision": "main@sha1:abc123"}}}),
+            chrono::Utc::now(),
+        );
+        app.refresh_timeline();
+
+        app.ui_state.command_buffer = "timeline ks".to_string();
+        app.execute_command();
+        assert_eq!(app.view_state.current_view, View::Timeline);
+        assert_eq!(app.view_state.timeline_kinds, vec!["Kustomization"]);
+        let entries = app.filtered_timeline();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "my-kustomization");
+
+        app.handle_key(make_key(KeyCode::Enter));
+        assert_eq!(app.view_state.current_view, View::ResourceDetail);
+        app.handle_key(make_key(KeyCode::Esc));
+        assert_eq!(app.view_state.current_view, View::Timeline);
+        app.handle_key(make_key(KeyCode::Esc));
+        assert_eq!(app.view_state.current_view, View::ResourceList);
+
+        app.ui_state.command_buffer = "timeline".to_string();
+        app.execute_command();
+        assert_eq!(app.filtered_timeline().len(), 2);
+
+        app.ui_state.command_buffer = "timeline nope".to_string();
+        app.execute_command();
+        assert!(
+            app.ui_state
+                .status_message
+                .as_ref()
+                .is_some_and(|(msg, err)| *err && msg.contains("'nope'"))
+        );
+    }
+
     #[test]
     fn esc_from_event_list_returns_to_resource_list_and_clears_feed() {
         let mut app = create_test_app(false);
@@ -3166,6 +3762,43 @@ mod tests {
         assert!(app.forbidden_watchers.is_empty());
     }
 
+    #[test]
+    fn namespace_sets_and_selectors_scope_the_list() {
+        let mut app = create_test_app(false);
+        app.ui_state.command_buffer = "ns apps,payments".to_string();
+        app.execute_command();
+        assert_eq!(app.namespace, None);
+        assert_eq!(app.namespaces, vec!["apps", "payments"]);
+        assert!(app.in_namespace_scope("payments"));
+        assert!(!app.in_namespace_scope("flux-system"));
+        assert_eq!(app.namespace_display().as_deref(), Some("apps,payments"));
+        assert_eq!(app.namespace_arg(), "apps,payments");
+
+        // A selector is resolved by the main loop, then shown with its count
+        app.ui_state.command_buffer = "ns team=payments".to_string();
+        app.execute_command();
+        let (selector, _tx) = app.async_state.namespace_selector.dispatch().unwrap();
+        assert_eq!(selector, "team=payments");
+        app.on_namespace_selector_resolved(Ok((selector.clone(), vec![])));
+        assert_eq!(
+            app.namespaces,
+            vec!["apps", "payments"],
+            "no match keeps the scope"
+        );
+        app.on_namespace_selector_resolved(Ok((selector, vec!["payments".to_string()])));
+        assert_eq!(app.namespace.as_deref(), Some("payments"));
+        assert_eq!(
+            app.namespace_display().as_deref(),
+            Some("team=payments (1)")
+        );
+        assert_eq!(app.namespace_arg(), "team=payments");
+
+        app.ui_state.command_buffer = "ns all".to_string();
+        app.execute_command();
+        assert_eq!(app.namespace_display(), None);
+        assert!(app.namespaces.is_empty() && app.in_namespace_scope("anything"));
+    }
+
     #[test]
     fn submenu_filter_follows_resource_filter_convention() {
         let mut app = create_test_app(false);
@@ -3368,7 +4001,7 @@ mod tests {
         }
 
         // Autocomplete offers the discovered aliases
-        let matches = crate::tui::commands::find_matching_commands("zephyr");
+        let matches = crate::tui::commands::find_matching_commands("zephyr", &app.aliases);
         assert!(matches.contains(&"zephyrtest".to_string()));
         assert!(matches.contains(&"zephyrtests".to_string()));
 
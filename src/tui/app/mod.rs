@@ -9,9 +9,15 @@ pub mod async_task;
 pub mod logs;
 
 mod async_ops;
+mod bulk;
+mod clusters;
 mod core;
 mod events;
+mod plugins;
+mod release;
 mod rendering;
+mod saved_views;
 
+pub use bulk::run_bulk_operation;
 pub use core::*;
 pub use state::PendingOperation;
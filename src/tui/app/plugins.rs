@@ -0,0 +1,96 @@
+//! Plugin shortcuts
+//!
+//! A plugin shortcut (see [`crate::plugins`]) runs its command on the
+//! resource the current view targets. Background plugins are spawned right
+//! away; foreground plugins are queued in
+//! [`AsyncOperationState::plugin_run`](super::state::AsyncOperationState::plugin_run)
+//! for the main loop, which suspends the TUI around them like it does for
+//! the editor.
+
+use super::core::App;
+use super::state::{PluginRun, View};
+
+impl App {
+    /// Scope name of the current view, `None` where plugins do not apply.
+    pub(crate) fn plugin_view_scope(&self) -> Option<&'static str> {
+        Some(match self.view_state.current_view {
+            View::ResourceList => "list",
+            View::ResourceFavorites => "favorites",
+            View::EventList => "events",
+            View::Timeline => "timeline",
+            View::ResourceGraph => "graph",
+            View::ResourceDetail => "detail",
+            View::ResourceYAML => "yaml",
+            View::ResourceDescribe => "describe",
+            View::ResourceTrace => "trace",
+            View::ResourceHistory => "history",
+            View::ResourceDiff => "diff",
+            View::ResourceRelease => "release",
+            _ => return None,
+        })
+    }
+
+    /// Run the plugin `name` on the current resource, if it applies here.
+    pub(crate) fn run_plugin(&mut self, name: &str) {
+        let Some(plugin) = self.config.plugins.get(name).cloned() else {
+            return;
+        };
+        let Some(view) = self.plugin_view_scope() else {
+            return;
+        };
+        let Some(resource) = self.get_current_resource() else {
+            return;
+        };
+        if !plugin.applies_to(&resource.resource_type, view) {
+            self.set_status_message((
+                format!(
+                    "Plugin '{}' does not apply to {} in this view",
+                    name, resource.resource_type
+                ),
+                true,
+            ));
+            return;
+        }
+
+        let (program, args) = plugin.command_line(&resource, &self.context);
+        if plugin.background {
+            match crate::plugins::spawn_background(name, &program, &args) {
+                Ok(()) => self.set_status_message((
+                    format!("Plugin '{}' started for {}", name, resource.name),
+                    false,
+                )),
+                Err(e) => {
+                    self.set_status_message((format!("Plugin '{}' failed: {}", name, e), true))
+                }
+            }
+        } else {
+            self.async_state.plugin_run = Some(PluginRun {
+                name: name.to_string(),
+                program,
+                args,
+            });
+        }
+    }
+
+    /// Foreground plugin for the main loop to run, if one is queued.
+    pub fn take_plugin_run(&mut self) -> Option<PluginRun> {
+        self.async_state.plugin_run.take()
+    }
+
+    /// Report how a foreground plugin ended.
+    pub fn on_plugin_finished(
+        &mut self,
+        name: &str,
+        result: std::io::Result<std::process::ExitStatus>,
+    ) {
+        match result {
+            Ok(status) if status.success() => {
+                self.set_status_message((format!("Plugin '{}' finished", name), false))
+            }
+            Ok(status) => {
+                self.set_status_message((format!("Plugin '{}' exited with {}", name, status), true))
+            }
+            Err(e) => self.set_status_message((format!("Plugin '{}' failed: {}", name, e), true)),
+        }
+    }
+}
@@ -0,0 +1,416 @@
+//! Helm release inspector
+//!
+//! `H` on a HelmRelease opens [`View::ResourceRelease`], fed by the
+//! [`AsyncOperationState::release`](super::state::AsyncOperationState::release)
+//! fetch of the release's storage Secrets. In the Revisions tab the cursor
+//! keys select a revision and Space picks it; `D` diffs the two picked
+//! revisions, or the selected one against its predecessor, into the Diff tab.
+//! Values from Secrets and the data of Secret manifests are redacted in the
+//! Values, Effective and Diff tabs until `x` reveals them.
+
+use super::core::App;
+use super::state::{ReleaseTab, ReleaseViewState, View};
+use crate::kube::helm::RevisionDiff;
+use crate::tui::keybindings::Action;
+
+impl App {
+    /// Open the inspector for the targeted HelmRelease, queueing the fetch.
+    pub(super) fn open_release_view(&mut self) {
+        let Some(target) = self.view_target() else {
+            return;
+        };
+        if target.resource_type != "HelmRelease" {
+            self.set_status_message((
+                format!(
+                    "Release view is only available for HelmReleases, not {}",
+                    target.resource_type
+                ),
+                true,
+            ));
+            return;
+        }
+        if let Some(key) = self.prepare_selected_resource_key_for_nested_view() {
+            self.async_state.release.request(key);
+            self.view_state.release = ReleaseViewState::default();
+            self.view_state.text_search.clear();
+            self.view_state.current_view = View::ResourceRelease;
+        }
+    }
+
+    /// Switch to the tab `step` places away (Tab / Shift+Tab).
+    pub(super) fn cycle_release_tab(&mut self, step: isize) {
+        let release = &mut self.view_state.release;
+        release.tab = release.tab.cycle(step);
+        release.scroll_offset = 0;
+        self.view_state.text_search.clear();
+    }
+
+    /// Show or redact Secret data in the tabs that show values or
+    /// manifests. Revealing is refused in readonly mode.
+    pub(super) fn toggle_release_secrets(&mut self) {
+        if !self.view_state.release.tab.shows_secrets() {
+            return;
+        }
+        if self.view_state.release.reveal_secrets {
+            self.redact_release_secrets();
+            self.set_status_message(("Secret values redacted".to_string(), false));
+            return;
+        }
+        if self.config.read_only {
+            self.set_status_message((
+                "Secret values stay redacted in readonly mode".to_string(),
+                true,
+            ));
+            return;
+        }
+        self.view_state.release.reveal_secrets = true;
+        self.refresh_release_diff();
+        self.set_status_message(("Secret values revealed".to_string(), false));
+    }
+
+    /// Hide revealed Secret data again; also run when readonly mode is
+    /// switched on.
+    pub(super) fn redact_release_secrets(&mut self) {
+        if self.view_state.release.reveal_secrets {
+            self.view_state.release.reveal_secrets = false;
+            self.refresh_release_diff();
+        }
+    }
+
+    /// Diff two revisions with the current redaction.
+    fn release_diff(&self, a: i64, b: i64) -> Option<RevisionDiff> {
+        let inspection = self.async_state.release.result()?;
+        let find = |v: i64| {
+            inspection
+                .revisions
+                .iter()
+                .find(|release| release.version == v)
+        };
+        let (from, to) = (find(a.min(b))?, find(a.max(b))?);
+        let redaction = self
+            .view_state
+            .release
+            .secret_redaction(&inspection.effective_values);
+        Some(RevisionDiff::between(from, to, redaction))
+    }
+
+    /// Recompute the shown diff after the redaction changed.
+    fn refresh_release_diff(&mut self) {
+        let Some((from, to)) = self
+            .view_state
+            .release
+            .diff
+            .as_ref()
+            .map(|d| (d.from, d.to))
+        else {
+            return;
+        };
+        self.view_state.release.diff = self.release_diff(from, to);
+    }
+
+    /// Whether the cursor keys move the revision selection rather than
+    /// scrolling.
+    pub(super) fn release_cursor_active(&self) -> bool {
+        self.view_state.current_view == View::ResourceRelease
+            && self.view_state.release.tab == ReleaseTab::Revisions
+    }
+
+    fn release_revision_count(&self) -> usize {
+        self.async_state
+            .release
+            .result()
+            .map_or(0, |inspection| inspection.revisions.len())
+    }
+
+    pub(super) fn move_release_cursor(&mut self, step: isize) {
+        let max = self.release_revision_count().saturating_sub(1);
+        let release = &mut self.view_state.release;
+        release.cursor = release.cursor.saturating_add_signed(step).min(max);
+    }
+
+    /// Pick or unpick the selected revision for the diff. Picking a third
+    /// revision drops the earliest pick.
+    pub(super) fn toggle_release_mark(&mut self) {
+        if self.view_state.release.tab != ReleaseTab::Revisions {
+            return;
+        }
+        let Some(version) = self.async_state.release.result().and_then(|inspection| {
+            inspection
+                .revisions
+                .get(self.view_state.release.cursor)
+                .map(|release| release.version)
+        }) else {
+            return;
+        };
+        let marks = &mut self.view_state.release.marks;
+        if let Some(pos) = marks.iter().position(|v| *v == version) {
+            marks.remove(pos);
+        } else {
+            if marks.len() == 2 {
+                marks.remove(0);
+            }
+            marks.push(version);
+        }
+        self.move_release_cursor(1);
+    }
+
+    /// Diff the picked revisions (or the picked one against the selected
+    /// one, or the selected one against its predecessor) and show the Diff
+    /// tab.
+    pub(super) fn diff_release_revisions(&mut self) {
+        let Some(inspection) = self.async_state.release.result() else {
+            self.set_status_message(("The release is still loading".to_string(), true));
+            return;
+        };
+        let revisions = &inspection.revisions;
+        let state = &self.view_state.release;
+        let selected = revisions.get(state.cursor).map(|release| release.version);
+        let pair = match (state.marks.as_slice(), selected) {
+            ([a, b], _) => Some((*a, *b)),
+            ([a], Some(selected)) if *a != selected => Some((*a, selected)),
+            // The selected revision against the one before it
+            (_, Some(selected)) => revisions
+                .get(state.cursor + 1)
+                .map(|previous| (previous.version, selected)),
+            _ => None,
+        };
+        let found = pair.and_then(|(a, b)| self.release_diff(a, b));
+        let Some(diff) = found else {
+            self.set_status_message((
+                format!(
+                    "Pick two revisions with {} in the Revisions tab, then press {}",
+                    self.keymap.label(Action::Mark),
+                    self.keymap.label(Action::Diff)
+                ),
+                true,
+            ));
+            return;
+        };
+
+        self.set_status_message((format!("Diff of v{} → v{}", diff.from, diff.to), false));
+        let release = &mut self.view_state.release;
+        release.diff = Some(diff);
+        release.tab = ReleaseTab::Diff;
+        release.scroll_offset = 0;
+        self.view_state.text_search.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{Config, UiConfig};
+    use crate::kube::helm::{ReleaseInspection, StoredRelease};
+    use crate::tui::Theme;
+    use crate::tui::app::App;
+    use crate::tui::app::state::{ReleaseTab, View};
+    use crate::watcher::{ResourceKey, ResourceState};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn app_with_revisions(versions: &[i64]) -> App {
+        let config = Config {
+            ui: UiConfig {
+                splashless: true,
+                ..UiConfig::default()
+            },
+            ..Config::default()
+        };
+        let mut app = App::new(
+            ResourceState::new(),
+            "prod".to_string(),
+            None,
+            config,
+            Theme::default(),
+        );
+        app.view_state.current_view = View::ResourceRelease;
+        app.async_state.release.request(ResourceKey::new(
+            "HelmRelease".to_string(),
+            "apps".to_string(),
+            "podinfo".to_string(),
+        ));
+        app.async_state.release.set_result(ReleaseInspection {
+            revisions: versions
+                .iter()
+                .map(|version| StoredRelease {
+                    version: *version,
+                    manifest: format!("replicas: {}\n", version),
+                    ..StoredRelease::default()
+                })
+                .collect(),
+            ..ReleaseInspection::default()
+        });
+        app.view_state.release.tab = ReleaseTab::Revisions;
+        app
+    }
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.handle_key(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn d_diffs_the_selected_revision_against_its_predecessor() {
+        let mut app = app_with_revisions(&[13, 12, 11]);
+        press(&mut app, KeyCode::Char('D'));
+        assert_eq!(app.current_view(), View::ResourceRelease);
+        assert_eq!(app.view_state.release.tab, ReleaseTab::Diff);
+        let diff = app.view_state.release.diff.as_ref().unwrap();
+        assert_eq!((diff.from, diff.to), (12, 13));
+        assert!(diff.lines.iter().any(|line| line.text == "+replicas: 13"));
+
+        // The oldest revision has nothing to compare with
+        app.view_state.release = Default::default();
+        app.view_state.release.tab = ReleaseTab::Revisions;
+        app.view_state.release.cursor = 2;
+        press(&mut app, KeyCode::Char('D'));
+        assert!(app.view_state.release.diff.is_none());
+        assert!(app.ui_state.status_message.as_ref().unwrap().1);
+    }
+
+    #[test]
+    fn space_picks_two_revisions_to_diff() {
+        let mut app = app_with_revisions(&[13, 12, 11]);
+        press(&mut app, KeyCode::Char(' ')); // 13, cursor moves to 12
+        press(&mut app, KeyCode::Down); // 11
+        press(&mut app, KeyCode::Char(' '));
+        assert_eq!(app.view_state.release.marks, vec![13, 11]);
+        press(&mut app, KeyCode::Up);
+        press(&mut app, KeyCode::Up);
+        press(&mut app, KeyCode::Char(' ')); // unpick 13
+        assert_eq!(app.view_state.release.marks, vec![11]);
+        press(&mut app, KeyCode::Char(' ')); // pick 12
+        press(&mut app, KeyCode::Char('D'));
+        let diff = app.view_state.release.diff.as_ref().unwrap();
+        assert_eq!((diff.from, diff.to), (11, 12));
+    }
+
+    #[test]
+    fn x_reveals_secret_values_only_outside_readonly_mode() {
+        let mut app = app_with_revisions(&[1]);
+        press(&mut app, KeyCode::Char('x')); // The Revisions tab shows no values
+        assert!(!app.view_state.release.reveal_secrets);
+
+        app.view_state.release.tab = ReleaseTab::Effective;
+        app.config.read_only = true;
+        press(&mut app, KeyCode::Char('x'));
+        assert!(!app.view_state.release.reveal_secrets);
+        assert!(app.ui_state.status_message.as_ref().unwrap().1);
+
+        app.config.read_only = false;
+        press(&mut app, KeyCode::Char('x'));
+        assert!(app.view_state.release.reveal_secrets);
+        press(&mut app, KeyCode::Char('x'));
+        assert!(!app.view_state.release.reveal_secrets);
+    }
+
+    #[test]
+    fn values_and_diff_tabs_stay_redacted_in_readonly_mode() {
+        use crate::kube::helm_values::{EffectiveValues, ValuesReference};
+        use crate::tui::views::release_tab_lines;
+
+        let mut app = app_with_revisions(&[2, 1]);
+        let inspection = app.async_state.release.result().unwrap().clone();
+        let revisions = inspection
+            .revisions
+            .into_iter()
+            .map(|release| StoredRelease {
+                info: crate::kube::helm::ReleaseInfo {
+                    status: "deployed".to_string(),
+                    ..Default::default()
+                },
+                config: serde_json::json!({"password": format!("hunter{}", release.version)}),
+                manifest: format!(
+                    "kind: Secret\nstringData:\n  password: hunter{}\n",
+                    release.version
+                ),
+                ..release
+            })
+            .collect();
+        let secret = ValuesReference {
+            kind: "Secret".to_string(),
+            name: "creds".to_string(),
+            values_key: "values.yaml".to_string(),
+            ..ValuesReference::default()
+        };
+        let data = std::collections::BTreeMap::from([(
+            "values.yaml".to_string(),
+            "password: hunter2\n".to_string(),
+        )]);
+        app.async_state.release.set_result(ReleaseInspection {
+            revisions,
+            effective_values: EffectiveValues::resolve(vec![(secret, Ok(Some(data)))], None),
+            ..ReleaseInspection::default()
+        });
+        let shown = |app: &App| -> String {
+            let inspection = app.async_state.release.result().unwrap();
+            release_tab_lines(
+                inspection,
+                &app.view_state.release,
+                &app.keymap,
+                &Theme::default(),
+            )
+            .into_iter()
+            .map(|(text, _)| text)
+            .collect::<Vec<_>>()
+            .join("\n")
+        };
+
+        app.config.read_only = true;
+        press(&mut app, KeyCode::Char('D'));
+        assert_eq!(app.view_state.release.tab, ReleaseTab::Diff);
+        press(&mut app, KeyCode::Char('x'));
+        let diff = shown(&app);
+        assert!(!diff.contains("hunter"), "{}", diff);
+        // Only the Secret data changed between the revisions; the diff still
+        // shows which keys did
+        assert!(!diff.contains("unchanged"), "{}", diff);
+        assert!(diff.contains("-password: <redacted>"));
+        assert!(diff.contains("+password: <redacted, changed>"));
+        assert!(diff.contains("-  password: <redacted>"));
+        assert!(diff.contains("+  password: <redacted, changed>"));
+
+        app.view_state.release.tab = ReleaseTab::Values;
+        press(&mut app, KeyCode::Char('x'));
+        assert_eq!(shown(&app), "password: <redacted>");
+
+        // Revealed outside readonly mode, on every tab, until readonly returns
+        app.config.read_only = false;
+        press(&mut app, KeyCode::Char('x'));
+        assert_eq!(shown(&app), "password: hunter2");
+        app.view_state.release.tab = ReleaseTab::Diff;
+        assert!(shown(&app).contains("+  password: hunter2"));
+        press(&mut app, KeyCode::Char(':'));
+        for c in "readonly".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Enter);
+        assert!(app.config.read_only);
+        assert!(!shown(&app).contains("hunter"));
+    }
+
+    #[test]
+    fn revision_hints_name_the_remapped_keys() {
+        use crate::tui::keybindings::Keymap;
+        use crate::tui::views::release_tab_lines;
+
+        let mut app = app_with_revisions(&[1]);
+        app.keymap = Keymap::from_config(&std::collections::HashMap::from([
+            ("mark".to_string(), "v".to_string()),
+            ("diff".to_string(), "J".to_string()),
+        ]))
+        .unwrap();
+        press(&mut app, KeyCode::Char('J'));
+        assert_eq!(
+            app.ui_state.status_message.as_ref().unwrap().0,
+            "Pick two revisions with v in the Revisions tab, then press J"
+        );
+
+        app.view_state.release.tab = ReleaseTab::Diff;
+        let lines = release_tab_lines(
+            app.async_state.release.result().unwrap(),
+            &app.view_state.release,
+            &app.keymap,
+            &Theme::default(),
+        );
+        assert!(lines[0].0.starts_with("Pick two revisions with v"));
+        assert!(lines[0].0.contains("J alone diffs"));
+    }
+}
@@ -129,11 +129,22 @@ impl App {
             let content_lines = base_content_lines + filter_line + resource_type_lines;
             // Minimum height for ASCII art + borders
             use crate::tui::constants::MIN_HEADER_HEIGHT;
-            self.ui_state.cached_header_height = (content_lines + 2).max(MIN_HEADER_HEIGHT);
+            self.ui_state.cached_header_height = if self.view_state.current_view == View::Clusters {
+                // The clusters header has no ASCII art; one line per wrap of
+                // the per-cluster health
+                clusters_header_lines(
+                    &self.cluster_health(),
+                    filter_line > 0,
+                    terminal_width,
+                    self.config.ui.no_icons,
+                ) + 2
+            } else {
+                (content_lines + 2).max(MIN_HEADER_HEIGHT)
+            };
 
             // Calculate footer height using centralized function
             self.ui_state.cached_footer_height =
-                calculate_footer_height(terminal_width, self.has_connection_error());
+                calculate_footer_height(terminal_width, self.has_connection_error(), &self.keymap);
         }
 
         let header_height = self.ui_state.cached_header_height;
@@ -176,7 +187,9 @@ impl App {
 
         let resources = self.get_filtered_resources();
         // Only render header if not in headless mode
-        if !self.config.ui.headless {
+        if !self.config.ui.headless && self.view_state.current_view == View::Clusters {
+            self.render_clusters_header(f, chunks[0]);
+        } else if !self.config.ui.headless {
             let health_percentage = self.calculate_health_percentage();
             let health_filter_status = match self.view_state.health_filter {
                 HealthFilter::Healthy => Some("healthy"),
@@ -190,7 +203,7 @@ impl App {
                 &self.state,
                 &self.controller_pods,
                 &self.context,
-                &self.namespace,
+                &self.namespace_display(),
                 &self.view_state.filter,
                 &self.view_state.selected_resource_type,
                 resources.len(),
@@ -219,6 +232,41 @@ impl App {
             &self.state,
             &self.theme,
             self.has_connection_error(),
+            &self.keymap,
+            &self.aliases,
+        );
+    }
+
+    /// Header of the `:clusters` view. The filter line is shown under the
+    /// same conditions the layout reserves it for.
+    fn render_clusters_header(&self, f: &mut Frame, area: Rect) {
+        let scoped = self.scoped_cluster_resources();
+        let shown = self.filtered_cluster_resources().len();
+        let mut filter_parts = Vec::new();
+        if !self.view_state.filter.is_empty() || self.view_state.selected_resource_type.is_some() {
+            if !self.view_state.filter.is_empty() {
+                filter_parts.push(format!("query='{}'", self.view_state.filter));
+            }
+            if let Some(ref resource_type) = self.view_state.selected_resource_type {
+                filter_parts.push(format!("type={}", resource_type));
+            }
+            match self.view_state.health_filter {
+                HealthFilter::Healthy => filter_parts.push("health=healthy".to_string()),
+                HealthFilter::Unhealthy => filter_parts.push("health=unhealthy".to_string()),
+                HealthFilter::All => {}
+            }
+        }
+        render_clusters_header(
+            f,
+            area,
+            &self.cluster_health(),
+            &self.namespace_display(),
+            &filter_parts,
+            shown,
+            scoped.len(),
+            self.config.read_only,
+            &self.theme,
+            self.config.ui.no_icons,
         );
     }
 
@@ -234,46 +282,9 @@ impl App {
             };
 
         // Apply namespace filter if set
-        if let Some(namespace) = self.namespace() {
-            filtered_resources.retain(|r| r.namespace == *namespace);
-        }
+        filtered_resources.retain(|r| self.in_namespace_scope(&r.namespace));
 
-        if !self.view_state.filter.is_empty() {
-            if let Some(label_filter) = self.view_state.filter.strip_prefix("label:") {
-                if label_filter.is_empty() {
-                    filtered_resources.retain(|r| !r.labels.is_empty());
-                } else if let Some((key, value)) = label_filter.split_once('=') {
-                    filtered_resources.retain(|r| {
-                        r.labels
-                            .iter()
-                            .any(|(k, v)| k.starts_with(key) && v.starts_with(value))
-                    });
-                } else {
-                    filtered_resources
-                        .retain(|r| r.labels.keys().any(|k| k.starts_with(label_filter)));
-                }
-            } else if let Some(ann_filter) = self
-                .view_state
-                .filter
-                .strip_prefix("ann:")
-                .or_else(|| self.view_state.filter.strip_prefix("annotations:"))
-            {
-                if ann_filter.is_empty() {
-                    filtered_resources.retain(|r| !r.annotations.is_empty());
-                } else if let Some((key, value)) = ann_filter.split_once('=') {
-                    filtered_resources.retain(|r| {
-                        r.annotations
-                            .iter()
-                            .any(|(k, v)| k.starts_with(key) && v.starts_with(value))
-                    });
-                } else {
-                    filtered_resources
-                        .retain(|r| r.annotations.keys().any(|k| k.starts_with(ann_filter)));
-                }
-            } else {
-                filtered_resources.retain(|r| r.name.contains(&self.view_state.filter));
-            }
-        }
+        self.apply_filter_query(&mut filtered_resources);
 
         if filtered_resources.is_empty() {
             return 100.0; // No resources = 100% healthy (nothing to be unhealthy)
@@ -299,7 +310,7 @@ impl App {
             self.render_connection_error_screen(f, area);
             // If help, submenu, or quit confirm is active, render it on top!
             if self.ui_state.show_help {
-                render_help(f, area, &self.theme, self.namespace_hotkeys());
+                render_help(f, area, &self.theme, self.namespace_hotkeys(), &self.keymap);
             } else if let Some(ref mut submenu) = self.view_state.submenu_state {
                 render_submenu(f, area, submenu, &self.theme);
             }
@@ -311,20 +322,54 @@ impl App {
 
         if self.async_state.confirmation_pending.is_some() {
             if let Some(ref confirmation) = self.async_state.confirmation_pending {
+                // Rows of the `:clusters` view live in their context's session
+                let resource = match confirmation.context.as_deref() {
+                    Some(context) => self
+                        .clusters
+                        .as_ref()
+                        .and_then(|clusters| clusters.session(context))
+                        .and_then(|session| {
+                            session.state().get(&crate::watcher::resource_key(
+                                &confirmation.namespace,
+                                &confirmation.name,
+                                &confirmation.resource_type,
+                            ))
+                        }),
+                    None => self.state.get(&crate::watcher::resource_key(
+                        &confirmation.namespace,
+                        &confirmation.name,
+                        &confirmation.resource_type,
+                    )),
+                };
                 render_confirmation(
                     f,
                     area,
                     confirmation,
+                    resource.as_ref(),
                     &self.operation_registry,
-                    &self.state,
                     &self.theme,
                 );
             }
             return;
         }
 
+        if let Some(ref pending) = self.async_state.bulk_confirmation {
+            views::render_bulk_confirmation(
+                f,
+                area,
+                pending,
+                &self.operation_registry,
+                &self.theme,
+            );
+            return;
+        }
+        if let Some(ref result) = self.async_state.bulk_summary {
+            views::render_bulk_summary(f, area, result, &self.operation_registry, &self.theme);
+            return;
+        }
+
         if self.ui_state.show_help {
-            render_help(f, area, &self.theme, self.namespace_hotkeys());
+            render_help(f, area, &self.theme, self.namespace_hotkeys(), &self.keymap);
         } else {
             match self.view_state.current_view {
                 View::ResourceList => {
@@ -338,10 +383,14 @@ impl App {
                         &mut self.view_state.scroll_offset,
                         &self.view_state.selected_resource_type,
                         &self.resource_objects,
+                        &self.config.columns,
                         &self.theme,
                         self.config.ui.no_icons,
-                        &self.selection_state.favorites,
-                        self.view_state.sort_field,
+                        RowMarks {
+                            favorites: &self.selection_state.favorites,
+                            marked: &self.selection_state.marked,
+                        },
+                        &self.view_state.sort_field,
                         self.view_state.sort_reverse,
                         access_notice.as_deref(),
                     );
@@ -384,6 +433,31 @@ impl App {
                         &self.theme,
                     );
                 }
+                View::ResourceDiff => {
+                    views::render_resource_diff(
+                        f,
+                        area,
+                        &self.selection_state.selected_resource_key,
+                        self.async_state.diff.result(),
+                        self.async_state.diff.is_loading(),
+                        &mut self.view_state.diff_scroll_offset,
+                        &mut self.view_state.text_search,
+                        &self.theme,
+                    );
+                }
+                View::ResourceRelease => {
+                    views::render_resource_release(
+                        f,
+                        area,
+                        &self.selection_state.selected_resource_key,
+                        self.async_state.release.result(),
+                        self.async_state.release.is_loading(),
+                        &mut self.view_state.release,
+                        &mut self.view_state.text_search,
+                        &self.keymap,
+                        &self.theme,
+                    );
+                }
                 View::ResourceTrace => {
                     views::trace::render_resource_trace(
                         f,
@@ -406,10 +480,14 @@ impl App {
                         &mut self.view_state.scroll_offset,
                         &self.view_state.selected_resource_type,
                         &self.resource_objects,
+                        &self.config.columns,
                         &self.theme,
                         self.config.ui.no_icons,
-                        &self.selection_state.favorites,
-                        self.view_state.sort_field,
+                        RowMarks {
+                            favorites: &self.selection_state.favorites,
+                            marked: &self.selection_state.marked,
+                        },
+                        &self.view_state.sort_field,
                         self.view_state.sort_reverse,
                         // Favorites is curated; an empty list there isn't an RBAC
                         // signal, so keep the neutral empty-state.
@@ -436,6 +514,7 @@ impl App {
                                 area,
                                 &resource,
                                 &self.resource_objects,
+                                self.history.events(key),
                                 &mut self.view_state.history_scroll_offset,
                                 &self.theme,
                             )
@@ -492,6 +571,37 @@ impl App {
                         &self.theme,
                     );
                 }
+                View::Timeline => {
+                    self.refresh_timeline();
+                    let total = self.scoped_timeline().len();
+                    let entries = self.filtered_timeline();
+                    views::render_timeline(
+                        f,
+                        area,
+                        &entries,
+                        total,
+                        self.view_state.selected_index,
+                        &mut self.view_state.scroll_offset,
+                        &self.view_state.filter,
+                        &self.view_state.timeline_kinds,
+                        self.namespace.is_none(),
+                        &self.theme,
+                    );
+                }
+                View::Clusters => {
+                    let rows = self.filtered_cluster_resources();
+                    let total = self.scoped_cluster_resources().len();
+                    views::render_cluster_list(
+                        f,
+                        area,
+                        &rows,
+                        total,
+                        self.view_state.selected_index,
+                        &mut self.view_state.scroll_offset,
+                        &self.theme,
+                        self.config.ui.no_icons,
+                    );
+                }
                 View::WorkloadList => {
                     views::render_workload_list(
                         f,
@@ -534,27 +644,41 @@ impl App {
                         &resources,
                         flux_report.as_ref(),
                         &self.controller_pods.get_all_pods(),
-                        self.namespace.as_deref(),
+                        self.namespace_display().as_deref(),
                         &mut self.view_state.pulse_scroll_offset,
                         &mut self.view_state.text_search,
                         &self.theme,
                     );
                 }
                 View::ResourceEdit => {
-                    let msg = if self.async_state.edit_save_result_rx.is_some() {
-                        "Saving changes..."
-                    } else if self.async_state.edit_editor_launched {
-                        "Editing in external editor..."
+                    if let Some(preview) = &self.async_state.edit_preview {
+                        views::render_edit_preview(
+                            f,
+                            area,
+                            self.async_state.edit_pending.as_ref(),
+                            preview,
+                            &mut self.view_state.edit_scroll_offset,
+                            &self.keymap,
+                            &self.theme,
+                        );
                     } else {
-                        "Loading resource..."
-                    };
-                    let paragraph = Paragraph::new(msg)
-                        .alignment(ratatui::layout::Alignment::Center)
-                        .block(Block::default().borders(Borders::ALL).title("Edit"));
-                    f.render_widget(paragraph, area);
+                        let msg = if self.async_state.edit_save_pending.is_some()
+                            || self.async_state.edit_save_result_rx.is_some()
+                        {
+                            "Saving changes..."
+                        } else if self.async_state.edit_editor_launched {
+                            "Editing in external editor..."
+                        } else {
+                            "Loading resource..."
+                        };
+                        let paragraph = Paragraph::new(msg)
+                            .alignment(ratatui::layout::Alignment::Center)
+                            .block(Block::default().borders(Borders::ALL).title("Edit"));
+                        f.render_widget(paragraph, area);
+                    }
                 }
                 View::Help => {
-                    render_help(f, area, &self.theme, self.namespace_hotkeys());
+                    render_help(f, area, &self.theme, self.namespace_hotkeys(), &self.keymap);
                 }
             }
 
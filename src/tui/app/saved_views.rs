@@ -0,0 +1,345 @@
+//! Saved views
+//!
+//! A saved view is a named preset of the resource list: type filter, `/`
+//! query, health filter, sort and namespace. Views live under `views` in the
+//! config, so cluster and context configs override them by name through the
+//! regular config merge; saving and deleting write to the layer that defines
+//! the view. `:view save <name>` captures the current list,
+//! `:view <name>` (or the view's function-key hotkey) recalls it.
+
+use super::core::App;
+use super::state::{HealthFilter, SortField, View};
+use crate::config::{SavedHealthFilter, SavedSortField, SavedView};
+use crate::tui::commands;
+use crate::tui::submenu::{SubmenuItem, SubmenuState};
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+impl App {
+    /// Snapshot the current list settings as a saved view.
+    pub(crate) fn capture_view(&self) -> SavedView {
+        SavedView {
+            resource_type: self.view_state.selected_resource_type.clone(),
+            query: self.view_state.filter.clone(),
+            health: match self.view_state.health_filter {
+                HealthFilter::Healthy => Some(SavedHealthFilter::Healthy),
+                HealthFilter::Unhealthy => Some(SavedHealthFilter::Unhealthy),
+                HealthFilter::All => None,
+            },
+            sort: match &self.view_state.sort_field {
+                SortField::Default => None,
+                SortField::Name => Some(SavedSortField::Name),
+                SortField::Age => Some(SavedSortField::Age),
+                SortField::Type => Some(SavedSortField::Type),
+                SortField::Status => Some(SavedSortField::Status),
+                SortField::Column(column) => Some(SavedSortField::Column(column.clone())),
+            },
+            sort_reverse: self.view_state.sort_reverse,
+            namespace: Some(self.namespace_arg()),
+            hotkey: None,
+        }
+    }
+
+    /// Apply the saved view `name` to the resource list.
+    pub(crate) fn apply_saved_view(&mut self, name: &str) {
+        let Some(view) = self.config.views.get(name).cloned() else {
+            self.set_status_message((format!("No saved view named '{}'", name), true));
+            return;
+        };
+
+        // Accept aliases (`hr`) as well as display names, like `:hr` does
+        self.view_state.selected_resource_type = view.resource_type.as_deref().map(|t| {
+            crate::watcher::get_display_name_for_command(&t.to_lowercase())
+                .map(str::to_string)
+                .or_else(|| crate::models::extra_kinds::global().resolve_command(&t.to_lowercase()))
+                .unwrap_or_else(|| t.to_string())
+        });
+        self.view_state.filter = view.query;
+        self.view_state.health_filter = match view.health {
+            Some(SavedHealthFilter::Healthy) => HealthFilter::Healthy,
+            Some(SavedHealthFilter::Unhealthy) => HealthFilter::Unhealthy,
+            None => HealthFilter::All,
+        };
+        self.view_state.sort_field = match view.sort {
+            None => SortField::Default,
+            Some(SavedSortField::Name) => SortField::Name,
+            Some(SavedSortField::Age) => SortField::Age,
+            Some(SavedSortField::Type) => SortField::Type,
+            Some(SavedSortField::Status) => SortField::Status,
+            Some(SavedSortField::Column(column)) => SortField::Column(column),
+        };
+        self.view_state.sort_reverse = view.sort_reverse;
+        self.view_state.current_view = View::ResourceList;
+        self.set_status_message((format!("View '{}'", name), false));
+
+        // Switching namespace restarts the watchers; it may also replace the
+        // status message with a failure.
+        match view.namespace.as_deref() {
+            Some(ns) => self.apply_namespace_arg(ns),
+            None => self.reset_list_position(),
+        }
+        self.invalidate_layout_cache();
+    }
+
+    /// Apply the saved view bound to function key `F{n}`, if any.
+    pub(crate) fn apply_view_hotkey(&mut self, n: u8) -> bool {
+        let hotkey = format!("F{}", n);
+        let name = self
+            .config
+            .views
+            .iter()
+            .filter(|(_, view)| {
+                view.hotkey
+                    .as_deref()
+                    .is_some_and(|h| h.eq_ignore_ascii_case(&hotkey))
+            })
+            .map(|(name, _)| name.clone())
+            .min();
+        match name {
+            Some(name) => {
+                self.apply_saved_view(&name);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// `:view [name]`, `:view save <name>`, `:view delete <name>`.
+    pub(super) fn cmd_view(&mut self, cmd: &str) {
+        let Some(arg) = commands::extract_command_arg(cmd, "view") else {
+            match self.views_submenu() {
+                Some(submenu) => self.view_state.submenu_state = Some(submenu),
+                None => self.set_status_message((
+                    "No saved views. Save the current list with :view save <name>".to_string(),
+                    true,
+                )),
+            }
+            return;
+        };
+
+        let (action, name) = match arg.split_once(' ') {
+            Some((action, name)) if matches!(action, "save" | "delete" | "rm") => {
+                (action, name.trim())
+            }
+            _ => ("", arg.as_str()),
+        };
+        match action {
+            "save" => {
+                let mut view = self.capture_view();
+                // Re-saving a view keeps its hotkey
+                view.hotkey = self.config.views.get(name).and_then(|v| v.hotkey.clone());
+                let result = self.persist_view(name, Some(view));
+                self.report_view_persist(result, format!("Saved view '{}'", name));
+            }
+            "delete" | "rm" => {
+                if !self.config.views.contains_key(name) {
+                    self.set_status_message((format!("No saved view named '{}'", name), true));
+                    return;
+                }
+                let result = self.persist_view(name, None);
+                self.report_view_persist(result, format!("Deleted view '{}'", name));
+            }
+            _ => self.apply_saved_view(name),
+        }
+    }
+
+    /// Picker listing the saved views, or `None` when there are none.
+    fn views_submenu(&self) -> Option<SubmenuState> {
+        if self.config.views.is_empty() {
+            return None;
+        }
+        let mut names: Vec<&String> = self.config.views.keys().collect();
+        names.sort();
+        let items = names
+            .into_iter()
+            .map(|name| {
+                let display = match self.config.views[name].hotkey.as_deref() {
+                    Some(hotkey) => format!("{} <{}>", name, hotkey),
+                    None => name.clone(),
+                };
+                SubmenuItem::with_display(name.clone(), display)
+            })
+            .collect();
+        Some(
+            SubmenuState::new("view".to_string(), items)
+                .with_title("Saved Views".to_string())
+                .with_help("j/k: Navigate | /: Filter | Enter: Apply | Esc: Cancel".to_string()),
+        )
+    }
+
+    /// Write `view` (or its removal) to the config files and the in-memory
+    /// config. A view is saved to the highest-precedence layer that defines
+    /// it (new views go to the root config) and deleted from every layer, so
+    /// a cluster or context config cannot bring it back on the next start.
+    fn persist_view(&mut self, name: &str, view: Option<SavedView>) -> Result<()> {
+        use crate::config::loader::ConfigLoader;
+
+        let defining: Vec<&PathBuf> = self
+            .config_layers
+            .iter()
+            .filter(|path| ConfigLoader::defines_view(path, name))
+            .collect();
+        match &view {
+            Some(view) => {
+                let target = defining
+                    .last()
+                    .copied()
+                    .or(self.config_layers.first())
+                    .context("No config file to save the view to")?;
+                ConfigLoader::save_view(target, name, Some(view))
+                    .context("Failed to save configuration file")?;
+            }
+            None => {
+                for path in defining {
+                    ConfigLoader::save_view(path, name, None)
+                        .context("Failed to save configuration file")?;
+                }
+            }
+        }
+
+        match view {
+            Some(view) => {
+                self.config.views.insert(name.to_string(), view);
+            }
+            None => {
+                self.config.views.remove(name);
+            }
+        }
+        Ok(())
+    }
+
+    fn report_view_persist(&mut self, result: Result<()>, success: String) {
+        match result {
+            Ok(()) => self.set_status_message((success, false)),
+            Err(e) => self.set_status_message((format!("{:#}", e), true)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::tui::Theme;
+    use crate::watcher::ResourceState;
+
+    fn create_test_app() -> App {
+        let mut config = Config::default();
+        config.ui.splashless = true;
+        App::new(
+            ResourceState::new(),
+            "test-context".to_string(),
+            Some("flux-system".to_string()),
+            config,
+            Theme::default(),
+        )
+    }
+
+    #[test]
+    fn captured_view_round_trips_through_apply() {
+        let mut app = create_test_app();
+        app.view_state.selected_resource_type = Some("HelmRelease".to_string());
+        app.view_state.filter = "label:team=payments".to_string();
+        app.view_state.health_filter = HealthFilter::Unhealthy;
+        app.view_state.sort_field = SortField::Age;
+        app.view_state.sort_reverse = true;
+
+        let view = app.capture_view();
+        assert_eq!(view.namespace.as_deref(), Some("flux-system"));
+        assert_eq!(view.health, Some(SavedHealthFilter::Unhealthy));
+        assert_eq!(view.sort, Some(SavedSortField::Age));
+        app.config.views.insert("payments".to_string(), view);
+
+        app.view_state.selected_resource_type = None;
+        app.view_state.filter.clear();
+        app.view_state.health_filter = HealthFilter::All;
+        app.view_state.sort_field = SortField::Default;
+        app.view_state.sort_reverse = false;
+        app.view_state.current_view = View::ResourceFavorites;
+
+        app.apply_saved_view("payments");
+        assert_eq!(
+            app.view_state.selected_resource_type.as_deref(),
+            Some("HelmRelease")
+        );
+        assert_eq!(app.view_state.filter, "label:team=payments");
+        assert_eq!(app.view_state.health_filter, HealthFilter::Unhealthy);
+        assert_eq!(app.view_state.sort_field, SortField::Age);
+        assert!(app.view_state.sort_reverse);
+        assert_eq!(app.view_state.current_view, View::ResourceList);
+        assert_eq!(app.namespace.as_deref(), Some("flux-system"));
+    }
+
+    #[test]
+    fn view_resolves_aliases_namespace_and_hotkey() {
+        let mut app = create_test_app();
+        app.config.views.insert(
+            "infra".to_string(),
+            SavedView {
+                resource_type: Some("ks".to_string()),
+                namespace: Some("all".to_string()),
+                hotkey: Some("f2".to_string()),
+                ..Default::default()
+            },
+        );
+
+        assert!(!app.apply_view_hotkey(1));
+        assert!(app.apply_view_hotkey(2));
+        assert_eq!(
+            app.view_state.selected_resource_type.as_deref(),
+            Some("Kustomization")
+        );
+        assert_eq!(app.namespace, None);
+        assert_eq!(app.view_state.health_filter, HealthFilter::All);
+    }
+
+    #[test]
+    fn unknown_view_reports_an_error() {
+        let mut app = create_test_app();
+        app.cmd_view("view nope");
+        let (msg, is_error) = app.ui_state.status_message.clone().unwrap();
+        assert!(is_error);
+        assert_eq!(msg, "No saved view named 'nope'");
+
+        app.cmd_view("view");
+        assert!(app.view_state.submenu_state.is_none());
+    }
+
+    #[test]
+    fn save_and_delete_write_the_layer_that_defines_the_view() {
+        use crate::config::ConfigLoader;
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("config.yaml");
+        let cluster = dir.path().join("clusters/prod/config.yaml");
+        std::fs::write(
+            &root,
+            "readOnly: true\nviews:\n  payments:\n    query: old\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(cluster.parent().unwrap()).unwrap();
+        std::fs::write(&cluster, "views:\n  payments:\n    namespace: payments\n").unwrap();
+
+        let mut app = create_test_app();
+        app.set_config_layers(vec![root.clone(), cluster.clone()]);
+        let load = |path: &PathBuf| ConfigLoader::load_file(path).unwrap();
+
+        app.view_state.filter = "label:team=payments".to_string();
+        app.cmd_view("view save payments");
+        assert_eq!(
+            load(&cluster).views["payments"].query,
+            "label:team=payments"
+        );
+        assert_eq!(load(&root).views["payments"].query, "old");
+
+        // New views go to the root config, which keeps its other settings
+        app.cmd_view("view save infra");
+        assert!(ConfigLoader::defines_view(&root, "infra"));
+        assert!(!ConfigLoader::defines_view(&cluster, "infra"));
+        assert!(load(&root).read_only);
+
+        app.cmd_view("view delete payments");
+        assert!(!ConfigLoader::defines_view(&root, "payments"));
+        assert!(!ConfigLoader::defines_view(&cluster, "payments"));
+        assert!(!app.config.views.contains_key("payments"));
+    }
+}
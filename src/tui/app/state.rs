@@ -19,9 +19,18 @@ pub enum View {
     ResourceGraph,
     ResourceFavorites,
     ResourceHistory,
+    /// Unified diff of the live spec against the last-applied or
+    /// Kustomization-applied version, opened with `D`.
+    ResourceDiff,
+    /// Helm release inspector for a HelmRelease, opened with `H`: the stored
+    /// release's values, chart, notes, hooks and revisions, one tab each.
+    ResourceRelease,
     /// Live Kubernetes events feed, opened with `:events`. The events watcher
     /// runs only while this view (or a detail view opened from it) is active.
     EventList,
+    /// Cluster-wide reconciliation timeline, opened with `:timeline`. Runs
+    /// the events watcher like [`View::EventList`] to merge Warning events.
+    Timeline,
     /// Controller pod log viewer, opened with `:logs`. The log stream runs
     /// only while this view is active.
     Logs,
@@ -38,6 +47,10 @@ pub enum View {
     /// Cluster pulse dashboard (#195): per-kind health counts, recent
     /// failures, and FluxReport distribution info. Opened with `:pulse`.
     Pulse,
+    /// Resources of several contexts merged into one list, opened with
+    /// `:clusters`. Rows come from the multi-cluster sessions, not the
+    /// current context's watcher.
+    Clusters,
     /// Waiting for external editor / SSA apply
     ResourceEdit,
     #[allow(dead_code)] // Reserved for future alternative help view implementation
@@ -56,20 +69,29 @@ impl View {
             View::ResourceDescribe => Some(&mut vs.describe_scroll_offset),
             View::ResourceTrace => Some(&mut vs.trace_scroll_offset),
             View::ResourceHistory => Some(&mut vs.history_scroll_offset),
+            View::ResourceDiff => Some(&mut vs.diff_scroll_offset),
+            View::ResourceRelease => Some(&mut vs.release.scroll_offset),
             View::Logs => Some(&mut vs.log_scroll_offset),
             View::WorkloadDetail => Some(&mut vs.workload_scroll_offset),
             View::Pulse => Some(&mut vs.pulse_scroll_offset),
+            View::ResourceEdit => Some(&mut vs.edit_scroll_offset),
             _ => None,
         }
     }
 
+    /// Whether this view shows Kubernetes events, so the lazily started
+    /// events watcher must run while it is open.
+    pub fn uses_kube_events(self) -> bool {
+        matches!(self, View::EventList | View::Timeline)
+    }
+
     /// Whether this is a list-style view (the main resource list or favorites)
     /// from which resources are selected and opened.
     pub fn is_list_view(self) -> bool {
         matches!(self, View::ResourceList | View::ResourceFavorites)
     }
 
-    /// Whether `/` opens an in-view text search (YAML/describe/trace/logs)
+    /// Whether `/` opens an in-view text search (YAML/describe/trace/diff/logs)
     /// rather than the resource-list filter.
     pub fn is_text_search_view(self) -> bool {
         matches!(
@@ -77,6 +99,8 @@ impl View {
             View::ResourceYAML
                 | View::ResourceDescribe
                 | View::ResourceTrace
+                | View::ResourceDiff
+                | View::ResourceRelease
                 | View::Logs
                 | View::WorkloadDetail
                 | View::Pulse
@@ -93,6 +117,8 @@ impl View {
                 | View::ResourceYAML
                 | View::ResourceTrace
                 | View::ResourceHistory
+                | View::ResourceDiff
+                | View::ResourceRelease
                 | View::ResourceGraph
         )
     }
@@ -117,7 +143,7 @@ pub enum ConnectionStatus {
 /// Sort field for the resource list (k9s-style shift-key sorting).
 ///
 /// Cycle per key press: ascending → descending → back to `Default` ordering.
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
 pub enum SortField {
     /// Default ordering: namespace, then type, then name
     #[default]
@@ -126,28 +152,44 @@ pub enum SortField {
     Age,
     Type,
     Status,
+    /// Any other column of the type-specific list (upper-case header),
+    /// including configured custom columns; set with `:sort <column>`
+    Column(String),
 }
 
 impl SortField {
     /// Header column name this sort field corresponds to (for the sort arrow)
-    pub fn column_name(&self) -> Option<&'static str> {
+    pub fn column_name(&self) -> Option<&str> {
         match self {
             SortField::Default => None,
             SortField::Name => Some("NAME"),
             SortField::Age => Some("AGE"),
             SortField::Type => Some("TYPE"),
             SortField::Status => Some("STATUS"),
+            SortField::Column(column) => Some(column),
         }
     }
 
     /// Human-readable name for status messages
-    pub fn display_name(&self) -> &'static str {
+    pub fn display_name(&self) -> String {
         match self {
-            SortField::Default => "default",
-            SortField::Name => "name",
-            SortField::Age => "age",
-            SortField::Type => "type",
-            SortField::Status => "status",
+            SortField::Default => "default".to_string(),
+            SortField::Name => "name".to_string(),
+            SortField::Age => "age".to_string(),
+            SortField::Type => "type".to_string(),
+            SortField::Status => "status".to_string(),
+            SortField::Column(column) => column.clone(),
+        }
+    }
+
+    /// Parse a `:sort` argument: the built-in sort names, or any column header.
+    pub fn from_column(column: &str) -> Self {
+        match column.to_lowercase().as_str() {
+            "name" => SortField::Name,
+            "age" => SortField::Age,
+            "type" | "kind" => SortField::Type,
+            "status" => SortField::Status,
+            _ => SortField::Column(column.to_uppercase()),
         }
     }
 }
@@ -182,6 +224,96 @@ impl TextSearchState {
     }
 }
 
+/// Tab of the Helm release inspector
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ReleaseTab {
+    /// User-supplied values of the current revision
+    #[default]
+    Values,
+    /// What `valuesFrom` and `values` resolve to now, key by key
+    Effective,
+    /// Chart metadata and release status
+    Chart,
+    /// Rendered NOTES.txt
+    Notes,
+    Hooks,
+    /// Every stored revision, newest first
+    Revisions,
+    /// Values and manifest changes between two revisions
+    Diff,
+}
+
+impl ReleaseTab {
+    pub const ALL: [ReleaseTab; 7] = [
+        ReleaseTab::Values,
+        ReleaseTab::Effective,
+        ReleaseTab::Chart,
+        ReleaseTab::Notes,
+        ReleaseTab::Hooks,
+        ReleaseTab::Revisions,
+        ReleaseTab::Diff,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            ReleaseTab::Values => "Values",
+            ReleaseTab::Effective => "Effective",
+            ReleaseTab::Chart => "Chart",
+            ReleaseTab::Notes => "Notes",
+            ReleaseTab::Hooks => "Hooks",
+            ReleaseTab::Revisions => "Revisions",
+            ReleaseTab::Diff => "Diff",
+        }
+    }
+
+    /// The tab `step` places away, wrapping around (Tab / Shift+Tab).
+    pub fn cycle(self, step: isize) -> Self {
+        let len = Self::ALL.len() as isize;
+        let index = Self::ALL.iter().position(|tab| *tab == self).unwrap_or(0) as isize;
+        Self::ALL[(index + step).rem_euclid(len) as usize]
+    }
+
+    /// Whether the tab shows values or manifests that can hold Secret data
+    pub fn shows_secrets(self) -> bool {
+        matches!(
+            self,
+            ReleaseTab::Values | ReleaseTab::Effective | ReleaseTab::Diff
+        )
+    }
+}
+
+/// State of the Helm release inspector, reset when it is opened
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReleaseViewState {
+    pub tab: ReleaseTab,
+    pub scroll_offset: usize,
+    /// Selected row of the Revisions tab (index into the revisions, newest
+    /// first)
+    pub cursor: usize,
+    /// Revisions picked with Space for the diff, at most two
+    pub marks: Vec<i64>,
+    /// Diff between the picked revisions, computed by `D`
+    pub diff: Option<crate::kube::helm::RevisionDiff>,
+    /// Show Secret-sourced values and Secret manifest data (`x`, not in
+    /// readonly mode)
+    pub reveal_secrets: bool,
+}
+
+impl ReleaseViewState {
+    /// The redaction the Values, Effective and Diff tabs apply
+    pub fn secret_redaction<'a>(
+        &self,
+        effective: &'a crate::kube::helm_values::EffectiveValues,
+    ) -> crate::kube::helm_values::SecretRedaction<'a> {
+        use crate::kube::helm_values::SecretRedaction;
+        if self.reveal_secrets {
+            SecretRedaction::Reveal
+        } else {
+            SecretRedaction::Redact(effective)
+        }
+    }
+}
+
 /// Health filter for resources
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum HealthFilter {
@@ -218,12 +350,18 @@ pub struct ViewState {
     pub trace_scroll_offset: usize,
     /// Scroll offset for history view
     pub history_scroll_offset: usize,
+    /// Scroll offset for the live-vs-applied diff view
+    pub diff_scroll_offset: usize,
+    /// Tab, scroll and revision picks of the Helm release inspector
+    pub release: ReleaseViewState,
     /// Scroll offset for the controller log view
     pub log_scroll_offset: usize,
     /// Scroll offset for the workload detail view
     pub workload_scroll_offset: usize,
     /// Scroll offset for the pulse dashboard
     pub pulse_scroll_offset: usize,
+    /// Scroll offset for the edit preview diff
+    pub edit_scroll_offset: usize,
     /// Where Back from the log view returns to, when logs were opened from
     /// somewhere other than a root list view (e.g. a workload's pods).
     /// Consumed on Back; `None` falls back to `previous_list_view`.
@@ -257,6 +395,8 @@ pub struct ViewState {
     pub sort_reverse: bool,
     /// Search state for text views (YAML, describe, trace)
     pub text_search: TextSearchState,
+    /// Kinds the timeline is limited to (display names; empty = every kind)
+    pub timeline_kinds: Vec<String>,
 }
 
 impl Default for ViewState {
@@ -273,9 +413,12 @@ impl Default for ViewState {
             describe_scroll_offset: 0,
             trace_scroll_offset: 0,
             history_scroll_offset: 0,
+            diff_scroll_offset: 0,
+            release: ReleaseViewState::default(),
             log_scroll_offset: 0,
             workload_scroll_offset: 0,
             pulse_scroll_offset: 0,
+            edit_scroll_offset: 0,
             logs_back_view: None,
             workload_rows: Vec::new(),
             inventory_rows: Vec::new(),
@@ -289,6 +432,7 @@ impl Default for ViewState {
             sort_field: SortField::default(),
             sort_reverse: false,
             text_search: TextSearchState::default(),
+            timeline_kinds: Vec::new(),
         }
     }
 }
@@ -302,6 +446,8 @@ pub struct SelectionState {
     pub favorites: HashSet<String>,
     /// Flag indicating favorites need to be saved
     pub favorites_pending_save: bool,
+    /// Resource keys marked for a bulk operation (session only)
+    pub marked: HashSet<String>,
 }
 
 /// UI-related state (command mode, status messages, layout cache)
@@ -366,6 +512,10 @@ pub struct AsyncOperationState {
     pub describe: AsyncTask<ResourceKey, crate::kube::fetch::DescribeData>,
     /// Ownership-chain trace backing the trace view.
     pub trace: AsyncTask<ResourceKey, crate::trace::TraceResult>,
+    /// Live-vs-applied spec diff backing the diff view.
+    pub diff: AsyncTask<ResourceKey, crate::kube::diff::SpecDiff>,
+    /// Stored Helm release revisions backing the release inspector.
+    pub release: AsyncTask<ResourceKey, crate::kube::helm::ReleaseInspection>,
     /// Relationship graph backing the graph view.
     pub graph: AsyncTask<ResourceKey, crate::trace::ResourceGraph>,
     /// Workload drill-down fetch backing the workload detail view (#194).
@@ -378,6 +528,12 @@ pub struct AsyncOperationState {
     pub last_operation_key: Option<char>,
     /// Operation waiting for the user's confirmation dialog.
     pub confirmation_pending: Option<PendingOperation>,
+    /// Bulk operation on the marked resources, waiting for confirmation.
+    pub bulk_confirmation: Option<BulkOperation>,
+    /// Bulk operation running over the marked resources.
+    pub bulk: AsyncTask<BulkOperation, BulkResult>,
+    /// Per-item outcome of the last bulk operation, shown until dismissed.
+    pub bulk_summary: Option<BulkResult>,
 
     // Edit operation
     /// Resource being edited (key used for SSA apply)
@@ -394,6 +550,30 @@ pub struct AsyncOperationState {
     pub edit_editor_launched: bool,
     /// The view that was active when `e` was pressed — returned to on cancel/save
     pub edit_return_view: View,
+    /// Document first handed to the editor — the baseline of the preview diff
+    pub edit_original: Option<String>,
+    /// The user's latest edited YAML, kept so the editor can be re-opened with it
+    pub edit_buffer: Option<String>,
+    /// Diff and dry-run outcome shown before the edit is applied
+    pub edit_preview: Option<EditPreview>,
+    /// Server-side dry-run apply of `edit_buffer`
+    pub edit_dry_run: AsyncTask<String, ()>,
+
+    /// `:clusters` connect: the requested contexts and their sessions
+    pub clusters: AsyncTask<Vec<String>, crate::services::MultiClusterSession>,
+    /// `:ns <selector>` lookup: the selector and the namespaces it matched
+    pub namespace_selector: AsyncTask<String, (String, Vec<String>)>,
+
+    /// Foreground plugin waiting for the main loop to hand it the terminal
+    pub plugin_run: Option<PluginRun>,
+}
+
+/// A foreground plugin command with its placeholders replaced
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginRun {
+    pub name: String,
+    pub program: String,
+    pub args: Vec<String>,
 }
 
 impl Default for AsyncOperationState {
@@ -403,10 +583,15 @@ impl Default for AsyncOperationState {
             describe: Default::default(),
             trace: Default::default(),
             graph: Default::default(),
+            diff: Default::default(),
+            release: Default::default(),
             workload: Default::default(),
             operation: Default::default(),
             last_operation_key: None,
             confirmation_pending: None,
+            bulk_confirmation: None,
+            bulk: Default::default(),
+            bulk_summary: None,
             edit_pending: None,
             edit_full_yaml: None,
             edit_save_pending: None,
@@ -414,6 +599,13 @@ impl Default for AsyncOperationState {
             edit_error_message: None,
             edit_editor_launched: false,
             edit_return_view: View::ResourceList,
+            edit_original: None,
+            edit_buffer: None,
+            edit_preview: None,
+            edit_dry_run: Default::default(),
+            clusters: Default::default(),
+            namespace_selector: Default::default(),
+            plugin_run: None,
         }
     }
 }
@@ -424,10 +616,18 @@ impl AsyncOperationState {
         self.describe.clear();
         self.trace.clear();
         self.graph.clear();
+        self.diff.clear();
+        self.release.clear();
         self.workload.clear();
         self.operation.clear();
         self.last_operation_key = None;
         self.confirmation_pending = None;
+        self.bulk_confirmation = None;
+        self.bulk.clear();
+        self.bulk_summary = None;
+        // `:clusters` sessions outlive a context switch; a selector lookup
+        // against the old context does not
+        self.namespace_selector.clear();
 
         self.edit_pending = None;
         self.edit_full_yaml = None;
@@ -436,6 +636,32 @@ impl AsyncOperationState {
         self.edit_error_message = None;
         self.edit_editor_launched = false;
         self.edit_return_view = View::ResourceList;
+        self.clear_edit_preview();
+    }
+
+    /// Drop the edit preview: the baseline, the kept buffer, the diff and
+    /// any dry-run in flight.
+    pub fn clear_edit_preview(&mut self) {
+        self.edit_original = None;
+        self.edit_buffer = None;
+        self.edit_preview = None;
+        self.edit_dry_run.clear();
+    }
+}
+
+/// Preview of an edit before it is applied
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditPreview {
+    /// Unified diff of the fetched document against the edited one
+    pub lines: Vec<crate::kube::diff::DiffLine>,
+    /// Server-side dry-run outcome; `None` while it runs
+    pub dry_run: Option<Result<(), String>>,
+}
+
+impl EditPreview {
+    /// Whether the dry-run passed, so the edit may be applied.
+    pub fn can_apply(&self) -> bool {
+        matches!(self.dry_run, Some(Ok(())))
     }
 }
 
@@ -446,6 +672,9 @@ pub struct PendingOperation {
     pub namespace: String,
     pub name: String,
     pub operation_key: char,
+    /// Context of a resource from the `:clusters` view, whose session's
+    /// client runs the operation; `None` uses the current context's client
+    pub context: Option<String>,
 }
 
 impl PendingOperation {
@@ -460,8 +689,53 @@ impl PendingOperation {
             namespace,
             name,
             operation_key,
+            context: None,
         }
     }
+
+    /// Run the operation with the client of `context` (a `:clusters` row).
+    pub fn in_context(mut self, context: Option<String>) -> Self {
+        self.context = context;
+        self
+    }
+}
+
+/// An operation over several marked resources
+#[derive(Clone, Debug)]
+pub struct BulkOperation {
+    pub operation_key: char,
+    /// Marked resources the operation runs on
+    pub targets: Vec<ResourceKey>,
+    /// Marked resources the operation is not valid for
+    pub skipped: Vec<ResourceKey>,
+}
+
+/// Per-item outcome of a bulk operation
+#[derive(Clone, Debug)]
+pub struct BulkResult {
+    pub operation_key: char,
+    /// Outcome for each target, in target order
+    pub outcomes: Vec<(ResourceKey, Result<(), String>)>,
+    /// Marked resources the operation was not valid for
+    pub skipped: Vec<ResourceKey>,
+}
+
+impl BulkResult {
+    /// Targets whose operation failed.
+    pub fn failed(&self) -> impl Iterator<Item = &ResourceKey> {
+        self.outcomes
+            .iter()
+            .filter(|(_, outcome)| outcome.is_err())
+            .map(|(rk, _)| rk)
+    }
+
+    /// Number of targets whose operation succeeded.
+    pub fn succeeded_count(&self) -> usize {
+        self.outcomes
+            .iter()
+            .filter(|(_, outcome)| outcome.is_ok())
+            .count()
+    }
 }
 
 /// Information about a Flux controller pod
@@ -527,12 +801,15 @@ impl ControllerPodState {
 #[derive(Debug, Default)]
 pub struct KubeEventStore {
     events: std::collections::HashMap<String, crate::kube::events::KubeEventInfo>,
+    /// Bumped on every change
+    generation: u64,
 }
 
 impl KubeEventStore {
     /// Insert or update an event by UID, evicting the oldest-seen entry when
     /// the store is over capacity.
     pub fn upsert(&mut self, info: crate::kube::events::KubeEventInfo) {
+        self.generation += 1;
         self.events.insert(info.uid.clone(), info);
         while self.events.len() > crate::constants::MAX_KUBE_EVENTS {
             let oldest_uid = self
@@ -551,9 +828,16 @@ impl KubeEventStore {
 
     /// Remove an event (deleted on the cluster, usually TTL expiry).
     pub fn remove(&mut self, uid: &str) {
+        self.generation += 1;
         self.events.remove(uid);
     }
 
+    /// Changes whenever the store does, so views derived from the events
+    /// know when to rebuild.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
     /// All events, newest last-seen first.
     pub fn sorted_events(&self) -> Vec<&crate::kube::events::KubeEventInfo> {
         let mut events: Vec<_> = self.events.values().collect();
@@ -573,6 +857,7 @@ impl KubeEventStore {
     /// Drop all events (e.g. on namespace or context switch — the restarted
     /// watcher re-lists the events in scope).
     pub fn clear(&mut self) {
+        self.generation += 1;
         self.events.clear();
     }
 }
@@ -644,6 +929,8 @@ mod tests {
         assert!(View::ResourceYAML.is_text_search_view());
         assert!(View::ResourceDescribe.is_text_search_view());
         assert!(View::ResourceTrace.is_text_search_view());
+        assert!(View::ResourceDiff.is_text_search_view());
+        assert!(View::ResourceRelease.is_text_search_view());
         assert!(!View::ResourceHistory.is_text_search_view());
         assert!(!View::ResourceList.is_text_search_view());
 
@@ -653,6 +940,8 @@ mod tests {
             View::ResourceYAML,
             View::ResourceTrace,
             View::ResourceHistory,
+            View::ResourceDiff,
+            View::ResourceRelease,
             View::ResourceGraph,
         ] {
             assert!(v.is_nested_view(), "{v:?} should be a nested view");
@@ -670,6 +959,13 @@ mod tests {
                 .scroll_offset_mut(&mut ViewState::default())
                 .is_none()
         );
+        // The timeline shares the events feed's navigation and watcher.
+        assert!(!View::Timeline.is_nested_view());
+        assert!(!View::Timeline.is_list_view());
+        assert!(!View::Timeline.is_text_search_view());
+        assert!(View::Timeline.uses_kube_events());
+        assert!(View::EventList.uses_kube_events());
+        assert!(!View::ResourceList.uses_kube_events());
 
         // The log view is a root-level text view: line-scrolled and
         // searchable with /, but not nested and not a resource list.
@@ -4,7 +4,8 @@
 //! to keep app.rs focused on application state management.
 
 use crate::tui::submenu::{CommandSubmenu, SubmenuItem, SubmenuState};
-use anyhow::Result;
+use anyhow::{Result, bail};
+use std::collections::HashMap;
 
 /// Command definition
 #[derive(Debug, Clone)]
@@ -21,6 +22,10 @@ pub const APP_COMMANDS: &[Command] = &[
         name: "events",
         takes_args: false,
     },
+    Command {
+        name: "timeline",
+        takes_args: true,
+    },
     Command {
         name: "pulse",
         takes_args: false,
@@ -49,6 +54,18 @@ pub const APP_COMMANDS: &[Command] = &[
         name: "discover",
         takes_args: false,
     },
+    Command {
+        name: "view",
+        takes_args: true,
+    },
+    Command {
+        name: "sort",
+        takes_args: true,
+    },
+    Command {
+        name: "clusters",
+        takes_args: true,
+    },
     Command {
         name: "help",
         takes_args: false,
@@ -97,10 +114,10 @@ pub const APP_COMMANDS: &[Command] = &[
 
 /// Find all commands that match the given prefix
 ///
-/// Returns commands sorted by priority (CRD commands first, then App commands)
-/// and then alphabetically within each category.
+/// Returns commands sorted by priority (CRD commands first, then App commands
+/// and configured aliases) and then alphabetically within each category.
 /// Commands that take arguments are returned with a trailing space (e.g., "skin ").
-pub fn find_matching_commands(prefix: &str) -> Vec<String> {
+pub fn find_matching_commands(prefix: &str, aliases: &HashMap<String, String>) -> Vec<String> {
     let prefix_lower = prefix.to_lowercase();
     let mut crd_matches: Vec<String> = Vec::new();
     let mut app_matches: Vec<String> = Vec::new();
@@ -154,6 +171,14 @@ pub fn find_matching_commands(prefix: &str) -> Vec<String> {
         }
     }
 
+    // Configured aliases complete like app commands
+    for alias in aliases.keys() {
+        let alias = alias.to_lowercase();
+        if alias.starts_with(&prefix_lower) && !app_matches.contains(&alias) {
+            app_matches.push(alias);
+        }
+    }
+
     // Sort matches alphabetically
     crd_matches.sort();
     app_matches.sort();
@@ -172,6 +197,15 @@ pub fn is_events_command(cmd: &str) -> bool {
     cmd_lower == "events" || cmd_lower == "event" || cmd_lower == "ev"
 }
 
+/// Check if command opens the reconciliation timeline (with optional kinds)
+pub fn is_timeline_command(cmd: &str) -> bool {
+    let cmd_lower = cmd.to_lowercase();
+    cmd_lower == "timeline"
+        || cmd_lower == "tl"
+        || cmd_lower.starts_with("timeline ")
+        || cmd_lower.starts_with("tl ")
+}
+
 /// Check if command opens the pulse dashboard
 pub fn is_pulse_command(cmd: &str) -> bool {
     let cmd_lower = cmd.to_lowercase();
@@ -200,6 +234,24 @@ pub fn is_discover_command(cmd: &str) -> bool {
     cmd_lower == "discover" || cmd_lower == "discovery"
 }
 
+/// Check if command recalls or manages saved views (with optional args)
+pub fn is_view_command(cmd: &str) -> bool {
+    let cmd_lower = cmd.to_lowercase();
+    cmd_lower == "view" || cmd_lower.starts_with("view ")
+}
+
+/// Check if command sorts the list (with optional column)
+pub fn is_sort_command(cmd: &str) -> bool {
+    let cmd_lower = cmd.to_lowercase();
+    cmd_lower == "sort" || cmd_lower.starts_with("sort ")
+}
+
+/// Check if command opens the multi-cluster view (with optional contexts)
+pub fn is_clusters_command(cmd: &str) -> bool {
+    let cmd_lower = cmd.to_lowercase();
+    cmd_lower == "clusters" || cmd_lower.starts_with("clusters ")
+}
+
 /// Check if command is help (handles "help", "h", "?")
 pub fn is_help_command(cmd: &str) -> bool {
     let cmd_lower = cmd.to_lowercase();
@@ -264,6 +316,72 @@ pub fn is_favorites_command(cmd: &str) -> bool {
     cmd_lower == "favorites" || cmd_lower == "fav"
 }
 
+/// Whether `name` is taken by a built-in command or a Flux kind command, so
+/// an alias cannot shadow it
+pub fn is_builtin_command(name: &str) -> bool {
+    const PREDICATES: &[fn(&str) -> bool] = &[
+        is_events_command,
+        is_timeline_command,
+        is_pulse_command,
+        is_logs_command,
+        is_readonly_command,
+        is_discover_command,
+        is_view_command,
+        is_sort_command,
+        is_clusters_command,
+        is_help_command,
+        is_quit_command,
+        is_healthy_command,
+        is_unhealthy_command,
+        is_all_command,
+        is_skin_command,
+        is_trace_command,
+        is_context_command,
+        is_namespace_command,
+        is_favorites_command,
+    ];
+    let name = name.to_lowercase();
+    PREDICATES.iter().any(|matches| matches(&name))
+        || crate::watcher::get_display_name_for_command(&name).is_some()
+}
+
+/// Check the `aliases` config: one-word names that do not shadow a built-in
+/// command, each expanding to a non-empty command.
+pub fn validate_aliases(aliases: &HashMap<String, String>) -> Result<()> {
+    let mut names: Vec<&String> = aliases.keys().collect();
+    names.sort();
+    for name in names {
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            bail!("aliases: '{}' must be a single word", name);
+        }
+        if is_builtin_command(name) {
+            bail!("aliases.{}: shadows the built-in :{} command", name, name);
+        }
+        if aliases[name].trim().trim_start_matches(':').is_empty() {
+            bail!("aliases.{}: expands to an empty command", name);
+        }
+    }
+    Ok(())
+}
+
+/// Expand a configured alias in the first word of `cmd`, keeping any further
+/// arguments: with `broken: hr status:failed`, `broken ns:apps` becomes
+/// `hr status:failed ns:apps`. Expansion is not repeated.
+pub fn expand_alias(cmd: &str, aliases: &HashMap<String, String>) -> Option<String> {
+    let cmd = cmd.trim();
+    let (word, rest) = cmd.split_once(char::is_whitespace).unwrap_or((cmd, ""));
+    let expansion = aliases
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(word))
+        .map(|(_, expansion)| expansion.trim().trim_start_matches(':').trim())?;
+    let rest = rest.trim();
+    Some(if rest.is_empty() {
+        expansion.to_string()
+    } else {
+        format!("{} {}", expansion, rest)
+    })
+}
+
 /// Extract argument from a command that takes arguments
 /// Returns None if command doesn't match or has no argument
 pub fn extract_command_arg(cmd: &str, command_name: &str) -> Option<String> {
@@ -606,13 +724,13 @@ mod tests {
     #[test]
     fn test_all_and_clear_appear_in_autocomplete() {
         // :all and :clear must be discoverable via autocomplete so users can find them
-        let all_matches = find_matching_commands("al");
+        let all_matches = find_matching_commands("al", &HashMap::new());
         assert!(
             all_matches.contains(&"all".to_string()),
             ":all should appear in autocomplete for prefix 'al'"
         );
 
-        let clear_matches = find_matching_commands("cl");
+        let clear_matches = find_matching_commands("cl", &HashMap::new());
         assert!(
             clear_matches.contains(&"clear".to_string()),
             ":clear should appear in autocomplete for prefix 'cl'"
@@ -636,7 +754,7 @@ mod tests {
         assert!(!is_discover_command("ks"));
 
         // Discoverable from autocomplete like the other app commands
-        assert!(find_matching_commands("disc").contains(&"discover".to_string()));
+        assert!(find_matching_commands("disc", &HashMap::new()).contains(&"discover".to_string()));
     }
 
     #[test]
@@ -711,4 +829,34 @@ mod tests {
         assert!(submenu.items[0].display_text.starts_with("ERR"));
         assert!(submenu.items[1].display_text.starts_with("OK"));
     }
+
+    #[test]
+    fn aliases_expand_complete_and_validate() {
+        let aliases = HashMap::from([
+            ("prod".to_string(), "ctx prod-eu-1".to_string()),
+            ("broken".to_string(), ":hr status:failed".to_string()),
+        ]);
+        assert_eq!(
+            expand_alias("prod", &aliases).as_deref(),
+            Some("ctx prod-eu-1")
+        );
+        assert_eq!(
+            expand_alias("BROKEN ns:apps", &aliases).as_deref(),
+            Some("hr status:failed ns:apps")
+        );
+        assert_eq!(expand_alias("production", &aliases), None);
+
+        assert!(find_matching_commands("pr", &aliases).contains(&"prod".to_string()));
+        assert!(validate_aliases(&aliases).is_ok());
+
+        let error = |name: &str, expansion: &str| {
+            validate_aliases(&HashMap::from([(name.to_string(), expansion.to_string())]))
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(error("hr", "ks").contains("shadows the built-in :hr"));
+        assert!(error("ctx", "ns apps").contains("shadows"));
+        assert!(error("two words", "ks").contains("single word"));
+        assert!(error("empty", " : ").contains("empty command"));
+    }
 }
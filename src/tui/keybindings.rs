@@ -3,57 +3,565 @@
 //! This module provides a single source of truth for all keybindings
 //! used in the footer, help view, and layout calculations.
 
+use anyhow::{Result, anyhow, bail};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::style::Color;
+use std::collections::HashMap;
+
+use crate::plugins::PluginsConfig;
+
+/// A remappable action, configured by name under `keymap:`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Command,
+    Filter,
+    Help,
+    Back,
+    Quit,
+    Details,
+    Yaml,
+    Describe,
+    Diff,
+    Edit,
+    Favorite,
+    Graph,
+    History,
+    Trace,
+    Release,
+    SortName,
+    SortAge,
+    SortType,
+    SortStatus,
+    Mark,
+    MarkAll,
+    InvertMarks,
+    Suspend,
+    Resume,
+    Reconcile,
+    ReconcileWithSource,
+    Delete,
+}
+
+impl Action {
+    /// Every remappable action
+    pub const ALL: &'static [Action] = &[
+        Action::Up,
+        Action::Down,
+        Action::PageUp,
+        Action::PageDown,
+        Action::Command,
+        Action::Filter,
+        Action::Help,
+        Action::Back,
+        Action::Quit,
+        Action::Details,
+        Action::Yaml,
+        Action::Describe,
+        Action::Diff,
+        Action::Edit,
+        Action::Favorite,
+        Action::Graph,
+        Action::History,
+        Action::Trace,
+        Action::Release,
+        Action::SortName,
+        Action::SortAge,
+        Action::SortType,
+        Action::SortStatus,
+        Action::Mark,
+        Action::MarkAll,
+        Action::InvertMarks,
+        Action::Suspend,
+        Action::Resume,
+        Action::Reconcile,
+        Action::ReconcileWithSource,
+        Action::Delete,
+    ];
+
+    /// Name under `keymap:` in config
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Up => "up",
+            Action::Down => "down",
+            Action::PageUp => "pageUp",
+            Action::PageDown => "pageDown",
+            Action::Command => "command",
+            Action::Filter => "filter",
+            Action::Help => "help",
+            Action::Back => "back",
+            Action::Quit => "quit",
+            Action::Details => "details",
+            Action::Yaml => "yaml",
+            Action::Describe => "describe",
+            Action::Diff => "diff",
+            Action::Edit => "edit",
+            Action::Favorite => "favorite",
+            Action::Graph => "graph",
+            Action::History => "history",
+            Action::Trace => "trace",
+            Action::Release => "release",
+            Action::SortName => "sortName",
+            Action::SortAge => "sortAge",
+            Action::SortType => "sortType",
+            Action::SortStatus => "sortStatus",
+            Action::Mark => "mark",
+            Action::MarkAll => "markAll",
+            Action::InvertMarks => "invertMarks",
+            Action::Suspend => "suspend",
+            Action::Resume => "resume",
+            Action::Reconcile => "reconcile",
+            Action::ReconcileWithSource => "reconcileWithSource",
+            Action::Delete => "delete",
+        }
+    }
+
+    /// Built-in key. For operations this is the operation's keybinding,
+    /// which stays its ID in the operation registry.
+    pub fn default_key(self) -> Key {
+        match self {
+            Action::Up => Key::char('k'),
+            Action::Down => Key::char('j'),
+            Action::PageUp => Key::ctrl('b'),
+            Action::PageDown => Key::ctrl('f'),
+            Action::Command => Key::char(':'),
+            Action::Filter => Key::char('/'),
+            Action::Help => Key::char('?'),
+            Action::Back => Key::char('q'),
+            Action::Quit => Key::char('Q'),
+            Action::Details => Key::named(KeyCode::Enter),
+            Action::Yaml => Key::char('y'),
+            Action::Describe => Key::char('d'),
+            Action::Diff => Key::char('D'),
+            Action::Edit => Key::char('e'),
+            Action::Favorite => Key::char('f'),
+            Action::Graph => Key::char('g'),
+            Action::History => Key::char('h'),
+            Action::Trace => Key::char('t'),
+            Action::Release => Key::char('H'),
+            Action::SortName => Key::char('N'),
+            Action::SortAge => Key::char('A'),
+            Action::SortType => Key::char('T'),
+            Action::SortStatus => Key::char('S'),
+            Action::Mark => Key::char(' '),
+            Action::MarkAll => Key::ctrl('a'),
+            Action::InvertMarks => Key::char('*'),
+            Action::Suspend => Key::char('s'),
+            Action::Resume => Key::char('r'),
+            Action::Reconcile => Key::char('R'),
+            Action::ReconcileWithSource => Key::char('W'),
+            Action::Delete => Key::ctrl('d'),
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|action| action.name().eq_ignore_ascii_case(name))
+    }
+}
+
+/// A key: a character (case-sensitive, so `N` is Shift+n), a character with
+/// Ctrl, or a named key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key {
+    code: KeyCode,
+    ctrl: bool,
+}
+
+impl Key {
+    const fn char(c: char) -> Self {
+        Self {
+            code: KeyCode::Char(c),
+            ctrl: false,
+        }
+    }
+
+    const fn ctrl(c: char) -> Self {
+        Self {
+            code: KeyCode::Char(c),
+            ctrl: true,
+        }
+    }
+
+    const fn named(code: KeyCode) -> Self {
+        Self { code, ctrl: false }
+    }
+
+    /// Parse `x`, `N`, `ctrl+d` (or `^d`, `Ctrl-D`), `shift+n`, `enter`,
+    /// `space`, `pgdn`, ...
+    pub fn parse(spec: &str) -> Result<Self> {
+        let spec = spec.trim();
+        let lower = spec.to_lowercase();
+        if let Some(rest) = ["shift+", "shift-"]
+            .iter()
+            .find_map(|prefix| lower.strip_prefix(prefix))
+        {
+            let mut chars = rest.chars();
+            return match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_ascii_alphabetic() => {
+                    Ok(Self::char(c.to_ascii_uppercase()))
+                }
+                _ => Err(anyhow!("'{}': Shift only combines with a letter", spec)),
+            };
+        }
+        let ctrl_char = ["ctrl+", "ctrl-", "c-", "^"]
+            .iter()
+            .find_map(|prefix| lower.strip_prefix(prefix))
+            .filter(|rest| !rest.is_empty());
+        if let Some(rest) = ctrl_char {
+            let mut chars = rest.chars();
+            return match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_ascii_alphabetic() => Ok(Self::ctrl(c)),
+                _ => Err(anyhow!("'{}': Ctrl only combines with a letter", spec)),
+            };
+        }
+        let mut chars = spec.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Ok(Self::char(c));
+        }
+        let code = match lower.as_str() {
+            "enter" | "return" => KeyCode::Enter,
+            "space" => KeyCode::Char(' '),
+            "tab" => KeyCode::Tab,
+            "backspace" => KeyCode::Backspace,
+            "esc" | "escape" => KeyCode::Esc,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "pgup" | "pageup" => KeyCode::PageUp,
+            "pgdn" | "pagedown" => KeyCode::PageDown,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "delete" | "del" => KeyCode::Delete,
+            f if f.starts_with('f') => match f[1..].parse::<u8>() {
+                Ok(n @ 1..=12) => KeyCode::F(n),
+                _ => bail!("Unknown key '{}'", spec),
+            },
+            _ => bail!("Unknown key '{}'", spec),
+        };
+        Ok(Self::named(code))
+    }
+
+    /// The key of a terminal event. Shift is part of the character.
+    pub fn from_event(event: &KeyEvent) -> Self {
+        Self {
+            code: event.code,
+            ctrl: event.modifiers.contains(KeyModifiers::CONTROL),
+        }
+    }
+
+    fn to_event(self) -> KeyEvent {
+        let modifiers = if self.ctrl {
+            KeyModifiers::CONTROL
+        } else {
+            KeyModifiers::NONE
+        };
+        KeyEvent::new(self.code, modifiers)
+    }
+
+    /// Short label for the footer: `y`, `^d`, `Enter`
+    pub fn label(&self) -> String {
+        let name = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::Backspace => "Backspace".to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            KeyCode::Up => "Up".to_string(),
+            KeyCode::Down => "Down".to_string(),
+            KeyCode::Left => "Left".to_string(),
+            KeyCode::Right => "Right".to_string(),
+            KeyCode::PageUp => "PgUp".to_string(),
+            KeyCode::PageDown => "PgDn".to_string(),
+            KeyCode::Home => "Home".to_string(),
+            KeyCode::End => "End".to_string(),
+            KeyCode::Delete => "Del".to_string(),
+            KeyCode::F(n) => format!("F{}", n),
+            other => format!("{:?}", other),
+        };
+        if self.ctrl {
+            format!("^{}", name)
+        } else {
+            name
+        }
+    }
+
+    /// Label for the help view: `<y>`, `<Ctrl+d>`
+    pub fn help_label(&self) -> String {
+        match self.label().strip_prefix('^') {
+            Some(rest) if self.ctrl => format!("<Ctrl+{}>", rest),
+            _ => format!("<{}>", self.label()),
+        }
+    }
+}
+
+/// Keys that keep their built-in meaning and cannot be given to an action:
+/// quit, namespace and saved view hotkeys, arrow/page keys, and the
+/// view-specific keys (search next/previous, log follow, pod logs, graph export).
+fn reserved_key(key: &Key) -> Option<&'static str> {
+    match (key.code, key.ctrl) {
+        (KeyCode::Char('c'), true) => Some("quit"),
+        (KeyCode::Char(c), false) if c.is_ascii_digit() => Some("namespace hotkeys"),
+        (KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown, _) => {
+            Some("navigation")
+        }
+        (KeyCode::Esc | KeyCode::Backspace, _) => Some("back"),
+        (KeyCode::F(_), _) => Some("saved view hotkeys"),
+        (KeyCode::Tab, _) => Some("autocomplete"),
+        (KeyCode::Char(c), false) if CONTEXT_KEYS.contains(&c) => Some("view-specific keys"),
+        _ => None,
+    }
+}
+
+/// Keys with a meaning only in some views (search `n`/`N`, log follow `G`,
+/// pod logs `l`, graph export `x`). Remapping an action away from one of
+/// these keeps them working.
+const CONTEXT_KEYS: &[char] = &['n', 'N', 'G', 'l', 'x'];
+
+/// Effective keybindings: the built-in keys with the `keymap` config applied.
+///
+/// Event handling matches the built-in keys, so [`translate`](Self::translate)
+/// rewrites a pressed key into the built-in key of the action it is bound to.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Keymap {
+    overrides: HashMap<Action, Key>,
+    plugins: Vec<PluginBinding>,
+}
+
+/// A plugin shortcut (see [`crate::plugins`])
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginBinding {
+    pub key: Key,
+    /// Plugin name under `plugins:`
+    pub name: String,
+    pub description: String,
+}
+
+impl Keymap {
+    /// Build from the `keymap` config (action name → key), rejecting unknown
+    /// actions and keys, reserved keys, and two actions sharing a key.
+    pub fn from_config(config: &HashMap<String, String>) -> Result<Self> {
+        let mut overrides = HashMap::new();
+        let mut names: Vec<&String> = config.keys().collect();
+        names.sort();
+        for name in names {
+            let action = Action::parse(name).ok_or_else(|| {
+                let known: Vec<&str> = Action::ALL.iter().map(|a| a.name()).collect();
+                anyhow!(
+                    "keymap: unknown action '{}' (use {})",
+                    name,
+                    known.join(", ")
+                )
+            })?;
+            let key = Key::parse(&config[name]).map_err(|e| anyhow!("keymap.{}: {}", name, e))?;
+            if key != action.default_key() {
+                if let Some(reason) = reserved_key(&key) {
+                    bail!(
+                        "keymap.{}: '{}' is reserved for {}",
+                        name,
+                        key.label(),
+                        reason
+                    );
+                }
+            }
+            overrides.insert(action, key);
+        }
+
+        let keymap = Self {
+            overrides,
+            plugins: Vec::new(),
+        };
+        let mut bound: HashMap<Key, Action> = HashMap::new();
+        for action in Action::ALL {
+            let key = keymap.key(*action);
+            if let Some(other) = bound.insert(key, *action) {
+                bail!(
+                    "keymap: '{}' is bound to both {} and {}",
+                    key.label(),
+                    other.name(),
+                    action.name()
+                );
+            }
+        }
+        Ok(keymap)
+    }
+
+    /// Add the plugin shortcuts, rejecting keys an action or another plugin
+    /// already uses and reserved keys.
+    pub fn with_plugins(mut self, plugins: &PluginsConfig) -> Result<Self> {
+        let mut names: Vec<&String> = plugins.keys().collect();
+        names.sort();
+        for name in names {
+            let plugin = &plugins[name];
+            let key = Key::parse(&plugin.shortcut)
+                .map_err(|e| anyhow!("plugins.{}.shortcut: {}", name, e))?;
+            if let Some(reason) = reserved_key(&key) {
+                bail!(
+                    "plugins.{}.shortcut: '{}' is reserved for {}",
+                    name,
+                    key.label(),
+                    reason
+                );
+            }
+            if let Some(action) = Action::ALL.iter().find(|a| self.key(**a) == key) {
+                bail!(
+                    "plugins.{}.shortcut: '{}' is bound to {}",
+                    name,
+                    key.label(),
+                    action.name()
+                );
+            }
+            if let Some(other) = self.plugins.iter().find(|p| p.key == key) {
+                bail!(
+                    "plugins.{}.shortcut: '{}' is also the shortcut of plugin {}",
+                    name,
+                    key.label(),
+                    other.name
+                );
+            }
+            self.plugins.push(PluginBinding {
+                key,
+                name: name.clone(),
+                description: plugin.description.clone().unwrap_or_else(|| name.clone()),
+            });
+        }
+        Ok(self)
+    }
+
+    /// Plugin shortcuts, in plugin name order
+    pub fn plugins(&self) -> &[PluginBinding] {
+        &self.plugins
+    }
+
+    /// The plugin bound to a pressed key
+    pub fn plugin_for(&self, event: &KeyEvent) -> Option<&PluginBinding> {
+        let pressed = Key::from_event(event);
+        self.plugins.iter().find(|plugin| plugin.key == pressed)
+    }
+
+    /// Effective key of an action
+    pub fn key(&self, action: Action) -> Key {
+        self.overrides
+            .get(&action)
+            .copied()
+            .unwrap_or_else(|| action.default_key())
+    }
+
+    /// Rewrite a pressed key into the built-in key of the action bound to it.
+    /// `None` means the key is a built-in key whose action moved elsewhere.
+    pub fn translate(&self, event: KeyEvent) -> Option<KeyEvent> {
+        if self.overrides.is_empty() {
+            return Some(event);
+        }
+        let pressed = Key::from_event(&event);
+        if self.plugins.iter().any(|plugin| plugin.key == pressed) {
+            return Some(event);
+        }
+        if let Some((action, _)) = self.overrides.iter().find(|(_, key)| **key == pressed) {
+            return Some(action.default_key().to_event());
+        }
+        let unbound = self
+            .overrides
+            .keys()
+            .any(|action| action.default_key() == pressed);
+        let keeps_context_meaning =
+            matches!(pressed.code, KeyCode::Char(c) if !pressed.ctrl && CONTEXT_KEYS.contains(&c));
+        if unbound && !keeps_context_meaning {
+            None
+        } else {
+            Some(event)
+        }
+    }
+
+    /// Footer label of an action's effective key
+    pub fn label(&self, action: Action) -> String {
+        self.key(action).label()
+    }
+
+    /// Help-view label of an action's effective key
+    pub fn help_label(&self, action: Action) -> String {
+        self.key(action).help_label()
+    }
+}
 
 /// Navigation command with keybinding and label
 #[derive(Debug, Clone)]
 pub struct NavigationCommand {
     /// The keybinding string (e.g., "j/k ", "y", "Enter")
-    pub key: &'static str,
+    pub key: String,
     /// The human-readable label (e.g., "Navigate", "YAML")
     pub label: &'static str,
 }
 
 impl NavigationCommand {
     /// Create a new navigation command
-    pub const fn new(key: &'static str, label: &'static str) -> Self {
-        Self { key, label }
+    pub fn new(key: impl Into<String>, label: &'static str) -> Self {
+        Self {
+            key: key.into(),
+            label,
+        }
     }
 }
 
-/// Get all default navigation commands in the order they should appear
-pub fn get_navigation_commands() -> Vec<NavigationCommand> {
-    // Order matches original footer.rs to maintain snapshot compatibility
+/// Get all navigation commands, with their effective keys, in the order they
+/// should appear
+pub fn get_navigation_commands(keymap: &Keymap) -> Vec<NavigationCommand> {
+    let key = |action| keymap.label(action);
+    // Order matches original footer.rs to maintain snapshot compatibility.
+    // The trailing space marks the navigation hint, rendered unstyled.
     vec![
-        NavigationCommand::new("j/k ", "Navigate"),
-        NavigationCommand::new("^f/^b", "PgDn/Up"),
-        NavigationCommand::new(":", "Command"),
-        NavigationCommand::new("Enter", "Details"),
-        NavigationCommand::new("/", "Filter/Search"),
-        NavigationCommand::new("N/A/T/S", "Sort"),
-        NavigationCommand::new("s", "Suspend"),
-        NavigationCommand::new("r", "Resume"),
-        NavigationCommand::new("R", "Reconcile"),
-        NavigationCommand::new("y", "YAML"),
-        NavigationCommand::new("d", "Describe"),
-        NavigationCommand::new("e", "Edit"),
-        NavigationCommand::new("f", "Favorite"),
-        NavigationCommand::new("g", "Graph"),
-        NavigationCommand::new("h", "History"),
-        NavigationCommand::new("t", "Trace"),
-        NavigationCommand::new("W", "Reconcile+Source"),
-        NavigationCommand::new("^d", "Delete"),
-        NavigationCommand::new("?", "Help"),
-        NavigationCommand::new("Esc/q", "Back"),
+        NavigationCommand::new(
+            format!("{}/{} ", key(Action::Down), key(Action::Up)),
+            "Navigate",
+        ),
+        NavigationCommand::new(
+            format!("{}/{}", key(Action::PageDown), key(Action::PageUp)),
+            "PgDn/Up",
+        ),
+        NavigationCommand::new(key(Action::Command), "Command"),
+        NavigationCommand::new(key(Action::Details), "Details"),
+        NavigationCommand::new(key(Action::Filter), "Filter/Search"),
+        NavigationCommand::new(
+            format!(
+                "{}/{}/{}/{}",
+                key(Action::SortName),
+                key(Action::SortAge),
+                key(Action::SortType),
+                key(Action::SortStatus)
+            ),
+            "Sort",
+        ),
+        NavigationCommand::new(key(Action::Suspend), "Suspend"),
+        NavigationCommand::new(key(Action::Resume), "Resume"),
+        NavigationCommand::new(key(Action::Reconcile), "Reconcile"),
+        NavigationCommand::new(key(Action::Yaml), "YAML"),
+        NavigationCommand::new(key(Action::Describe), "Describe"),
+        NavigationCommand::new(key(Action::Diff), "Diff"),
+        NavigationCommand::new(key(Action::Edit), "Edit"),
+        NavigationCommand::new(key(Action::Favorite), "Favorite"),
+        NavigationCommand::new(key(Action::Graph), "Graph"),
+        NavigationCommand::new(key(Action::History), "History"),
+        NavigationCommand::new(key(Action::Trace), "Trace"),
+        NavigationCommand::new(key(Action::ReconcileWithSource), "Reconcile+Source"),
+        NavigationCommand::new(key(Action::Delete), "Delete"),
+        NavigationCommand::new(key(Action::Help), "Help"),
+        NavigationCommand::new(format!("Esc/{}", key(Action::Back)), "Back"),
     ]
 }
 
 /// Get navigation commands for the connection error state
-pub fn get_connection_error_commands() -> Vec<NavigationCommand> {
+pub fn get_connection_error_commands(keymap: &Keymap) -> Vec<NavigationCommand> {
     vec![
-        NavigationCommand::new(":", "Command"),
-        NavigationCommand::new("?", "Help"),
-        NavigationCommand::new("Esc/q", "Quit"),
+        NavigationCommand::new(keymap.label(Action::Command), "Command"),
+        NavigationCommand::new(keymap.label(Action::Help), "Help"),
+        NavigationCommand::new(format!("Esc/{}", keymap.label(Action::Back)), "Quit"),
     ]
 }
 
@@ -64,7 +572,7 @@ pub fn navigation_commands_to_segments(
 ) -> Vec<(String, String, Color)> {
     commands
         .iter()
-        .map(|cmd| (cmd.key.to_string(), cmd.label.to_string(), color))
+        .map(|cmd| (cmd.key.clone(), cmd.label.to_string(), color))
         .collect()
 }
 
@@ -74,50 +582,51 @@ pub fn navigation_commands_to_segments_simple(
 ) -> Vec<(String, String)> {
     commands
         .iter()
-        .map(|cmd| (cmd.key.to_string(), cmd.label.to_string()))
+        .map(|cmd| (cmd.key.clone(), cmd.label.to_string()))
         .collect()
 }
 
 /// Get resource-specific commands for help view
 ///
-/// Returns commands that are resource-specific (excluding navigation, command mode, filter, help, quit)
-/// in the order they should appear in the help view, with help-specific descriptions.
-pub fn get_resource_help_commands() -> Vec<(&'static str, &'static str)> {
-    let commands = get_navigation_commands();
-    commands
-        .iter()
-        .filter_map(|cmd| {
-            // Map resource-specific commands to help format
-            match cmd.key {
-                "Enter" => Some(("<Enter>", "View resource details")),
-                "s" => Some(("<s>", "Suspend reconciliation")),
-                "r" => Some(("<r>", "Resume reconciliation")),
-                "R" => Some(("<R>", "Reconcile resource")),
-                "y" => Some(("<y>", "View YAML manifest")),
-                "d" => Some(("<d>", "Describe resource")),
-                "e" => Some(("<e>", "Edit resource in system editor")),
-                "f" => Some(("<f>", "Toggle favorite")),
-                "g" => Some(("<g>", "View resource graph")),
-                "h" => Some(("<h>", "View reconciliation history")),
-                "t" => Some(("<t>", "Trace ownership chain")),
-                "W" => Some(("<W>", "Reconcile with source")),
-                "^d" => Some(("<Ctrl+d>", "Delete resource")),
-                _ => None, // Skip non-resource commands (j/k, :, /, ?, Esc)
-            }
-        })
-        .collect()
+/// Returns the resource actions with their effective keys, in the order they
+/// should appear in the help view, with help-specific descriptions.
+pub fn get_resource_help_commands(keymap: &Keymap) -> Vec<(String, &'static str)> {
+    [
+        (Action::Details, "View resource details"),
+        (Action::Suspend, "Suspend reconciliation"),
+        (Action::Resume, "Resume reconciliation"),
+        (Action::Reconcile, "Reconcile resource"),
+        (Action::Yaml, "View YAML manifest"),
+        (Action::Describe, "Describe resource"),
+        (Action::Diff, "Diff live spec vs applied"),
+        (Action::Edit, "Edit resource in system editor"),
+        (Action::Favorite, "Toggle favorite"),
+        (Action::Graph, "View resource graph"),
+        (Action::History, "View reconciliation history"),
+        (Action::Trace, "Trace ownership chain"),
+        (Action::Release, "Inspect Helm release (HelmRelease)"),
+        (Action::ReconcileWithSource, "Reconcile with source"),
+        (Action::Delete, "Delete resource"),
+    ]
+    .into_iter()
+    .map(|(action, description)| (keymap.help_label(action), description))
+    .collect()
 }
 
 /// Calculate footer height based on navigation segments
 ///
 /// This function uses the exact same logic as `render_navigation_footer` in footer.rs
 /// to ensure consistent height calculations.
-pub fn calculate_footer_height(terminal_width: u16, has_connection_error: bool) -> u16 {
+pub fn calculate_footer_height(
+    terminal_width: u16,
+    has_connection_error: bool,
+    keymap: &Keymap,
+) -> u16 {
     // Get base navigation commands
     let nav_segments = if has_connection_error {
-        navigation_commands_to_segments_simple(&get_connection_error_commands())
+        navigation_commands_to_segments_simple(&get_connection_error_commands(keymap))
     } else {
-        navigation_commands_to_segments_simple(&get_navigation_commands())
+        navigation_commands_to_segments_simple(&get_navigation_commands(keymap))
     };
 
     let footer_available_width = terminal_width.saturating_sub(2); // Account for borders
@@ -126,7 +635,7 @@ pub fn calculate_footer_height(terminal_width: u16, has_connection_error: bool)
     let mut segment_lengths: Vec<usize> = Vec::new();
     for (idx, (key, label)) in nav_segments.iter().enumerate() {
         let separator_len = if idx > 0 { 3 } else { 0 }; // " | "
-        let segment_len = if key == "j/k " {
+        let segment_len = if key.ends_with(' ') {
             key.len() + label.len()
         } else {
             key.len() + 1 + label.len() // key + space + label
@@ -168,15 +677,126 @@ mod tests {
     #[test]
     fn test_calculate_footer_height_connection_error() {
         // Under a connection error state, the footer has fewer options and fits on one line.
-        let height = calculate_footer_height(80, true);
+        let height = calculate_footer_height(80, true, &Keymap::default());
         assert_eq!(height, 3); // 1 content line + 2 borders
     }
 
     #[test]
     fn test_calculate_footer_height_normal() {
         // Under normal circumstances, check that it calculates correctly.
-        let height = calculate_footer_height(80, false);
+        let height = calculate_footer_height(80, false, &Keymap::default());
         // Normally at 80 cols, the default commands (lots of them) wrap to 2 lines.
         assert_eq!(height, 4); // 2 content lines + 2 borders
     }
+
+    #[test]
+    fn keymap_translates_remapped_keys() {
+        let config = HashMap::from([
+            ("describe".to_string(), "i".to_string()),
+            ("delete".to_string(), "ctrl+x".to_string()),
+            ("sortName".to_string(), "o".to_string()),
+        ]);
+        let keymap = Keymap::from_config(&config).unwrap();
+        let press = |c: char| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+
+        assert_eq!(keymap.translate(press('i')), Some(press('d')));
+        assert_eq!(
+            keymap.translate(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::CONTROL)),
+            Some(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL))
+        );
+        // The old key is unbound, unrelated keys pass through
+        assert_eq!(keymap.translate(press('d')), None);
+        assert_eq!(keymap.translate(press('y')), Some(press('y')));
+        // N keeps its search meaning in text views
+        assert_eq!(keymap.translate(press('N')), Some(press('N')));
+
+        assert_eq!(keymap.label(Action::Delete), "^x");
+        assert_eq!(keymap.help_label(Action::Delete), "<Ctrl+x>");
+        let commands = get_navigation_commands(&keymap);
+        assert!(
+            commands
+                .iter()
+                .any(|c| c.key == "i" && c.label == "Describe")
+        );
+        assert!(
+            commands
+                .iter()
+                .any(|c| c.key == "o/A/T/S" && c.label == "Sort")
+        );
+    }
+
+    #[test]
+    fn keymap_rejects_conflicts_and_reserved_keys() {
+        let error = |pairs: &[(&str, &str)]| {
+            let config = pairs
+                .iter()
+                .map(|(a, k)| (a.to_string(), k.to_string()))
+                .collect();
+            Keymap::from_config(&config).unwrap_err().to_string()
+        };
+        assert!(error(&[("describe", "y")]).contains("'y' is bound to both"));
+        assert!(error(&[("yaml", "d"), ("describe", "d")]).contains("bound to both"));
+        assert!(error(&[("nope", "x")]).contains("unknown action 'nope'"));
+        assert!(error(&[("yaml", "3")]).contains("reserved for namespace hotkeys"));
+        assert!(error(&[("yaml", "ctrl+c")]).contains("reserved for quit"));
+        assert!(error(&[("yaml", "f2")]).contains("reserved for saved view hotkeys"));
+        assert!(error(&[("yaml", "ctrl+1")]).contains("Ctrl only combines"));
+        assert!(error(&[("yaml", "hyper")]).contains("Unknown key 'hyper'"));
+
+        // Swapping two keys is fine
+        let swapped = Keymap::from_config(&HashMap::from([
+            ("up".to_string(), "j".to_string()),
+            ("down".to_string(), "k".to_string()),
+        ]))
+        .unwrap();
+        let press = |c: char| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        assert_eq!(swapped.translate(press('j')), Some(press('k')));
+        assert_eq!(
+            get_navigation_commands(&Keymap::default())[0].key,
+            "j/k ",
+            "default footer hint is unchanged"
+        );
+    }
+
+    #[test]
+    fn plugin_shortcuts_must_be_free() {
+        let plugins = |shortcut: &str| {
+            crate::plugins::PluginsConfig::from([(
+                "diff".to_string(),
+                crate::plugins::PluginConfig {
+                    shortcut: shortcut.to_string(),
+                    command: "flux".to_string(),
+                    ..Default::default()
+                },
+            )])
+        };
+        let keymap = Keymap::default().with_plugins(&plugins("Shift-P")).unwrap();
+        let binding = keymap
+            .plugin_for(&KeyEvent::new(KeyCode::Char('P'), KeyModifiers::SHIFT))
+            .unwrap();
+        assert_eq!(
+            (binding.name.as_str(), binding.description.as_str()),
+            ("diff", "diff")
+        );
+
+        let error = |shortcut: &str| {
+            Keymap::default()
+                .with_plugins(&plugins(shortcut))
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(error("d").contains("is bound to describe"));
+        assert!(error("Ctrl-D").contains("is bound to delete"));
+        assert!(error("G").contains("reserved for view-specific keys"));
+
+        // A key freed by the keymap can go to a plugin
+        let remapped =
+            Keymap::from_config(&HashMap::from([("describe".to_string(), "i".to_string())]))
+                .unwrap()
+                .with_plugins(&plugins("d"))
+                .unwrap();
+        let d = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE);
+        assert_eq!(remapped.translate(d), Some(d));
+        assert!(remapped.plugin_for(&d).is_some());
+    }
 }
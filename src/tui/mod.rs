@@ -4,12 +4,13 @@
 //! Built with ratatui for a K9s-inspired experience.
 
 pub mod app;
-mod commands;
+pub(crate) mod commands;
 pub mod constants;
-mod keybindings;
+pub mod keybindings;
 pub mod operations;
 mod submenu;
 mod theme;
+mod timeline;
 pub mod trace;
 pub mod views;
 
@@ -32,14 +33,6 @@ use crate::models::FluxResourceKind;
 
 pub use crate::kube::fetch::fetch_resource_yaml;
 
-/// Extract Flux bundle version from deployment metadata labels
-/// Returns the app.kubernetes.io/version label if present (e.g., "v2.7.5")
-fn extract_flux_bundle_version(deployment_json: &serde_json::Value) -> Option<String> {
-    deployment_json["metadata"]["labels"]["app.kubernetes.io/version"]
-        .as_str()
-        .map(|s| s.to_string())
-}
-
 /// Parse Flux controller pod status from Kubernetes API JSON
 fn extract_controller_pod_info(
     pod_json: &serde_json::Value,
@@ -90,6 +83,35 @@ fn extract_controller_pod_info(
     })
 }
 
+/// Hand the terminal to a child process (editor, plugin): leave raw mode and
+/// the alternate screen.
+fn suspend_tui(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    enable_mouse: bool,
+) -> Result<()> {
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    if enable_mouse {
+        execute!(terminal.backend_mut(), DisableMouseCapture)?;
+    }
+    Ok(())
+}
+
+/// Take the terminal back after [`suspend_tui`]: restore raw mode and the
+/// alternate screen, and redraw from scratch.
+fn resume_tui(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    enable_mouse: bool,
+) -> Result<()> {
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    if enable_mouse {
+        execute!(io::stdout(), EnableMouseCapture)?;
+    }
+    terminal.clear()?;
+    Ok(())
+}
+
 /// Best-effort terminal restore: disable raw mode, leave the alternate screen,
 /// release mouse capture, and show the cursor. Safe to call multiple times and
 /// when the corresponding mode was never enabled. Used by the normal exit path,
@@ -113,6 +135,7 @@ pub async fn run_tui_with_async_init(
     debug: bool,
     kubeconfig_path: Option<&std::path::Path>,
     config_warning: Option<String>,
+    config_layers: Vec<std::path::PathBuf>,
     log_file: Option<std::path::PathBuf>,
 ) -> Result<()> {
     use crate::kube::health::ConnectionError;
@@ -158,11 +181,15 @@ pub async fn run_tui_with_async_init(
 
     // Record the log file path so the connection error screen can point to it.
     app.set_log_path(log_file);
+    app.set_config_layers(config_layers);
 
     // Initialize splash timer right before first render
     // This ensures the timer starts when TUI actually renders, not during async initialization
     app.init_splash_timer();
 
+    // Status-transition hooks from config, fired as Applied events update state
+    let hooks = crate::hooks::HookRunner::new(config.hooks.clone());
+
     // Bounded timeout for the startup connectivity probe (env-overridable).
     let connect_timeout =
         crate::kube::health::resolve_connect_timeout(config.connect_timeout_seconds);
@@ -356,8 +383,15 @@ pub async fn run_tui_with_async_init(
                                 tracing::debug!("Applied default resource filter: {}", filter);
                             }
 
-                            if let Some(ref warning) = config_warning {
-                                app.set_status_message((warning.clone(), true));
+                            // Config problems, including the keymap, plugins or
+                            // aliases sections the app had to ignore
+                            let warnings: Vec<String> = config_warning
+                                .iter()
+                                .chain(app.config_errors.iter())
+                                .cloned()
+                                .collect();
+                            if !warnings.is_empty() {
+                                app.set_status_message((warnings.join("; "), true));
                             }
 
                             app.set_connected();
@@ -376,7 +410,7 @@ pub async fn run_tui_with_async_init(
 
             terminal.draw(|f| app.render(f))?;
 
-            // Dispatch queued view fetches (YAML, describe, trace, graph).
+            // Dispatch queued view fetches (YAML, diff, describe, trace, graph).
             // Each spawned task reports back through its AsyncTask channel.
             if kube_initialized {
                 if let Some(client) = app.kube_client.clone() {
@@ -398,6 +432,54 @@ pub async fn run_tui_with_async_init(
                         });
                     }
 
+                    if let Some((rk, tx)) = app.async_state.diff.dispatch() {
+                        let client = client.clone();
+                        tokio::spawn(async move {
+                            tracing::debug!("Diffing live spec for {}", rk);
+                            let result = crate::kube::fetch_resource(
+                                &client,
+                                &rk.resource_type,
+                                &rk.namespace,
+                                &rk.name,
+                            )
+                            .await
+                            .and_then(|obj| crate::kube::diff::spec_diff(&obj));
+                            if let Err(ref e) = result {
+                                tracing::warn!("Failed to diff {}: {}", rk, e);
+                            }
+                            let _ = tx.send(result);
+                        });
+                    }
+
+                    if let Some((rk, tx)) = app.async_state.release.dispatch() {
+                        let client = client.clone();
+                        tokio::spawn(async move {
+                            tracing::debug!("Inspecting Helm release of {}", rk);
+                            let result = match crate::kube::fetch_resource(
+                                &client,
+                                &rk.resource_type,
+                                &rk.namespace,
+                                &rk.name,
+                            )
+                            .await
+                            {
+                                Ok(obj) => {
+                                    crate::kube::helm::fetch_release_inspection(
+                                        &client,
+                                        &obj,
+                                        &rk.namespace,
+                                    )
+                                    .await
+                                }
+                                Err(e) => Err(e),
+                            };
+                            if let Err(ref e) = result {
+                                tracing::warn!("Failed to inspect Helm release of {}: {}", rk, e);
+                            }
+                            let _ = tx.send(result);
+                        });
+                    }
+
                     if let Some((rk, tx)) = app.async_state.describe.dispatch() {
                         let client = client.clone();
                         tokio::spawn(async move {
@@ -543,11 +625,7 @@ pub async fn run_tui_with_async_init(
                         // If edit was pending, cancel it and return to origin view
                         if app.view_state.current_view == crate::tui::app::state::View::ResourceEdit
                         {
-                            app.async_state.edit_pending = None;
-                            app.async_state.edit_editor_launched = false;
-                            app.view_state.current_view = app.async_state.edit_return_view;
-                            app.async_state.edit_return_view =
-                                crate::tui::app::state::View::ResourceList;
+                            app.end_edit();
                         }
                         app.async_state.yaml.set_error();
                         app.set_status_message((format!("Failed to fetch YAML: {}", e), true));
@@ -555,6 +633,29 @@ pub async fn run_tui_with_async_init(
                 }
             }
 
+            if let Some(result) = app.async_state.diff.try_recv() {
+                match result {
+                    Ok(diff) => app.async_state.diff.set_result(diff),
+                    Err(e) => {
+                        app.async_state.diff.set_error();
+                        app.set_status_message((format!("Failed to diff: {}", e), true));
+                    }
+                }
+            }
+
+            if let Some(result) = app.async_state.release.try_recv() {
+                match result {
+                    Ok(inspection) => app.async_state.release.set_result(inspection),
+                    Err(e) => {
+                        app.async_state.release.set_error();
+                        app.set_status_message((
+                            format!("Failed to inspect Helm release: {:#}", e),
+                            true,
+                        ));
+                    }
+                }
+            }
+
             if let Some(result) = app.async_state.describe.try_recv() {
                 match result {
                     Ok(describe) => app.async_state.describe.set_result(describe),
@@ -663,35 +764,47 @@ pub async fn run_tui_with_async_init(
                 app.set_operation_result(result);
             }
 
-            // If we have a full YAML ready for editing, launch the system editor synchronously.
+            // Run a confirmed bulk operation over the marked resources
+            if let Some(req) = app.trigger_bulk_execution() {
+                tokio::spawn(async move {
+                    let result =
+                        crate::tui::app::run_bulk_operation(req.client, req.operation).await;
+                    let _ = req.tx.send(Ok(result));
+                });
+            }
+
+            if let Some(result) = app.async_state.bulk.try_recv() {
+                match result {
+                    Ok(result) => app.set_bulk_result(result),
+                    Err(e) => {
+                        app.async_state.bulk.set_error();
+                        app.set_status_message((format!("Bulk operation failed: {}", e), true));
+                    }
+                }
+            }
+
+            // If we have a full YAML ready for editing (or the user re-opened a kept edit
+            // from the preview), launch the system editor synchronously.
             // Must run on the main thread so we can properly suspend/resume the TUI terminal.
             if app.view_state.current_view == crate::tui::app::state::View::ResourceEdit
-                && app.async_state.edit_full_yaml.is_some()
+                && (app.async_state.edit_full_yaml.is_some()
+                    || app.async_state.edit_buffer.is_some())
+                && app.async_state.edit_preview.is_none()
                 && app.async_state.edit_save_pending.is_none()
                 && app.async_state.edit_save_result_rx.is_none()
                 && !app.async_state.edit_editor_launched
             {
                 app.async_state.edit_editor_launched = true;
 
-                if let Some(full_yaml_json) = app.async_state.edit_full_yaml.take() {
-                    // Present the same trimmed document the YAML view shows, not the
-                    // raw API response (no managedFields, no status, no explicit nulls).
-                    let edit_doc = crate::tui::views::prepare_edit_document(&full_yaml_json);
-                    let yaml_str =
-                        serde_yaml::to_string(&edit_doc).unwrap_or_else(|_| "{}".to_string());
+                if let Some(yaml_str) = app.take_edit_document() {
                     let editor_candidates =
                         crate::editor::editor_candidates(app.config.editor.as_deref());
                     let enable_mouse = app.config.ui.enable_mouse;
 
-                    // Suspend TUI: leave raw mode and alternate screen so the editor
-                    // can take over the terminal normally.
-                    disable_raw_mode()?;
-                    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-                    if enable_mouse {
-                        execute!(terminal.backend_mut(), DisableMouseCapture)?;
-                    }
+                    // Suspend TUI so the editor can take over the terminal normally.
+                    suspend_tui(&mut terminal, enable_mouse)?;
 
-                    let edit_result: anyhow::Result<Option<String>> = (|| {
+                    let edit_result: anyhow::Result<String> = (|| {
                         let mut tmp = tempfile::Builder::new().suffix(".yaml").tempfile()?;
                         use std::io::Write;
                         tmp.write_all(yaml_str.as_bytes())?;
@@ -699,49 +812,58 @@ pub async fn run_tui_with_async_init(
 
                         crate::editor::open_in_editor_with_fallback(&editor_candidates, &tmp_path)?;
 
-                        let edited = std::fs::read_to_string(&tmp_path)?;
-                        if edited.trim() == yaml_str.trim() {
-                            Ok(None)
-                        } else {
-                            Ok(Some(edited))
-                        }
+                        Ok(std::fs::read_to_string(&tmp_path)?)
                     })();
 
-                    // Re-enter TUI: restore raw mode and alternate screen.
-                    enable_raw_mode()?;
-                    execute!(io::stdout(), EnterAlternateScreen)?;
-                    if enable_mouse {
-                        execute!(io::stdout(), EnableMouseCapture)?;
-                    }
-                    terminal.clear()?;
+                    resume_tui(&mut terminal, enable_mouse)?;
 
                     match edit_result {
-                        Ok(Some(edited_yaml)) => {
-                            app.async_state.edit_save_pending = Some(edited_yaml);
-                        }
-                        Ok(None) => {
-                            app.set_status_message((
-                                "Edit cancelled (no changes)".to_string(),
-                                false,
-                            ));
-                            app.async_state.edit_pending = None;
-                            app.async_state.edit_editor_launched = false;
-                            app.view_state.current_view = app.async_state.edit_return_view;
-                            app.async_state.edit_return_view =
-                                crate::tui::app::state::View::ResourceList;
-                        }
+                        // Preview the change and dry-run it before anything is applied
+                        Ok(edited_yaml) => app.on_editor_closed(edited_yaml),
                         Err(e) => {
+                            app.end_edit();
                             app.set_status_message((format!("Editor error: {}", e), true));
-                            app.async_state.edit_pending = None;
-                            app.async_state.edit_editor_launched = false;
-                            app.view_state.current_view = app.async_state.edit_return_view;
-                            app.async_state.edit_return_view =
-                                crate::tui::app::state::View::ResourceList;
                         }
                     }
                 }
             }
 
+            // Foreground plugins get the terminal the same way the editor does
+            if let Some(run) = app.take_plugin_run() {
+                let enable_mouse = app.config.ui.enable_mouse;
+                suspend_tui(&mut terminal, enable_mouse)?;
+                let result = std::process::Command::new(&run.program)
+                    .args(&run.args)
+                    .status();
+                resume_tui(&mut terminal, enable_mouse)?;
+                app.on_plugin_finished(&run.name, result);
+            }
+
+            // Server-side dry-run of the edit shown in the preview
+            if kube_initialized {
+                if let (Some(client), Some(rk)) = (
+                    app.kube_client.clone(),
+                    app.async_state.edit_pending.clone(),
+                ) {
+                    if let Some((yaml, tx)) = app.async_state.edit_dry_run.dispatch() {
+                        tokio::spawn(async move {
+                            let result = crate::operations::dry_run_resource_yaml(
+                                &client,
+                                &rk.resource_type,
+                                &rk.namespace,
+                                &rk.name,
+                                &yaml,
+                            )
+                            .await;
+                            let _ = tx.send(result);
+                        });
+                    }
+                }
+            }
+            if let Some(result) = app.async_state.edit_dry_run.try_recv() {
+                app.set_edit_dry_run_result(result);
+            }
+
             // Trigger SSA apply if edited YAML is pending
             if kube_initialized {
                 if let Some(req) = app.trigger_edit_save() {
@@ -779,6 +901,56 @@ pub async fn run_tui_with_async_init(
                 });
             }
 
+            // Persist observed reconciliation history (throttled)
+            app.history.flush_if_due();
+
+            // Connect the `:clusters` sessions if requested. They run beside the
+            // main watcher, whose loop already fires the configured hooks.
+            if let Some((contexts, tx)) = app.async_state.clusters.dispatch() {
+                let session_config = crate::config::Config {
+                    hooks: Vec::new(),
+                    ..app.config.clone()
+                };
+                tokio::spawn(async move {
+                    let clusters = crate::services::MultiClusterSession::connect(
+                        &contexts,
+                        None,
+                        &session_config,
+                    )
+                    .await;
+                    let _ = tx.send(Ok(clusters));
+                });
+            }
+            if let Some(result) = app.async_state.clusters.try_recv() {
+                match result {
+                    Ok(clusters) => app.set_clusters(clusters),
+                    Err(e) => {
+                        app.async_state.clusters.set_error();
+                        app.set_status_message((
+                            format!("Failed to connect clusters: {}", e),
+                            true,
+                        ));
+                    }
+                }
+            }
+
+            // Resolve a `:ns <selector>` into the namespaces it matches
+            if let Some((selector, tx)) = app.async_state.namespace_selector.dispatch() {
+                let client = app.kube_client.clone();
+                tokio::spawn(async move {
+                    let result = match client {
+                        Some(client) => crate::kube::list_namespaces_matching(&client, &selector)
+                            .await
+                            .map(|namespaces| (selector, namespaces)),
+                        None => Err(anyhow::anyhow!("Not connected to a cluster")),
+                    };
+                    let _ = tx.send(result);
+                });
+            }
+            if let Some(result) = app.async_state.namespace_selector.try_recv() {
+                app.on_namespace_selector_resolved(result);
+            }
+
             // Handle context switch if pending
             if let Some(new_context) = app.take_pending_context_switch() {
                 kube_init_done = true;
@@ -920,7 +1092,7 @@ pub async fn run_tui_with_async_init(
 
             // Process watch events (non-blocking)
             // Update state from watch events
-            let mut events_processed = 0;
+            let mut events_processed = app.drain_cluster_events();
             // Track resource type count to detect when header layout needs recalculation
             let resource_type_count_before = app.state().count_by_type().len();
 
@@ -971,7 +1143,7 @@ pub async fn run_tui_with_async_init(
                             let annotations = crate::watcher::extract_annotations(&obj_json);
 
                             // Build reconciliation history
-                            let mut history = if let Some(existing) = existing_info {
+                            let mut history = if let Some(existing) = &existing_info {
                                 existing.reconciliation_history.clone()
                             } else {
                                 Vec::new()
@@ -989,25 +1161,28 @@ pub async fn run_tui_with_async_init(
                                 }
                             }
 
-                            app.state().upsert(
-                                key.clone(),
-                                crate::watcher::ResourceInfo {
-                                    name,
-                                    namespace: ns,
-                                    resource_type,
-                                    age: crate::watcher::extract_creation_timestamp(&obj_json),
-                                    suspended,
-                                    ready,
-                                    message,
-                                    revision,
-                                    labels,
-                                    annotations,
-                                    last_reconciled: reconciliation_event
-                                        .as_ref()
-                                        .map(|e| e.timestamp),
-                                    reconciliation_history: history,
-                                },
+                            let info = crate::watcher::ResourceInfo {
+                                name,
+                                namespace: ns,
+                                resource_type,
+                                age: crate::watcher::extract_creation_timestamp(&obj_json),
+                                suspended,
+                                ready,
+                                message,
+                                revision,
+                                labels,
+                                annotations,
+                                last_reconciled: reconciliation_event.as_ref().map(|e| e.timestamp),
+                                reconciliation_history: history,
+                            };
+                            hooks.fire(&app.context, existing_info.as_ref(), &info);
+                            app.history.observe(
+                                &key,
+                                &info.resource_type,
+                                &obj_json,
+                                chrono::Utc::now(),
                             );
+                            app.state().upsert(key.clone(), info);
                             // Store full object for detail view
                             app.resource_objects.insert(key.clone(), obj_json);
                         }
@@ -1021,6 +1196,8 @@ pub async fn run_tui_with_async_init(
                             // Errors are also shown in the TUI if needed
                             tracing::warn!("Watch event error: {}", msg);
                         }
+                        // The TUI renders incrementally; it never waits on a full snapshot
+                        crate::watcher::WatchEvent::InitialSyncDone(_) => {}
                         crate::watcher::WatchEvent::WatcherDegraded(name) => {
                             app.watch_degraded(name);
                         }
@@ -1039,7 +1216,7 @@ pub async fn run_tui_with_async_init(
                             app.controller_pods.remove_pod(&name);
                         }
                         crate::watcher::WatchEvent::DeploymentApplied(deployment_json) => {
-                            let version = extract_flux_bundle_version(&deployment_json);
+                            let version = crate::kube::check::flux_bundle_version(&deployment_json);
                             app.controller_pods.set_flux_bundle_version(version);
                         }
                         crate::watcher::WatchEvent::KubeEventApplied(event_json) => {
@@ -1101,6 +1278,7 @@ pub async fn run_tui_with_async_init(
     .await;
 
     tracing::debug!("TUI shutting down");
+    app.history.flush();
 
     // Restore terminal (best-effort, also covers the error path above)
     restore_terminal();
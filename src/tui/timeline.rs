@@ -0,0 +1,268 @@
+//! Cluster-wide reconciliation timeline
+//!
+//! Merges the observed reconciliation history of every watched resource (see
+//! [`HistoryStore`]) with Warning Kubernetes events into one chronological
+//! stream for the `:timeline` view, so the order in which sources,
+//! Kustomizations and HelmReleases moved during a rollout is visible at once.
+
+use chrono::{DateTime, Utc};
+
+use crate::kube::events::KubeEventInfo;
+use crate::services::HistoryStore;
+use crate::watcher::{ReconciliationEvent, ResourceKey};
+
+/// The scoped timeline, rebuilt only when its inputs change
+#[derive(Debug, Default)]
+pub struct TimelineCache {
+    /// History and event generations and the namespace scope it was built for
+    key: Option<(u64, u64, Option<String>, Vec<String>)>,
+    pub entries: Vec<TimelineEntry>,
+}
+
+impl TimelineCache {
+    /// Whether the entries were built for `key`.
+    pub fn is_current(&self, key: &(u64, u64, Option<String>, Vec<String>)) -> bool {
+        self.key.as_ref() == Some(key)
+    }
+
+    pub fn store(
+        &mut self,
+        key: (u64, u64, Option<String>, Vec<String>),
+        entries: Vec<TimelineEntry>,
+    ) {
+        self.key = Some(key);
+        self.entries = entries;
+    }
+
+    /// Force a rebuild (the history store was replaced).
+    pub fn invalidate(&mut self) {
+        self.key = None;
+    }
+}
+
+/// What happened at a point on the timeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimelineChange {
+    /// First seen Ready, or Ready again after an unknown status
+    Ready,
+    /// Became (or was first seen) not Ready
+    Failed,
+    /// Ready again after a failure
+    Recovered,
+    /// Moved to a new revision while staying Ready
+    Revision,
+    /// Ready status is unknown (reconciling, or no Ready condition)
+    Unknown,
+    /// A Warning Kubernetes event about the resource
+    Warning,
+}
+
+impl TimelineChange {
+    pub fn label(self) -> &'static str {
+        match self {
+            TimelineChange::Ready => "Ready",
+            TimelineChange::Failed => "Failed",
+            TimelineChange::Recovered => "Recovered",
+            TimelineChange::Revision => "Revision",
+            TimelineChange::Unknown => "Unknown",
+            TimelineChange::Warning => "Warning",
+        }
+    }
+
+    /// Whether the entry is a problem (drives error styling).
+    pub fn is_failure(self) -> bool {
+        matches!(self, TimelineChange::Failed | TimelineChange::Warning)
+    }
+}
+
+/// One row of the timeline
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineEntry {
+    pub time: DateTime<Utc>,
+    pub change: TimelineChange,
+    pub kind: String,
+    pub namespace: String,
+    pub name: String,
+    pub revision: Option<String>,
+    pub message: String,
+}
+
+impl TimelineEntry {
+    /// `Kind/name` label for the OBJECT column.
+    pub fn object_label(&self) -> String {
+        format!("{}/{}", self.kind, self.name)
+    }
+}
+
+/// Classify one resource's observed events (oldest first) into timeline
+/// entries.
+pub fn resource_entries(
+    resource: &ResourceKey,
+    events: &[ReconciliationEvent],
+) -> Vec<TimelineEntry> {
+    let mut previous: Option<&ReconciliationEvent> = None;
+    let mut entries = Vec::with_capacity(events.len());
+    for event in events {
+        let change = match (previous.map(|p| p.status.as_str()), event.status.as_str()) {
+            (_, "Failed") => TimelineChange::Failed,
+            (Some("Failed"), "Success") => TimelineChange::Recovered,
+            (Some("Success"), "Success") => TimelineChange::Revision,
+            (_, "Success") => TimelineChange::Ready,
+            _ => TimelineChange::Unknown,
+        };
+        entries.push(TimelineEntry {
+            time: event.timestamp,
+            change,
+            kind: resource.resource_type.clone(),
+            namespace: resource.namespace.clone(),
+            name: resource.name.clone(),
+            revision: event.revision.clone(),
+            message: event.message.clone().unwrap_or_default(),
+        });
+        previous = Some(event);
+    }
+    entries
+}
+
+/// The timeline entry for a Warning event; `None` for Normal events and
+/// events without a timestamp.
+pub fn warning_entry(event: &KubeEventInfo) -> Option<TimelineEntry> {
+    if !event.is_warning() {
+        return None;
+    }
+    Some(TimelineEntry {
+        time: event.last_seen?,
+        change: TimelineChange::Warning,
+        kind: event.involved_kind.clone(),
+        namespace: event.involved_namespace.clone(),
+        name: event.involved_name.clone(),
+        revision: None,
+        message: format!("{}: {}", event.reason, event.message),
+    })
+}
+
+/// Every resource's history plus the Warning events, newest first.
+pub fn build_timeline<'a>(
+    history: &HistoryStore,
+    events: impl IntoIterator<Item = &'a KubeEventInfo>,
+) -> Vec<TimelineEntry> {
+    let mut entries: Vec<TimelineEntry> = history
+        .iter()
+        .filter_map(|(key, events)| Some(resource_entries(&ResourceKey::parse(key)?, events)))
+        .flatten()
+        .chain(events.into_iter().filter_map(warning_entry))
+        .collect();
+    entries.sort_by(|a, b| {
+        b.time
+            .cmp(&a.time)
+            .then_with(|| a.kind.cmp(&b.kind))
+            .then_with(|| a.namespace.cmp(&b.namespace))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn event(minute: u32, status: &str, revision: &str) -> ReconciliationEvent {
+        ReconciliationEvent {
+            timestamp: Utc.with_ymd_and_hms(2026, 10, 16, 9, minute, 0).unwrap(),
+            revision: Some(revision.to_string()),
+            status: status.to_string(),
+            message: None,
+        }
+    }
+
+    #[test]
+    fn resource_entries_classify_transitions() {
+        let key = ResourceKey::new("Kustomization", "flux-system", "apps");
+        let entries = resource_entries(
+            &key,
+            &[
+                event(0, "Success", "main@sha1:a"),
+                event(1, "Success", "main@sha1:b"),
+                event(2, "Failed", "main@sha1:b"),
+                event(3, "Success", "main@sha1:c"),
+                event(4, "Unknown", "main@sha1:c"),
+                event(5, "Success", "main@sha1:c"),
+            ],
+        );
+        let changes: Vec<_> = entries.iter().map(|e| e.change).collect();
+        assert_eq!(
+            changes,
+            [
+                TimelineChange::Ready,
+                TimelineChange::Revision,
+                TimelineChange::Failed,
+                TimelineChange::Recovered,
+                TimelineChange::Unknown,
+                TimelineChange::Ready,
+            ]
+        );
+        assert_eq!(entries[0].object_label(), "Kustomization/apps");
+    }
+
+    #[test]
+    fn timeline_merges_resources_and_warnings_newest_first() {
+        let mut history = HistoryStore::in_memory();
+        let source = serde_json::json!({"status": {
+            "artifact": {"revision": "main@sha1:b", "lastUpdateTime": "2026-10-16T09:01:00Z"},
+            "conditions": [{"type": "Ready", "status": "True"}]
+        }});
+        let release = serde_json::json!({"status": {
+            "lastAttemptedRevision": "1.2.0",
+            "conditions": [{"type": "Ready", "status": "False",
+                "lastTransitionTime": "2026-10-16T09:03:00Z"}]
+        }});
+        history.observe(
+            "GitRepository:flux-system:repo",
+            "GitRepository",
+            &source,
+            Utc::now(),
+        );
+        history.observe(
+            "HelmRelease:apps:podinfo",
+            "HelmRelease",
+            &release,
+            Utc::now(),
+        );
+
+        let warning = KubeEventInfo::from_json(&serde_json::json!({
+            "metadata": {"uid": "w", "namespace": "apps"},
+            "involvedObject": {"kind": "HelmRelease", "name": "podinfo"},
+            "type": "Warning",
+            "reason": "UpgradeFailed",
+            "message": "timed out",
+            "lastTimestamp": "2026-10-16T09:02:00Z"
+        }))
+        .unwrap();
+        let normal = KubeEventInfo::from_json(&serde_json::json!({
+            "metadata": {"uid": "n", "namespace": "apps"},
+            "involvedObject": {"kind": "HelmRelease", "name": "podinfo"},
+            "type": "Normal",
+            "reason": "Progressing",
+            "message": "upgrading",
+            "lastTimestamp": "2026-10-16T09:02:30Z"
+        }))
+        .unwrap();
+
+        let timeline = build_timeline(&history, [&warning, &normal]);
+        let rows: Vec<_> = timeline
+            .iter()
+            .map(|e| (e.change, e.object_label()))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                (TimelineChange::Failed, "HelmRelease/podinfo".to_string()),
+                (TimelineChange::Warning, "HelmRelease/podinfo".to_string()),
+                (TimelineChange::Ready, "GitRepository/repo".to_string()),
+            ]
+        );
+        assert_eq!(timeline[1].message, "UpgradeFailed: timed out");
+        assert_eq!(timeline[0].revision.as_deref(), Some("1.2.0"));
+    }
+}
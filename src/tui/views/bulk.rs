@@ -0,0 +1,284 @@
+//! Bulk operation confirmation and summary rendering
+
+use crate::tui::app::state::{BulkOperation, BulkResult};
+use crate::tui::operations::OperationRegistry;
+use crate::tui::theme::Theme;
+use crate::watcher::ResourceKey;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Append one line per resource, eliding whatever does not fit in `budget`.
+fn push_resource_lines<'a>(
+    lines: &mut Vec<Line<'a>>,
+    items: impl ExactSizeIterator<Item = Line<'a>>,
+    budget: usize,
+    theme: &Theme,
+) {
+    let total = items.len();
+    // Keep one line for the "… and N more" note when the list is cut
+    let shown = if total > budget {
+        budget.saturating_sub(1)
+    } else {
+        total
+    };
+    lines.extend(items.take(shown));
+    if total > shown {
+        lines.push(Line::styled(
+            format!("  … and {} more", total - shown),
+            Style::default().fg(theme.text_secondary),
+        ));
+    }
+}
+
+fn resource_line(prefix: &str, rk: &ResourceKey) -> Line<'static> {
+    Line::from(format!("  {}{}", prefix, rk))
+}
+
+/// Render the aggregated confirmation for an operation over marked resources
+pub fn render_bulk_confirmation(
+    f: &mut Frame,
+    area: Rect,
+    pending: &BulkOperation,
+    operation_registry: &OperationRegistry,
+    theme: &Theme,
+) {
+    let name = operation_registry
+        .get_by_keybinding(pending.operation_key)
+        .map(|op| op.name())
+        .unwrap_or("Operation");
+    let key_style = |color| Style::default().fg(color).add_modifier(Modifier::BOLD);
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("⚠ ", theme.operation_warning_style()),
+            Span::styled("CONFIRMATION REQUIRED", theme.operation_warning_style()),
+        ]),
+        Line::from(""),
+        Line::from(format!(
+            "{} {} marked resources?",
+            name,
+            pending.targets.len()
+        )),
+        Line::from(vec![
+            Span::raw("Press "),
+            Span::styled("y", key_style(theme.operation_confirm)),
+            Span::raw(" to confirm, "),
+            Span::styled("n", key_style(theme.operation_cancel)),
+            Span::raw(" or "),
+            Span::styled("Esc", key_style(theme.operation_cancel)),
+            Span::raw(" to cancel"),
+        ]),
+        Line::from(""),
+    ];
+
+    // Borders, the header above and the skipped section below
+    let skipped_lines = if pending.skipped.is_empty() {
+        0
+    } else {
+        pending.skipped.len().min(3) + 2
+    };
+    let budget = (area.height as usize)
+        .saturating_sub(2 + lines.len() + skipped_lines)
+        .max(1);
+    push_resource_lines(
+        &mut lines,
+        pending.targets.iter().map(|rk| resource_line("", rk)),
+        budget,
+        theme,
+    );
+    if !pending.skipped.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(
+            format!("Skipped ({} not valid for this operation):", name),
+            Style::default().fg(theme.text_secondary),
+        ));
+        push_resource_lines(
+            &mut lines,
+            pending.skipped.iter().map(|rk| resource_line("", rk)),
+            3,
+            theme,
+        );
+    }
+
+    let block = Block::default()
+        .title("Confirm Bulk Operation")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.operation_warning));
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Render the per-item outcome of a bulk operation, failures first
+pub fn render_bulk_summary(
+    f: &mut Frame,
+    area: Rect,
+    result: &BulkResult,
+    operation_registry: &OperationRegistry,
+    theme: &Theme,
+) {
+    let name = operation_registry
+        .get_by_keybinding(result.operation_key)
+        .map(|op| op.name())
+        .unwrap_or("Operation");
+    let succeeded = result.succeeded_count();
+    let failed = result.outcomes.len() - succeeded;
+
+    let mut summary = vec![
+        Span::styled(
+            format!("{} succeeded", succeeded),
+            theme.operation_success_style(),
+        ),
+        Span::raw(", "),
+        Span::styled(
+            format!("{} failed", failed),
+            if failed > 0 {
+                theme.operation_error_style()
+            } else {
+                Style::default()
+            },
+        ),
+    ];
+    if !result.skipped.is_empty() {
+        summary.push(Span::raw(format!(", {} skipped", result.skipped.len())));
+    }
+    let mut lines = vec![
+        Line::from(summary),
+        Line::styled(
+            "Failed resources stay marked — press the operation key again to retry. Any key closes.",
+            Style::default().fg(theme.text_secondary),
+        ),
+        Line::from(""),
+    ];
+
+    let items: Vec<Line> = result
+        .outcomes
+        .iter()
+        .filter(|(_, outcome)| outcome.is_err())
+        .chain(
+            result
+                .outcomes
+                .iter()
+                .filter(|(_, outcome)| outcome.is_ok()),
+        )
+        .map(|(rk, outcome)| match outcome {
+            Ok(()) => Line::from(vec![
+                Span::styled("  ✓ ", Style::default().fg(theme.operation_success)),
+                Span::raw(rk.to_string()),
+            ]),
+            Err(e) => Line::from(vec![
+                Span::styled("  ✗ ", Style::default().fg(theme.operation_error)),
+                Span::raw(format!("{}: ", rk)),
+                Span::styled(e.clone(), Style::default().fg(theme.operation_error)),
+            ]),
+        })
+        .collect();
+    let budget = (area.height as usize)
+        .saturating_sub(2 + lines.len())
+        .max(1);
+    push_resource_lines(&mut lines, items.into_iter(), budget, theme);
+
+    let block = Block::default()
+        .title(format!("{}: bulk result", name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(if failed > 0 {
+            theme.operation_error
+        } else {
+            theme.operation_success
+        }));
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    fn buffer_text(terminal: &Terminal<TestBackend>) -> String {
+        let buffer = terminal.backend().buffer().clone();
+        let mut text = String::new();
+        for y in 0..buffer.area.height {
+            for x in 0..buffer.area.width {
+                text.push_str(buffer[(x, y)].symbol());
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    #[test]
+    fn confirmation_lists_targets_and_skipped() {
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        let pending = BulkOperation {
+            operation_key: 'W',
+            targets: vec![
+                ResourceKey::new("HelmRelease", "apps", "podinfo"),
+                ResourceKey::new("Kustomization", "flux-system", "apps"),
+            ],
+            skipped: vec![ResourceKey::new(
+                "GitRepository",
+                "flux-system",
+                "flux-system",
+            )],
+        };
+        terminal
+            .draw(|frame| {
+                render_bulk_confirmation(
+                    frame,
+                    frame.area(),
+                    &pending,
+                    &OperationRegistry::new(),
+                    &Theme::default(),
+                );
+            })
+            .unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Reconcile with Source 2 marked resources?"));
+        assert!(text.contains("apps/podinfo (HelmRelease)"));
+        assert!(text.contains("Skipped"));
+        assert!(text.contains("flux-system/flux-system (GitRepository)"));
+    }
+
+    #[test]
+    fn summary_lists_failures_first_and_elides_overflow() {
+        let mut terminal = Terminal::new(TestBackend::new(100, 10)).unwrap();
+        let mut outcomes: Vec<_> = (0..10)
+            .map(|i| {
+                (
+                    ResourceKey::new("HelmRelease", "apps", format!("ok-{}", i)),
+                    Ok(()),
+                )
+            })
+            .collect();
+        outcomes.push((
+            ResourceKey::new("HelmRelease", "apps", "broken"),
+            Err("context deadline exceeded".to_string()),
+        ));
+        let result = BulkResult {
+            operation_key: 'R',
+            outcomes,
+            skipped: vec![],
+        };
+        terminal
+            .draw(|frame| {
+                render_bulk_summary(
+                    frame,
+                    frame.area(),
+                    &result,
+                    &OperationRegistry::new(),
+                    &Theme::default(),
+                );
+            })
+            .unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Reconcile: bulk result"));
+        assert!(text.contains("10 succeeded, 1 failed"));
+        assert!(text.contains("✗ apps/broken (HelmRelease): context deadline exceeded"));
+        assert!(text.contains("… and 7 more"));
+    }
+}
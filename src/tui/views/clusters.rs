@@ -0,0 +1,425 @@
+//! Multi-cluster view rendering
+//!
+//! Renders the `:clusters` view: the resources of every connected context in
+//! one table with a CONTEXT column, and a header summarizing each cluster's
+//! health (unreachable contexts included).
+
+use crate::services::{ClusterHealth, ClusterResource};
+use crate::tui::theme::Theme;
+use crate::tui::views::get_status_indicator;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+};
+use std::cmp;
+
+/// Width taken by the "Clusters: " label that prefixes the health lines
+const HEALTH_PREFIX_WIDTH: usize = 10;
+
+/// One cluster's entry in the header: `prod-eu-1 ● 98.2% (120)`, or the
+/// context and `unreachable` when it failed to connect.
+fn health_entry(health: &ClusterHealth, theme: &Theme, no_icons: bool) -> (String, Color) {
+    if health.error.is_some() {
+        let icon = if no_icons { "[ERR]" } else { "✗" };
+        return (
+            format!("{} {} unreachable", health.context, icon),
+            theme.status_error,
+        );
+    }
+    let percentage = health.percentage();
+    let (icon, color) = if percentage >= 90.0 {
+        (if no_icons { "[OK]" } else { "●" }, theme.status_ready)
+    } else if percentage >= 70.0 {
+        (if no_icons { "[WARN]" } else { "⚠" }, theme.status_unknown)
+    } else {
+        (if no_icons { "[ERR]" } else { "✗" }, theme.status_error)
+    };
+    (
+        format!(
+            "{} {} {:.1}% ({})",
+            health.context, icon, percentage, health.total
+        ),
+        color,
+    )
+}
+
+/// Wrap the per-cluster entries into lines no wider than `width`.
+fn wrap_health_entries(
+    health: &[ClusterHealth],
+    width: usize,
+    theme: &Theme,
+    no_icons: bool,
+) -> Vec<Vec<(String, Color)>> {
+    let mut lines: Vec<Vec<(String, Color)>> = vec![Vec::new()];
+    let mut current_len = 0;
+    for entry in health.iter().map(|h| health_entry(h, theme, no_icons)) {
+        let len = entry.0.chars().count() + 2;
+        if current_len + len > width && current_len > 0 {
+            lines.push(Vec::new());
+            current_len = 0;
+        }
+        current_len += len;
+        if let Some(line) = lines.last_mut() {
+            line.push(entry);
+        }
+    }
+    lines
+}
+
+/// Content lines of the `:clusters` header (borders excluded), so the
+/// layout can size the header before rendering it.
+pub fn clusters_header_lines(
+    health: &[ClusterHealth],
+    has_filter: bool,
+    width: u16,
+    no_icons: bool,
+) -> u16 {
+    let available = (width as usize).saturating_sub(HEALTH_PREFIX_WIDTH + 2);
+    let health_lines = wrap_health_entries(health, available, &Theme::default(), no_icons).len();
+    // Summary line, optional filter line, then the per-cluster health
+    1 + u16::from(has_filter) + health_lines as u16
+}
+
+/// Render the `:clusters` header: cluster count and namespace scope, the
+/// active filters, and each cluster's health.
+pub fn render_clusters_header(
+    f: &mut Frame,
+    area: Rect,
+    health: &[ClusterHealth],
+    namespace: &Option<String>,
+    filter_parts: &[String],
+    shown: usize,
+    total: usize,
+    read_only: bool,
+    theme: &Theme,
+    no_icons: bool,
+) {
+    let unreachable = health.iter().filter(|h| h.error.is_some()).count();
+    let namespace_display = namespace.as_deref().unwrap_or("all");
+
+    let mut summary = vec![
+        Span::styled("Clusters: ", Style::default().fg(theme.header_resources)),
+        Span::styled(
+            health.len().to_string(),
+            Style::default()
+                .fg(theme.header_total)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ];
+    if unreachable > 0 {
+        summary.push(Span::styled(
+            format!(" ({} unreachable)", unreachable),
+            Style::default().fg(theme.status_error),
+        ));
+    }
+    summary.extend([
+        Span::raw("  "),
+        Span::styled("Namespace: ", Style::default().fg(theme.header_resources)),
+        Span::styled(
+            namespace_display,
+            theme.header_namespace_style(namespace_display == "all"),
+        ),
+        Span::raw("  "),
+        Span::styled(
+            "Total Resources: ",
+            Style::default().fg(theme.header_resources),
+        ),
+        Span::styled(
+            total.to_string(),
+            Style::default()
+                .fg(theme.header_total)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]);
+    if read_only {
+        summary.push(Span::raw("  "));
+        summary.push(Span::styled(
+            if no_icons {
+                "[READONLY]"
+            } else {
+                "🔒 READONLY"
+            },
+            Style::default()
+                .fg(theme.status_error)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    let mut lines = vec![Line::from(summary)];
+
+    if !filter_parts.is_empty() {
+        let filter_icon = if no_icons { "[FILTER]" } else { "⚠ Filter: " };
+        lines.push(Line::from(vec![
+            Span::styled(
+                filter_icon,
+                Style::default()
+                    .fg(theme.header_filter)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                filter_parts.join(" + "),
+                Style::default().fg(theme.header_filter),
+            ),
+            Span::raw("  |  "),
+            Span::styled(
+                format!("Showing {} of {}", shown, total),
+                Style::default().fg(theme.header_filter),
+            ),
+        ]));
+    }
+
+    let available = (area.width as usize).saturating_sub(HEALTH_PREFIX_WIDTH + 2);
+    for (idx, entries) in wrap_health_entries(health, available, theme, no_icons)
+        .into_iter()
+        .enumerate()
+    {
+        let prefix = if idx == 0 { "Health:   " } else { "          " };
+        let mut spans = vec![Span::styled(
+            prefix,
+            Style::default().fg(theme.header_resources),
+        )];
+        for (text, color) in entries {
+            spans.push(Span::styled(text, Style::default().fg(color)));
+            spans.push(Span::raw("  "));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    let header = Paragraph::new(lines).block(Block::default().borders(Borders::ALL));
+    f.render_widget(header, area);
+}
+
+/// Render the merged resource table of the `:clusters` view.
+///
+/// `rows` is pre-filtered and sorted; `total` is the unfiltered count across
+/// all clusters.
+pub fn render_cluster_list(
+    f: &mut Frame,
+    area: Rect,
+    rows: &[ClusterResource],
+    total: usize,
+    selected_index: usize,
+    scroll_offset: &mut usize,
+    theme: &Theme,
+    no_icons: bool,
+) {
+    let visible_height = (area.height as usize).saturating_sub(2);
+    const SCROLL_BUFFER: usize = 2; // Keep 2 rows buffer before scrolling
+
+    crate::tui::views::helpers::update_scroll_offset(
+        selected_index,
+        visible_height,
+        scroll_offset,
+        SCROLL_BUFFER,
+    );
+
+    let mut title = if rows.len() == total {
+        format!("Clusters ({})", total)
+    } else {
+        format!("Clusters ({}/{})", rows.len(), total)
+    };
+
+    if rows.is_empty() {
+        let (message, hint) = if total == 0 {
+            (
+                "No resources found",
+                "Waiting for the clusters to report resources...",
+            )
+        } else {
+            (
+                "No resources match the filter",
+                "Press / to change the filter",
+            )
+        };
+        crate::tui::views::helpers::render_empty_state(f, area, &title, message, hint, theme);
+        return;
+    }
+
+    let valid_selected = cmp::min(selected_index, rows.len().saturating_sub(1));
+
+    let header = Row::new(vec![
+        "STATUS",
+        "CONTEXT",
+        "NAMESPACE",
+        "NAME",
+        "TYPE",
+        "READY",
+        "AGE",
+        "MESSAGE",
+    ])
+    .style(
+        Style::default()
+            .fg(theme.table_header)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let table_rows: Vec<Row> = rows
+        .iter()
+        .skip(*scroll_offset)
+        .take(visible_height)
+        .enumerate()
+        .map(|(idx, row)| {
+            let r = &row.info;
+            let style = if *scroll_offset + idx == valid_selected {
+                theme.table_selected_style()
+            } else {
+                Style::default()
+            };
+            let (status_indicator, status_color) =
+                get_status_indicator(r.ready, r.suspended, theme, no_icons);
+            let message = r.message.as_deref().unwrap_or("-");
+            Row::new(vec![
+                Cell::from(Span::styled(
+                    status_indicator,
+                    Style::default().fg(status_color),
+                )),
+                Cell::from(Span::styled(
+                    row.context.clone(),
+                    Style::default().fg(theme.header_context),
+                )),
+                Cell::from(r.namespace.clone()),
+                Cell::from(r.name.clone()),
+                Cell::from(r.resource_type.clone()),
+                Cell::from(crate::tui::views::helpers::format_bool_option(r.ready)),
+                Cell::from(crate::tui::views::helpers::format_age(r.age)),
+                Cell::from(crate::tui::views::helpers::truncate_message(message, 40)),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let constraints = [
+        Constraint::Length(7),      // STATUS
+        Constraint::Min(16),        // CONTEXT
+        Constraint::Min(15),        // NAMESPACE
+        Constraint::Min(30),        // NAME
+        Constraint::Min(20),        // TYPE
+        Constraint::Length(6),      // READY
+        Constraint::Length(7),      // AGE
+        Constraint::Percentage(35), // MESSAGE
+    ];
+
+    if rows.len() > visible_height {
+        let first = *scroll_offset + 1;
+        let last = cmp::min(*scroll_offset + visible_height, rows.len());
+        title = format!("{} ─── {}-{}", title, first, last);
+    }
+
+    let table = Table::new(table_rows, constraints)
+        .header(header)
+        .block(Block::default().title(title).borders(Borders::ALL));
+    f.render_widget(table, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::watcher::ResourceInfo;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    fn row(context: &str, name: &str, ready: Option<bool>) -> ClusterResource {
+        ClusterResource {
+            context: context.to_string(),
+            info: ResourceInfo {
+                name: name.to_string(),
+                namespace: "apps".to_string(),
+                resource_type: "HelmRelease".to_string(),
+                age: None,
+                suspended: Some(false),
+                ready,
+                message: None,
+                revision: None,
+                labels: Default::default(),
+                annotations: Default::default(),
+                last_reconciled: None,
+                reconciliation_history: vec![],
+            },
+        }
+    }
+
+    fn buffer_text(terminal: &Terminal<TestBackend>) -> String {
+        let buffer = terminal.backend().buffer();
+        let mut text = String::new();
+        for y in 0..buffer.area.height {
+            for x in 0..buffer.area.width {
+                text.push_str(buffer[(x, y)].symbol());
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    #[test]
+    fn list_shows_context_column() {
+        let rows = [
+            row("prod-eu-1", "podinfo", Some(true)),
+            row("prod-us-1", "podinfo", Some(false)),
+        ];
+        let mut terminal = Terminal::new(TestBackend::new(160, 8)).unwrap();
+        let mut scroll_offset = 0;
+        terminal
+            .draw(|f| {
+                render_cluster_list(
+                    f,
+                    f.area(),
+                    &rows,
+                    3,
+                    0,
+                    &mut scroll_offset,
+                    &Theme::default(),
+                    false,
+                )
+            })
+            .unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("CONTEXT"));
+        assert!(text.contains("prod-eu-1"));
+        assert!(text.contains("prod-us-1"));
+        assert!(text.contains("Clusters (2/3)"));
+    }
+
+    #[test]
+    fn header_summarizes_each_cluster() {
+        let health = [
+            ClusterHealth {
+                context: "prod-eu-1".to_string(),
+                total: 4,
+                healthy: 3,
+                failed: 1,
+                ..ClusterHealth::default()
+            },
+            ClusterHealth {
+                context: "staging".to_string(),
+                error: Some("connection refused".to_string()),
+                ..ClusterHealth::default()
+            },
+        ];
+        assert_eq!(clusters_header_lines(&health, false, 160, true), 2);
+        assert_eq!(clusters_header_lines(&health, true, 30, true), 4);
+
+        let mut terminal = Terminal::new(TestBackend::new(160, 6)).unwrap();
+        terminal
+            .draw(|f| {
+                render_clusters_header(
+                    f,
+                    f.area(),
+                    &health,
+                    &None,
+                    &[],
+                    4,
+                    4,
+                    false,
+                    &Theme::default(),
+                    true,
+                )
+            })
+            .unwrap();
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Clusters: 2 (1 unreachable)"));
+        assert!(text.contains("prod-eu-1 [WARN] 75.0% (4)"));
+        assert!(text.contains("staging [ERR] unreachable"));
+    }
+}
@@ -3,7 +3,7 @@
 use crate::tui::app::PendingOperation;
 use crate::tui::operations::OperationRegistry;
 use crate::tui::theme::Theme;
-use crate::watcher::{ResourceState, resource_key};
+use crate::watcher::ResourceInfo;
 use ratatui::{
     Frame,
     layout::Rect,
@@ -12,24 +12,22 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 
-/// Render the confirmation dialog
+/// Render the confirmation dialog for the operation on `resource` (looked up
+/// by the caller in the state of the operation's context)
 pub fn render_confirmation(
     f: &mut Frame,
     area: Rect,
     confirmation_pending: &PendingOperation,
+    resource: Option<&ResourceInfo>,
     operation_registry: &OperationRegistry,
-    state: &ResourceState,
     theme: &Theme,
 ) {
-    let resource_type = &confirmation_pending.resource_type;
-    let namespace = &confirmation_pending.namespace;
-    let name = &confirmation_pending.name;
     let op_key = confirmation_pending.operation_key;
 
     if let Some(operation) = operation_registry.get_by_keybinding(op_key) {
-        if let Some(resource) = state.get(&resource_key(namespace, name, resource_type)) {
-            let msg = operation.confirmation_message(&resource);
-            let lines = vec![
+        if let Some(resource) = resource {
+            let msg = operation.confirmation_message(resource);
+            let mut lines = vec![
                 Line::from(""),
                 Line::from(vec![
                     ratatui::text::Span::styled("⚠ ", theme.operation_warning_style()),
@@ -41,6 +39,15 @@ pub fn render_confirmation(
                 Line::from(""),
                 Line::from(msg.clone()),
                 Line::from(""),
+            ];
+            if let Some(context) = &confirmation_pending.context {
+                lines.push(Line::from(vec![
+                    ratatui::text::Span::raw("Context: "),
+                    ratatui::text::Span::styled(context.as_str(), theme.header_context_style()),
+                ]));
+                lines.push(Line::from(""));
+            }
+            lines.extend([
                 Line::from(vec![
                     ratatui::text::Span::raw("Press "),
                     ratatui::text::Span::styled(
@@ -82,7 +89,7 @@ pub fn render_confirmation(
                     ),
                     ratatui::text::Span::raw(" to cancel"),
                 ]),
-            ];
+            ]);
 
             let block = Block::default()
                 .title("Confirm Operation")
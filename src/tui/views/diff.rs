@@ -0,0 +1,186 @@
+//! Live-vs-applied diff view rendering
+
+use crate::kube::diff::{DiffLineKind, SpecDiff};
+use crate::tui::app::state::TextSearchState;
+use crate::tui::theme::Theme;
+use crate::tui::views::yaml::{apply_text_search, decorate_title_with_search, find_match_lines};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::Line,
+    widgets::Paragraph,
+};
+
+/// Style of a unified diff line: additions green, removals red.
+pub(crate) fn diff_line_style(kind: DiffLineKind, theme: &Theme) -> Style {
+    match kind {
+        DiffLineKind::Header => Style::default()
+            .fg(theme.text_label)
+            .add_modifier(Modifier::BOLD),
+        DiffLineKind::Hunk => Style::default().fg(theme.text_label),
+        DiffLineKind::Added => Style::default().fg(theme.status_ready),
+        DiffLineKind::Removed => Style::default().fg(theme.status_error),
+        DiffLineKind::Context => Style::default().fg(theme.text_primary),
+    }
+}
+
+/// Render the unified diff of a resource's live spec against its applied
+/// baseline: additions green, removals red.
+pub fn render_resource_diff(
+    f: &mut Frame,
+    area: Rect,
+    selected_resource_key: &Option<String>,
+    diff: Option<&SpecDiff>,
+    loading: bool,
+    scroll_offset: &mut usize,
+    search: &mut TextSearchState,
+    theme: &Theme,
+) {
+    if loading {
+        crate::tui::views::helpers::render_loading_state(
+            f,
+            area,
+            "Diff",
+            "Fetching live object...",
+            theme,
+        );
+        return;
+    }
+
+    let Some(diff) = diff else {
+        crate::tui::views::helpers::render_empty_state(
+            f,
+            area,
+            "Diff",
+            "No diff available",
+            "Select a resource and press 'D' to diff its live spec",
+            theme,
+        );
+        return;
+    };
+
+    let mut title = match selected_resource_key {
+        Some(key) => format!("Diff: {}", key),
+        None => "Diff".to_string(),
+    };
+
+    if !diff.has_drift() {
+        title.push_str(" (no drift)");
+        crate::tui::views::helpers::render_empty_state(
+            f,
+            area,
+            &title,
+            &format!("Live spec matches the {}", diff.baseline.label()),
+            "Hand edits (e.g. with 'e') show up here until the next reconcile",
+            theme,
+        );
+        return;
+    }
+
+    let texts: Vec<&str> = diff.lines.iter().map(|line| line.text.as_str()).collect();
+    let visible_height = (area.height as usize).saturating_sub(2);
+    let max_scroll = texts.len().saturating_sub(visible_height);
+
+    let match_lines = find_match_lines(&texts, &search.query);
+    let current_match_line = apply_text_search(search, &match_lines, scroll_offset, visible_height);
+    decorate_title_with_search(&mut title, search);
+    *scroll_offset = (*scroll_offset).min(max_scroll);
+
+    let visible_lines: Vec<Line> = diff
+        .lines
+        .iter()
+        .enumerate()
+        .skip(*scroll_offset)
+        .take(visible_height)
+        .map(|(idx, line)| {
+            let style = diff_line_style(line.kind, theme);
+            let styled = Line::styled(line.text.clone(), style);
+            if Some(idx) == current_match_line {
+                styled.style(style.add_modifier(Modifier::REVERSED))
+            } else if match_lines.binary_search(&idx).is_ok() {
+                styled.style(style.add_modifier(Modifier::UNDERLINED))
+            } else {
+                styled
+            }
+        })
+        .collect();
+
+    if texts.len() > visible_height {
+        let first = *scroll_offset + 1;
+        let last = (*scroll_offset + visible_height).min(texts.len());
+        title.push_str(&format!(" [{}-{}/{}]", first, last, texts.len()));
+    }
+
+    let block = crate::tui::views::helpers::create_themed_block(&title, theme);
+    let paragraph = Paragraph::new(visible_lines).block(block);
+    f.render_widget(paragraph, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kube::diff::{DiffBaseline, DiffLine};
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    fn render_to_text(diff: &SpecDiff) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(80, 10)).unwrap();
+        let mut scroll_offset = 0;
+        let mut search = TextSearchState::default();
+        terminal
+            .draw(|frame| {
+                render_resource_diff(
+                    frame,
+                    frame.area(),
+                    &Some("HelmRelease:apps:podinfo".to_string()),
+                    Some(diff),
+                    false,
+                    &mut scroll_offset,
+                    &mut search,
+                    &Theme::default(),
+                );
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        let mut text = String::new();
+        for y in 0..buffer.area.height {
+            for x in 0..buffer.area.width {
+                text.push_str(buffer[(x, y)].symbol());
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    #[test]
+    fn renders_diff_lines_and_no_drift_state() {
+        let line = |kind, text: &str| DiffLine {
+            kind,
+            text: text.to_string(),
+        };
+        let diff = SpecDiff {
+            baseline: DiffBaseline::LastApplied,
+            lines: vec![
+                line(DiffLineKind::Header, "--- last-applied-configuration"),
+                line(DiffLineKind::Header, "+++ live"),
+                line(DiffLineKind::Hunk, "@@ -1,2 +1,2 @@"),
+                line(DiffLineKind::Context, " spec:"),
+                line(DiffLineKind::Removed, "-  interval: 10m"),
+                line(DiffLineKind::Added, "+  interval: 1m"),
+            ],
+        };
+        let text = render_to_text(&diff);
+        assert!(text.contains("Diff: HelmRelease:apps:podinfo"));
+        assert!(text.contains("-  interval: 10m"));
+        assert!(text.contains("+  interval: 1m"));
+
+        let clean = SpecDiff {
+            baseline: DiffBaseline::LastApplied,
+            lines: Vec::new(),
+        };
+        let text = render_to_text(&clean);
+        assert!(text.contains("(no drift)"));
+        assert!(text.contains("Live spec matches the last-applied-configuration"));
+    }
+}
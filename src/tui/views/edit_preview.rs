@@ -0,0 +1,172 @@
+//! Edit preview rendering
+//!
+//! Shown after the external editor closes: the diff of the edit against the
+//! fetched document and the server-side dry-run outcome, before anything is
+//! applied.
+
+use crate::tui::app::state::EditPreview;
+use crate::tui::keybindings::{Action, Keymap};
+use crate::tui::theme::Theme;
+use crate::tui::views::diff::diff_line_style;
+use crate::watcher::ResourceKey;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Wrap},
+};
+
+/// Render the edit preview: dry-run status, key hints, then the diff.
+pub fn render_edit_preview(
+    f: &mut Frame,
+    area: Rect,
+    resource: Option<&ResourceKey>,
+    preview: &EditPreview,
+    scroll_offset: &mut usize,
+    // `y`/`e` in the preview follow remaps of the YAML and edit keys
+    keymap: &Keymap,
+    theme: &Theme,
+) {
+    let title = match resource {
+        Some(rk) => format!(
+            "Edit preview: {}/{} ({})",
+            rk.resource_type, rk.name, rk.namespace
+        ),
+        None => "Edit preview".to_string(),
+    };
+
+    let key_style = Style::default()
+        .fg(theme.text_label)
+        .add_modifier(Modifier::BOLD);
+    let mut lines: Vec<Line> = match &preview.dry_run {
+        None => vec![Line::styled(
+            "Server-side dry-run running...",
+            Style::default().fg(theme.text_secondary),
+        )],
+        Some(Ok(())) => vec![Line::styled(
+            "✓ Server-side dry-run passed",
+            Style::default().fg(theme.status_ready),
+        )],
+        Some(Err(message)) => {
+            let mut lines = vec![Line::styled(
+                "✗ Server-side dry-run failed:",
+                Style::default()
+                    .fg(theme.status_error)
+                    .add_modifier(Modifier::BOLD),
+            )];
+            lines.extend(
+                message
+                    .lines()
+                    .map(|line| Line::styled(line.to_string(), theme.status_error)),
+            );
+            lines
+        }
+    };
+    let mut hints = Vec::new();
+    if preview.can_apply() {
+        hints.push(Span::styled(keymap.label(Action::Yaml), key_style));
+        hints.push(Span::raw(" apply   "));
+    }
+    hints.extend([
+        Span::styled(keymap.label(Action::Edit), key_style),
+        Span::raw(" edit again   "),
+        Span::styled("Esc", key_style),
+        Span::raw(" discard"),
+    ]);
+    lines.push(Line::from(hints));
+    lines.push(Line::from(""));
+    lines.extend(
+        preview
+            .lines
+            .iter()
+            .map(|line| Line::styled(line.text.clone(), diff_line_style(line.kind, theme))),
+    );
+
+    *scroll_offset = (*scroll_offset).min(lines.len().saturating_sub(1));
+    let block = crate::tui::views::helpers::create_themed_block(&title, theme);
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        // Keep leading spaces: YAML indentation is meaningful in the diff
+        .wrap(Wrap { trim: false })
+        .scroll((*scroll_offset as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kube::diff::{DiffLine, DiffLineKind};
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    fn render_to_text(preview: &EditPreview, keymap: &Keymap) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(80, 12)).unwrap();
+        let rk = ResourceKey::new("Kustomization", "flux-system", "apps");
+        let mut scroll_offset = 0;
+        terminal
+            .draw(|frame| {
+                render_edit_preview(
+                    frame,
+                    frame.area(),
+                    Some(&rk),
+                    preview,
+                    &mut scroll_offset,
+                    keymap,
+                    &Theme::default(),
+                );
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        let mut text = String::new();
+        for y in 0..buffer.area.height {
+            for x in 0..buffer.area.width {
+                text.push_str(buffer[(x, y)].symbol());
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    #[test]
+    fn preview_shows_dry_run_outcome_and_diff() {
+        let mut preview = EditPreview {
+            lines: vec![
+                DiffLine {
+                    kind: DiffLineKind::Removed,
+                    text: "-  interval: 10m".to_string(),
+                },
+                DiffLine {
+                    kind: DiffLineKind::Added,
+                    text: "+  interval: 1m".to_string(),
+                },
+            ],
+            dry_run: Some(Ok(())),
+        };
+        let text = render_to_text(&preview, &Keymap::default());
+        assert!(text.contains("Edit preview: Kustomization/apps (flux-system)"));
+        assert!(text.contains("dry-run passed"));
+        assert!(text.contains("y apply"));
+        assert!(text.contains("+  interval: 1m"));
+
+        preview.dry_run = Some(Err(
+            "admission webhook \"validate\" denied the request".to_string()
+        ));
+        let text = render_to_text(&preview, &Keymap::default());
+        assert!(text.contains("dry-run failed"));
+        assert!(text.contains("admission webhook"));
+        assert!(!text.contains("y apply"));
+        assert!(text.contains("e edit again"));
+
+        // Hints follow remapped YAML and edit keys
+        let keymap = Keymap::from_config(&std::collections::HashMap::from([
+            ("yaml".to_string(), "o".to_string()),
+            ("edit".to_string(), "i".to_string()),
+        ]))
+        .unwrap();
+        preview.dry_run = Some(Ok(()));
+        let text = render_to_text(&preview, &keymap);
+        assert!(text.contains("o apply"));
+        assert!(text.contains("i edit again"));
+    }
+}
@@ -1,7 +1,10 @@
 //! Footer view rendering
 
 use crate::tui::app::PendingOperation;
-use crate::tui::keybindings::{get_navigation_commands, navigation_commands_to_segments};
+use crate::tui::keybindings::{
+    Action, Keymap, get_connection_error_commands, get_navigation_commands,
+    navigation_commands_to_segments,
+};
 use crate::tui::operations::OperationRegistry;
 use crate::tui::theme::Theme;
 use crate::watcher::ResourceState;
@@ -12,6 +15,7 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
 };
+use std::collections::HashMap;
 
 /// Render the footer based on current application state
 /// Returns the number of lines used (for dynamic height calculation)
@@ -32,9 +36,11 @@ pub fn render_footer(
     state: &ResourceState,
     theme: &Theme,
     has_connection_error: bool,
+    keymap: &Keymap,
+    aliases: &HashMap<String, String>,
 ) -> usize {
     if command_mode {
-        return render_command_footer(f, area, command_buffer, theme);
+        return render_command_footer(f, area, command_buffer, aliases, theme);
     }
 
     if filter_mode {
@@ -53,14 +59,14 @@ pub fn render_footer(
 
     // Handle default navigation footer (wrapped for smaller screens)
     if !show_help && confirmation_pending.is_none() && status_message.is_none() {
-        return render_navigation_footer(f, area, theme, has_connection_error);
+        return render_navigation_footer(f, area, theme, has_connection_error, keymap);
     }
 
     // Build footer text for non-default cases
     let footer_text: Vec<Span> = if show_help {
         vec![
             Span::raw("Press "),
-            Span::styled("?", theme.footer_key_style()),
+            Span::styled(keymap.label(Action::Help), theme.footer_key_style()),
             Span::raw(" to hide help"),
         ]
     } else if let Some(pending) = confirmation_pending {
@@ -92,7 +98,13 @@ pub fn render_footer(
     1 // Single line for these cases
 }
 
-fn render_command_footer(f: &mut Frame, area: Rect, command_buffer: &str, theme: &Theme) -> usize {
+fn render_command_footer(
+    f: &mut Frame,
+    area: Rect,
+    command_buffer: &str,
+    aliases: &HashMap<String, String>,
+    theme: &Theme,
+) -> usize {
     let cmd = command_buffer.trim().to_lowercase();
     let mut command_line = vec![
         Span::styled(":", theme.command_prompt_style()),
@@ -102,7 +114,7 @@ fn render_command_footer(f: &mut Frame, area: Rect, command_buffer: &str, theme:
 
     // Add autocomplete hint
     if !cmd.is_empty() {
-        let matches = crate::tui::commands::find_matching_commands(&cmd);
+        let matches = crate::tui::commands::find_matching_commands(&cmd, aliases);
         if !matches.is_empty() {
             // Show first match, or multiple if there are conflicts
             if matches.len() == 1 {
@@ -150,12 +162,19 @@ fn render_command_footer(f: &mut Frame, area: Rect, command_buffer: &str, theme:
 }
 
 fn render_filter_footer(f: &mut Frame, area: Rect, filter: &str, theme: &Theme) -> usize {
-    let filter_line = vec![
+    let mut filter_line = vec![
         Span::styled("/", theme.filter_prompt_style()),
         Span::raw(filter),
         Span::raw("_"), // Cursor
-        Span::raw(" (Esc to cancel, Enter to apply)"),
     ];
+    // Report an invalid query in place of the key hints
+    match crate::watcher::FilterQuery::parse(filter) {
+        Ok(_) => filter_line.push(Span::raw(" (Esc to cancel, Enter to apply)")),
+        Err(e) => filter_line.push(Span::styled(
+            format!(" ✗ {}", e),
+            Style::default().fg(theme.status_error),
+        )),
+    }
     let footer =
         Paragraph::new(Line::from(filter_line)).block(Block::default().borders(Borders::ALL));
     f.render_widget(footer, area);
@@ -182,14 +201,15 @@ fn render_navigation_footer(
     area: Rect,
     theme: &Theme,
     has_connection_error: bool,
+    keymap: &Keymap,
 ) -> usize {
     // Default navigation hints - wrap for smaller screens
     // Returns the number of lines used
     // Use centralized keybindings
     let commands = if has_connection_error {
-        crate::tui::keybindings::get_connection_error_commands()
+        get_connection_error_commands(keymap)
     } else {
-        get_navigation_commands()
+        get_navigation_commands(keymap)
     };
     let nav_segments = navigation_commands_to_segments(&commands, theme.footer_key);
 
@@ -201,7 +221,7 @@ fn render_navigation_footer(
     let mut segment_lengths: Vec<usize> = Vec::new();
     for (idx, (key, label, _)) in nav_segments.iter().enumerate() {
         let separator_len = if idx > 0 { 3 } else { 0 }; // " | "
-        let segment_len = if *key == "j/k " {
+        let segment_len = if key.ends_with(' ') {
             key.len() + label.len()
         } else {
             key.len() + 1 + label.len() // key + space + label
@@ -246,7 +266,7 @@ fn render_navigation_footer(
             if *idx > 0 {
                 line1_spans.push(Span::raw(" | "));
             }
-            if *key == "j/k " {
+            if key.ends_with(' ') {
                 line1_spans.push(Span::raw(key.clone()));
                 line1_spans.push(Span::styled(label.clone(), Style::default().fg(*color)));
             } else {
@@ -264,7 +284,7 @@ fn render_navigation_footer(
             if *idx > 0 {
                 line2_spans.push(Span::raw(" | "));
             }
-            if *key == "j/k " {
+            if key.ends_with(' ') {
                 line2_spans.push(Span::raw(key.clone()));
                 line2_spans.push(Span::styled(label.clone(), Style::default().fg(*color)));
             } else {
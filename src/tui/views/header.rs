@@ -57,7 +57,11 @@ pub fn render_header(
     let mut filter_parts = Vec::new();
 
     if !filter.is_empty() {
-        filter_parts.push(format!("name='{}'", filter));
+        let is_name_only = crate::watcher::FilterQuery::parse(filter)
+            .map(|query| query.is_name_only())
+            .unwrap_or(false);
+        let field = if is_name_only { "name" } else { "query" };
+        filter_parts.push(format!("{}='{}'", field, filter));
     }
 
     if let Some(resource_type) = selected_resource_type {
@@ -1,6 +1,6 @@
 //! Help view rendering
 
-use crate::tui::keybindings::get_resource_help_commands;
+use crate::tui::keybindings::{Action, Keymap, get_resource_help_commands};
 use crate::tui::theme::Theme;
 use ratatui::{
     Frame,
@@ -11,7 +11,13 @@ use ratatui::{
 };
 
 /// Render the help view with columns (K9s-style)
-pub fn render_help(f: &mut Frame, area: Rect, theme: &Theme, namespace_hotkeys: &[String]) {
+pub fn render_help(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    namespace_hotkeys: &[String],
+    keymap: &Keymap,
+) {
     // Create inner area with padding for the border
     let inner_area = Rect {
         x: area.x + 1,
@@ -31,17 +37,32 @@ pub fn render_help(f: &mut Frame, area: Rect, theme: &Theme, namespace_hotkeys:
         ])
         .split(inner_area);
 
-    // RESOURCE column - use centralized keybindings
-    let resource_items = get_resource_help_commands();
+    // RESOURCE column - use centralized keybindings, followed by the plugin shortcuts
+    let mut resource_items: Vec<(String, &str)> = get_resource_help_commands(keymap);
+    for plugin in keymap.plugins() {
+        resource_items.push((plugin.key.help_label(), plugin.description.as_str()));
+    }
+    let resource_items: Vec<(&str, &str)> = resource_items
+        .iter()
+        .map(|(key, description)| (key.as_str(), *description))
+        .collect();
     render_help_column(f, column_chunks[0], "RESOURCE", &resource_items, theme);
 
+    // Effective keys of the remappable actions (`keymap` config)
+    let key = |action| keymap.help_label(action);
+    let back = format!("{}/<Esc>", key(Action::Back));
+    let quit = key(Action::Quit);
+    let help = key(Action::Help);
+    let command = key(Action::Command);
+    let filter = key(Action::Filter);
+
     // GENERAL column
     let general_items = vec![
-        ("<q>/<Esc>", "Back (confirm quit at root)"),
-        ("<Q>", "Quit immediately"),
-        ("<?>", "Show/hide help"),
-        ("<:>", "Command mode"),
-        ("</>", "Filter list / search text views"),
+        (back.as_str(), "Back (confirm quit at root)"),
+        (quit.as_str(), "Quit immediately"),
+        (help.as_str(), "Show/hide help"),
+        (command.as_str(), "Command mode"),
+        (filter.as_str(), "Filter list / search text views"),
         ("<Tab>", "Autocomplete command"),
         ("(menus)", "Type to filter, PgUp/PgDn to page"),
         (":help", "Show/hide help"),
@@ -50,15 +71,20 @@ pub fn render_help(f: &mut Frame, area: Rect, theme: &Theme, namespace_hotkeys:
         (":skin <n>", "Change theme/skin"),
         (":ctx <n>", "Switch context"),
         (":ctx", "Open context submenu"),
+        (":clusters [ctx…]", "Multi-cluster view"),
         (":ns <n>", "Switch namespace"),
         (":ns", "Open namespace picker"),
         (":ns all", "Show all namespaces"),
         (":all", "Show all resources"),
         (":healthy", "Show healthy resources"),
         (":unhealthy", "Show unhealthy resources"),
+        (":view <n>", "Apply saved view (F1-F12)"),
+        (":view save <n>", "Save current list as view"),
+        (":sort <column>", "Sort by any list column"),
         (":favorites", "View favorites"),
         (":fav", "View favorites"),
         (":events", "Live Kubernetes events feed"),
+        (":timeline [kind]", "Reconciliation timeline"),
         (":pulse", "Cluster health dashboard"),
         (":logs [pod]", "Stream controller logs"),
         (":q", "Quit application"),
@@ -74,18 +100,44 @@ pub fn render_help(f: &mut Frame, area: Rect, theme: &Theme, namespace_hotkeys:
     render_help_column(f, column_chunks[1], "GENERAL", &general_items, theme);
 
     // NAVIGATION column
+    let down = format!("{}/<Down>", key(Action::Down));
+    let up = format!("{}/<Up>", key(Action::Up));
+    let page_down = format!("{}/<PgDn>", key(Action::PageDown));
+    let page_up = format!("{}/<PgUp>", key(Action::PageUp));
+    let details = key(Action::Details);
+    let sort = format!(
+        "{}/{}/{}/{}",
+        key(Action::SortName),
+        key(Action::SortAge),
+        key(Action::SortType),
+        key(Action::SortStatus)
+    );
+    let mark = key(Action::Mark);
+    let pick_revisions = format!("{}/{}", mark, key(Action::Diff));
+    let mark_all = key(Action::MarkAll);
+    let invert_marks = key(Action::InvertMarks);
     let nav_items = vec![
-        ("<j>/<Down>", "Navigate down"),
-        ("<k>/<Up>", "Navigate up"),
-        ("<Ctrl+f>/<PgDn>", "Page down"),
-        ("<Ctrl+b>/<PgUp>", "Page up"),
-        ("<Enter>", "Open details / graph node / group"),
+        (down.as_str(), "Navigate down"),
+        (up.as_str(), "Navigate up"),
+        (page_down.as_str(), "Page down"),
+        (page_up.as_str(), "Page up"),
+        (details.as_str(), "Open details / graph node / group"),
         ("<l>", "Pod logs (workload detail)"),
-        ("<N>/<A>/<T>/<S>", "Sort name/age/type/status"),
-        ("</>", "Search in YAML/describe/trace/logs"),
+        ("<x>", "Export graph (DOT/Mermaid/JSON)"),
+        ("<Tab>/<S-Tab>", "Next/prev tab (release view)"),
+        (
+            pick_revisions.as_str(),
+            "Pick/diff revisions (release view)",
+        ),
+        ("<x>", "Reveal Secret values (release view)"),
+        (sort.as_str(), "Sort name/age/type/status"),
+        (mark.as_str(), "Mark/unmark row"),
+        (mark_all.as_str(), "Mark all filtered rows"),
+        (invert_marks.as_str(), "Invert marks"),
+        (filter.as_str(), "Search in YAML/describe/trace/logs"),
         ("<n>/<N>", "Next/prev search match"),
         ("<G>", "Follow newest line (logs view)"),
-        ("<q>/<Esc>", "Back / quit at root"),
+        (back.as_str(), "Back / quit at root"),
     ];
     render_help_column(f, column_chunks[2], "NAVIGATION", &nav_items, theme);
 
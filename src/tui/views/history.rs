@@ -1,7 +1,7 @@
 //! Reconciliation history view rendering
 
 use crate::tui::theme::Theme;
-use crate::watcher::ResourceInfo;
+use crate::watcher::{ReconciliationEvent, ResourceInfo};
 use ratatui::{
     Frame,
     layout::Rect,
@@ -12,12 +12,14 @@ use ratatui::{
 use std::collections::HashMap;
 
 /// Render the reconciliation history view for a resource
-/// Extracts history directly from status.history field in the resource object
+/// Extracts history directly from status.history field in the resource object,
+/// or shows `observed` (oldest first, rendered newest first) when it has none
 pub fn render_reconciliation_history(
     f: &mut Frame,
     area: Rect,
     resource: &ResourceInfo,
     resource_objects: &HashMap<String, serde_json::Value>,
+    observed: &[ReconciliationEvent],
     scroll_offset: &mut usize,
     theme: &Theme,
 ) -> Result<(), String> {
@@ -43,13 +45,18 @@ pub fn render_reconciliation_history(
         }
     };
 
-    // Extract history from status.history
-    let history_array = match obj
+    // Extract history from status.history, falling back to the history
+    // flux9s observed itself for kinds that don't keep one
+    let history_obj = match obj
         .get("status")
         .and_then(|s| s.get("history"))
         .and_then(|h| h.as_array())
     {
-        Some(arr) if !arr.is_empty() => arr,
+        Some(arr) if !arr.is_empty() => serde_json::json!({ "history": arr }),
+        _ if !observed.is_empty() => {
+            let newest_first: Vec<&ReconciliationEvent> = observed.iter().rev().collect();
+            serde_json::json!({ "observedHistory": newest_first })
+        }
         _ => {
             // No history field or empty - show error message
             use crate::models::FluxResourceKind;
@@ -67,6 +74,10 @@ pub fn render_reconciliation_history(
                 text.push(Line::from(format!("  - {}", kind.as_str())));
             }
             text.push(Line::from(""));
+            text.push(Line::from(
+                "Other kinds show the status changes flux9s has observed.",
+            ));
+            text.push(Line::from(""));
             text.push(Line::from("Press Esc to go back"));
             let paragraph = Paragraph::new(text)
                 .style(Style::default().fg(theme.text_secondary))
@@ -76,12 +87,6 @@ pub fn render_reconciliation_history(
         }
     };
 
-    // Convert history array to YAML format
-    // Create a wrapper object with just the history field for cleaner output
-    let history_obj = serde_json::json!({
-        "history": history_array
-    });
-
     let yaml_str = match serde_yaml::to_string(&history_obj) {
         Ok(yaml) => yaml,
         Err(e) => {
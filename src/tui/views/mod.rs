@@ -4,10 +4,14 @@
 //! in the TUI. Each component is responsible for rendering a specific
 //! part of the interface.
 
+mod bulk;
+mod clusters;
 mod confirmation;
 mod connection_error;
 mod describe;
 mod detail;
+mod diff;
+mod edit_preview;
 mod events;
 mod footer;
 mod graph;
@@ -19,18 +23,24 @@ mod inventory;
 mod logs;
 mod pulse;
 mod quit_confirm;
+mod release;
 pub mod resource_fields;
 mod resource_list;
 mod splash;
 mod submenu;
+mod timeline;
 pub mod trace;
 mod workloads;
 mod yaml;
 
+pub use bulk::*;
+pub use clusters::*;
 pub use confirmation::*;
 pub use connection_error::render_connection_error;
 pub use describe::*;
 pub use detail::*;
+pub use diff::*;
+pub use edit_preview::*;
 pub use events::*;
 // favorites module is not exported - favorites view uses render_resource_list instead
 pub use footer::*;
@@ -44,9 +54,11 @@ pub use inventory::*;
 pub use logs::*;
 pub use pulse::*;
 pub use quit_confirm::*;
+pub use release::*;
 pub use resource_fields::*;
 pub use resource_list::*;
 pub use splash::*;
 pub use submenu::*;
+pub use timeline::*;
 pub use workloads::*;
 pub use yaml::*;
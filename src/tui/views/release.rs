@@ -0,0 +1,556 @@
+//! Helm release inspector rendering
+
+use crate::kube::helm::{ReleaseInspection, StoredRelease};
+use crate::kube::helm_values::InputStatus;
+use crate::tui::app::state::{ReleaseTab, ReleaseViewState, TextSearchState};
+use crate::tui::keybindings::{Action, Keymap};
+use crate::tui::theme::Theme;
+use crate::tui::views::diff::diff_line_style;
+use crate::tui::views::yaml::{apply_text_search, decorate_title_with_search, find_match_lines};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Paragraph, Tabs},
+};
+
+/// Helm timestamps carry nanoseconds; show them to the second.
+fn format_time(time: Option<&str>) -> String {
+    match time.map(chrono::DateTime::parse_from_rfc3339) {
+        Some(Ok(time)) => time.format("%Y-%m-%d %H:%M:%S").to_string(),
+        Some(Err(_)) => time.unwrap_or_default().to_string(),
+        None => "-".to_string(),
+    }
+}
+
+fn chart_lines(inspection: &ReleaseInspection, release: &StoredRelease) -> Vec<String> {
+    let metadata = &release.chart.metadata;
+    let rows = [
+        ("Release", release.name.clone()),
+        ("Namespace", release.namespace.clone()),
+        (
+            "Revision",
+            format!("{} ({})", release.version, release.info.status),
+        ),
+        (
+            "Storage",
+            format!(
+                "Secret {}/sh.helm.release.v1.{}.v{}",
+                inspection.storage_namespace, inspection.release_name, release.version
+            ),
+        ),
+        ("Chart", metadata.name.clone()),
+        ("Version", metadata.version.clone()),
+        (
+            "App version",
+            metadata
+                .app_version
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+        (
+            "Description",
+            metadata
+                .description
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+        (
+            "First deployed",
+            format_time(release.info.first_deployed.as_deref()),
+        ),
+        (
+            "Last deployed",
+            format_time(release.info.last_deployed.as_deref()),
+        ),
+        ("Message", release.info.description.clone()),
+    ];
+    rows.into_iter()
+        .map(|(label, value)| format!("{:<16}{}", format!("{}:", label), value))
+        .collect()
+}
+
+fn hook_lines(release: &StoredRelease) -> Vec<String> {
+    if release.hooks.is_empty() {
+        return vec!["No hooks in this release".to_string()];
+    }
+    let mut lines = Vec::new();
+    for hook in &release.hooks {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(format!("{} ({})", hook.name, hook.kind));
+        lines.push(format!("  events:   {}", hook.events.join(", ")));
+        lines.push(format!("  weight:   {}", hook.weight));
+        lines.push(format!("  template: {}", hook.path));
+        let last_run = if hook.last_run.phase.is_empty() {
+            "never run".to_string()
+        } else {
+            format!(
+                "{} ({} → {})",
+                hook.last_run.phase,
+                format_time(hook.last_run.started_at.as_deref()),
+                format_time(hook.last_run.completed_at.as_deref())
+            )
+        };
+        lines.push(format!("  last run: {}", last_run));
+    }
+    lines
+}
+
+fn revision_lines(
+    inspection: &ReleaseInspection,
+    state: &ReleaseViewState,
+    theme: &Theme,
+) -> Vec<(String, Style)> {
+    let row = |mark: &str,
+               revision: &str,
+               updated: &str,
+               status: &str,
+               chart: &str,
+               app: &str,
+               desc: &str| {
+        format!(
+            "{:<2}{:<9} {:<20} {:<16} {:<28} {:<12} {}",
+            mark, revision, updated, status, chart, app, desc
+        )
+    };
+    let mut lines = vec![(
+        row(
+            "",
+            "REVISION",
+            "UPDATED",
+            "STATUS",
+            "CHART",
+            "APP VERSION",
+            "DESCRIPTION",
+        ),
+        Style::default()
+            .fg(theme.table_header)
+            .add_modifier(Modifier::BOLD),
+    )];
+    lines.extend(
+        inspection
+            .revisions
+            .iter()
+            .enumerate()
+            .map(|(idx, release)| {
+                let mark = if state.marks.contains(&release.version) {
+                    "+"
+                } else {
+                    ""
+                };
+                let text = row(
+                    mark,
+                    &release.version.to_string(),
+                    &format_time(release.info.last_deployed.as_deref()),
+                    &release.info.status,
+                    &release.chart_label(),
+                    release.chart.metadata.app_version.as_deref().unwrap_or("-"),
+                    &release.info.description,
+                );
+                let style = if idx == state.cursor {
+                    theme.table_selected_style()
+                } else {
+                    Style::default().fg(theme.text_primary)
+                };
+                (text, style)
+            }),
+    );
+    if let Some(ref error) = inspection.storage_error {
+        lines.push((error.clone(), Style::default().fg(theme.status_error)));
+    }
+    lines
+}
+
+fn diff_lines(state: &ReleaseViewState, keymap: &Keymap, theme: &Theme) -> Vec<(String, Style)> {
+    match state.diff {
+        Some(ref diff) => diff
+            .lines
+            .iter()
+            .map(|line| (line.text.clone(), diff_line_style(line.kind, theme)))
+            .collect(),
+        None => vec![(
+            format!(
+                "Pick two revisions with {mark} in the Revisions tab and press {diff}; \
+                 {diff} alone diffs the selected revision against the previous one",
+                mark = keymap.label(Action::Mark),
+                diff = keymap.label(Action::Diff)
+            ),
+            Style::default().fg(theme.text_secondary),
+        )],
+    }
+}
+
+fn effective_lines(
+    inspection: &ReleaseInspection,
+    state: &ReleaseViewState,
+    theme: &Theme,
+) -> Vec<(String, Style)> {
+    let effective = &inspection.effective_values;
+    let primary = Style::default().fg(theme.text_primary);
+    let secondary = Style::default().fg(theme.text_secondary);
+    let mut lines = Vec::new();
+    if !effective.inputs.is_empty() {
+        lines.push((
+            "# valuesFrom, merged in order before spec.values".to_string(),
+            secondary,
+        ));
+        for input in &effective.inputs {
+            let label = input.reference.label();
+            lines.push(match input.status {
+                InputStatus::Applied => (format!("#   ✓ {}", label), secondary),
+                InputStatus::Skipped(ref why) => (
+                    format!("#   - {} skipped (optional): {}", label, why),
+                    Style::default().fg(theme.status_pending),
+                ),
+                InputStatus::Error(ref why) => (
+                    format!("#   ✗ {}: {}", label, why),
+                    Style::default().fg(theme.status_error),
+                ),
+            });
+        }
+        lines.push((String::new(), primary));
+    }
+    let values = effective.annotated_lines(state.secret_redaction(effective).is_revealed());
+    if values.is_empty() {
+        lines.push(("{}".to_string(), primary));
+    }
+    lines.extend(values.into_iter().map(|line| (line, primary)));
+    lines
+}
+
+/// Lines of one inspector tab with their styles. Values, chart, notes and
+/// hooks are those of the current revision.
+pub(crate) fn release_tab_lines(
+    inspection: &ReleaseInspection,
+    state: &ReleaseViewState,
+    keymap: &Keymap,
+    theme: &Theme,
+) -> Vec<(String, Style)> {
+    match state.tab {
+        ReleaseTab::Revisions => return revision_lines(inspection, state, theme),
+        ReleaseTab::Diff => return diff_lines(state, keymap, theme),
+        ReleaseTab::Effective => return effective_lines(inspection, state, theme),
+        _ => {}
+    }
+    let Some(release) = inspection.current() else {
+        let error = inspection
+            .storage_error
+            .clone()
+            .unwrap_or_else(|| "The release has no stored revisions".to_string());
+        return vec![(error, Style::default().fg(theme.status_error))];
+    };
+    let lines = match state.tab {
+        ReleaseTab::Values => release
+            .values_yaml(state.secret_redaction(&inspection.effective_values))
+            .lines()
+            .map(str::to_string)
+            .collect(),
+        ReleaseTab::Chart => chart_lines(inspection, release),
+        ReleaseTab::Notes if release.info.notes.trim().is_empty() => {
+            vec!["The chart has no NOTES.txt".to_string()]
+        }
+        ReleaseTab::Notes => release.info.notes.lines().map(str::to_string).collect(),
+        ReleaseTab::Hooks => hook_lines(release),
+        ReleaseTab::Revisions | ReleaseTab::Diff | ReleaseTab::Effective => Vec::new(),
+    };
+    let style = Style::default().fg(theme.text_primary);
+    lines.into_iter().map(|line| (line, style)).collect()
+}
+
+/// Render the Helm release inspector: a tab bar over the active tab's text,
+/// which scrolls and supports `/` search like the other text views.
+pub fn render_resource_release(
+    f: &mut Frame,
+    area: Rect,
+    selected_resource_key: &Option<String>,
+    inspection: Option<&ReleaseInspection>,
+    loading: bool,
+    state: &mut ReleaseViewState,
+    search: &mut TextSearchState,
+    keymap: &Keymap,
+    theme: &Theme,
+) {
+    if loading {
+        crate::tui::views::helpers::render_loading_state(
+            f,
+            area,
+            "Release",
+            "Reading Helm release storage...",
+            theme,
+        );
+        return;
+    }
+
+    let Some(inspection) = inspection else {
+        crate::tui::views::helpers::render_empty_state(
+            f,
+            area,
+            "Release",
+            "No release available",
+            "Select a HelmRelease and press 'H' to inspect its Helm release",
+            theme,
+        );
+        return;
+    };
+
+    let mut title = match (selected_resource_key, inspection.current()) {
+        (Some(key), Some(release)) => format!("Release: {} (rev {})", key, release.version),
+        (Some(key), None) => format!("Release: {}", key),
+        (None, _) => "Release".to_string(),
+    };
+    if let (ReleaseTab::Diff, Some(diff)) = (state.tab, state.diff.as_ref()) {
+        title.push_str(&format!(" v{} → v{}", diff.from, diff.to));
+    }
+    if state.tab.shows_secrets() && state.reveal_secrets {
+        title.push_str(" (secrets revealed)");
+    }
+
+    let lines = release_tab_lines(inspection, state, keymap, theme);
+    let block = crate::tui::views::helpers::create_themed_block("", theme);
+    let inner = block.inner(area);
+    let [tabs_area, body_area] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0)])
+        .areas(inner);
+
+    let visible_height = body_area.height as usize;
+    let max_scroll = lines.len().saturating_sub(visible_height);
+    let scroll_offset = &mut state.scroll_offset;
+    // Keep the selected revision on screen (line 0 is the header)
+    if state.tab == ReleaseTab::Revisions && visible_height > 0 {
+        let cursor_line = state.cursor + 1;
+        if cursor_line >= *scroll_offset + visible_height {
+            *scroll_offset = cursor_line + 1 - visible_height;
+        } else if state.cursor < *scroll_offset {
+            *scroll_offset = state.cursor;
+        }
+    }
+    let texts: Vec<&str> = lines.iter().map(|(text, _)| text.as_str()).collect();
+    let match_lines = find_match_lines(&texts, &search.query);
+    let current_match_line = apply_text_search(search, &match_lines, scroll_offset, visible_height);
+    decorate_title_with_search(&mut title, search);
+    *scroll_offset = (*scroll_offset).min(max_scroll);
+
+    if lines.len() > visible_height {
+        let first = *scroll_offset + 1;
+        let last = (*scroll_offset + visible_height).min(lines.len());
+        title.push_str(&format!(" [{}-{}/{}]", first, last, lines.len()));
+    }
+
+    let visible_lines: Vec<Line> = lines
+        .iter()
+        .enumerate()
+        .skip(*scroll_offset)
+        .take(visible_height)
+        .map(|(idx, (text, style))| {
+            let style = if Some(idx) == current_match_line {
+                style.add_modifier(Modifier::REVERSED)
+            } else if match_lines.binary_search(&idx).is_ok() {
+                style.add_modifier(Modifier::UNDERLINED)
+            } else {
+                *style
+            };
+            Line::styled(text.clone(), style)
+        })
+        .collect();
+
+    let selected = ReleaseTab::ALL
+        .iter()
+        .position(|t| *t == state.tab)
+        .unwrap_or_default();
+    let tabs = Tabs::new(ReleaseTab::ALL.iter().map(|t| t.title()))
+        .select(selected)
+        .style(Style::default().fg(theme.text_secondary))
+        .highlight_style(
+            Style::default()
+                .fg(theme.text_label)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        );
+
+    f.render_widget(block.title(title), area);
+    f.render_widget(tabs, tabs_area);
+    f.render_widget(Paragraph::new(visible_lines), body_area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kube::helm::{ChartMetadata, ReleaseHook, ReleaseInfo};
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    fn revision(version: i64, status: &str, chart_version: &str) -> StoredRelease {
+        StoredRelease {
+            name: "podinfo".to_string(),
+            namespace: "apps".to_string(),
+            version,
+            info: ReleaseInfo {
+                status: status.to_string(),
+                notes: "Visit http://podinfo.local".to_string(),
+                last_deployed: Some("2024-05-01T10:00:00.123456789Z".to_string()),
+                description: "Upgrade complete".to_string(),
+                ..ReleaseInfo::default()
+            },
+            chart: crate::kube::helm::Chart {
+                metadata: ChartMetadata {
+                    name: "podinfo".to_string(),
+                    version: chart_version.to_string(),
+                    app_version: Some(chart_version.to_string()),
+                    description: None,
+                },
+                ..crate::kube::helm::Chart::default()
+            },
+            config: serde_json::json!({"replicaCount": 2}),
+            hooks: vec![ReleaseHook {
+                name: "podinfo-test".to_string(),
+                kind: "Pod".to_string(),
+                events: vec!["test".to_string()],
+                ..ReleaseHook::default()
+            }],
+            ..StoredRelease::default()
+        }
+    }
+
+    fn inspection() -> ReleaseInspection {
+        ReleaseInspection {
+            release_name: "podinfo".to_string(),
+            storage_namespace: "apps".to_string(),
+            revisions: vec![
+                revision(2, "deployed", "6.5.0"),
+                revision(1, "superseded", "6.4.0"),
+            ],
+            ..ReleaseInspection::default()
+        }
+    }
+
+    fn render_to_text(tab: ReleaseTab) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(100, 16)).unwrap();
+        let mut state = ReleaseViewState {
+            tab,
+            ..ReleaseViewState::default()
+        };
+        let mut search = TextSearchState::default();
+        let inspection = inspection();
+        terminal
+            .draw(|frame| {
+                render_resource_release(
+                    frame,
+                    frame.area(),
+                    &Some("HelmRelease:apps:podinfo".to_string()),
+                    Some(&inspection),
+                    false,
+                    &mut state,
+                    &mut search,
+                    &Keymap::default(),
+                    &Theme::default(),
+                );
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        let mut text = String::new();
+        for y in 0..buffer.area.height {
+            for x in 0..buffer.area.width {
+                text.push_str(buffer[(x, y)].symbol());
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    #[test]
+    fn renders_each_tab_of_the_current_revision() {
+        let text = render_to_text(ReleaseTab::Values);
+        assert!(text.contains("Release: HelmRelease:apps:podinfo (rev 2)"));
+        assert!(text.contains("Values"));
+        assert!(text.contains("Revisions"));
+        assert!(text.contains("replicaCount: 2"));
+
+        let text = render_to_text(ReleaseTab::Chart);
+        assert!(text.contains("App version:    6.5.0"));
+        assert!(text.contains("Secret apps/sh.helm.release.v1.podinfo.v2"));
+        assert!(text.contains("2024-05-01 10:00:00"));
+
+        assert!(render_to_text(ReleaseTab::Notes).contains("Visit http://podinfo.local"));
+        let text = render_to_text(ReleaseTab::Hooks);
+        assert!(text.contains("podinfo-test (Pod)"));
+        assert!(text.contains("last run: never run"));
+
+        let state = ReleaseViewState {
+            tab: ReleaseTab::Revisions,
+            marks: vec![1],
+            ..ReleaseViewState::default()
+        };
+        let lines: Vec<String> =
+            release_tab_lines(&inspection(), &state, &Keymap::default(), &Theme::default())
+                .into_iter()
+                .map(|(text, _)| text)
+                .collect();
+        assert!(lines[0].starts_with("  REVISION"));
+        assert!(lines[1].starts_with("  2") && lines[1].contains("podinfo-6.5.0"));
+        assert!(lines[2].starts_with("+ 1") && lines[2].contains("superseded"));
+
+        let text = render_to_text(ReleaseTab::Diff);
+        assert!(text.contains("Pick two revisions with Space"));
+    }
+
+    #[test]
+    fn effective_tab_flags_inputs_and_redacts_secrets() {
+        use crate::kube::helm_values::{EffectiveValues, ValuesReference};
+        let reference = |kind: &str, name: &str| ValuesReference {
+            kind: kind.to_string(),
+            name: name.to_string(),
+            values_key: "values.yaml".to_string(),
+            ..ValuesReference::default()
+        };
+        let secret = std::collections::BTreeMap::from([(
+            "values.yaml".to_string(),
+            "password: hunter2\n".to_string(),
+        )]);
+        let inspection = ReleaseInspection {
+            storage_error: Some("No Helm storage Secrets found".to_string()),
+            effective_values: EffectiveValues::resolve(
+                vec![
+                    (reference("Secret", "creds"), Ok(Some(secret))),
+                    (reference("ConfigMap", "gone"), Ok(None)),
+                ],
+                Some(&serde_json::json!({"replicas": 2})),
+            ),
+            ..ReleaseInspection::default()
+        };
+        let lines = |state: &ReleaseViewState| -> Vec<String> {
+            release_tab_lines(&inspection, state, &Keymap::default(), &Theme::default())
+                .into_iter()
+                .map(|(text, _)| text)
+                .collect()
+        };
+
+        let mut state = ReleaseViewState {
+            tab: ReleaseTab::Effective,
+            ..ReleaseViewState::default()
+        };
+        let text = lines(&state);
+        assert!(text.contains(&"#   ✓ Secret/creds[values.yaml]".to_string()));
+        assert!(
+            text.contains(
+                &"#   ✗ ConfigMap/gone[values.yaml]: ConfigMap gone not found".to_string()
+            )
+        );
+        assert!(text.contains(&"password: <redacted>  # Secret/creds[values.yaml]".to_string()));
+        assert!(text.contains(&"replicas: 2  # spec.values".to_string()));
+        state.reveal_secrets = true;
+        assert!(
+            lines(&state)
+                .contains(&"password: \"hunter2\"  # Secret/creds[values.yaml]".to_string())
+        );
+
+        // Tabs of the stored release explain why it is missing
+        state.tab = ReleaseTab::Values;
+        assert_eq!(
+            lines(&state),
+            vec!["No Helm storage Secrets found".to_string()]
+        );
+    }
+}
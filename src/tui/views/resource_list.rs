@@ -1,8 +1,9 @@
 //! Resource list view rendering
 
+use crate::models::columns::ColumnsConfig;
 use crate::tui::app::state::SortField;
 use crate::tui::theme::Theme;
-use crate::tui::views::{extract_resource_specific_fields, get_resource_type_columns};
+use crate::tui::views::{extract_fields_with_columns, get_list_columns};
 use crate::watcher::ResourceInfo;
 use ratatui::{
     Frame,
@@ -15,7 +16,12 @@ use std::cmp;
 use std::collections::{HashMap, HashSet};
 
 /// Decorate a header column name with a sort arrow when it is the active sort.
-fn decorate_header(col: &str, sort_field: SortField, sort_reverse: bool, no_icons: bool) -> String {
+fn decorate_header(
+    col: &str,
+    sort_field: &SortField,
+    sort_reverse: bool,
+    no_icons: bool,
+) -> String {
     if sort_field.column_name() == Some(col) {
         let arrow = match (sort_reverse, no_icons) {
             (false, false) => "↑",
@@ -29,6 +35,15 @@ fn decorate_header(col: &str, sort_field: SortField, sort_reverse: bool, no_icon
     }
 }
 
+/// Keys of the rows flagged in a resource list
+#[derive(Clone, Copy)]
+pub struct RowMarks<'a> {
+    /// Starred favorites
+    pub favorites: &'a HashSet<String>,
+    /// Rows marked for a bulk operation
+    pub marked: &'a HashSet<String>,
+}
+
 /// Render the resource list table
 pub fn render_resource_list(
     f: &mut Frame,
@@ -38,15 +53,18 @@ pub fn render_resource_list(
     scroll_offset: &mut usize,
     selected_resource_type: &Option<String>,
     resource_objects: &HashMap<String, serde_json::Value>,
+    // Columns added, hidden or reordered per kind in config
+    columns: &ColumnsConfig,
     theme: &Theme,
     no_icons: bool,
-    favorites: &HashSet<String>,
-    sort_field: SortField,
+    marks: RowMarks<'_>,
+    sort_field: &SortField,
     sort_reverse: bool,
     // Contextual RBAC notice shown in place of the default empty-state message
     // when the list is empty because a kind is restricted (see App::access_notice).
     access_notice: Option<&str>,
 ) {
+    let RowMarks { favorites, marked } = marks;
     let visible_height = (area.height as usize).saturating_sub(2);
     const SCROLL_BUFFER: usize = 2; // Keep 2 rows buffer before scrolling
 
@@ -123,11 +141,15 @@ pub fn render_resource_list(
                 let actual_idx = *scroll_offset + idx;
                 let is_selected = actual_idx == valid_selected;
 
-                let style = if is_selected {
-                    theme.table_selected_style()
-                } else {
-                    Style::default()
-                };
+                let style = row_style(
+                    is_selected,
+                    marked.contains(&crate::watcher::resource_key(
+                        &r.namespace,
+                        &r.name,
+                        &r.resource_type,
+                    )),
+                    theme,
+                );
 
                 let suspended_str = r
                     .suspended
@@ -148,25 +170,7 @@ pub fn render_resource_list(
                 let message = r.message.as_deref().unwrap_or("-");
                 let message_display = crate::tui::views::helpers::truncate_message(message, 40);
 
-                // Check if resource is favorited
-                let resource_key =
-                    crate::watcher::resource_key(&r.namespace, &r.name, &r.resource_type);
-                let is_favorite = favorites.contains(&resource_key);
-                let name_display = if is_favorite {
-                    format!("★ {}", r.name)
-                } else {
-                    r.name.clone()
-                };
-                let name_cell = if is_favorite {
-                    Cell::from(Span::styled(
-                        name_display,
-                        Style::default()
-                            .fg(theme.text_primary)
-                            .add_modifier(Modifier::BOLD),
-                    ))
-                } else {
-                    Cell::from(name_display)
-                };
+                let name_cell = name_cell(r, favorites, marked, theme, no_icons);
 
                 Row::new(vec![
                     Cell::from(Span::styled(
@@ -200,12 +204,12 @@ pub fn render_resource_list(
 
         (rows, header, constraints)
     } else {
-        // Resource-type-specific view: show type-specific fields (plus AGE)
+        // Resource-type-specific view: show type-specific fields (plus AGE and
+        // the configured columns)
         // `is_unified` already established this is `Some`; degrade to the generic
         // column set rather than panicking if that ever stops holding.
         let resource_type = selected_resource_type.as_deref().unwrap_or_default();
-        let mut column_names = get_resource_type_columns(resource_type);
-        column_names.push("AGE");
+        let column_names = get_list_columns(resource_type, columns);
         let header_cells: Vec<String> = column_names
             .iter()
             .map(|col| decorate_header(col, sort_field, sort_reverse, no_icons))
@@ -223,11 +227,15 @@ pub fn render_resource_list(
                 let actual_idx = *scroll_offset + idx;
                 let is_selected = actual_idx == valid_selected;
 
-                let style = if is_selected {
-                    theme.table_selected_style()
-                } else {
-                    Style::default()
-                };
+                let style = row_style(
+                    is_selected,
+                    marked.contains(&crate::watcher::resource_key(
+                        &r.namespace,
+                        &r.name,
+                        &r.resource_type,
+                    )),
+                    theme,
+                );
 
                 let (status_indicator, status_color) =
                     get_status_indicator(r.ready, r.suspended, theme, no_icons);
@@ -236,41 +244,19 @@ pub fn render_resource_list(
                 let key = crate::watcher::resource_key(&r.namespace, &r.name, &r.resource_type);
                 let specific_fields = resource_objects
                     .get(&key)
-                    .map(|obj| extract_resource_specific_fields(resource_type, obj))
+                    .map(|obj| extract_fields_with_columns(resource_type, obj, columns))
                     .unwrap_or_default();
 
                 // Build row cells based on column names
                 let mut cells = Vec::new();
                 for col in &column_names {
-                    let cell = match *col {
+                    let cell = match col.as_str() {
                         "STATUS" => Cell::from(Span::styled(
                             status_indicator,
                             Style::default().fg(status_color),
                         )),
                         "NAMESPACE" => Cell::from(r.namespace.clone()),
-                        "NAME" => {
-                            let resource_key = crate::watcher::resource_key(
-                                &r.namespace,
-                                &r.name,
-                                &r.resource_type,
-                            );
-                            let is_favorite = favorites.contains(&resource_key);
-                            let name_display = if is_favorite {
-                                format!("★ {}", r.name)
-                            } else {
-                                r.name.clone()
-                            };
-                            if is_favorite {
-                                Cell::from(Span::styled(
-                                    name_display,
-                                    Style::default()
-                                        .fg(theme.text_primary)
-                                        .add_modifier(Modifier::BOLD),
-                                ))
-                            } else {
-                                Cell::from(name_display)
-                            }
-                        }
+                        "NAME" => name_cell(r, favorites, marked, theme, no_icons),
                         "TYPE" => Cell::from(
                             specific_fields
                                 .get("TYPE")
@@ -310,12 +296,9 @@ pub fn render_resource_list(
                             };
                             Cell::from(display)
                         }
-                        _ => Cell::from(
-                            specific_fields
-                                .get(*col)
-                                .cloned()
-                                .unwrap_or("-".to_string()),
-                        ),
+                        _ => {
+                            Cell::from(specific_fields.get(col).cloned().unwrap_or("-".to_string()))
+                        }
                     };
                     cells.push(cell);
                 }
@@ -327,7 +310,7 @@ pub fn render_resource_list(
         // Build constraints based on column names
         let constraints: Vec<Constraint> = column_names
             .iter()
-            .map(|col| match *col {
+            .map(|col| match col.as_str() {
                 // 7 chars fits "STATUS" plus a sort arrow (and "PAUSED" in no-icons mode)
                 "STATUS" => Constraint::Length(7),
                 "NAMESPACE" => Constraint::Min(15),
@@ -359,10 +342,26 @@ pub fn render_resource_list(
         String::new()
     };
 
+    let marked_count = resources
+        .iter()
+        .filter(|r| {
+            marked.contains(&crate::watcher::resource_key(
+                &r.namespace,
+                &r.name,
+                &r.resource_type,
+            ))
+        })
+        .count();
+    let marked_suffix = if marked_count > 0 {
+        format!(" [{} marked]", marked_count)
+    } else {
+        String::new()
+    };
+
     let title = if let Some(rt) = selected_resource_type {
-        format!("{} ({}){}", rt, total, range_suffix)
+        format!("{} ({}){}{}", rt, total, marked_suffix, range_suffix)
     } else {
-        format!("All Resources ({}){}", total, range_suffix)
+        format!("All Resources ({}){}{}", total, marked_suffix, range_suffix)
     };
 
     let table = Table::new(rows, constraints)
@@ -372,6 +371,48 @@ pub fn render_resource_list(
     f.render_widget(table, area);
 }
 
+/// Row style: the selection highlight wins over the bulk-operation mark.
+fn row_style(is_selected: bool, is_marked: bool, theme: &Theme) -> Style {
+    if is_selected {
+        theme.table_selected_style()
+    } else if is_marked {
+        Style::default().fg(theme.operation_confirm)
+    } else {
+        Style::default()
+    }
+}
+
+/// NAME cell: marked rows get a check mark, favorites a star (bold).
+fn name_cell(
+    r: &ResourceInfo,
+    favorites: &HashSet<String>,
+    marked: &HashSet<String>,
+    theme: &Theme,
+    no_icons: bool,
+) -> Cell<'static> {
+    let key = crate::watcher::resource_key(&r.namespace, &r.name, &r.resource_type);
+    let is_favorite = favorites.contains(&key);
+    let mut name_display = if is_favorite {
+        format!("★ {}", r.name)
+    } else {
+        r.name.clone()
+    };
+    if marked.contains(&key) {
+        let mark = if no_icons { "+" } else { "✓" };
+        name_display = format!("{} {}", mark, name_display);
+    }
+    if is_favorite {
+        Cell::from(Span::styled(
+            name_display,
+            Style::default()
+                .fg(theme.text_primary)
+                .add_modifier(Modifier::BOLD),
+        ))
+    } else {
+        Cell::from(name_display)
+    }
+}
+
 pub fn get_status_indicator(
     ready: Option<bool>,
     suspended: Option<bool>,
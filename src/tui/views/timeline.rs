@@ -0,0 +1,222 @@
+//! Reconciliation timeline view rendering
+//!
+//! Renders the `:timeline` stream: observed reconciliation changes of every
+//! watched resource and Warning Kubernetes events, newest first, with
+//! failures highlighted.
+
+use crate::tui::theme::Theme;
+use crate::tui::timeline::{TimelineChange, TimelineEntry};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    widgets::{Row, Table},
+};
+use std::cmp;
+
+/// Render the reconciliation timeline.
+///
+/// `entries` is pre-filtered and sorted newest-first; `total_count` is the
+/// unfiltered size and `kinds` the active kind filter (both shown in the
+/// title so active filters are visible).
+pub fn render_timeline(
+    f: &mut Frame,
+    area: Rect,
+    entries: &[TimelineEntry],
+    total_count: usize,
+    selected_index: usize,
+    scroll_offset: &mut usize,
+    filter: &str,
+    kinds: &[String],
+    all_namespaces: bool,
+    theme: &Theme,
+) {
+    let visible_height = (area.height as usize).saturating_sub(2);
+    const SCROLL_BUFFER: usize = 2; // Keep 2 rows buffer before scrolling
+
+    crate::tui::views::helpers::update_scroll_offset(
+        selected_index,
+        visible_height,
+        scroll_offset,
+        SCROLL_BUFFER,
+    );
+
+    let mut title = if kinds.is_empty() {
+        "Timeline".to_string()
+    } else {
+        format!("Timeline [{}]", kinds.join(", "))
+    };
+    if filter.is_empty() && kinds.is_empty() {
+        title = format!("{} ({})", title, total_count);
+    } else {
+        title = format!("{} ({}/{})", title, entries.len(), total_count);
+    }
+    if !filter.is_empty() {
+        title = format!("{} /{}", title, filter);
+    }
+
+    if entries.is_empty() {
+        let (message, hint) = if filter.is_empty() && kinds.is_empty() {
+            (
+                "No reconciliation changes observed yet",
+                "Status and revision changes appear here as resources reconcile...",
+            )
+        } else {
+            (
+                "No timeline entries match the filter",
+                "Press / to change the filter, or :timeline to show every kind",
+            )
+        };
+        crate::tui::views::helpers::render_empty_state(f, area, &title, message, hint, theme);
+        return;
+    }
+
+    let valid_selected = cmp::min(selected_index, entries.len().saturating_sub(1));
+
+    let mut header_cells = vec!["TIME (UTC)", "CHANGE"];
+    if all_namespaces {
+        header_cells.push("NAMESPACE");
+    }
+    header_cells.extend(["OBJECT", "REVISION", "MESSAGE"]);
+    let header = Row::new(header_cells).style(
+        Style::default()
+            .fg(theme.table_header)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let rows: Vec<Row> = entries
+        .iter()
+        .skip(*scroll_offset)
+        .take(visible_height)
+        .enumerate()
+        .map(|(idx, entry)| {
+            let actual_idx = *scroll_offset + idx;
+            let style = if actual_idx == valid_selected {
+                theme.table_selected_style()
+            } else if entry.change.is_failure() {
+                Style::default().fg(theme.status_error)
+            } else if entry.change == TimelineChange::Recovered {
+                Style::default().fg(theme.status_ready)
+            } else {
+                Style::default().fg(theme.text_primary)
+            };
+
+            let mut cells = vec![
+                entry.time.format("%m-%d %H:%M:%S").to_string(),
+                entry.change.label().to_string(),
+            ];
+            if all_namespaces {
+                cells.push(entry.namespace.clone());
+            }
+            cells.extend([
+                entry.object_label(),
+                entry.revision.clone().unwrap_or_else(|| "-".to_string()),
+                // Single row per entry: collapse multi-line messages
+                entry.message.replace('\n', " "),
+            ]);
+            Row::new(cells).style(style)
+        })
+        .collect();
+
+    let mut constraints = vec![
+        Constraint::Length(15), // TIME
+        Constraint::Length(10), // CHANGE
+    ];
+    if all_namespaces {
+        constraints.push(Constraint::Length(16)); // NAMESPACE
+    }
+    constraints.extend([
+        Constraint::Length(36), // OBJECT
+        Constraint::Length(28), // REVISION
+        Constraint::Min(20),    // MESSAGE
+    ]);
+
+    // Scroll position indicator, matching the resource list style
+    if entries.len() > visible_height {
+        let first = *scroll_offset + 1;
+        let last = cmp::min(*scroll_offset + visible_height, entries.len());
+        title = format!("{} [{}-{}]", title, first, last);
+    }
+
+    let block = crate::tui::views::helpers::create_themed_block(&title, theme);
+    let table = Table::new(rows, constraints).header(header).block(block);
+    f.render_widget(table, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    fn entry(change: TimelineChange, kind: &str, name: &str) -> TimelineEntry {
+        TimelineEntry {
+            time: chrono::Utc
+                .with_ymd_and_hms(2026, 10, 16, 9, 30, 5)
+                .unwrap(),
+            change,
+            kind: kind.to_string(),
+            namespace: "flux-system".to_string(),
+            name: name.to_string(),
+            revision: Some("main@sha1:abc".to_string()),
+            message: "line one\nline two".to_string(),
+        }
+    }
+
+    fn render_to_text(entries: &[TimelineEntry], kinds: &[String], all_namespaces: bool) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(160, 12)).unwrap();
+        let mut scroll_offset = 0;
+        terminal
+            .draw(|frame| {
+                render_timeline(
+                    frame,
+                    frame.area(),
+                    entries,
+                    3,
+                    0,
+                    &mut scroll_offset,
+                    "",
+                    kinds,
+                    all_namespaces,
+                    &Theme::default(),
+                );
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        let mut text = String::new();
+        for y in 0..buffer.area.height {
+            for x in 0..buffer.area.width {
+                text.push_str(buffer[(x, y)].symbol());
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    #[test]
+    fn renders_timeline_rows() {
+        let entries = [
+            entry(TimelineChange::Failed, "HelmRelease", "podinfo"),
+            entry(TimelineChange::Revision, "GitRepository", "repo"),
+        ];
+        let text = render_to_text(&entries, &[], true);
+        assert!(text.contains("CHANGE"));
+        assert!(text.contains("NAMESPACE"));
+        assert!(text.contains("10-16 09:30:05"));
+        assert!(text.contains("HelmRelease/podinfo"));
+        assert!(text.contains("main@sha1:abc"));
+        assert!(text.contains("line one line two"));
+    }
+
+    #[test]
+    fn kind_filter_is_shown_in_title() {
+        let entries = [entry(TimelineChange::Ready, "Kustomization", "apps")];
+        let text = render_to_text(&entries, &["Kustomization".to_string()], false);
+        assert!(text.contains("Timeline [Kustomization] (1/3)"));
+        assert!(!text.contains("NAMESPACE"));
+
+        let empty = render_to_text(&[], &["HelmRelease".to_string()], false);
+        assert!(empty.contains("No timeline entries match the filter"));
+    }
+}
@@ -3,10 +3,16 @@
 //! Provides watch functionality for Flux CRD resources.
 //! Designed to be extensible - new resource types can be easily added.
 
+mod namespaces;
+mod query;
+mod record;
 mod registry;
 mod resource;
 mod state;
 
+pub use namespaces::{NamespaceScope, is_label_selector};
+pub use query::*;
+pub use record::*;
 pub use registry::*;
 pub use resource::*;
 pub use state::*;
@@ -27,6 +33,7 @@ use crate::kube::api::{
     get_flux_api_resources_with_fallback, is_forbidden_error, is_version_missing_error,
 };
 use crate::models::FluxResourceKind;
+use namespaces::KindStatus;
 
 /// Maximum interval between watch-reconnect attempts.
 ///
@@ -81,6 +88,12 @@ pub enum WatchEvent {
     Deleted(String, String, String), // resource_type, namespace, name
     /// Watch error occurred
     Error(String),
+    /// A resource watcher finished its initial list: everything that existed
+    /// when the watch started has been sent as `Applied`. Also sent when a
+    /// watcher stops for good before listing (CRD absent), so consumers
+    /// waiting for a complete snapshot don't hang on kinds that will never arrive.
+    /// The controller deployment watcher sends it with its watcher name.
+    InitialSyncDone(String), // resource_type or watcher name
     /// A watcher started erroring and is retrying with backoff.
     /// Drives the "watch degraded" banner in the UI.
     WatcherDegraded(String), // watcher display name
@@ -132,10 +145,12 @@ where
 ///
 /// Watchers are namespace-aware and can be restarted when namespace changes.
 /// This allows efficient watching: Api::namespaced for specific namespace,
-/// Api::all for all namespaces.
+/// Api::all for all namespaces. A set of namespaces runs one watcher per
+/// namespace and kind (see [`NamespaceScope`]).
 pub struct ResourceWatcher {
     client: Client,
-    current_namespace: Option<String>,
+    /// Watched namespaces; empty watches all namespaces
+    namespaces: Vec<String>,
     controller_namespace: String,
     event_tx: mpsc::UnboundedSender<WatchEvent>,
     handles: Vec<JoinHandle<()>>,
@@ -143,7 +158,8 @@ pub struct ResourceWatcher {
     /// lazily (first time the events view opens) and can be stopped without
     /// tearing down the resource watchers, since Events are the churniest
     /// resource in a cluster and shouldn't be paid for while unused.
-    kube_events_handle: Option<JoinHandle<()>>,
+    /// One handle per watched namespace.
+    kube_events_handles: Vec<JoinHandle<()>>,
     /// Opt-in CRD discovery (#197). Off by default: when false, neither the
     /// CRD watcher nor any dynamic kind watcher ever starts. Toggled at
     /// runtime by `:discover` via [`Self::set_discovery_enabled`].
@@ -152,9 +168,9 @@ pub struct ResourceWatcher {
     /// `handles`) so `:discover` can arm and disarm it mid-session without
     /// touching the resource watchers.
     crd_discovery_handle: Option<JoinHandle<()>>,
-    /// Dynamic watchers for discovered kinds, keyed by kind name so a
-    /// deleted CRD can stop exactly its own watcher.
-    extra_handles: std::collections::HashMap<String, JoinHandle<()>>,
+    /// Dynamic watchers for discovered kinds (one per watched namespace),
+    /// keyed by kind name so a deleted CRD can stop exactly its own watchers.
+    extra_handles: std::collections::HashMap<String, Vec<JoinHandle<()>>>,
 }
 
 impl ResourceWatcher {
@@ -172,11 +188,11 @@ impl ResourceWatcher {
         (
             Self {
                 client,
-                current_namespace: namespace,
+                namespaces: namespace.into_iter().collect(),
                 controller_namespace,
                 event_tx: tx,
                 handles: Vec::new(),
-                kube_events_handle: None,
+                kube_events_handles: Vec::new(),
                 discovery_enabled,
                 crd_discovery_handle: None,
                 extra_handles: std::collections::HashMap::new(),
@@ -190,14 +206,20 @@ impl ResourceWatcher {
     /// This is more efficient than watching all namespaces and filtering,
     /// especially for large clusters. Watchers are restarted with the new namespace.
     pub fn set_namespace(&mut self, namespace: Option<String>) -> Result<()> {
-        if self.current_namespace == namespace {
+        self.set_namespaces(namespace.into_iter().collect())
+    }
+
+    /// Watch a set of namespaces, one watcher per namespace and kind, and
+    /// restart all watchers. An empty set watches all namespaces.
+    pub fn set_namespaces(&mut self, namespaces: Vec<String>) -> Result<()> {
+        if self.namespaces == namespaces {
             return Ok(()); // No change needed
         }
 
         tracing::debug!(
             "Changing namespace filter: {:?} -> {:?}",
-            self.current_namespace,
-            namespace
+            self.namespaces,
+            namespaces
         );
 
         // Stop existing watchers (remembering whether the lazily started
@@ -205,10 +227,10 @@ impl ResourceWatcher {
         let events_active = self.is_watching_kube_events();
         self.stop();
 
-        // Update namespace
-        self.current_namespace = namespace;
+        // Update namespaces
+        self.namespaces = namespaces;
 
-        // Restart all watchers with new namespace
+        // Restart all watchers with new namespaces
         self.watch_all()?;
         if events_active {
             self.watch_kube_events()?;
@@ -216,6 +238,21 @@ impl ResourceWatcher {
         Ok(())
     }
 
+    /// Watched namespaces; empty when all namespaces are watched.
+    pub fn namespaces(&self) -> &[String] {
+        &self.namespaces
+    }
+
+    /// Namespace of each watcher to start for a kind: one per watched
+    /// namespace, or a single cluster-wide `None`.
+    fn namespace_targets(&self) -> Vec<Option<String>> {
+        if self.namespaces.is_empty() {
+            vec![None]
+        } else {
+            self.namespaces.iter().cloned().map(Some).collect()
+        }
+    }
+
     /// Start watching a specific resource type
     ///
     /// Uses Api::namespaced if namespace is set, Api::all otherwise.
@@ -227,146 +264,154 @@ impl ResourceWatcher {
         R: WatchableResource + kube::Resource<Scope = kube::core::NamespaceResourceScope>,
         R::DynamicType: Default,
     {
-        let client = self.client.clone();
-        let namespace = self.current_namespace.clone();
-        let event_tx = self.event_tx.clone();
-        let display_name = R::display_name().to_string();
-        let resource_type = display_name.clone();
+        let targets = self.namespace_targets();
+        let status = KindStatus::new(R::display_name(), targets.len(), self.event_tx.clone());
+        for namespace in targets {
+            let client = self.client.clone();
+            let event_tx = self.event_tx.clone();
+            let status = status.clone();
+            let display_name = R::display_name().to_string();
+            let resource_type = display_name.clone();
+
+            let handle = tokio::spawn(async move {
+                // Use namespaced API if namespace is specified (more efficient)
+                // Otherwise use Api::all for watching all namespaces
+                // All Flux resources are namespaced, so both work
+                let api: Api<R> = match namespace {
+                    Some(ref ns) => {
+                        tracing::debug!("Starting {} watcher for namespace: {}", display_name, ns);
+                        Api::namespaced(client.clone(), ns)
+                    }
+                    None => {
+                        tracing::debug!("Starting {} watcher for all namespaces", display_name);
+                        Api::all(client.clone())
+                    }
+                };
 
-        let handle = tokio::spawn(async move {
-            // Use namespaced API if namespace is specified (more efficient)
-            // Otherwise use Api::all for watching all namespaces
-            // All Flux resources are namespaced, so both work
-            let api: Api<R> = match namespace {
-                Some(ref ns) => {
-                    tracing::debug!("Starting {} watcher for namespace: {}", display_name, ns);
-                    Api::namespaced(client.clone(), ns)
-                }
-                None => {
-                    tracing::debug!("Starting {} watcher for all namespaces", display_name);
-                    Api::all(client.clone())
-                }
-            };
+                // In kube 2.0, watcher handles initial resource loading via InitApply events
+                // We no longer need to manually list resources - the watcher does this automatically.
+                // CappedBackoff paces retries exponentially (resets on success), so a network blip
+                // or API-server restart recovers automatically without a tight error loop.
+                let mut w = Box::pin(
+                    watcher(api, watcher::Config::default()).backoff(CappedBackoff::new()),
+                );
+                let mut error_count = 0u32;
+                let mut synced = false;
 
-            // In kube 2.0, watcher handles initial resource loading via InitApply events
-            // We no longer need to manually list resources - the watcher does this automatically.
-            // CappedBackoff paces retries exponentially (resets on success), so a network blip
-            // or API-server restart recovers automatically without a tight error loop.
-            let mut w =
-                Box::pin(watcher(api, watcher::Config::default()).backoff(CappedBackoff::new()));
-            let mut error_count = 0u32;
+                while let Some(event) = w.next().await {
+                    let ev = match event {
+                        Ok(ev) => ev,
+                        Err(e) => {
+                            let err_str = format!("{}", e);
+                            if is_version_missing_error(&err_str) {
+                                // CRD not installed or version not served — stop, don't retry.
+                                // Clear any degraded state: this watcher is intentionally
+                                // stopping, not reconnecting.
+                                if error_count > 0 {
+                                    status.recovered();
+                                }
+                                tracing::info!(
+                                    "{} CRD not available in cluster, stopping watcher",
+                                    display_name
+                                );
+                                let _ = event_tx.send(WatchEvent::Error(format!(
+                                    "{} CRD not available in cluster",
+                                    display_name
+                                )));
+                                status.gave_up();
+                                break;
+                            }
 
-            while let Some(event) = w.next().await {
-                let ev = match event {
-                    Ok(ev) => ev,
-                    Err(e) => {
-                        let err_str = format!("{}", e);
-                        if is_version_missing_error(&err_str) {
-                            // CRD not installed or version not served — stop, don't retry.
-                            // Clear any degraded state: this watcher is intentionally
-                            // stopping, not reconnecting.
-                            if error_count > 0 {
-                                let _ = event_tx
-                                    .send(WatchEvent::WatcherRecovered(display_name.clone()));
+                            if is_forbidden_error(&err_str) {
+                                // RBAC denies access to this resource — retrying won't help
+                                // within the session, so stop rather than flag the watch
+                                // as degraded. Clear any degraded state from earlier errors.
+                                if error_count > 0 {
+                                    status.recovered();
+                                }
+                                tracing::info!(
+                                    "{} watch forbidden by RBAC, stopping watcher: {}",
+                                    display_name,
+                                    e
+                                );
+                                // Persistent, kind-specific: surfaced as the contextual
+                                // "restricted" empty-state (see #210), not a transient error.
+                                status.forbidden();
+                                break;
                             }
-                            tracing::info!(
-                                "{} CRD not available in cluster, stopping watcher",
-                                display_name
-                            );
-                            let _ = event_tx.send(WatchEvent::Error(format!(
-                                "{} CRD not available in cluster",
-                                display_name
-                            )));
-                            break;
-                        }
 
-                        if is_forbidden_error(&err_str) {
-                            // RBAC denies access to this resource — retrying won't help
-                            // within the session, so stop rather than flag the watch
-                            // as degraded. Clear any degraded state from earlier errors.
-                            if error_count > 0 {
-                                let _ = event_tx
-                                    .send(WatchEvent::WatcherRecovered(display_name.clone()));
+                            error_count += 1;
+                            if error_count == 1 {
+                                // First error after healthy operation: flag as degraded
+                                status.degraded();
                             }
-                            tracing::info!(
-                                "{} watch forbidden by RBAC, stopping watcher: {}",
-                                display_name,
-                                e
-                            );
-                            // Persistent, kind-specific: surfaced as the contextual
-                            // "restricted" empty-state (see #210), not a transient error.
-                            let _ =
-                                event_tx.send(WatchEvent::WatcherForbidden(display_name.clone()));
-                            break;
+                            // Keep watching: backoff paces the retries, so transient
+                            // outages (laptop sleep, VPN reconnect, API-server restart)
+                            // recover automatically. Only log occasionally to avoid spam.
+                            if error_count == 1 || error_count.is_multiple_of(10) {
+                                tracing::warn!(
+                                    "{} watcher error ({}): {}",
+                                    display_name,
+                                    error_count,
+                                    e
+                                );
+                                let _ = event_tx.send(WatchEvent::Error(format!(
+                                    "{} watcher error ({}): {}",
+                                    display_name, error_count, e
+                                )));
+                            } else {
+                                tracing::debug!(
+                                    "{} watcher error ({}): {}",
+                                    display_name,
+                                    error_count,
+                                    e
+                                );
+                            }
+                            continue;
                         }
+                    };
 
-                        error_count += 1;
-                        if error_count == 1 {
-                            // First error after healthy operation: flag as degraded
+                    // Any successful event after errors means the watch recovered.
+                    // Init is excluded: it fires before the HTTP request, so counting it
+                    // would flap the degraded state on every retry cycle.
+                    if error_count > 0 && !matches!(ev, watcher::Event::Init) {
+                        error_count = 0;
+                        status.recovered();
+                    }
+
+                    match ev {
+                        // Apply events (initial sync and updates) — namespace filtering happens in TUI
+                        watcher::Event::InitApply(obj) | watcher::Event::Apply(obj) => {
+                            let name = obj.name_any();
+                            let ns = obj.namespace().unwrap_or_default();
+                            let obj_json = serde_json::to_value(&obj).unwrap_or_default();
+                            let _ = event_tx.send(WatchEvent::Applied(
+                                resource_type.clone(),
+                                ns,
+                                name,
+                                obj_json,
+                            ));
+                        }
+                        watcher::Event::Delete(obj) => {
+                            let name = obj.name_any();
+                            let ns = obj.namespace().unwrap_or_default();
                             let _ =
-                                event_tx.send(WatchEvent::WatcherDegraded(display_name.clone()));
+                                event_tx.send(WatchEvent::Deleted(resource_type.clone(), ns, name));
                         }
-                        // Keep watching: backoff paces the retries, so transient
-                        // outages (laptop sleep, VPN reconnect, API-server restart)
-                        // recover automatically. Only log occasionally to avoid spam.
-                        if error_count == 1 || error_count.is_multiple_of(10) {
-                            tracing::warn!(
-                                "{} watcher error ({}): {}",
-                                display_name,
-                                error_count,
-                                e
-                            );
-                            let _ = event_tx.send(WatchEvent::Error(format!(
-                                "{} watcher error ({}): {}",
-                                display_name, error_count, e
-                            )));
-                        } else {
-                            tracing::debug!(
-                                "{} watcher error ({}): {}",
-                                display_name,
-                                error_count,
-                                e
-                            );
+                        watcher::Event::Init => {
+                            tracing::debug!("{} watcher init event", display_name);
+                        }
+                        watcher::Event::InitDone => {
+                            tracing::debug!("{} watcher initial list complete", display_name);
+                            status.synced(!synced);
+                            synced = true;
                         }
-                        continue;
                     }
-                };
-
-                // Any successful event after errors means the watch recovered.
-                // Init is excluded: it fires before the HTTP request, so counting it
-                // would flap the degraded state on every retry cycle.
-                if error_count > 0 && !matches!(ev, watcher::Event::Init) {
-                    error_count = 0;
-                    let _ = event_tx.send(WatchEvent::WatcherRecovered(display_name.clone()));
                 }
+            });
 
-                match ev {
-                    // Apply events (initial sync and updates) — namespace filtering happens in TUI
-                    watcher::Event::InitApply(obj) | watcher::Event::Apply(obj) => {
-                        let name = obj.name_any();
-                        let ns = obj.namespace().unwrap_or_default();
-                        let obj_json = serde_json::to_value(&obj).unwrap_or_default();
-                        let _ = event_tx.send(WatchEvent::Applied(
-                            resource_type.clone(),
-                            ns,
-                            name,
-                            obj_json,
-                        ));
-                    }
-                    watcher::Event::Delete(obj) => {
-                        let name = obj.name_any();
-                        let ns = obj.namespace().unwrap_or_default();
-                        let _ = event_tx.send(WatchEvent::Deleted(resource_type.clone(), ns, name));
-                    }
-                    // Watcher lifecycle events, no action needed
-                    watcher::Event::Init | watcher::Event::InitDone => {
-                        tracing::debug!("{} watcher init event", display_name);
-                    }
-                }
-            }
-        });
-
-        self.handles.push(handle);
+            self.handles.push(handle);
+        }
         Ok(())
     }
 
@@ -476,6 +521,9 @@ impl ResourceWatcher {
                                     .send(WatchEvent::WatcherRecovered(WATCHER_NAME.to_string()));
                             }
                             tracing::info!("Deployment watcher forbidden by RBAC, stopping: {}", e);
+                            // Nothing will ever be listed; don't leave sync waiters hanging
+                            let _ = event_tx
+                                .send(WatchEvent::InitialSyncDone(WATCHER_NAME.to_string()));
                             break;
                         }
                         error_count += 1;
@@ -513,9 +561,14 @@ impl ResourceWatcher {
                     watcher::Event::Delete(_) => {
                         // We don't need to track deletion - version will just become unavailable
                     }
-                    watcher::Event::Init | watcher::Event::InitDone => {
+                    watcher::Event::Init => {
                         tracing::debug!("Flux controller deployment watcher initialized");
                     }
+                    watcher::Event::InitDone => {
+                        tracing::debug!("Flux controller deployment watcher initial list complete");
+                        let _ =
+                            event_tx.send(WatchEvent::InitialSyncDone(WATCHER_NAME.to_string()));
+                    }
                 }
             }
         });
@@ -532,190 +585,198 @@ impl ResourceWatcher {
     ///   - HelmRelease                                           (v2beta2 → v2)
     fn watch_with_version_fallback(&mut self, resource_kind: FluxResourceKind) -> Result<()> {
         let api_resources = get_flux_api_resources_with_fallback(resource_kind)?;
-        let client = self.client.clone();
-        let namespace = self.current_namespace.clone();
-        let event_tx = self.event_tx.clone();
         let display_name = resource_kind.as_str();
-        let resource_type = display_name.to_string();
+        let targets = self.namespace_targets();
+        let status = KindStatus::new(display_name, targets.len(), self.event_tx.clone());
+        for namespace in targets {
+            let api_resources = api_resources.clone();
+            let client = self.client.clone();
+            let event_tx = self.event_tx.clone();
+            let status = status.clone();
+            let resource_type = display_name.to_string();
+
+            let handle = tokio::spawn(async move {
+                // Tracks the degraded banner across version attempts so a recovery
+                // (or giving up) on a later version still clears it.
+                let mut degraded_sent = false;
+                let mut synced = false;
+                for api_resource in api_resources {
+                    let version = api_resource.version.clone();
+                    let api: Api<DynamicObject> = match namespace {
+                        Some(ref ns) => {
+                            tracing::debug!(
+                                "Starting {} watcher (version {}) for namespace: {}",
+                                display_name,
+                                version,
+                                ns
+                            );
+                            Api::namespaced_with(client.clone(), ns, &api_resource)
+                        }
+                        None => {
+                            tracing::debug!(
+                                "Starting {} watcher (version {}) for all namespaces",
+                                display_name,
+                                version
+                            );
+                            Api::all_with(client.clone(), &api_resource)
+                        }
+                    };
 
-        let handle = tokio::spawn(async move {
-            // Tracks the degraded banner across version attempts so a recovery
-            // (or giving up) on a later version still clears it.
-            let mut degraded_sent = false;
-            for api_resource in api_resources {
-                let version = api_resource.version.clone();
-                let api: Api<DynamicObject> = match namespace {
-                    Some(ref ns) => {
-                        tracing::debug!(
-                            "Starting {} watcher (version {}) for namespace: {}",
-                            display_name,
-                            version,
-                            ns
-                        );
-                        Api::namespaced_with(client.clone(), ns, &api_resource)
-                    }
-                    None => {
-                        tracing::debug!(
-                            "Starting {} watcher (version {}) for all namespaces",
-                            display_name,
-                            version
-                        );
-                        Api::all_with(client.clone(), &api_resource)
-                    }
-                };
+                    let mut w = Box::pin(
+                        watcher(api, watcher::Config::default()).backoff(CappedBackoff::new()),
+                    );
+                    let mut error_count = 0u32;
+                    let mut version_working = false;
+
+                    loop {
+                        let ev = match w.next().await {
+                            Some(Ok(ev)) => ev,
+                            Some(Err(e)) => {
+                                let err_str = format!("{}", e);
+                                if is_version_missing_error(&err_str) && !version_working {
+                                    tracing::debug!(
+                                        "{} version {} not available, trying next version",
+                                        display_name,
+                                        version
+                                    );
+                                    break; // Try next version
+                                }
 
-                let mut w = Box::pin(
-                    watcher(api, watcher::Config::default()).backoff(CappedBackoff::new()),
-                );
-                let mut error_count = 0u32;
-                let mut version_working = false;
+                                if is_forbidden_error(&err_str) {
+                                    // RBAC denies access to this resource — every version
+                                    // would be forbidden too, so stop rather than flag the
+                                    // watch as degraded. Clear any degraded state first.
+                                    if degraded_sent {
+                                        status.recovered();
+                                    }
+                                    tracing::info!(
+                                        "{} watch forbidden by RBAC, stopping watcher: {}",
+                                        display_name,
+                                        e
+                                    );
+                                    // Persistent, kind-specific: drives the contextual
+                                    // "restricted" empty-state (see #210).
+                                    status.forbidden();
+                                    return;
+                                }
 
-                loop {
-                    let ev = match w.next().await {
-                        Some(Ok(ev)) => ev,
-                        Some(Err(e)) => {
-                            let err_str = format!("{}", e);
-                            if is_version_missing_error(&err_str) && !version_working {
+                                error_count += 1;
+                                if !degraded_sent {
+                                    degraded_sent = true;
+                                    status.degraded();
+                                }
+                                // Keep watching: backoff paces the retries, so transient
+                                // outages recover automatically instead of killing the watcher.
+                                if error_count == 1 || error_count.is_multiple_of(10) {
+                                    tracing::warn!(
+                                        "{} watcher (version {}) error ({}): {}",
+                                        display_name,
+                                        version,
+                                        error_count,
+                                        e
+                                    );
+                                    let _ = event_tx.send(WatchEvent::Error(format!(
+                                        "{} watcher error ({}): {}",
+                                        display_name, error_count, e
+                                    )));
+                                } else {
+                                    tracing::debug!(
+                                        "{} watcher (version {}) error ({}): {}",
+                                        display_name,
+                                        version,
+                                        error_count,
+                                        e
+                                    );
+                                }
+                                continue;
+                            }
+                            None => {
                                 tracing::debug!(
-                                    "{} version {} not available, trying next version",
+                                    "{} watcher (version {}) stream ended",
                                     display_name,
                                     version
                                 );
-                                break; // Try next version
+                                break;
                             }
-
-                            if is_forbidden_error(&err_str) {
-                                // RBAC denies access to this resource — every version
-                                // would be forbidden too, so stop rather than flag the
-                                // watch as degraded. Clear any degraded state first.
-                                if degraded_sent {
-                                    let _ = event_tx
-                                        .send(WatchEvent::WatcherRecovered(resource_type.clone()));
-                                }
-                                tracing::info!(
-                                    "{} watch forbidden by RBAC, stopping watcher: {}",
-                                    display_name,
-                                    e
-                                );
-                                // Persistent, kind-specific: drives the contextual
-                                // "restricted" empty-state (see #210).
-                                let _ = event_tx
-                                    .send(WatchEvent::WatcherForbidden(resource_type.clone()));
-                                return;
+                        };
+
+                        // Any successful event after errors means the watch recovered.
+                        // Init is excluded: it fires before the HTTP request succeeds.
+                        if !matches!(ev, watcher::Event::Init) {
+                            error_count = 0;
+                            if degraded_sent {
+                                degraded_sent = false;
+                                status.recovered();
                             }
+                        }
 
-                            error_count += 1;
-                            if !degraded_sent {
-                                degraded_sent = true;
-                                let _ = event_tx
-                                    .send(WatchEvent::WatcherDegraded(resource_type.clone()));
+                        match ev {
+                            watcher::Event::InitApply(obj) | watcher::Event::Apply(obj) => {
+                                version_working = true;
+                                let name = obj.name_any();
+                                let ns = obj.namespace().unwrap_or_default();
+                                let obj_json = serde_json::to_value(&obj).unwrap_or_default();
+                                let _ = event_tx.send(WatchEvent::Applied(
+                                    resource_type.clone(),
+                                    ns,
+                                    name,
+                                    obj_json,
+                                ));
                             }
-                            // Keep watching: backoff paces the retries, so transient
-                            // outages recover automatically instead of killing the watcher.
-                            if error_count == 1 || error_count.is_multiple_of(10) {
-                                tracing::warn!(
-                                    "{} watcher (version {}) error ({}): {}",
+                            watcher::Event::Delete(obj) => {
+                                version_working = true;
+                                let name = obj.name_any();
+                                let ns = obj.namespace().unwrap_or_default();
+                                let _ = event_tx.send(WatchEvent::Deleted(
+                                    resource_type.clone(),
+                                    ns,
+                                    name,
+                                ));
+                            }
+                            watcher::Event::Init => {
+                                // Init fires before the HTTP request — does NOT confirm the
+                                // version exists on this cluster.
+                                tracing::debug!(
+                                    "{} watcher (version {}) starting",
                                     display_name,
-                                    version,
-                                    error_count,
-                                    e
+                                    version
                                 );
-                                let _ = event_tx.send(WatchEvent::Error(format!(
-                                    "{} watcher error ({}): {}",
-                                    display_name, error_count, e
-                                )));
-                            } else {
+                            }
+                            watcher::Event::InitDone => {
+                                // InitDone fires after a successful initial list — confirms the version
+                                // exists on this cluster (even if there are no resources yet).
+                                version_working = true;
                                 tracing::debug!(
-                                    "{} watcher (version {}) error ({}): {}",
+                                    "{} watcher (version {}) confirmed available",
                                     display_name,
-                                    version,
-                                    error_count,
-                                    e
+                                    version
                                 );
+                                status.synced(!synced);
+                                synced = true;
                             }
-                            continue;
-                        }
-                        None => {
-                            tracing::debug!(
-                                "{} watcher (version {}) stream ended",
-                                display_name,
-                                version
-                            );
-                            break;
-                        }
-                    };
-
-                    // Any successful event after errors means the watch recovered.
-                    // Init is excluded: it fires before the HTTP request succeeds.
-                    if !matches!(ev, watcher::Event::Init) {
-                        error_count = 0;
-                        if degraded_sent {
-                            degraded_sent = false;
-                            let _ =
-                                event_tx.send(WatchEvent::WatcherRecovered(resource_type.clone()));
                         }
                     }
 
-                    match ev {
-                        watcher::Event::InitApply(obj) | watcher::Event::Apply(obj) => {
-                            version_working = true;
-                            let name = obj.name_any();
-                            let ns = obj.namespace().unwrap_or_default();
-                            let obj_json = serde_json::to_value(&obj).unwrap_or_default();
-                            let _ = event_tx.send(WatchEvent::Applied(
-                                resource_type.clone(),
-                                ns,
-                                name,
-                                obj_json,
-                            ));
-                        }
-                        watcher::Event::Delete(obj) => {
-                            version_working = true;
-                            let name = obj.name_any();
-                            let ns = obj.namespace().unwrap_or_default();
-                            let _ =
-                                event_tx.send(WatchEvent::Deleted(resource_type.clone(), ns, name));
-                        }
-                        watcher::Event::Init => {
-                            // Init fires before the HTTP request — does NOT confirm the
-                            // version exists on this cluster.
-                            tracing::debug!(
-                                "{} watcher (version {}) starting",
-                                display_name,
-                                version
-                            );
-                        }
-                        watcher::Event::InitDone => {
-                            // InitDone fires after a successful initial list — confirms the version
-                            // exists on this cluster (even if there are no resources yet).
-                            version_working = true;
-                            tracing::debug!(
-                                "{} watcher (version {}) confirmed available",
-                                display_name,
-                                version
-                            );
-                        }
+                    if version_working {
+                        tracing::info!("{} watcher using version {}", display_name, version);
+                        return;
                     }
                 }
 
-                if version_working {
-                    tracing::info!("{} watcher using version {}", display_name, version);
-                    return;
+                // Clear any degraded state before giving up: the watcher is stopping
+                // for good (CRD absent), not reconnecting.
+                if degraded_sent {
+                    status.recovered();
                 }
-            }
-
-            // Clear any degraded state before giving up: the watcher is stopping
-            // for good (CRD absent), not reconnecting.
-            if degraded_sent {
-                let _ = event_tx.send(WatchEvent::WatcherRecovered(resource_type.clone()));
-            }
-            let _ = event_tx.send(WatchEvent::Error(format!(
-                "{} watcher: no supported API version found on this cluster",
-                display_name
-            )));
-        });
-
-        self.handles.push(handle);
+                let _ = event_tx.send(WatchEvent::Error(format!(
+                    "{} watcher: no supported API version found on this cluster",
+                    display_name
+                )));
+                status.gave_up();
+            });
+
+            self.handles.push(handle);
+        }
         Ok(())
     }
 
@@ -784,9 +845,9 @@ impl ResourceWatcher {
 
     /// Whether the Kubernetes Events watcher is currently running.
     pub fn is_watching_kube_events(&self) -> bool {
-        self.kube_events_handle
-            .as_ref()
-            .is_some_and(|handle| !handle.is_finished())
+        self.kube_events_handles
+            .iter()
+            .any(|handle| !handle.is_finished())
     }
 
     /// Start watching Kubernetes Events (core/v1) in the current namespace
@@ -798,86 +859,92 @@ impl ResourceWatcher {
             return Ok(());
         }
 
-        let client = self.client.clone();
-        let namespace = self.current_namespace.clone();
-        let event_tx = self.event_tx.clone();
-
-        let handle = tokio::spawn(async move {
-            use k8s_openapi::api::core::v1::Event as CoreEvent;
+        const WATCHER_NAME: &str = "Kubernetes events";
+        let targets = self.namespace_targets();
+        let status = KindStatus::new(WATCHER_NAME, targets.len(), self.event_tx.clone());
+        for namespace in targets {
+            let client = self.client.clone();
+            let event_tx = self.event_tx.clone();
+            let status = status.clone();
 
-            let api: Api<CoreEvent> = match namespace {
-                Some(ref ns) => Api::namespaced(client, ns),
-                None => Api::all(client),
-            };
-            let mut w =
-                Box::pin(watcher(api, watcher::Config::default()).backoff(CappedBackoff::new()));
-            let mut error_count = 0u32;
+            let handle = tokio::spawn(async move {
+                use k8s_openapi::api::core::v1::Event as CoreEvent;
 
-            const WATCHER_NAME: &str = "Kubernetes events";
-            tracing::debug!("Starting Kubernetes events watcher");
+                let api: Api<CoreEvent> = match namespace {
+                    Some(ref ns) => Api::namespaced(client, ns),
+                    None => Api::all(client),
+                };
+                let mut w = Box::pin(
+                    watcher(api, watcher::Config::default()).backoff(CappedBackoff::new()),
+                );
+                let mut error_count = 0u32;
 
-            while let Some(event) = w.next().await {
-                let ev = match event {
-                    Ok(ev) => ev,
-                    Err(e) => {
-                        if is_forbidden_error(&format!("{}", e)) {
-                            // RBAC denies access — retrying won't help, so stop rather
-                            // than flag the watch as degraded. Clear earlier degraded state.
-                            if error_count > 0 {
-                                let _ = event_tx
-                                    .send(WatchEvent::WatcherRecovered(WATCHER_NAME.to_string()));
+                tracing::debug!("Starting Kubernetes events watcher");
+
+                while let Some(event) = w.next().await {
+                    let ev = match event {
+                        Ok(ev) => ev,
+                        Err(e) => {
+                            if is_forbidden_error(&format!("{}", e)) {
+                                // RBAC denies access — retrying won't help, so stop rather
+                                // than flag the watch as degraded. Clear earlier degraded state.
+                                if error_count > 0 {
+                                    status.recovered();
+                                }
+                                let _ = event_tx.send(WatchEvent::Error(
+                                    "Events watcher forbidden by RBAC".to_string(),
+                                ));
+                                tracing::info!("Events watcher forbidden by RBAC, stopping: {}", e);
+                                break;
                             }
-                            let _ = event_tx.send(WatchEvent::Error(
-                                "Events watcher forbidden by RBAC".to_string(),
-                            ));
-                            tracing::info!("Events watcher forbidden by RBAC, stopping: {}", e);
-                            break;
-                        }
-                        error_count += 1;
-                        if error_count == 1 {
-                            let _ = event_tx
-                                .send(WatchEvent::WatcherDegraded(WATCHER_NAME.to_string()));
-                        }
-                        // Keep watching: backoff paces the retries.
-                        if error_count == 1 || error_count.is_multiple_of(10) {
-                            tracing::warn!("Events watcher error ({}): {}", error_count, e);
+                            error_count += 1;
+                            if error_count == 1 {
+                                status.degraded();
+                            }
+                            // Keep watching: backoff paces the retries.
+                            if error_count == 1 || error_count.is_multiple_of(10) {
+                                tracing::warn!("Events watcher error ({}): {}", error_count, e);
+                            }
+                            continue;
                         }
-                        continue;
-                    }
-                };
-
-                // Init is excluded: it fires before the HTTP request succeeds
-                if error_count > 0 && !matches!(ev, watcher::Event::Init) {
-                    error_count = 0;
-                    let _ = event_tx.send(WatchEvent::WatcherRecovered(WATCHER_NAME.to_string()));
-                }
+                    };
 
-                match ev {
-                    watcher::Event::InitApply(kube_event) | watcher::Event::Apply(kube_event) => {
-                        let event_json = serde_json::to_value(&kube_event).unwrap_or_default();
-                        let _ = event_tx.send(WatchEvent::KubeEventApplied(event_json));
+                    // Init is excluded: it fires before the HTTP request succeeds
+                    if error_count > 0 && !matches!(ev, watcher::Event::Init) {
+                        error_count = 0;
+                        status.recovered();
                     }
-                    watcher::Event::Delete(kube_event) => {
-                        if let Some(uid) = kube_event.metadata.uid {
-                            let _ = event_tx.send(WatchEvent::KubeEventDeleted(uid));
+
+                    match ev {
+                        watcher::Event::InitApply(kube_event)
+                        | watcher::Event::Apply(kube_event) => {
+                            let event_json = serde_json::to_value(&kube_event).unwrap_or_default();
+                            let _ = event_tx.send(WatchEvent::KubeEventApplied(event_json));
+                        }
+                        watcher::Event::Delete(kube_event) => {
+                            if let Some(uid) = kube_event.metadata.uid {
+                                let _ = event_tx.send(WatchEvent::KubeEventDeleted(uid));
+                            }
+                        }
+                        watcher::Event::Init | watcher::Event::InitDone => {
+                            tracing::debug!("Kubernetes events watcher initialized");
                         }
-                    }
-                    watcher::Event::Init | watcher::Event::InitDone => {
-                        tracing::debug!("Kubernetes events watcher initialized");
                     }
                 }
-            }
-        });
+            });
 
-        self.kube_events_handle = Some(handle);
+            self.kube_events_handles.push(handle);
+        }
         Ok(())
     }
 
     /// Stop the Kubernetes Events watcher without touching the resource
     /// watchers. A no-op if it isn't running.
     pub fn stop_kube_events(&mut self) {
-        if let Some(handle) = self.kube_events_handle.take() {
+        if !self.kube_events_handles.is_empty() {
             tracing::debug!("Stopping Kubernetes events watcher");
+        }
+        for handle in self.kube_events_handles.drain(..) {
             handle.abort();
         }
     }
@@ -987,7 +1054,7 @@ impl ResourceWatcher {
             if let Some(handle) = self.crd_discovery_handle.take() {
                 handle.abort();
             }
-            for (_, handle) in self.extra_handles.drain() {
+            for handle in self.extra_handles.drain().flat_map(|(_, handles)| handles) {
                 handle.abort();
             }
             // Same reasoning as in `stop()`: registered kinds must not outlive
@@ -1007,102 +1074,115 @@ impl ResourceWatcher {
         if self
             .extra_handles
             .get(&extra.kind)
-            .is_some_and(|handle| !handle.is_finished())
+            .is_some_and(|handles| handles.iter().any(|handle| !handle.is_finished()))
         {
             return;
         }
 
-        let client = self.client.clone();
-        let namespace = self.current_namespace.clone();
-        let event_tx = self.event_tx.clone();
-        let resource_type = extra.kind.clone();
-        let api_resource = kube::core::ApiResource {
-            group: extra.group.clone(),
-            version: extra.version.clone(),
-            api_version: format!("{}/{}", extra.group, extra.version),
-            kind: extra.kind.clone(),
-            plural: extra.plural.clone(),
-        };
-
-        let handle = tokio::spawn(async move {
-            let api: Api<DynamicObject> = match namespace {
-                Some(ref ns) => Api::namespaced_with(client, ns, &api_resource),
-                None => Api::all_with(client, &api_resource),
+        let targets = self.namespace_targets();
+        let status = KindStatus::new(extra.kind.clone(), targets.len(), self.event_tx.clone());
+        let mut handles = Vec::new();
+        for namespace in targets {
+            let client = self.client.clone();
+            let event_tx = self.event_tx.clone();
+            let status = status.clone();
+            let resource_type = extra.kind.clone();
+            let api_resource = kube::core::ApiResource {
+                group: extra.group.clone(),
+                version: extra.version.clone(),
+                api_version: format!("{}/{}", extra.group, extra.version),
+                kind: extra.kind.clone(),
+                plural: extra.plural.clone(),
             };
-            let mut w =
-                Box::pin(watcher(api, watcher::Config::default()).backoff(CappedBackoff::new()));
-            let mut error_count = 0u32;
-            tracing::debug!("Starting discovered-kind watcher for {}", resource_type);
 
-            while let Some(event) = w.next().await {
-                let ev = match event {
-                    Ok(ev) => ev,
-                    Err(e) => {
-                        if is_forbidden_error(&format!("{}", e)) {
-                            if error_count > 0 {
-                                let _ = event_tx
-                                    .send(WatchEvent::WatcherRecovered(resource_type.clone()));
+            let handle = tokio::spawn(async move {
+                let api: Api<DynamicObject> = match namespace {
+                    Some(ref ns) => Api::namespaced_with(client, ns, &api_resource),
+                    None => Api::all_with(client, &api_resource),
+                };
+                let mut w = Box::pin(
+                    watcher(api, watcher::Config::default()).backoff(CappedBackoff::new()),
+                );
+                let mut error_count = 0u32;
+                let mut synced = false;
+                tracing::debug!("Starting discovered-kind watcher for {}", resource_type);
+
+                while let Some(event) = w.next().await {
+                    let ev = match event {
+                        Ok(ev) => ev,
+                        Err(e) => {
+                            if is_forbidden_error(&format!("{}", e)) {
+                                if error_count > 0 {
+                                    status.recovered();
+                                }
+                                tracing::info!(
+                                    "{} watcher forbidden by RBAC, stopping: {}",
+                                    resource_type,
+                                    e
+                                );
+                                break;
                             }
-                            tracing::info!(
-                                "{} watcher forbidden by RBAC, stopping: {}",
-                                resource_type,
-                                e
-                            );
-                            break;
-                        }
-                        error_count += 1;
-                        if error_count == 1 {
-                            let _ =
-                                event_tx.send(WatchEvent::WatcherDegraded(resource_type.clone()));
-                        }
-                        if error_count == 1 || error_count.is_multiple_of(10) {
-                            tracing::warn!(
-                                "{} watcher error ({}): {}",
-                                resource_type,
-                                error_count,
-                                e
-                            );
+                            error_count += 1;
+                            if error_count == 1 {
+                                status.degraded();
+                            }
+                            if error_count == 1 || error_count.is_multiple_of(10) {
+                                tracing::warn!(
+                                    "{} watcher error ({}): {}",
+                                    resource_type,
+                                    error_count,
+                                    e
+                                );
+                            }
+                            continue;
                         }
-                        continue;
-                    }
-                };
+                    };
 
-                if error_count > 0 && !matches!(ev, watcher::Event::Init) {
-                    error_count = 0;
-                    let _ = event_tx.send(WatchEvent::WatcherRecovered(resource_type.clone()));
-                }
+                    if error_count > 0 && !matches!(ev, watcher::Event::Init) {
+                        error_count = 0;
+                        status.recovered();
+                    }
 
-                match ev {
-                    watcher::Event::InitApply(obj) | watcher::Event::Apply(obj) => {
-                        let name = obj.name_any();
-                        let ns = obj.namespace().unwrap_or_default();
-                        if let Ok(obj_json) = serde_json::to_value(&obj) {
-                            let _ = event_tx.send(WatchEvent::Applied(
-                                resource_type.clone(),
-                                ns,
-                                name,
-                                obj_json,
-                            ));
+                    match ev {
+                        watcher::Event::InitApply(obj) | watcher::Event::Apply(obj) => {
+                            let name = obj.name_any();
+                            let ns = obj.namespace().unwrap_or_default();
+                            if let Ok(obj_json) = serde_json::to_value(&obj) {
+                                let _ = event_tx.send(WatchEvent::Applied(
+                                    resource_type.clone(),
+                                    ns,
+                                    name,
+                                    obj_json,
+                                ));
+                            }
+                        }
+                        watcher::Event::Delete(obj) => {
+                            let name = obj.name_any();
+                            let ns = obj.namespace().unwrap_or_default();
+                            let _ =
+                                event_tx.send(WatchEvent::Deleted(resource_type.clone(), ns, name));
+                        }
+                        watcher::Event::Init => {}
+                        watcher::Event::InitDone => {
+                            status.synced(!synced);
+                            synced = true;
                         }
                     }
-                    watcher::Event::Delete(obj) => {
-                        let name = obj.name_any();
-                        let ns = obj.namespace().unwrap_or_default();
-                        let _ = event_tx.send(WatchEvent::Deleted(resource_type.clone(), ns, name));
-                    }
-                    watcher::Event::Init | watcher::Event::InitDone => {}
                 }
-            }
-        });
+            });
 
-        self.extra_handles.insert(extra.kind.clone(), handle);
+            handles.push(handle);
+        }
+        self.extra_handles.insert(extra.kind.clone(), handles);
     }
 
     /// Stop the dynamic watcher for one discovered kind (its CRD is gone).
     pub fn stop_extra(&mut self, kind: &str) {
-        if let Some(handle) = self.extra_handles.remove(kind) {
+        if let Some(handles) = self.extra_handles.remove(kind) {
             tracing::debug!("Stopping discovered-kind watcher for {}", kind);
-            handle.abort();
+            for handle in handles {
+                handle.abort();
+            }
         }
     }
 
@@ -1116,7 +1196,7 @@ impl ResourceWatcher {
         if let Some(handle) = self.crd_discovery_handle.take() {
             handle.abort();
         }
-        for (_, handle) in self.extra_handles.drain() {
+        for handle in self.extra_handles.drain().flat_map(|(_, handles)| handles) {
             handle.abort();
         }
         // Discovered kinds belong to the cluster this watcher was pointed at.
@@ -1201,6 +1281,15 @@ pub fn extract_reconciliation_info(
     })
 }
 
+/// Readiness as displayed: stateless kinds have no `status.conditions`, so a
+/// missing Ready condition counts as ready for them.
+pub fn resolve_ready(resource_type: &str, ready: Option<bool>) -> Option<bool> {
+    match FluxResourceKind::parse_optional(resource_type) {
+        Some(kind) if ready.is_none() && kind.is_stateless() => Some(true),
+        _ => ready,
+    }
+}
+
 /// Extract common status fields from a Flux CRD object JSON
 pub fn extract_status_fields(
     obj: &serde_json::Value,
@@ -1262,7 +1351,7 @@ pub fn extract_status_fields(
 impl std::fmt::Debug for ResourceWatcher {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ResourceWatcher")
-            .field("current_namespace", &self.current_namespace)
+            .field("namespaces", &self.namespaces)
             .field("handles", &format!("<{} handles>", self.handles.len()))
             .field("client", &"<kube::Client>")
             .field("event_tx", &"<mpsc::UnboundedSender>")
@@ -0,0 +1,232 @@
+//! Namespace scopes with several namespaces
+//!
+//! Besides one namespace or all of them, the watchers can cover an explicit
+//! set (`apps,payments`) or the namespaces matching a label selector
+//! (`team=payments`). Tenants that may only list a few namespaces get a 403
+//! for cluster-wide watches, so each namespace of a set is watched on its
+//! own, all feeding the same [`WatchEvent`] channel.
+//!
+//! [`KindStatus`] keeps the status events of those per-namespace watchers at
+//! kind level, so consumers see one initial sync, one degraded/recovered pair
+//! and one forbidden per kind regardless of the namespace count.
+
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+
+use super::WatchEvent;
+
+/// What the watchers cover, as typed after `:ns`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NamespaceScope {
+    /// Every namespace (`all`, `-A`)
+    All,
+    /// One or more namespaces, each watched on its own
+    Namespaces(Vec<String>),
+    /// Namespaces whose labels match a selector, resolved when applied
+    Selector(String),
+}
+
+impl NamespaceScope {
+    /// Parse a `:ns` argument. A selector is recognized by its operators
+    /// (`=`, `!=`, `in (…)`, `notin (…)`), which namespace names cannot
+    /// contain; otherwise commas separate namespaces.
+    pub fn parse(arg: &str) -> Self {
+        let arg = arg.trim();
+        if arg.is_empty() || arg == "all" || arg == "-A" {
+            return Self::All;
+        }
+        if is_label_selector(arg) {
+            return Self::Selector(arg.to_string());
+        }
+        let mut namespaces: Vec<String> = Vec::new();
+        for ns in arg.split([',', ' ']).filter(|ns| !ns.is_empty()) {
+            if !namespaces.iter().any(|n| n == ns) {
+                namespaces.push(ns.to_string());
+            }
+        }
+        Self::Namespaces(namespaces)
+    }
+}
+
+/// Whether a `:ns` argument is a label selector rather than namespace names
+pub fn is_label_selector(arg: &str) -> bool {
+    arg.contains('=') || arg.contains(" in ") || arg.contains(" notin ")
+}
+
+/// Kind-level status of the per-namespace watchers of one kind.
+///
+/// Each watcher task reports through a clone. With a single namespace every
+/// call passes straight through, matching a lone watcher.
+#[derive(Clone)]
+pub(super) struct KindStatus {
+    name: String,
+    event_tx: mpsc::UnboundedSender<WatchEvent>,
+    counts: Arc<Mutex<KindCounts>>,
+}
+
+#[derive(Default)]
+struct KindCounts {
+    watchers: usize,
+    /// Watchers that finished their first list, were forbidden or gave up
+    done: usize,
+    forbidden: usize,
+    degraded: usize,
+}
+
+impl KindStatus {
+    pub(super) fn new(
+        name: impl Into<String>,
+        watchers: usize,
+        event_tx: mpsc::UnboundedSender<WatchEvent>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            event_tx,
+            counts: Arc::new(Mutex::new(KindCounts {
+                watchers,
+                ..KindCounts::default()
+            })),
+        }
+    }
+
+    fn counts(&self) -> std::sync::MutexGuard<'_, KindCounts> {
+        self.counts
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn send(&self, event: WatchEvent) {
+        let _ = self.event_tx.send(event);
+    }
+
+    /// A watcher finished an initial list. `first` is false for relists after
+    /// a desync, which are passed on as they come.
+    pub(super) fn synced(&self, first: bool) {
+        if first {
+            self.finish(false);
+        } else {
+            self.send(WatchEvent::InitialSyncDone(self.name.clone()));
+        }
+    }
+
+    /// A watcher stopped for good without listing (CRD absent).
+    pub(super) fn gave_up(&self) {
+        self.finish(false);
+    }
+
+    /// A watcher was stopped because RBAC forbids it. The kind is reported
+    /// forbidden only when every namespace is; otherwise the others' lists
+    /// complete the kind's sync.
+    pub(super) fn forbidden(&self) {
+        self.finish(true);
+    }
+
+    fn finish(&self, forbidden: bool) {
+        let event = {
+            let mut counts = self.counts();
+            counts.done += 1;
+            if forbidden {
+                counts.forbidden += 1;
+            }
+            if counts.done != counts.watchers {
+                None
+            } else if counts.forbidden == counts.watchers {
+                Some(WatchEvent::WatcherForbidden(self.name.clone()))
+            } else {
+                Some(WatchEvent::InitialSyncDone(self.name.clone()))
+            }
+        };
+        if let Some(event) = event {
+            self.send(event);
+        }
+    }
+
+    /// A watcher started erroring; the kind turns degraded with the first.
+    pub(super) fn degraded(&self) {
+        let first = {
+            let mut counts = self.counts();
+            counts.degraded += 1;
+            counts.degraded == 1
+        };
+        if first {
+            self.send(WatchEvent::WatcherDegraded(self.name.clone()));
+        }
+    }
+
+    /// A degraded watcher recovered; the kind recovers with the last.
+    pub(super) fn recovered(&self) {
+        let last = {
+            let mut counts = self.counts();
+            counts.degraded = counts.degraded.saturating_sub(1);
+            counts.degraded == 0
+        };
+        if last {
+            self.send(WatchEvent::WatcherRecovered(self.name.clone()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain(rx: &mut mpsc::UnboundedReceiver<WatchEvent>) -> Vec<String> {
+        let mut events = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            events.push(format!("{:?}", event));
+        }
+        events
+    }
+
+    #[test]
+    fn scopes_parse_sets_and_selectors() {
+        assert_eq!(NamespaceScope::parse("all"), NamespaceScope::All);
+        assert_eq!(NamespaceScope::parse("-A"), NamespaceScope::All);
+        assert_eq!(
+            NamespaceScope::parse("apps"),
+            NamespaceScope::Namespaces(vec!["apps".to_string()])
+        );
+        assert_eq!(
+            NamespaceScope::parse("apps,payments, flux-system,apps"),
+            NamespaceScope::Namespaces(vec![
+                "apps".to_string(),
+                "payments".to_string(),
+                "flux-system".to_string()
+            ])
+        );
+        assert_eq!(
+            NamespaceScope::parse("team=payments,env!=dev"),
+            NamespaceScope::Selector("team=payments,env!=dev".to_string())
+        );
+        assert_eq!(
+            NamespaceScope::parse("team in (a,b)"),
+            NamespaceScope::Selector("team in (a,b)".to_string())
+        );
+    }
+
+    #[test]
+    fn kind_status_reports_once_per_kind() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let status = KindStatus::new("HelmRelease", 3, tx);
+
+        status.degraded();
+        status.degraded();
+        status.recovered();
+        assert_eq!(drain(&mut rx), vec!["WatcherDegraded(\"HelmRelease\")"]);
+        status.recovered();
+        assert_eq!(drain(&mut rx), vec!["WatcherRecovered(\"HelmRelease\")"]);
+
+        // One namespace forbidden, the others listed: the kind is synced
+        status.forbidden();
+        status.synced(true);
+        assert!(drain(&mut rx).is_empty());
+        status.synced(true);
+        assert_eq!(drain(&mut rx), vec!["InitialSyncDone(\"HelmRelease\")"]);
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let status = KindStatus::new("Kustomization", 2, tx);
+        status.forbidden();
+        status.forbidden();
+        assert_eq!(drain(&mut rx), vec!["WatcherForbidden(\"Kustomization\")"]);
+    }
+}
@@ -0,0 +1,525 @@
+//! Structured filter queries for resource lists
+//!
+//! A query is a whitespace-separated list of terms that must all match.
+//! A bare word matches names containing it (the original `/` filter);
+//! qualified terms match other fields of [`ResourceInfo`]:
+//!
+//! | Term                        | Matches                                         |
+//! | --------------------------- | ----------------------------------------------- |
+//! | `name:web`, `name~WEB`      | name contains (`~` ignores case)                |
+//! | `kind:hr`                   | resource type (kind, short name or plural)      |
+//! | `ns:apps`                   | namespace                                       |
+//! | `status:failed`             | `ready`, `failed`, `suspended`, `unknown`, `healthy`, `unhealthy` |
+//! | `label:team=payments`       | label key/value prefixes; `label:` any label    |
+//! | `ann:key=value`             | annotation key/value prefixes                   |
+//! | `msg~"timed out"`           | status message contains                         |
+//! | `rev:abc123`                | revision contains                               |
+//! | `age>2h`, `age<=7d`         | creation age (`s`, `m`, `h`, `d`, `w` units)    |
+//! | `col:CHART=podinfo`         | list column value contains; `col:X` has a value |
+//! | `ctx:prod`, `ctx~EU`        | kubeconfig context contains                     |
+//!
+//! A leading `!` negates a term; double quotes allow spaces in a value.
+
+use super::ResourceInfo;
+use crate::models::FluxResourceKind;
+use anyhow::{Result, anyhow, bail};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// A parsed filter query: all terms must match.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FilterQuery {
+    terms: Vec<Term>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Term {
+    negated: bool,
+    predicate: Predicate,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Predicate {
+    Name(TextMatch),
+    Kind(String),
+    Namespace(String),
+    Status(Status),
+    Label {
+        key: String,
+        value: Option<String>,
+    },
+    Annotation {
+        key: String,
+        value: Option<String>,
+    },
+    Message(TextMatch),
+    Revision(TextMatch),
+    Age {
+        op: AgeOp,
+        seconds: i64,
+    },
+    /// A list column (built-in or configured), keyed by upper-case header
+    Column {
+        name: String,
+        value: Option<TextMatch>,
+    },
+    /// The kubeconfig context the resource was seen in
+    Context(TextMatch),
+}
+
+/// Substring match; the case-insensitive needle is stored lowercased.
+#[derive(Debug, Clone, PartialEq)]
+enum TextMatch {
+    Contains(String),
+    ContainsIgnoreCase(String),
+}
+
+impl TextMatch {
+    fn matches(&self, text: &str) -> bool {
+        match self {
+            TextMatch::Contains(needle) => text.contains(needle.as_str()),
+            TextMatch::ContainsIgnoreCase(needle) => text.to_lowercase().contains(needle.as_str()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Status {
+    Ready,
+    Failed,
+    Suspended,
+    Unknown,
+    Healthy,
+    Unhealthy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AgeOp {
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+}
+
+impl FilterQuery {
+    /// Parse a query. Errors name the offending term.
+    pub fn parse(query: &str) -> Result<Self> {
+        let terms = tokenize(query)?
+            .iter()
+            .map(|token| parse_term(token))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { terms })
+    }
+
+    /// Whether the query has no terms (matches everything).
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Whether the query is only plain name substrings, i.e. what the `/`
+    /// filter matched before qualified terms existed.
+    pub fn is_name_only(&self) -> bool {
+        self.terms.iter().all(|term| {
+            !term.negated && matches!(term.predicate, Predicate::Name(TextMatch::Contains(_)))
+        })
+    }
+
+    /// Whether any term reads list column values, which the caller then
+    /// passes to [`matches_with_columns`](Self::matches_with_columns).
+    pub fn uses_columns(&self) -> bool {
+        self.terms
+            .iter()
+            .any(|term| matches!(term.predicate, Predicate::Column { .. }))
+    }
+
+    /// Whether `resource` matches every term.
+    pub fn matches(&self, resource: &ResourceInfo) -> bool {
+        self.matches_at(resource, Utc::now())
+    }
+
+    /// Like [`matches`](Self::matches), with ages measured at `now`.
+    pub fn matches_at(&self, resource: &ResourceInfo, now: DateTime<Utc>) -> bool {
+        self.matches_with_columns(resource, &HashMap::new(), now)
+    }
+
+    /// Like [`matches_at`](Self::matches_at), with the resource's list column
+    /// values (as extracted for the type-specific list) for `col:` terms.
+    pub fn matches_with_columns(
+        &self,
+        resource: &ResourceInfo,
+        columns: &HashMap<String, String>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        self.matches_in_context(resource, "", columns, now)
+    }
+
+    /// Like [`matches_with_columns`](Self::matches_with_columns), for a
+    /// resource seen in kubeconfig `context` (matched by `ctx:` terms).
+    pub fn matches_in_context(
+        &self,
+        resource: &ResourceInfo,
+        context: &str,
+        columns: &HashMap<String, String>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        self.terms
+            .iter()
+            .all(|term| term.predicate.matches(resource, context, columns, now) != term.negated)
+    }
+}
+
+impl Predicate {
+    fn matches(
+        &self,
+        r: &ResourceInfo,
+        context: &str,
+        columns: &HashMap<String, String>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        match self {
+            Predicate::Name(text) => text.matches(&r.name),
+            Predicate::Kind(kind) => r.resource_type.eq_ignore_ascii_case(kind),
+            Predicate::Namespace(namespace) => r.namespace == *namespace,
+            Predicate::Status(status) => match status {
+                Status::Ready => r.ready == Some(true),
+                Status::Failed => r.ready == Some(false),
+                Status::Suspended => r.effective_suspended(),
+                Status::Unknown => r.ready.is_none(),
+                Status::Healthy => r.is_healthy(),
+                Status::Unhealthy => !r.is_healthy(),
+            },
+            Predicate::Label { key, value } => prefix_pair_matches(&r.labels, key, value),
+            Predicate::Annotation { key, value } => prefix_pair_matches(&r.annotations, key, value),
+            Predicate::Message(text) => r.message.as_deref().is_some_and(|m| text.matches(m)),
+            Predicate::Revision(text) => r.revision.as_deref().is_some_and(|rev| text.matches(rev)),
+            Predicate::Age { op, seconds } => r.age.is_some_and(|created| {
+                let age = (now - created).num_seconds();
+                match op {
+                    AgeOp::Greater => age > *seconds,
+                    AgeOp::GreaterOrEqual => age >= *seconds,
+                    AgeOp::Less => age < *seconds,
+                    AgeOp::LessOrEqual => age <= *seconds,
+                }
+            }),
+            Predicate::Column { name, value } => {
+                columns.get(name).is_some_and(|column| match value {
+                    Some(text) => text.matches(column),
+                    None => !column.is_empty(),
+                })
+            }
+            Predicate::Context(text) => text.matches(context),
+        }
+    }
+}
+
+/// Key and value prefix match; an empty key matches any entry.
+fn prefix_pair_matches(map: &HashMap<String, String>, key: &str, value: &Option<String>) -> bool {
+    map.iter().any(|(k, v)| {
+        k.starts_with(key)
+            && value
+                .as_ref()
+                .is_none_or(|value| v.starts_with(value.as_str()))
+    })
+}
+
+/// Split on whitespace outside double quotes, dropping the quotes.
+fn tokenize(query: &str) -> Result<Vec<String>> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    for c in query.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if in_quotes {
+        bail!("Unterminated quote");
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    Ok(tokens)
+}
+
+fn parse_term(token: &str) -> Result<Term> {
+    let (negated, body) = match token.strip_prefix('!') {
+        Some(rest) if !rest.is_empty() => (true, rest),
+        _ => (false, token),
+    };
+
+    let Some(op_start) = body.find([':', '~', '>', '<']) else {
+        return Ok(Term {
+            negated,
+            predicate: Predicate::Name(TextMatch::Contains(body.to_string())),
+        });
+    };
+    let field = &body[..op_start];
+    let rest = &body[op_start..];
+    let (op, value) = ["<=", ">=", ":", "~", ">", "<"]
+        .iter()
+        .find_map(|op| rest.strip_prefix(op).map(|value| (*op, value)))
+        .ok_or_else(|| anyhow!("Invalid term '{}'", token))?;
+    if field.is_empty() {
+        bail!("Missing field before '{}' in '{}'", op, token);
+    }
+
+    let field = field.to_lowercase();
+    let is_comparison = matches!(op, ">" | ">=" | "<" | "<=");
+    if is_comparison != (field == "age") {
+        if is_comparison {
+            bail!("'{}' only applies to age (e.g. age>2h)", op);
+        }
+        bail!("age needs a comparison, e.g. age>2h or age<30m");
+    }
+    // An empty label/annotation filter means "has any"; everything else needs a value
+    let allows_empty = matches!(
+        field.as_str(),
+        "label" | "labels" | "ann" | "annotation" | "annotations"
+    );
+    if value.is_empty() && !allows_empty {
+        bail!("'{}{}' needs a value", field, op);
+    }
+
+    let text = || {
+        if op == "~" {
+            TextMatch::ContainsIgnoreCase(value.to_lowercase())
+        } else {
+            TextMatch::Contains(value.to_string())
+        }
+    };
+    let exact_only = |name: &str| {
+        if op == "~" {
+            Err(anyhow!(
+                "'{}' only supports ':' (e.g. {}:{})",
+                name,
+                name,
+                value
+            ))
+        } else {
+            Ok(())
+        }
+    };
+
+    let predicate = match field.as_str() {
+        "name" => Predicate::Name(text()),
+        "msg" | "message" => Predicate::Message(text()),
+        "rev" | "revision" => Predicate::Revision(text()),
+        "ctx" | "context" => Predicate::Context(text()),
+        "kind" | "type" => {
+            exact_only("kind")?;
+            Predicate::Kind(resolve_kind(value)?)
+        }
+        "ns" | "namespace" => {
+            exact_only("ns")?;
+            Predicate::Namespace(value.to_string())
+        }
+        "status" => {
+            exact_only("status")?;
+            Predicate::Status(parse_status(value)?)
+        }
+        "label" | "labels" => {
+            exact_only("label")?;
+            let (key, value) = split_pair(value);
+            Predicate::Label { key, value }
+        }
+        "ann" | "annotation" | "annotations" => {
+            exact_only("ann")?;
+            let (key, value) = split_pair(value);
+            Predicate::Annotation { key, value }
+        }
+        "age" => Predicate::Age {
+            op: match op {
+                ">" => AgeOp::Greater,
+                ">=" => AgeOp::GreaterOrEqual,
+                "<" => AgeOp::Less,
+                _ => AgeOp::LessOrEqual,
+            },
+            seconds: parse_age(value)?,
+        },
+        "col" | "column" => {
+            let (name, column_value) = split_pair(value);
+            if name.is_empty() {
+                bail!("'{}{}' needs a column name", field, op);
+            }
+            Predicate::Column {
+                name: name.to_uppercase(),
+                value: column_value.filter(|v| !v.is_empty()).map(|v| {
+                    if op == "~" {
+                        TextMatch::ContainsIgnoreCase(v.to_lowercase())
+                    } else {
+                        TextMatch::Contains(v)
+                    }
+                }),
+            }
+        }
+        other => bail!(
+            "Unknown field '{}' (use name, kind, ns, status, label, ann, msg, rev, age, col or ctx)",
+            other
+        ),
+    };
+    Ok(Term { negated, predicate })
+}
+
+fn split_pair(value: &str) -> (String, Option<String>) {
+    match value.split_once('=') {
+        Some((key, value)) => (key.to_string(), Some(value.to_string())),
+        None => (value.to_string(), None),
+    }
+}
+
+/// Resolve a kind, short name or plural to the resource type name.
+fn resolve_kind(value: &str) -> Result<String> {
+    if let Some(kind) = FluxResourceKind::from_str_case_insensitive(value) {
+        return Ok(kind.as_str().to_string());
+    }
+    crate::models::extra_kinds::global()
+        .resolve_command(value)
+        .ok_or_else(|| anyhow!("Unknown kind '{}'", value))
+}
+
+fn parse_status(value: &str) -> Result<Status> {
+    Ok(match value.to_lowercase().as_str() {
+        "ready" => Status::Ready,
+        "failed" | "notready" | "false" => Status::Failed,
+        "suspended" => Status::Suspended,
+        "unknown" => Status::Unknown,
+        "healthy" => Status::Healthy,
+        "unhealthy" => Status::Unhealthy,
+        _ => bail!(
+            "Unknown status '{}' (use ready, failed, suspended, unknown, healthy or unhealthy)",
+            value
+        ),
+    })
+}
+
+/// Parse an age (see [`crate::duration::parse_duration`]) into seconds.
+fn parse_age(value: &str) -> Result<i64> {
+    let age = crate::duration::parse_duration(value)
+        .map_err(|_| anyhow!("Invalid age '{}': use e.g. 30m, 2h or 7d", value))?;
+    i64::try_from(age.as_secs()).map_err(|_| anyhow!("Age '{}' is too large", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource(name: &str, resource_type: &str, ready: Option<bool>) -> ResourceInfo {
+        ResourceInfo {
+            name: name.to_string(),
+            namespace: "apps".to_string(),
+            resource_type: resource_type.to_string(),
+            age: None,
+            suspended: Some(false),
+            ready,
+            message: None,
+            revision: None,
+            labels: HashMap::new(),
+            annotations: HashMap::new(),
+            last_reconciled: None,
+            reconciliation_history: vec![],
+        }
+    }
+
+    #[test]
+    fn bare_words_match_names() {
+        let query = FilterQuery::parse("pod").unwrap();
+        assert!(query.is_name_only());
+        assert!(query.matches(&resource("podinfo", "HelmRelease", Some(true))));
+        assert!(!query.matches(&resource("apps", "HelmRelease", Some(true))));
+        assert!(FilterQuery::parse("  ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn qualified_terms_combine() {
+        let now = Utc::now();
+        let mut hr = resource("payments-api", "HelmRelease", Some(false));
+        hr.labels.insert("team".to_string(), "payments".to_string());
+        hr.message = Some("install retries exhausted: context deadline exceeded".to_string());
+        hr.revision = Some("6.5.0@sha256:abc123".to_string());
+        hr.age = Some(now - chrono::Duration::hours(3));
+
+        let query = FilterQuery::parse(
+            "kind:hr ns:apps status:failed label:team=payments msg~\"Deadline Exceeded\" rev:abc123 age>2h",
+        )
+        .unwrap();
+        assert!(!query.is_name_only());
+        assert!(query.matches_at(&hr, now));
+
+        let young = FilterQuery::parse("age<1h").unwrap();
+        assert!(!young.matches_at(&hr, now));
+        let not_hr = FilterQuery::parse("!kind:HelmRelease").unwrap();
+        assert!(!not_hr.matches_at(&hr, now));
+        let ks = resource("payments-api", "Kustomization", Some(false));
+        assert!(!query.matches_at(&ks, now));
+        assert!(FilterQuery::parse("label:").unwrap().matches_at(&hr, now));
+        assert!(!FilterQuery::parse("label:").unwrap().matches_at(&ks, now));
+    }
+
+    #[test]
+    fn invalid_queries_are_reported() {
+        let error = |q: &str| FilterQuery::parse(q).unwrap_err().to_string();
+        assert!(error("foo:bar").contains("Unknown field 'foo'"));
+        assert!(error("status:broken").contains("Unknown status 'broken'"));
+        assert!(error("kind:nope").contains("Unknown kind 'nope'"));
+        assert!(error("age>2x").contains("Invalid age '2x'"));
+        assert!(error("age:2h").contains("needs a comparison"));
+        assert!(error("ns>apps").contains("only applies to age"));
+        assert!(error("msg~\"timeout").contains("Unterminated quote"));
+        assert!(error("rev:").contains("needs a value"));
+    }
+
+    #[test]
+    fn column_terms_match_list_column_values() {
+        let now = Utc::now();
+        let hr = resource("podinfo", "HelmRelease", Some(true));
+        let columns = HashMap::from([
+            ("CHART".to_string(), "podinfo".to_string()),
+            ("VALUES_FROM".to_string(), "common,db".to_string()),
+        ]);
+
+        let query = FilterQuery::parse("col:values_from=db col~Chart=POD").unwrap();
+        assert!(query.uses_columns());
+        assert!(query.matches_with_columns(&hr, &columns, now));
+        assert!(!query.matches_at(&hr, now));
+        assert!(
+            FilterQuery::parse("col:VALUES_FROM")
+                .unwrap()
+                .matches_with_columns(&hr, &columns, now)
+        );
+        assert!(
+            !FilterQuery::parse("col:VALUES_FROM=prod")
+                .unwrap()
+                .matches_with_columns(&hr, &columns, now)
+        );
+        assert!(!FilterQuery::parse("kind:hr").unwrap().uses_columns());
+        assert!(
+            FilterQuery::parse("col:")
+                .unwrap_err()
+                .to_string()
+                .contains("needs a value")
+        );
+    }
+
+    #[test]
+    fn context_terms_match_the_resource_context() {
+        let now = Utc::now();
+        let hr = resource("podinfo", "HelmRelease", Some(true));
+        let none = HashMap::new();
+
+        let query = FilterQuery::parse("ctx:prod-eu").unwrap();
+        assert!(query.matches_in_context(&hr, "prod-eu-1", &none, now));
+        assert!(!query.matches_in_context(&hr, "staging", &none, now));
+        assert!(!query.matches_at(&hr, now), "no context never matches");
+        assert!(
+            FilterQuery::parse("context~EU !ctx:staging")
+                .unwrap()
+                .matches_in_context(&hr, "prod-eu-1", &none, now)
+        );
+    }
+}
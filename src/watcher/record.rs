@@ -0,0 +1,266 @@
+//! Serialized form of [`WatchEvent`] for streaming consumers
+//!
+//! `flux9s watch -o jsonl` writes one [`WatchRecord`] per line so the watch
+//! stream can be piped into `jq` or a log shipper. Resource events carry the
+//! same extracted status fields as [`ResourceInfo`](super::ResourceInfo)
+//! rather than the full object, which keeps lines short and stable across
+//! CRD versions.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::{WatchEvent, extract_reconciliation_info, extract_status_fields, resolve_ready};
+use crate::kube::events::KubeEventInfo;
+
+/// One watch event with the time it was observed
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WatchRecord {
+    pub time: DateTime<Utc>,
+    #[serde(flatten)]
+    pub event: WatchRecordKind,
+}
+
+/// The event itself, tagged by `type`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum WatchRecordKind {
+    /// A resource was added or updated
+    Applied {
+        kind: String,
+        namespace: String,
+        name: String,
+        ready: Option<bool>,
+        suspended: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        revision: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        last_reconciled: Option<DateTime<Utc>>,
+    },
+    /// A resource was deleted
+    Deleted {
+        kind: String,
+        namespace: String,
+        name: String,
+    },
+    /// A watch stream reported an error
+    Error { message: String },
+    /// A watcher finished its initial list
+    InitialSyncDone { watcher: String },
+    /// A watcher started erroring and is retrying
+    WatcherDegraded { watcher: String },
+    /// A degraded watcher is receiving events again
+    WatcherRecovered { watcher: String },
+    /// A watcher stopped because RBAC forbids the kind
+    WatcherForbidden { watcher: String },
+    /// A Kubernetes Event was added or updated
+    KubeEvent {
+        uid: String,
+        event_type: String,
+        reason: String,
+        message: String,
+        involved_kind: String,
+        involved_namespace: String,
+        involved_name: String,
+        count: i64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        last_seen: Option<DateTime<Utc>>,
+        source: String,
+    },
+    /// A Kubernetes Event expired
+    KubeEventDeleted { uid: String },
+}
+
+impl WatchRecord {
+    /// Build the record for an event observed at `time`.
+    ///
+    /// Returns `None` for events that only feed the TUI chrome (controller
+    /// pods and deployments, CRD discovery) and for Kubernetes Events without
+    /// a UID.
+    pub fn from_event(event: &WatchEvent, time: DateTime<Utc>) -> Option<Self> {
+        let event = match event {
+            WatchEvent::Applied(resource_type, namespace, name, obj) => {
+                let (suspended, ready, message, revision) = extract_status_fields(obj);
+                WatchRecordKind::Applied {
+                    kind: resource_type.clone(),
+                    namespace: namespace.clone(),
+                    name: name.clone(),
+                    ready: resolve_ready(resource_type, ready),
+                    suspended,
+                    revision,
+                    message,
+                    last_reconciled: extract_reconciliation_info(obj).map(|e| e.timestamp),
+                }
+            }
+            WatchEvent::Deleted(resource_type, namespace, name) => WatchRecordKind::Deleted {
+                kind: resource_type.clone(),
+                namespace: namespace.clone(),
+                name: name.clone(),
+            },
+            WatchEvent::Error(message) => WatchRecordKind::Error {
+                message: message.clone(),
+            },
+            WatchEvent::InitialSyncDone(watcher) => WatchRecordKind::InitialSyncDone {
+                watcher: watcher.clone(),
+            },
+            WatchEvent::WatcherDegraded(watcher) => WatchRecordKind::WatcherDegraded {
+                watcher: watcher.clone(),
+            },
+            WatchEvent::WatcherRecovered(watcher) => WatchRecordKind::WatcherRecovered {
+                watcher: watcher.clone(),
+            },
+            WatchEvent::WatcherForbidden(watcher) => WatchRecordKind::WatcherForbidden {
+                watcher: watcher.clone(),
+            },
+            WatchEvent::KubeEventApplied(event_json) => {
+                let info = KubeEventInfo::from_json(event_json)?;
+                WatchRecordKind::KubeEvent {
+                    uid: info.uid,
+                    event_type: info.event_type,
+                    reason: info.reason,
+                    message: info.message,
+                    involved_kind: info.involved_kind,
+                    involved_namespace: info.involved_namespace,
+                    involved_name: info.involved_name,
+                    count: info.count,
+                    last_seen: info.last_seen,
+                    source: info.source,
+                }
+            }
+            WatchEvent::KubeEventDeleted(uid) => {
+                WatchRecordKind::KubeEventDeleted { uid: uid.clone() }
+            }
+            WatchEvent::PodApplied(..)
+            | WatchEvent::PodDeleted(_)
+            | WatchEvent::DeploymentApplied(_)
+            | WatchEvent::ExtraKindDiscovered(_)
+            | WatchEvent::ExtraKindRemoved(_) => return None,
+        };
+        Some(Self { time, event })
+    }
+
+    /// The record as a single JSON line (no trailing newline).
+    pub fn to_json_line(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Kind of the resource the record is about: the resource kind for
+    /// applied/deleted, the involved object's kind for Kubernetes Events.
+    pub fn subject_kind(&self) -> Option<&str> {
+        match &self.event {
+            WatchRecordKind::Applied { kind, .. } | WatchRecordKind::Deleted { kind, .. } => {
+                Some(kind)
+            }
+            WatchRecordKind::KubeEvent { involved_kind, .. } => Some(involved_kind),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    fn at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 16, 9, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn applied_record_carries_status_fields() {
+        let obj = json!({
+            "kind": "Kustomization",
+            "spec": {"suspend": false},
+            "status": {
+                "lastAppliedRevision": "main@sha1:abc123",
+                "conditions": [{"type": "Ready", "status": "False", "message": "build failed"}]
+            }
+        });
+        let event = WatchEvent::Applied(
+            "Kustomization".to_string(),
+            "flux-system".to_string(),
+            "apps".to_string(),
+            obj,
+        );
+        let line = WatchRecord::from_event(&event, at())
+            .unwrap()
+            .to_json_line()
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "time": "2026-10-16T09:30:00Z",
+                "type": "applied",
+                "kind": "Kustomization",
+                "namespace": "flux-system",
+                "name": "apps",
+                "ready": false,
+                "suspended": false,
+                "revision": "main@sha1:abc123",
+                "message": "build failed"
+            })
+        );
+    }
+
+    #[test]
+    fn stateless_kinds_are_ready() {
+        let event = WatchEvent::Applied(
+            "Alert".to_string(),
+            "flux-system".to_string(),
+            "slack".to_string(),
+            json!({"kind": "Alert", "spec": {}}),
+        );
+        let record = WatchRecord::from_event(&event, at()).unwrap();
+        assert!(matches!(
+            record.event,
+            WatchRecordKind::Applied {
+                ready: Some(true),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn notices_and_kube_events_serialize_with_type_tags() {
+        let degraded = WatchRecord::from_event(
+            &WatchEvent::WatcherDegraded("HelmRelease".to_string()),
+            at(),
+        )
+        .unwrap();
+        assert_eq!(
+            degraded.to_json_line().unwrap(),
+            r#"{"time":"2026-10-16T09:30:00Z","type":"watcherDegraded","watcher":"HelmRelease"}"#
+        );
+
+        let event = WatchEvent::KubeEventApplied(json!({
+            "metadata": {"uid": "e-1", "namespace": "apps"},
+            "type": "Warning",
+            "reason": "InstallFailed",
+            "message": "timed out",
+            "involvedObject": {"kind": "HelmRelease", "name": "podinfo"},
+            "count": 2
+        }));
+        let record = WatchRecord::from_event(&event, at()).unwrap();
+        assert_eq!(record.subject_kind(), Some("HelmRelease"));
+        let value: serde_json::Value =
+            serde_json::from_str(&record.to_json_line().unwrap()).unwrap();
+        assert_eq!(value["type"], "kubeEvent");
+        assert_eq!(value["eventType"], "Warning");
+        assert_eq!(value["involvedNamespace"], "apps");
+        assert_eq!(value["count"], 2);
+    }
+
+    #[test]
+    fn tui_only_events_have_no_record() {
+        let event = WatchEvent::DeploymentApplied(json!({}));
+        assert!(WatchRecord::from_event(&event, at()).is_none());
+        assert!(WatchRecord::from_event(&WatchEvent::PodDeleted("p".into()), at()).is_none());
+    }
+}
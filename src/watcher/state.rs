@@ -2,6 +2,7 @@
 //!
 //! Tracks the current state of watched resources for display in the TUI.
 
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
 use std::sync::{Arc, RwLock};
@@ -72,14 +73,14 @@ impl fmt::Display for ResourceKey {
 }
 
 /// Reconciliation event tracking
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ReconciliationEvent {
     pub timestamp: chrono::DateTime<chrono::Utc>,
-    #[allow(dead_code)] // Reserved for future use
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub revision: Option<String>,
-    #[allow(dead_code)] // Reserved for future use
     pub status: String, // "Success", "Failed", "Unknown"
-    #[allow(dead_code)] // Reserved for future use
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
@@ -1,9 +1,10 @@
 //! Graph functionality tests
 //!
-//! Tests for resource graph building, layout calculation, and node rendering
+//! Tests for resource graph building, layout calculation, node rendering, and export
 
 use flux9s::trace::{
-    GraphEdge, GraphNode, NodeType, RelationshipType, ResourceGraph, is_resource_type_with_graph,
+    GraphEdge, GraphFormat, GraphNode, NodeType, RelationshipType, ResourceGraph, export_file_name,
+    export_graph, is_resource_type_with_graph, to_dot, to_mermaid, write_new_export,
 };
 
 #[test]
@@ -378,3 +379,140 @@ fn test_is_resource_type_with_graph() {
     assert!(!is_resource_type_with_graph("ConfigMap"));
     assert!(!is_resource_type_with_graph(""));
 }
+
+/// A Kustomization sourced from a GitRepository that owns a workload group
+/// and a resource group — the shape the graph builder produces.
+fn export_sample_graph() -> ResourceGraph {
+    let node =
+        |id: &str, kind: &str, name: &str, node_type, ready, description: Option<&str>| GraphNode {
+            id: id.to_string(),
+            kind: kind.to_string(),
+            name: name.to_string(),
+            namespace: "flux-system".to_string(),
+            node_type,
+            ready,
+            position: None,
+            description: description.map(str::to_string),
+        };
+
+    let mut graph = ResourceGraph::new();
+    graph.add_node(node(
+        "Kustomization:flux-system:apps",
+        "Kustomization",
+        "apps",
+        NodeType::Object,
+        Some(false),
+        Some("./apps"),
+    ));
+    graph.add_node(node(
+        "GitRepository:flux-system:flux-system",
+        "GitRepository",
+        "flux-system",
+        NodeType::Source,
+        Some(true),
+        Some("https://github.com/org/\"fleet\""),
+    ));
+    graph.add_node(node(
+        "workloadgroup:flux-system",
+        "Workloads",
+        "Workloads (1)",
+        NodeType::WorkloadGroup,
+        None,
+        Some("Deployment|podinfo|apps|●|Replicas: 1/1"),
+    ));
+    graph.add_node(node(
+        "resourcegroup:flux-system",
+        "Resources",
+        "Resources (2)",
+        NodeType::ResourceGroup,
+        None,
+        Some("ConfigMap|apps|settings|v1\nService|apps|podinfo|v1"),
+    ));
+    graph.add_edge(GraphEdge {
+        from: "GitRepository:flux-system:flux-system".to_string(),
+        to: "Kustomization:flux-system:apps".to_string(),
+        relationship: RelationshipType::SourcedFrom,
+    });
+    for group in ["workloadgroup:flux-system", "resourcegroup:flux-system"] {
+        graph.add_edge(GraphEdge {
+            from: "Kustomization:flux-system:apps".to_string(),
+            to: group.to_string(),
+            relationship: RelationshipType::Owns,
+        });
+    }
+    graph
+}
+
+#[test]
+fn test_export_dot() {
+    let dot = to_dot(&export_sample_graph());
+
+    assert!(dot.starts_with("digraph flux {"));
+    assert!(dot.trim_end().ends_with('}'));
+    assert!(dot.contains(
+        "\"GitRepository:flux-system:flux-system\" -> \"Kustomization:flux-system:apps\" [label=\"sources\"];"
+    ));
+    // Quotes inside labels are escaped, and groups list their members
+    assert!(dot.contains("https://github.com/org/\\\"fleet\\\""));
+    assert!(dot.contains("Workloads (1)\\nDeployment/podinfo"));
+    assert!(dot.contains("ConfigMap/settings\\nService/podinfo"));
+    assert!(dot.contains("fillcolor=\"#f8d7da\""));
+}
+
+#[test]
+fn test_export_mermaid() {
+    let mermaid = to_mermaid(&export_sample_graph());
+
+    assert!(mermaid.starts_with("flowchart TD\n"));
+    assert!(mermaid.contains("  n0[\"Kustomization<br/>apps<br/>./apps\"]"));
+    assert!(mermaid.contains("  n1 -->|sources| n0"));
+    assert!(mermaid.contains("  n0 -->|owns| n2"));
+    assert!(mermaid.contains("#quot;fleet#quot;"));
+    assert!(mermaid.contains("class n1 ready"));
+    assert!(mermaid.contains("class n0 notReady"));
+}
+
+#[test]
+fn test_export_json() {
+    let json = export_graph(&export_sample_graph(), GraphFormat::Json).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(value["nodes"].as_array().unwrap().len(), 4);
+    assert_eq!(value["nodes"][0]["type"], "object");
+    assert_eq!(value["nodes"][0]["ready"], false);
+    // Group nodes expose decoded members instead of the encoded description
+    assert!(value["nodes"][3].get("description").is_none());
+    assert_eq!(value["nodes"][3]["members"][1]["kind"], "Service");
+    assert_eq!(value["nodes"][3]["members"][1]["namespace"], "apps");
+    assert_eq!(value["edges"][0]["relationship"], "sourcedFrom");
+}
+
+#[test]
+fn test_export_format_parsing_and_file_name() {
+    assert_eq!(
+        "Mermaid".parse::<GraphFormat>().unwrap(),
+        GraphFormat::Mermaid
+    );
+    assert!("png".parse::<GraphFormat>().is_err());
+
+    let graph = export_sample_graph();
+    assert_eq!(
+        export_file_name(&graph, GraphFormat::Mermaid),
+        "kustomization-flux-system-apps.mmd"
+    );
+    assert_eq!(
+        export_file_name(&ResourceGraph::new(), GraphFormat::Dot),
+        "graph.dot"
+    );
+}
+
+#[test]
+fn test_write_new_export_never_overwrites() {
+    let dir = tempfile::tempdir().unwrap();
+    let first = write_new_export(dir.path(), "graph.dot", "one").unwrap();
+    let second = write_new_export(dir.path(), "graph.dot", "two").unwrap();
+    assert_eq!(first, dir.path().join("graph.dot"));
+    assert_eq!(second, dir.path().join("graph-1.dot"));
+    assert_eq!(std::fs::read_to_string(first).unwrap(), "one");
+    assert_eq!(std::fs::read_to_string(second).unwrap(), "two");
+}
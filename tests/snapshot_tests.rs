@@ -6,8 +6,9 @@
 use flux9s::config::{Config, UiConfig};
 use flux9s::tui::Theme;
 use flux9s::tui::app::state::{ControllerPodState, SortField, TextSearchState};
+use flux9s::tui::keybindings::Keymap;
 use flux9s::tui::views::{
-    render_footer, render_header, render_resource_describe, render_resource_detail,
+    RowMarks, render_footer, render_header, render_resource_describe, render_resource_detail,
     render_resource_list, render_resource_yaml,
 };
 use flux9s::watcher::{ResourceInfo, ResourceState};
@@ -43,6 +44,14 @@ fn create_test_config() -> Config {
         default_resource_filter: None,
         connect_timeout_seconds: flux9s::kube::health::DEFAULT_CONNECT_TIMEOUT_SECS,
         editor: None,
+        hooks: vec![],
+        history_retention_days: 0,
+        views: HashMap::new(),
+        columns: HashMap::new(),
+        keymap: HashMap::new(),
+        plugins: HashMap::new(),
+        aliases: HashMap::new(),
+        clusters: Vec::new(),
     }
 }
 
@@ -238,6 +247,8 @@ fn test_render_footer_navigation() {
                 &state,
                 &theme,
                 false,
+                &Keymap::default(),
+                &HashMap::new(),
             );
         })
         .unwrap();
@@ -273,6 +284,8 @@ fn test_render_footer_command_mode() {
                 &state,
                 &theme,
                 false,
+                &Keymap::default(),
+                &HashMap::new(),
             );
         })
         .unwrap();
@@ -308,6 +321,8 @@ fn test_render_footer_filter_mode() {
                 &state,
                 &theme,
                 false,
+                &Keymap::default(),
+                &HashMap::new(),
             );
         })
         .unwrap();
@@ -345,10 +360,14 @@ fn test_render_resource_list() {
                 &mut scroll_offset,
                 &None,
                 &resource_objects,
+                &config.columns,
                 &theme,
                 config.ui.no_icons,
-                &HashSet::new(),
-                SortField::Default,
+                RowMarks {
+                    favorites: &HashSet::new(),
+                    marked: &HashSet::new(),
+                },
+                &SortField::Default,
                 false,
                 None,
             );
@@ -388,10 +407,14 @@ fn test_render_resource_list_with_selection() {
                 &mut scroll_offset,
                 &None,
                 &resource_objects,
+                &config.columns,
                 &theme,
                 config.ui.no_icons,
-                &HashSet::new(),
-                SortField::Default,
+                RowMarks {
+                    favorites: &HashSet::new(),
+                    marked: &HashSet::new(),
+                },
+                &SortField::Default,
                 false,
                 None,
             );
@@ -428,10 +451,14 @@ fn test_render_resource_list_with_resource_type_filter() {
                 &mut scroll_offset,
                 &Some("Kustomization".to_string()),
                 &resource_objects,
+                &config.columns,
                 &theme,
                 config.ui.no_icons,
-                &HashSet::new(),
-                SortField::Default,
+                RowMarks {
+                    favorites: &HashSet::new(),
+                    marked: &HashSet::new(),
+                },
+                &SortField::Default,
                 false,
                 None,
             );
@@ -461,10 +488,14 @@ fn test_render_resource_list_empty() {
                 &mut scroll_offset,
                 &None,
                 &resource_objects,
+                &config.columns,
                 &theme,
                 config.ui.no_icons,
-                &HashSet::new(),
-                SortField::Default,
+                RowMarks {
+                    favorites: &HashSet::new(),
+                    marked: &HashSet::new(),
+                },
+                &SortField::Default,
                 false,
                 None,
             );
@@ -765,6 +796,8 @@ fn test_render_footer_connection_error() {
                 &state,
                 &theme,
                 true, // has_connection_error = true
+                &Keymap::default(),
+                &HashMap::new(),
             );
         })
         .unwrap();
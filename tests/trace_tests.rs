@@ -417,3 +417,95 @@ fn test_trace_kustomization_direct_structure() {
     assert_eq!(result.source.as_ref().unwrap().kind, "GitRepository");
     assert_eq!(result.source.as_ref().unwrap().name, "flux-system");
 }
+
+fn sample_helmrelease_trace() -> TraceResult {
+    use flux9s::trace::{SourceRef, TraceNode, TraceSpec, TraceStatus};
+
+    TraceResult {
+        object: TraceNode {
+            kind: "Deployment".to_string(),
+            name: "podinfo".to_string(),
+            namespace: "default".to_string(),
+            status: None,
+            spec: None,
+        },
+        chain: vec![TraceNode {
+            kind: "HelmRelease".to_string(),
+            name: "podinfo".to_string(),
+            namespace: "default".to_string(),
+            status: Some(TraceStatus {
+                ready: Some(false),
+                message: Some("install retries exhausted".to_string()),
+                last_reconciled: Some("2024-01-01T00:00:00Z".to_string()),
+                revision: Some("6.3.5".to_string()),
+            }),
+            spec: Some(TraceSpec {
+                path: None,
+                url: None,
+                branch: None,
+                source_ref: Some(SourceRef {
+                    kind: "HelmRepository".to_string(),
+                    name: "podinfo".to_string(),
+                    namespace: Some("flux-system".to_string()),
+                }),
+            }),
+        }],
+        source: Some(TraceNode {
+            kind: "HelmRepository".to_string(),
+            name: "podinfo".to_string(),
+            namespace: "flux-system".to_string(),
+            status: Some(TraceStatus {
+                ready: Some(true),
+                message: None,
+                last_reconciled: None,
+                revision: Some("sha256:abc".to_string()),
+            }),
+            spec: Some(TraceSpec {
+                path: None,
+                url: Some("https://stefanprodan.github.io/podinfo".to_string()),
+                branch: None,
+                source_ref: None,
+            }),
+        }),
+    }
+}
+
+/// `flux9s trace -o json` serializes the same structure the TUI renders
+#[test]
+fn test_trace_result_serializes_to_json() {
+    let result = sample_helmrelease_trace();
+    let json = serde_json::to_value(&result).unwrap();
+
+    assert_eq!(json["object"]["kind"], "Deployment");
+    assert_eq!(json["chain"][0]["status"]["ready"], false);
+    assert_eq!(
+        json["chain"][0]["status"]["lastReconciled"],
+        "2024-01-01T00:00:00Z"
+    );
+    assert_eq!(
+        json["chain"][0]["spec"]["sourceRef"]["namespace"],
+        "flux-system"
+    );
+    assert_eq!(json["source"]["status"]["revision"], "sha256:abc");
+
+    let round_trip: TraceResult = serde_json::from_value(json).unwrap();
+    verify_trace_structure(&round_trip);
+}
+
+/// The text renderer prints the object, each managing resource, and the source
+#[test]
+fn test_format_trace_result_text() {
+    let text = flux9s::trace::format_trace_result(&sample_helmrelease_trace());
+
+    assert!(text.contains("Object:          Deployment/podinfo"));
+    assert!(text.contains("managed by"));
+    assert!(text.contains("HelmRelease:   podinfo"));
+    assert!(text.contains("Ready:           False"));
+    assert!(text.contains("sourced from"));
+    assert!(text.contains("URL:             https://stefanprodan.github.io/podinfo"));
+    assert!(text.contains("Revision:        sha256:abc"));
+    assert!(
+        text.find("managed by").unwrap() < text.find("sourced from").unwrap(),
+        "chain is printed before the source"
+    );
+}